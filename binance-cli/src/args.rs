@@ -0,0 +1,191 @@
+use std::path::PathBuf;
+
+use binance_rs::common::{futures, spot};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+
+use crate::stream::Topic;
+
+/// Everyday Binance operations from the terminal. Responses are printed to
+/// stdout as JSON, errors to stderr.
+#[derive(Debug, Parser)]
+#[command(name = "binance", version, about)]
+pub struct Cli {
+    /// Market to run against, futures by default for `income` and `stream`
+    /// and spot otherwise.
+    #[arg(short, long, global = true, value_enum)]
+    pub market: Option<Market>,
+
+    /// Uses the testnet hosts and the `testnet` profile of the config file.
+    #[arg(long, global = true)]
+    pub testnet: bool,
+
+    /// Config file, `$XDG_CONFIG_HOME/binance/config.toml` by default.
+    #[arg(long, global = true, env = "BINANCE_CONFIG")]
+    pub config: Option<PathBuf>,
+
+    /// Config profile, `testnet` with `--testnet` and `default` otherwise.
+    #[arg(long, global = true)]
+    pub profile: Option<String>,
+
+    /// REST host replacing the one of the market, e.g. a proxy.
+    #[arg(long, global = true)]
+    pub host: Option<String>,
+
+    /// Combined streams host replacing the ones of the market.
+    #[arg(long, global = true)]
+    pub stream_host: Option<String>,
+
+    /// Pretty prints the responses.
+    #[arg(long, global = true)]
+    pub pretty: bool,
+
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+impl Cli {
+    pub fn market(&self) -> Market {
+        match (self.market, &self.command) {
+            (Some(market), _) => market,
+            (None, Command::Income(_) | Command::Stream(_)) => Market::Futures,
+            (None, _) => Market::Spot,
+        }
+    }
+
+    pub fn profile(&self) -> &str {
+        match (&self.profile, self.testnet) {
+            (Some(profile), _) => profile,
+            (None, true) => "testnet",
+            (None, false) => "default",
+        }
+    }
+
+    pub fn rest_host(&self) -> String {
+        if let Some(host) = &self.host {
+            return host.clone();
+        }
+
+        match (self.market(), self.testnet) {
+            (Market::Spot, false) => spot::endpoint::host::Host::Api.as_ref().to_string(),
+            (Market::Spot, true) => spot::endpoint::host::Host::Test.as_ref().to_string(),
+            (Market::Futures, false) => futures::endpoint::host::Host::Api.as_ref().to_string(),
+            (Market::Futures, true) => futures::endpoint::host::Host::Test.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Market {
+    Spot,
+    Futures,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Tests connectivity to the REST API.
+    Ping,
+    /// Server time.
+    Time,
+    /// Trading rules and symbol information, of every symbol by default.
+    ExchangeInfo { symbols: Vec<String> },
+    /// 24h statistics, or the last price only with `--price`.
+    Ticker {
+        symbol: Option<String>,
+        #[arg(long)]
+        price: bool,
+    },
+    /// Order book of a symbol.
+    Depth {
+        symbol: String,
+        #[arg(long, default_value_t = 20)]
+        limit: u16,
+    },
+    /// Places and cancels orders.
+    #[command(subcommand)]
+    Order(OrderCommand),
+    /// Open orders of a symbol, or of every symbol.
+    OpenOrders { symbol: Option<String> },
+    /// Non-zero spot balances or futures wallet balances.
+    Balances,
+    /// Futures income history.
+    Income(IncomeArgs),
+    /// Streams futures market topics to stdout, one JSON frame per line.
+    Stream(StreamArgs),
+}
+
+#[derive(Debug, Subcommand)]
+pub enum OrderCommand {
+    /// Validates an order on the test endpoint, or places it with `--live`.
+    New(NewOrderArgs),
+    /// Cancels an order by id or client order id.
+    Cancel {
+        symbol: String,
+        #[arg(long, required_unless_present = "client_order_id")]
+        order_id: Option<u64>,
+        #[arg(long, conflicts_with = "order_id")]
+        client_order_id: Option<String>,
+    },
+    /// Cancels every open order of a symbol.
+    CancelAll { symbol: String },
+}
+
+#[derive(Debug, Args)]
+pub struct NewOrderArgs {
+    pub symbol: String,
+    /// BUY or SELL.
+    pub side: String,
+    /// Order type of the market, e.g. LIMIT, MARKET or STOP_MARKET.
+    pub r#type: String,
+    #[arg(long)]
+    pub quantity: Option<f64>,
+    /// Quote amount to spend or receive, spot market orders only.
+    #[arg(long)]
+    pub quote_quantity: Option<f64>,
+    #[arg(long)]
+    pub price: Option<f64>,
+    #[arg(long)]
+    pub stop_price: Option<f64>,
+    /// GTC, IOC or FOK. LIMIT orders default to GTC.
+    #[arg(long)]
+    pub time_in_force: Option<String>,
+    #[arg(long)]
+    pub client_order_id: Option<String>,
+    /// BOTH, LONG or SHORT, futures only.
+    #[arg(long)]
+    pub position_side: Option<String>,
+    /// Futures only.
+    #[arg(long)]
+    pub reduce_only: bool,
+    /// Places the order for real instead of only validating it.
+    #[arg(long)]
+    pub live: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct IncomeArgs {
+    pub symbol: Option<String>,
+    /// Income type, e.g. REALIZED_PNL or FUNDING_FEE.
+    #[arg(long = "type")]
+    pub income_type: Option<String>,
+    /// Start time in ms since the epoch.
+    #[arg(long)]
+    pub start: Option<u64>,
+    /// End time in ms since the epoch.
+    #[arg(long)]
+    pub end: Option<u64>,
+    #[arg(long)]
+    pub limit: Option<u16>,
+}
+
+#[derive(Debug, Args)]
+pub struct StreamArgs {
+    /// Topics written `name[:argument...]`, e.g. `agg-trade:BTCUSDT`,
+    /// `kline:ETHUSDT:1m` or `depth:BTCUSDT:10:100ms`. Names: agg-trade,
+    /// mark-price, mark-price-all, kline, mini-ticker, mini-ticker-all, ticker,
+    /// ticker-all, book-ticker-all, liquidation, liquidation-all and depth.
+    #[arg(required = true)]
+    pub topics: Vec<Topic>,
+    /// Stops after this many frames instead of running until Ctrl-C.
+    #[arg(long)]
+    pub count: Option<usize>,
+}
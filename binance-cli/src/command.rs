@@ -0,0 +1,391 @@
+use binance_rs::{
+    common::{
+        futures::{
+            endpoint::route as futures_route,
+            model::params::{
+                account::{FuturesBalanceParams, IncomeHistoryParams},
+                market::Symbol,
+                trade as futures_trade,
+            },
+        },
+        spot::{
+            endpoint::route as spot_route,
+            model::params::{
+                EmptyParams,
+                account::InfoParams,
+                general::ExchangeInformationParams,
+                market::{DepthParams, PriceTickerParams, Ticker24hParams},
+                trade as spot_trade,
+            },
+        },
+    },
+    core::{client::asynchronous::Client, signer::signature::Signature},
+};
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::{
+    args::{Cli, Command, Market, NewOrderArgs, OrderCommand},
+    error::CliError,
+};
+
+const RECV_WINDOW: u16 = 5000;
+
+/// Runs a REST command of `cli` with `client`. `signed` tells whether the
+/// client holds real credentials, signed commands fail early without them.
+pub(crate) async fn execute<'a, S>(
+    cli: &Cli,
+    client: &Client<'a, S>,
+    signed: bool,
+) -> Result<Value, CliError>
+where
+    S: Signature<'a>,
+{
+    let requires_signature = matches!(
+        cli.command,
+        Command::Order(_) | Command::OpenOrders { .. } | Command::Balances | Command::Income(_)
+    );
+
+    if requires_signature && !signed {
+        return Err(CliError::Credentials(format!(
+            "No API key found, set {} and {} or add the `{}` profile to the config file.",
+            crate::config::API_KEY,
+            crate::config::SECRET_KEY,
+            cli.profile()
+        )));
+    }
+
+    match cli.market() {
+        Market::Spot => spot(cli, client).await,
+        Market::Futures => futures(cli, client).await,
+    }
+}
+
+async fn spot<'a, S>(cli: &Cli, client: &Client<'a, S>) -> Result<Value, CliError>
+where
+    S: Signature<'a>,
+{
+    use spot_route::{Account, General, Market, Trade};
+
+    let value = match &cli.command {
+        Command::Ping => client.get(General::Ping, EmptyParams).await?,
+        Command::Time => client.get(General::ServerTime, EmptyParams).await?,
+        Command::ExchangeInfo { symbols } if symbols.is_empty() => {
+            client.get(General::ExchangeInfo, EmptyParams).await?
+        }
+        Command::ExchangeInfo { symbols } => {
+            let params = ExchangeInformationParams::new().symbols(symbols);
+            client.get(General::ExchangeInfo, params).await?
+        }
+        Command::Ticker { symbol, price } => match (symbol.as_deref(), price) {
+            (Some(symbol), true) => {
+                let params = PriceTickerParams::new().symbol(symbol);
+                client.get(Market::TickerPrice, params).await?
+            }
+            (None, true) => client.get(Market::TickerPrice, EmptyParams).await?,
+            (Some(symbol), false) => {
+                let params = Ticker24hParams::new().symbol(symbol);
+                client.get(Market::Ticker24h, params).await?
+            }
+            (None, false) => client.get(Market::Ticker24h, EmptyParams).await?,
+        },
+        Command::Depth { symbol, limit } => {
+            let params = DepthParams::new(symbol).limit(*limit);
+            client.get(Market::Depth, params).await?
+        }
+        Command::Order(OrderCommand::New(args)) => {
+            let route = match args.live {
+                true => Trade::NewOrder,
+                false => Trade::TestOrder,
+            };
+
+            let params = spot_order(args)?;
+            client.send(route, &params, Method::POST).await?
+        }
+        Command::Order(OrderCommand::Cancel {
+            symbol,
+            order_id,
+            client_order_id,
+        }) => {
+            let mut params = spot_trade::CancelOrderParams::new(symbol);
+
+            if let Some(order_id) = order_id {
+                params = params.order_id(*order_id);
+            }
+
+            if let Some(client_order_id) = client_order_id {
+                params = params.orig_client_order_id(client_order_id);
+            }
+
+            client
+                .send(Trade::CancelOrder, params, Method::DELETE)
+                .await?
+        }
+        Command::Order(OrderCommand::CancelAll { symbol }) => {
+            let params = spot_trade::CancelAllOrdersParms::new(symbol);
+            client
+                .send(Trade::CancelAllOrders, params, Method::DELETE)
+                .await?
+        }
+        Command::OpenOrders {
+            symbol: Some(symbol),
+        } => {
+            let params = spot_trade::OpenOrdersParams::new(symbol);
+            client.send(Trade::OpenOrders, params, Method::GET).await?
+        }
+        Command::OpenOrders { symbol: None } => {
+            client
+                .send(Trade::OpenOrders, EmptyParams, Method::GET)
+                .await?
+        }
+        Command::Balances => {
+            let params = InfoParams::new().omit_zero_balances(true);
+            let mut info: Value = client.send(Account::Info, params, Method::GET).await?;
+
+            info["balances"].take()
+        }
+        Command::Income(_) => {
+            return Err(CliError::Argument(
+                "Income history is only available on futures.".to_string(),
+            ));
+        }
+        Command::Stream(_) => unreachable!("streams are not REST commands"),
+    };
+
+    Ok(value)
+}
+
+async fn futures<'a, S>(cli: &Cli, client: &Client<'a, S>) -> Result<Value, CliError>
+where
+    S: Signature<'a>,
+{
+    use futures_route::{Account, Market, Trade};
+
+    let value = match &cli.command {
+        Command::Ping => client.get(Market::Ping, EmptyParams).await?,
+        Command::Time => client.get(Market::ServerTime, EmptyParams).await?,
+        Command::ExchangeInfo { symbols } => {
+            let mut info: Value = client.get(Market::ExchangeInfo, EmptyParams).await?;
+
+            // The futures endpoint has no symbol filter.
+            if let (false, Some(listed)) = (symbols.is_empty(), info["symbols"].as_array_mut()) {
+                listed.retain(|listed| {
+                    symbols
+                        .iter()
+                        .any(|symbol| listed["symbol"].as_str() == Some(symbol.as_str()))
+                });
+            }
+
+            info
+        }
+        Command::Ticker { symbol, price } => {
+            let route = match price {
+                true => Market::TickerPrice,
+                false => Market::Ticker24h,
+            };
+
+            match symbol {
+                Some(symbol) => client.get(route, Symbol::new(symbol)).await?,
+                None => client.get(route, EmptyParams).await?,
+            }
+        }
+        Command::Depth { symbol, limit } => {
+            let params = DepthParams::new(symbol).limit(*limit);
+            client.get(Market::Depth, params).await?
+        }
+        Command::Order(OrderCommand::New(args)) => {
+            let route = match args.live {
+                true => Trade::NewOrder,
+                false => Trade::TestNewOrder,
+            };
+
+            let params = futures_order(args)?;
+            client.send(route, &params, Method::POST).await?
+        }
+        Command::Order(OrderCommand::Cancel {
+            symbol,
+            order_id,
+            client_order_id,
+        }) => {
+            let mut params = futures_trade::CancelOrderParams::new(symbol);
+
+            if let Some(order_id) = order_id {
+                params = params.order_id(*order_id);
+            }
+
+            if let Some(client_order_id) = client_order_id {
+                params = params.orig_client_order_id(client_order_id);
+            }
+
+            client
+                .send(Trade::CancelOrder, params, Method::DELETE)
+                .await?
+        }
+        Command::Order(OrderCommand::CancelAll { symbol }) => {
+            let params = futures_trade::CancelAllOrdersParams::new(symbol);
+            client
+                .send(Trade::CancelAllOrders, params, Method::DELETE)
+                .await?
+        }
+        Command::OpenOrders {
+            symbol: Some(symbol),
+        } => {
+            let params = Symbol::new(symbol);
+            client.send(Trade::OpenOrders, params, Method::GET).await?
+        }
+        Command::OpenOrders { symbol: None } => {
+            client
+                .send(Trade::OpenOrders, EmptyParams, Method::GET)
+                .await?
+        }
+        Command::Balances => {
+            let params = FuturesBalanceParams::new(RECV_WINDOW);
+            let mut balances: Value = client
+                .send(Account::FuturesBalanceV3, params, Method::GET)
+                .await?;
+
+            if let Some(balances) = balances.as_array_mut() {
+                balances.retain(|balance| !is_zero(&balance["balance"]));
+            }
+
+            balances
+        }
+        Command::Income(args) => {
+            let mut params = IncomeHistoryParams::new();
+
+            if let Some(symbol) = &args.symbol {
+                params = params.symbol(symbol);
+            }
+
+            if let Some(income_type) = &args.income_type {
+                params = params.income_type(parse("income type", income_type)?);
+            }
+
+            if let Some(start) = args.start {
+                params = params.start_time(start);
+            }
+
+            if let Some(end) = args.end {
+                params = params.end_time(end);
+            }
+
+            if let Some(limit) = args.limit {
+                params = params.limit(limit);
+            }
+
+            client
+                .send(Account::IncomeHistory, params, Method::GET)
+                .await?
+        }
+        Command::Stream(_) => unreachable!("streams are not REST commands"),
+    };
+
+    Ok(value)
+}
+
+fn spot_order(args: &NewOrderArgs) -> Result<spot_trade::NewOrderParams<'_>, CliError> {
+    if args.position_side.is_some() || args.reduce_only {
+        return Err(CliError::Argument(
+            "--position-side and --reduce-only are futures only.".to_string(),
+        ));
+    }
+
+    let mut params = spot_trade::NewOrderParams::new(
+        &args.symbol,
+        parse("side", &args.side)?,
+        parse("order type", &args.r#type)?,
+    );
+
+    if let Some(time_in_force) = time_in_force(args) {
+        params = params.time_in_force(parse("time in force", time_in_force)?);
+    }
+
+    if let Some(quantity) = args.quantity {
+        params = params.quantity(quantity);
+    }
+
+    if let Some(quote_quantity) = args.quote_quantity {
+        params = params.quote_order_qty(quote_quantity);
+    }
+
+    if let Some(price) = args.price {
+        params = params.price(price);
+    }
+
+    if let Some(stop_price) = args.stop_price {
+        params = params.stop_price(stop_price);
+    }
+
+    if let Some(client_order_id) = &args.client_order_id {
+        params = params.new_client_order_id(client_order_id);
+    }
+
+    Ok(params)
+}
+
+fn futures_order(args: &NewOrderArgs) -> Result<futures_trade::NewOrderParams<'_>, CliError> {
+    if args.quote_quantity.is_some() {
+        return Err(CliError::Argument(
+            "--quote-quantity is spot only.".to_string(),
+        ));
+    }
+
+    let mut params = futures_trade::NewOrderParams::new(
+        &args.symbol,
+        parse("side", &args.side)?,
+        parse("order type", &args.r#type)?,
+    );
+
+    if let Some(time_in_force) = time_in_force(args) {
+        params = params.time_in_force(parse("time in force", time_in_force)?);
+    }
+
+    if let Some(position_side) = &args.position_side {
+        params = params.position_side(parse("position side", position_side)?);
+    }
+
+    if let Some(quantity) = args.quantity {
+        params = params.quantity(quantity);
+    }
+
+    if let Some(price) = args.price {
+        params = params.price(price);
+    }
+
+    if let Some(stop_price) = args.stop_price {
+        params = params.stop_price(stop_price);
+    }
+
+    if let Some(client_order_id) = &args.client_order_id {
+        params = params.new_client_order_id(client_order_id);
+    }
+
+    if args.reduce_only {
+        params = params.reduce_only(true);
+    }
+
+    Ok(params)
+}
+
+/// LIMIT orders are rejected without a time in force, GTC is the usual one.
+fn time_in_force(args: &NewOrderArgs) -> Option<&str> {
+    match &args.time_in_force {
+        Some(time_in_force) => Some(time_in_force),
+        None if args.r#type.eq_ignore_ascii_case("limit") => Some("GTC"),
+        None => None,
+    }
+}
+
+/// Parses an enum of the API from its wire name, case-insensitively.
+fn parse<T: DeserializeOwned>(name: &str, value: &str) -> Result<T, CliError> {
+    serde_json::from_value(Value::String(value.to_ascii_uppercase()))
+        .map_err(|_| CliError::Argument(format!("Invalid {}: {}", name, value)))
+}
+
+fn is_zero(amount: &Value) -> bool {
+    amount
+        .as_str()
+        .and_then(|amount| amount.parse::<f64>().ok())
+        .is_some_and(|amount| amount == 0.0)
+}
@@ -0,0 +1,117 @@
+use std::{
+    collections::HashMap,
+    env,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+
+use crate::error::CliError;
+
+pub const API_KEY: &str = "BINANCE_API_KEY";
+pub const SECRET_KEY: &str = "BINANCE_SECRET_KEY";
+pub const PRIVATE_KEY: &str = "BINANCE_PRIVATE_KEY";
+
+/// Keys of one account. A `private_key` PEM file selects Ed25519 signing,
+/// otherwise requests are signed with HMAC-SHA256 and `secret_key`.
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct Profile {
+    pub api_key: Option<String>,
+    pub secret_key: Option<String>,
+    pub private_key: Option<PathBuf>,
+}
+
+/// Profiles by name, one TOML table each:
+///
+/// ```toml
+/// [default]
+/// api_key = "..."
+/// secret_key = "..."
+///
+/// [testnet]
+/// api_key = "..."
+/// private_key = "/path/to/ed25519.pem"
+/// ```
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct Config {
+    #[serde(flatten)]
+    pub profiles: HashMap<String, Profile>,
+}
+
+#[derive(Clone, Debug)]
+pub enum Credentials {
+    Hmac {
+        api_key: String,
+        secret_key: String,
+    },
+    Ed25519 {
+        api_key: String,
+        private_key: String,
+    },
+}
+
+impl Config {
+    pub fn parse(toml: &str) -> Result<Config, CliError> {
+        toml::from_str(toml).map_err(|e| CliError::Config(e.to_string()))
+    }
+
+    /// Reads `path`, or the default file when there is one. Running without a
+    /// config file is fine for public commands and keys from the environment.
+    pub fn load(path: Option<&Path>) -> Result<Config, CliError> {
+        let path = match path {
+            Some(path) => path.to_path_buf(),
+            None => match default_path() {
+                Some(path) if path.exists() => path,
+                _ => return Ok(Config::default()),
+            },
+        };
+
+        let toml = std::fs::read_to_string(&path)
+            .map_err(|e| CliError::Config(format!("{}: {}", path.display(), e)))?;
+
+        Config::parse(&toml)
+    }
+
+    /// Keys of `profile`, each one overridden by `BINANCE_API_KEY`,
+    /// `BINANCE_SECRET_KEY` or `BINANCE_PRIVATE_KEY` when set. `None` when no
+    /// API key is known.
+    pub fn credentials(&self, profile: &str) -> Result<Option<Credentials>, CliError> {
+        let profile = self.profiles.get(profile).cloned().unwrap_or_default();
+
+        let Some(api_key) = env::var(API_KEY).ok().or(profile.api_key) else {
+            return Ok(None);
+        };
+
+        let private_key = env::var_os(PRIVATE_KEY)
+            .map(PathBuf::from)
+            .or(profile.private_key);
+
+        if let Some(path) = private_key {
+            let private_key = std::fs::read_to_string(&path)
+                .map_err(|e| CliError::Credentials(format!("{}: {}", path.display(), e)))?;
+
+            return Ok(Some(Credentials::Ed25519 {
+                api_key,
+                private_key,
+            }));
+        }
+
+        match env::var(SECRET_KEY).ok().or(profile.secret_key) {
+            Some(secret_key) => Ok(Some(Credentials::Hmac {
+                api_key,
+                secret_key,
+            })),
+            None => Err(CliError::Credentials(
+                "An API key was given without a secret or private key.".to_string(),
+            )),
+        }
+    }
+}
+
+fn default_path() -> Option<PathBuf> {
+    let config = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+
+    Some(config.join("binance").join("config.toml"))
+}
@@ -0,0 +1,48 @@
+use std::fmt::Display;
+
+use binance_rs::common::error::BinanceError;
+
+#[derive(Debug)]
+pub enum CliError {
+    Argument(String),
+    Binance(BinanceError),
+    Config(String),
+    Credentials(String),
+    Io(std::io::Error),
+    Json(serde_json::Error),
+    Stream(String),
+}
+
+impl Display for CliError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CliError::Argument(msg) => write!(f, "[CliError::Argument] {}", msg),
+            CliError::Binance(e) => write!(f, "{}", e),
+            CliError::Config(msg) => write!(f, "[CliError::Config] {}", msg),
+            CliError::Credentials(msg) => write!(f, "[CliError::Credentials] {}", msg),
+            CliError::Io(e) => write!(f, "[CliError::Io] {}", e),
+            CliError::Json(e) => write!(f, "[CliError::Json] {}", e),
+            CliError::Stream(msg) => write!(f, "[CliError::Stream] {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for CliError {}
+
+impl From<BinanceError> for CliError {
+    fn from(value: BinanceError) -> Self {
+        CliError::Binance(value)
+    }
+}
+
+impl From<std::io::Error> for CliError {
+    fn from(value: std::io::Error) -> Self {
+        CliError::Io(value)
+    }
+}
+
+impl From<serde_json::Error> for CliError {
+    fn from(value: serde_json::Error) -> Self {
+        CliError::Json(value)
+    }
+}
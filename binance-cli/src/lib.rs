@@ -0,0 +1,58 @@
+pub mod args;
+pub mod config;
+pub mod error;
+pub mod stream;
+
+mod command;
+
+use std::io::Write;
+
+use binance_rs::core::{
+    client::asynchronous::Client,
+    signer::{ed25519::Ed25519Dalek, hmacsha256::HmacSha256},
+};
+
+use crate::{
+    args::{Cli, Command},
+    config::{Config, Credentials},
+    error::CliError,
+};
+
+/// Runs `cli` and writes its JSON output to `output`.
+pub async fn run(cli: &Cli, config: &Config, output: &mut impl Write) -> Result<(), CliError> {
+    if let Command::Stream(args) = &cli.command {
+        let routes = stream::routes(cli, &args.topics)?;
+        return stream::stream(routes, args.count, output).await;
+    }
+
+    let host = cli.rest_host();
+
+    let value = match config.credentials(cli.profile())? {
+        Some(Credentials::Hmac {
+            api_key,
+            secret_key,
+        }) => {
+            let client = Client::new(&host, HmacSha256::new(&api_key, &secret_key));
+            command::execute(cli, &client, true).await?
+        }
+        Some(Credentials::Ed25519 {
+            api_key,
+            private_key,
+        }) => {
+            let client = Client::new(&host, Ed25519Dalek::new(api_key, &private_key)?);
+            command::execute(cli, &client, true).await?
+        }
+        None => {
+            let client = Client::new(&host, HmacSha256::new("", ""));
+            command::execute(cli, &client, false).await?
+        }
+    };
+
+    match cli.pretty {
+        true => serde_json::to_writer_pretty(&mut *output, &value)?,
+        false => serde_json::to_writer(&mut *output, &value)?,
+    }
+
+    writeln!(output)?;
+    Ok(())
+}
@@ -0,0 +1,22 @@
+use std::process::ExitCode;
+
+use binance_cli::{args::Cli, config::Config};
+use clap::Parser;
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let result = match Config::load(cli.config.as_deref()) {
+        Ok(config) => binance_cli::run(&cli, &config, &mut std::io::stdout().lock()).await,
+        Err(error) => Err(error),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: {}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,273 @@
+use std::{io::Write, str::FromStr};
+
+use binance_rs::{
+    common::{
+        enums::{Interval, WebSocketStreamRate, WebSocketType},
+        futures::{endpoint::host::WebSocketHost, model::params::websocket::WebSocketParams},
+    },
+    core::websocket::{
+        futures::market::{
+            controller::WebSocketMarketController, engine::WebSocketMarketEngine,
+            supervisor::WebSocketMarketSupervisor,
+        },
+        supervisor::WebSocketSupervisor,
+    },
+};
+
+use crate::{args::Cli, error::CliError};
+
+type MarketSupervisor = WebSocketMarketSupervisor<WebSocketMarketController, WebSocketMarketEngine>;
+
+/// Frames buffered per connection before the socket is slowed down.
+const FRAMES: usize = 1024;
+
+/// A `WebSocketParams` stream, written `name[:argument...]`:
+///
+/// | Topic | Arguments |
+/// |---|---|
+/// | `agg-trade` | symbol |
+/// | `mark-price` | symbol, rate `1s` or `3s` (`1s`) |
+/// | `mark-price-all` | rate (`1s`) |
+/// | `kline` | symbol, interval |
+/// | `mini-ticker` | symbol |
+/// | `mini-ticker-all` | |
+/// | `ticker` | symbol |
+/// | `ticker-all` | |
+/// | `book-ticker-all` | |
+/// | `liquidation` | symbol |
+/// | `liquidation-all` | |
+/// | `depth` | symbol, levels 5, 10 or 20 (`10`), rate (`250ms`) |
+#[derive(Clone, Debug, PartialEq)]
+pub enum Topic {
+    AggTrade(String),
+    MarkPrice(String, WebSocketStreamRate),
+    MarkPriceAll(WebSocketStreamRate),
+    Kline(String, Interval),
+    MiniTicker(String),
+    MiniTickerAll,
+    Ticker(String),
+    TickerAll,
+    BookTickerAll,
+    Liquidation(String),
+    LiquidationAll,
+    Depth(String, u8, WebSocketStreamRate),
+}
+
+impl Topic {
+    pub fn subscribe(&self, params: WebSocketParams) -> WebSocketParams {
+        match self {
+            Topic::AggTrade(symbol) => params.agg_trade(symbol),
+            Topic::MarkPrice(symbol, rate) => params.mark_price(symbol, *rate),
+            Topic::MarkPriceAll(rate) => params.mark_price_all(*rate),
+            Topic::Kline(symbol, interval) => params.kline_candlesticks(symbol, *interval),
+            Topic::MiniTicker(symbol) => params.symbol_ticker_mini(symbol),
+            Topic::MiniTickerAll => params.symbol_ticker_all_mini(),
+            Topic::Ticker(symbol) => params.symbol_ticker(symbol),
+            Topic::TickerAll => params.symbol_ticker_all(),
+            Topic::BookTickerAll => params.all_book_tickers(),
+            Topic::Liquidation(symbol) => params.liquidation_order(symbol),
+            Topic::LiquidationAll => params.all_market_liquidation_order(),
+            Topic::Depth(symbol, levels, rate) => params.partial_book_depth(symbol, *levels, *rate),
+        }
+    }
+
+    /// Order book topics, served from the public host rather than the market
+    /// one.
+    pub fn is_public(&self) -> bool {
+        matches!(self, Topic::BookTickerAll | Topic::Depth(..))
+    }
+}
+
+impl FromStr for Topic {
+    type Err = String;
+
+    fn from_str(topic: &str) -> Result<Self, Self::Err> {
+        let mut parts = topic.split(':');
+        let name = parts.next().unwrap_or_default();
+        let arguments: Vec<&str> = parts.collect();
+
+        let arity = |min: usize, max: usize| {
+            if arguments.len() < min || arguments.len() > max {
+                Err(format!("Wrong number of arguments for topic {}", topic))
+            } else {
+                Ok(())
+            }
+        };
+
+        let symbol = || arguments[0].to_string();
+        let rate = |index: usize, default: WebSocketStreamRate| match arguments.get(index) {
+            Some(rate) => parse_rate(rate),
+            None => Ok(default),
+        };
+
+        match name {
+            "agg-trade" => arity(1, 1).map(|_| Topic::AggTrade(symbol())),
+            "mark-price" => {
+                arity(1, 2)?;
+                Ok(Topic::MarkPrice(
+                    symbol(),
+                    rate(1, WebSocketStreamRate::Seconds1)?,
+                ))
+            }
+            "mark-price-all" => {
+                arity(0, 1)?;
+                Ok(Topic::MarkPriceAll(rate(0, WebSocketStreamRate::Seconds1)?))
+            }
+            "kline" => {
+                arity(2, 2)?;
+                let interval = serde_json::from_value(arguments[1].into())
+                    .map_err(|_| format!("Invalid interval: {}", arguments[1]))?;
+
+                Ok(Topic::Kline(symbol(), interval))
+            }
+            "mini-ticker" => arity(1, 1).map(|_| Topic::MiniTicker(symbol())),
+            "mini-ticker-all" => arity(0, 0).map(|_| Topic::MiniTickerAll),
+            "ticker" => arity(1, 1).map(|_| Topic::Ticker(symbol())),
+            "ticker-all" => arity(0, 0).map(|_| Topic::TickerAll),
+            "book-ticker-all" => arity(0, 0).map(|_| Topic::BookTickerAll),
+            "liquidation" => arity(1, 1).map(|_| Topic::Liquidation(symbol())),
+            "liquidation-all" => arity(0, 0).map(|_| Topic::LiquidationAll),
+            "depth" => {
+                arity(1, 3)?;
+                let levels = match arguments.get(1) {
+                    Some(&levels @ ("5" | "10" | "20")) => levels.parse().unwrap_or(10),
+                    Some(levels) => return Err(format!("Invalid depth levels: {}", levels)),
+                    None => 10,
+                };
+
+                Ok(Topic::Depth(
+                    symbol(),
+                    levels,
+                    rate(2, WebSocketStreamRate::Milliseconds250)?,
+                ))
+            }
+            _ => Err(format!("Unknown topic: {}", name)),
+        }
+    }
+}
+
+fn parse_rate(rate: &str) -> Result<WebSocketStreamRate, String> {
+    match rate {
+        "100ms" => Ok(WebSocketStreamRate::Milliseconds100),
+        "250ms" => Ok(WebSocketStreamRate::Milliseconds250),
+        "500ms" => Ok(WebSocketStreamRate::Milliseconds500),
+        "1s" => Ok(WebSocketStreamRate::Seconds1),
+        "3s" => Ok(WebSocketStreamRate::Seconds3),
+        _ => Err(format!("Invalid rate: {}", rate)),
+    }
+}
+
+/// Routes carrying `topics`. Order book topics go to the public host and the
+/// rest to the market host, unless `--stream-host` or `--testnet` name a
+/// single host for both.
+pub fn routes(cli: &Cli, topics: &[Topic]) -> Result<Vec<String>, CliError> {
+    let (public, market) = match (&cli.stream_host, cli.testnet) {
+        (Some(host), _) => (host.clone(), host.clone()),
+        (None, true) => {
+            let host = WebSocketHost::CombinedStreamsTest.as_ref().to_string();
+            (host.clone(), host)
+        }
+        (None, false) => (
+            WebSocketHost::CombinedStreamsPublic.as_ref().to_string(),
+            WebSocketHost::CombinedStreamsMarket.as_ref().to_string(),
+        ),
+    };
+
+    if public == market {
+        let params = topics
+            .iter()
+            .fold(WebSocketParams::new(market), |params, topic| {
+                topic.subscribe(params)
+            });
+
+        return Ok(params.routes()?);
+    }
+
+    let mut routes = Vec::new();
+
+    for (host, public) in [(public, true), (market, false)] {
+        let params = topics
+            .iter()
+            .filter(|topic| topic.is_public() == public)
+            .fold(WebSocketParams::new(host), |params, topic| {
+                topic.subscribe(params)
+            });
+
+        if !params.streams().is_empty() {
+            routes.extend(params.routes()?);
+        }
+    }
+
+    Ok(routes)
+}
+
+/// Writes every frame of `routes` to `output` as it arrives, one line each,
+/// until `count` frames were written or Ctrl-C is pressed.
+pub async fn stream(
+    routes: Vec<String>,
+    count: Option<usize>,
+    output: &mut impl Write,
+) -> Result<(), CliError> {
+    let (tx_frames, mut rx_frames) = tokio::sync::mpsc::channel::<String>(FRAMES);
+    let mut supervisors: Vec<MarketSupervisor> = Vec::with_capacity(routes.len());
+
+    for route in routes {
+        let mut supervisor = MarketSupervisor::new(WebSocketType::MultiStream);
+        let mut frames = supervisor.frames(FRAMES)?;
+
+        if let Err(error) = supervisor.start(route).await {
+            stop(supervisors).await?;
+            return Err(error.into());
+        }
+
+        let tx_frames = tx_frames.clone();
+
+        tokio::spawn(async move {
+            while let Some(frame) = frames.recv().await {
+                if tx_frames.send(frame.as_str().to_string()).await.is_err() {
+                    break;
+                }
+            }
+        });
+
+        supervisors.push(supervisor);
+    }
+
+    drop(tx_frames);
+
+    let mut written = 0;
+
+    let result = loop {
+        if count.is_some_and(|count| written >= count) {
+            break Ok(());
+        }
+
+        let frame = tokio::select! {
+            frame = rx_frames.recv() => frame,
+            _ = tokio::signal::ctrl_c() => break Ok(()),
+        };
+
+        let Some(frame) = frame else {
+            break Err(CliError::Stream(
+                "Every connection was closed by the server.".to_string(),
+            ));
+        };
+
+        if let Err(error) = writeln!(output, "{}", frame).and_then(|_| output.flush()) {
+            break Err(error.into());
+        }
+
+        written += 1;
+    };
+
+    stop(supervisors).await?;
+    result
+}
+
+async fn stop(supervisors: Vec<MarketSupervisor>) -> Result<(), CliError> {
+    for mut supervisor in supervisors {
+        supervisor.stop().await?;
+    }
+
+    Ok(())
+}
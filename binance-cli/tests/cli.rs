@@ -0,0 +1,283 @@
+#[cfg(test)]
+mod cli_integration_tests {
+    use std::{str::FromStr, time::Duration};
+
+    use binance_cli::{
+        args::{Cli, Command},
+        config::Config,
+        error::CliError,
+        stream::{self, Topic},
+    };
+    use binance_mock::{credentials::Credentials, server::MockServer};
+    use binance_rs::common::{
+        enums::{Interval, WebSocketStreamRate, WebSocketType},
+        futures::endpoint::route::{Account, Trade},
+        spot::endpoint::route as spot_route,
+    };
+    use clap::Parser;
+    use serde_json::Value;
+
+    fn server() -> MockServer {
+        MockServer::builder()
+            .credentials("api_key", Credentials::hmac_sha256("secret_key"))
+            .stream_interval(Duration::from_millis(10))
+            .start()
+            .unwrap()
+    }
+
+    fn config() -> Config {
+        Config::parse(
+            r#"
+                [default]
+                api_key = "api_key"
+                secret_key = "secret_key"
+
+                [testnet]
+                api_key = "testnet_key"
+                secret_key = "testnet_secret"
+            "#,
+        )
+        .unwrap()
+    }
+
+    async fn run(server: &MockServer, config: &Config, args: &[&str]) -> Result<Value, CliError> {
+        let cli = Cli::parse_from(["binance", "--host", server.url()].iter().chain(args));
+
+        let mut output = Vec::new();
+        binance_cli::run(&cli, config, &mut output).await?;
+
+        Ok(serde_json::from_slice(&output).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_public_commands() {
+        let server = server();
+        let config = Config::default();
+
+        assert_eq!(
+            run(&server, &config, &["ping"]).await.unwrap(),
+            Value::Object(Default::default())
+        );
+        assert!(run(&server, &config, &["time"]).await.unwrap()["serverTime"].is_u64());
+        assert!(run(&server, &config, &["depth", "BTCUSDT"]).await.unwrap()["bids"].is_array());
+        assert!(
+            run(&server, &config, &["-m", "futures", "ticker", "--price"])
+                .await
+                .is_ok()
+        );
+
+        let info = run(
+            &server,
+            &config,
+            &["-m", "futures", "exchange-info", "BTCUSDT"],
+        )
+        .await
+        .unwrap();
+        let symbols = info["symbols"].as_array().unwrap();
+
+        assert!(!symbols.is_empty());
+        assert!(symbols.iter().all(|symbol| symbol["symbol"] == "BTCUSDT"));
+    }
+
+    #[tokio::test]
+    async fn test_orders_are_only_tested_unless_live() {
+        let server = server();
+        let config = config();
+        let order = [
+            "order",
+            "new",
+            "BTCUSDT",
+            "buy",
+            "limit",
+            "--quantity",
+            "1",
+            "--price",
+            "100",
+        ];
+
+        run(&server, &config, &[&["-m", "futures"], &order[..]].concat())
+            .await
+            .unwrap();
+
+        let tested = server.requests_to(Trade::TestNewOrder);
+
+        assert_eq!(tested.len(), 1);
+        assert_eq!(tested[0].param("side"), Some("BUY"));
+        assert_eq!(tested[0].param("timeInForce"), Some("GTC"));
+        assert!(server.requests_to(Trade::NewOrder).is_empty());
+
+        let placed = run(
+            &server,
+            &config,
+            &[&["-m", "futures"], &order[..], &["--live"]].concat(),
+        )
+        .await
+        .unwrap();
+
+        assert!(placed["orderId"].is_u64());
+        assert_eq!(server.requests_to(Trade::NewOrder).len(), 1);
+
+        let market = [
+            "order",
+            "new",
+            "BTCUSDT",
+            "SELL",
+            "MARKET",
+            "--quantity",
+            "1",
+        ];
+        run(&server, &config, &market).await.unwrap();
+
+        assert_eq!(server.requests_to(spot_route::Trade::TestOrder).len(), 1);
+
+        let error = run(
+            &server,
+            &config,
+            &["order", "new", "BTCUSDT", "SELL", "MARKET", "--reduce-only"],
+        )
+        .await
+        .unwrap_err();
+
+        assert!(matches!(error, CliError::Argument(_)));
+    }
+
+    #[tokio::test]
+    async fn test_account_commands() {
+        let server = server();
+        let config = config();
+
+        assert!(
+            run(&server, &config, &["balances"])
+                .await
+                .unwrap()
+                .is_array()
+        );
+        assert!(
+            run(&server, &config, &["-m", "futures", "balances"])
+                .await
+                .unwrap()
+                .is_array()
+        );
+        assert!(
+            run(&server, &config, &["open-orders", "BTCUSDT"])
+                .await
+                .unwrap()
+                .is_array()
+        );
+
+        let income = run(
+            &server,
+            &config,
+            &["income", "--type", "funding_fee", "--limit", "10"],
+        )
+        .await
+        .unwrap();
+        let requests = server.requests_to(Account::IncomeHistory);
+
+        assert!(income.is_array());
+        assert_eq!(requests[0].param("incomeType"), Some("FUNDING_FEE"));
+
+        // Spot has no income history, and the testnet profile holds other keys.
+        assert!(matches!(
+            run(&server, &config, &["-m", "spot", "income"]).await,
+            Err(CliError::Argument(_))
+        ));
+        assert!(matches!(
+            run(&server, &config, &["--testnet", "balances"]).await,
+            Err(CliError::Binance(_))
+        ));
+        assert!(matches!(
+            run(&server, &Config::default(), &["balances"]).await,
+            Err(CliError::Credentials(_))
+        ));
+    }
+
+    #[test]
+    fn test_topics() {
+        assert_eq!(
+            Topic::from_str("kline:BTCUSDT:1M").unwrap(),
+            Topic::Kline("BTCUSDT".to_string(), Interval::Months1)
+        );
+        assert_eq!(
+            Topic::from_str("depth:ETHUSDT").unwrap(),
+            Topic::Depth(
+                "ETHUSDT".to_string(),
+                10,
+                WebSocketStreamRate::Milliseconds250
+            )
+        );
+        assert!(Topic::from_str("depth:ETHUSDT:7").is_err());
+        assert!(Topic::from_str("agg-trade").is_err());
+        assert!(Topic::from_str("trades:BTCUSDT").is_err());
+
+        let cli = Cli::parse_from([
+            "binance",
+            "stream",
+            "agg-trade:BTCUSDT",
+            "depth:BTCUSDT:5:100ms",
+            "mark-price-all:3s",
+        ]);
+        let Command::Stream(args) = &cli.command else {
+            panic!("not a stream command");
+        };
+
+        assert_eq!(
+            stream::routes(&cli, &args.topics).unwrap(),
+            vec![
+                "wss://fstream.binance.com/public/stream?streams=btcusdt@depth5@100ms",
+                "wss://fstream.binance.com/market/stream?streams=btcusdt@aggTrade/!markPrice@arr@3s",
+            ]
+        );
+
+        let cli = Cli::parse_from([
+            "binance",
+            "--testnet",
+            "stream",
+            "agg-trade:BTCUSDT",
+            "book-ticker-all",
+        ]);
+        let Command::Stream(args) = &cli.command else {
+            panic!("not a stream command");
+        };
+
+        assert_eq!(
+            stream::routes(&cli, &args.topics).unwrap(),
+            vec!["wss://fstream.binancefuture.com/stream?streams=btcusdt@aggTrade/!bookTicker"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_stream_writes_json_lines() {
+        let server = server();
+        let host = server.websocket_host(&WebSocketType::MultiStream);
+
+        let cli = Cli::parse_from([
+            "binance",
+            "stream",
+            "--stream-host",
+            &host,
+            "--count",
+            "6",
+            "kline:ETHUSDT:5m",
+            "agg-trade:SOLUSDT",
+        ]);
+
+        let mut output = Vec::new();
+        binance_cli::run(&cli, &Config::default(), &mut output)
+            .await
+            .unwrap();
+
+        let lines: Vec<Value> = String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+
+        assert_eq!(lines.len(), 6);
+        assert!(
+            lines
+                .iter()
+                .all(|line| line["stream"].is_string() && line["data"].is_object())
+        );
+    }
+}
@@ -0,0 +1,71 @@
+use binance_common::{
+    enums::WebSocketType,
+    futures::model::response::websocket::{
+        CombinedStreamResponse, WebSocketResponse, borrowed::WebSocketResponseRef,
+    },
+};
+use criterion::{Criterion, black_box, criterion_group, criterion_main};
+
+const BOOK_TICKER: &str = r#"{"stream":"btcusdt@bookTicker","data":{"e":"bookTicker","u":400900217,"E":1568014460893,"T":1568014460891,"s":"BTCUSDT","b":"25.35190000","B":"31.21000000","a":"25.36520000","A":"40.66000000"}}"#;
+
+fn depth() -> String {
+    let levels = |base: f64| {
+        (0..20)
+            .map(|level| format!(r#"["{:.2}","{:.3}"]"#, base + level as f64 * 0.1, 1.5))
+            .collect::<Vec<_>>()
+            .join(",")
+    };
+
+    format!(
+        r#"{{"stream":"btcusdt@depth20@100ms","data":{{"e":"depthUpdate","E":1571889248277,"T":1571889248276,"s":"BTCUSDT","U":390497796,"u":390497878,"pu":390497794,"b":[{}],"a":[{}]}}}}"#,
+        levels(7403.0),
+        levels(7405.0)
+    )
+}
+
+fn owned(message: &str) -> WebSocketResponse {
+    serde_json::from_str::<CombinedStreamResponse>(message)
+        .unwrap()
+        .data
+}
+
+fn benchmark(criterion: &mut Criterion, name: &str, message: &str) {
+    let mut group = criterion.benchmark_group(name);
+
+    group.bench_function("owned", |bencher| {
+        bencher.iter(|| owned(black_box(message)))
+    });
+
+    group.bench_function("borrowed", |bencher| {
+        bencher.iter(|| {
+            WebSocketResponseRef::decode(black_box(message), WebSocketType::MultiStream).unwrap();
+        })
+    });
+
+    #[cfg(feature = "simd")]
+    {
+        let mut decoder =
+            binance_common::futures::model::response::websocket::borrowed::SimdDecoder::new();
+
+        group.bench_function("simd", |bencher| {
+            bencher.iter(|| {
+                decoder
+                    .decode(black_box(message), WebSocketType::MultiStream)
+                    .unwrap();
+            })
+        });
+    }
+
+    group.finish();
+}
+
+fn book_ticker(criterion: &mut Criterion) {
+    benchmark(criterion, "book_ticker", BOOK_TICKER);
+}
+
+fn depth_update(criterion: &mut Criterion) {
+    benchmark(criterion, "depth_update", &depth());
+}
+
+criterion_group!(benches, book_ticker, depth_update);
+criterion_main!(benches);
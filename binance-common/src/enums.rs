@@ -1,7 +1,12 @@
 pub mod futures;
+pub mod margin;
 pub mod spot;
+pub mod sub_account;
+pub mod wallet;
 
 use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Interval {
     Seconds1,
     Minutes1,
@@ -22,7 +27,13 @@ pub enum Interval {
 }
 
 impl AsRef<str> for Interval {
-    fn as_ref(&self) -> &'static str {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Interval {
+    pub fn as_str(&self) -> &'static str {
         match self {
             Interval::Seconds1 => "1s",
             Interval::Minutes1 => "1m",
@@ -42,9 +53,35 @@ impl AsRef<str> for Interval {
             Interval::Months1 => "1M",
         }
     }
+
+    /// Length of one candle in milliseconds, `None` for `1M` whose length depends on the month.
+    pub fn milliseconds(&self) -> Option<u64> {
+        const SECOND: u64 = 1_000;
+        const MINUTE: u64 = 60 * SECOND;
+        const HOUR: u64 = 60 * MINUTE;
+        const DAY: u64 = 24 * HOUR;
+
+        match self {
+            Interval::Seconds1 => Some(SECOND),
+            Interval::Minutes1 => Some(MINUTE),
+            Interval::Minutes3 => Some(3 * MINUTE),
+            Interval::Minutes5 => Some(5 * MINUTE),
+            Interval::Minutes15 => Some(15 * MINUTE),
+            Interval::Minutes30 => Some(30 * MINUTE),
+            Interval::Hours1 => Some(HOUR),
+            Interval::Hours2 => Some(2 * HOUR),
+            Interval::Hours4 => Some(4 * HOUR),
+            Interval::Hours6 => Some(6 * HOUR),
+            Interval::Hours8 => Some(8 * HOUR),
+            Interval::Hours12 => Some(12 * HOUR),
+            Interval::Days1 => Some(DAY),
+            Interval::Days3 => Some(3 * DAY),
+            Interval::Weeks1 => Some(7 * DAY),
+            Interval::Months1 => None,
+        }
+    }
 }
 
-//PARTIAL IMPLEMENTATION
 impl<'de> Deserialize<'de> for Interval {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -54,7 +91,27 @@ impl<'de> Deserialize<'de> for Interval {
 
         Ok(match value.as_str() {
             "1s" => Interval::Seconds1,
-            _ => return Err(serde::de::Error::custom("Invalid interval")),
+            "1m" => Interval::Minutes1,
+            "3m" => Interval::Minutes3,
+            "5m" => Interval::Minutes5,
+            "15m" => Interval::Minutes15,
+            "30m" => Interval::Minutes30,
+            "1h" => Interval::Hours1,
+            "2h" => Interval::Hours2,
+            "4h" => Interval::Hours4,
+            "6h" => Interval::Hours6,
+            "8h" => Interval::Hours8,
+            "12h" => Interval::Hours12,
+            "1d" => Interval::Days1,
+            "3d" => Interval::Days3,
+            "1w" => Interval::Weeks1,
+            "1M" => Interval::Months1,
+            _ => {
+                return Err(serde::de::Error::custom(format!(
+                    "Invalid interval: {}",
+                    value
+                )));
+            }
         })
     }
 }
@@ -118,6 +175,7 @@ pub enum WebSocketState {
     Idle,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum WebSocketStreamRate {
     Milliseconds100,
     Milliseconds250,
@@ -140,7 +198,7 @@ impl std::fmt::Display for WebSocketStreamRate {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum WebSocketType {
     SingleStream,
     MultiStream,
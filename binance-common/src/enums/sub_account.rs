@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize, Serializer};
+
+/// Wallets that can send or receive a sub-account universal transfer.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AccountType {
+    #[default]
+    Spot,
+    UsdtFuture,
+    CoinFuture,
+    Margin,
+    IsolatedMargin,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FuturesType {
+    #[default]
+    UsdM,
+    CoinM,
+}
+
+impl Serialize for FuturesType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            FuturesType::UsdM => serializer.serialize_u8(1),
+            FuturesType::CoinM => serializer.serialize_u8(2),
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+
+/// Source and destination wallets of a universal transfer.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum UniversalTransferType {
+    #[default]
+    #[serde(rename = "MAIN_UMFUTURE")]
+    SpotToUsdMFutures,
+    #[serde(rename = "MAIN_CMFUTURE")]
+    SpotToCoinMFutures,
+    #[serde(rename = "MAIN_MARGIN")]
+    SpotToMargin,
+    #[serde(rename = "MAIN_FUNDING")]
+    SpotToFunding,
+    #[serde(rename = "UMFUTURE_MAIN")]
+    UsdMFuturesToSpot,
+    #[serde(rename = "UMFUTURE_MARGIN")]
+    UsdMFuturesToMargin,
+    #[serde(rename = "UMFUTURE_FUNDING")]
+    UsdMFuturesToFunding,
+    #[serde(rename = "CMFUTURE_MAIN")]
+    CoinMFuturesToSpot,
+    #[serde(rename = "CMFUTURE_MARGIN")]
+    CoinMFuturesToMargin,
+    #[serde(rename = "CMFUTURE_FUNDING")]
+    CoinMFuturesToFunding,
+    #[serde(rename = "MARGIN_MAIN")]
+    MarginToSpot,
+    #[serde(rename = "MARGIN_UMFUTURE")]
+    MarginToUsdMFutures,
+    #[serde(rename = "MARGIN_CMFUTURE")]
+    MarginToCoinMFutures,
+    #[serde(rename = "MARGIN_FUNDING")]
+    MarginToFunding,
+    #[serde(rename = "MARGIN_ISOLATEDMARGIN")]
+    MarginToIsolatedMargin,
+    #[serde(rename = "ISOLATEDMARGIN_MARGIN")]
+    IsolatedMarginToMargin,
+    #[serde(rename = "ISOLATEDMARGIN_ISOLATEDMARGIN")]
+    IsolatedMarginToIsolatedMargin,
+    #[serde(rename = "FUNDING_MAIN")]
+    FundingToSpot,
+    #[serde(rename = "FUNDING_UMFUTURE")]
+    FundingToUsdMFutures,
+    #[serde(rename = "FUNDING_CMFUTURE")]
+    FundingToCoinMFutures,
+    #[serde(rename = "FUNDING_MARGIN")]
+    FundingToMargin,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum DustAccountType {
+    Spot,
+    Margin,
+}
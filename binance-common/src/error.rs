@@ -1,6 +1,10 @@
+pub mod code;
+
 use hmac::digest::InvalidLength;
 use serde::Deserialize;
-use std::fmt::Display;
+use std::{fmt::Display, time::Duration};
+
+pub use code::ErrorCode;
 
 #[derive(Debug)]
 pub enum BinanceError {
@@ -8,12 +12,15 @@ pub enum BinanceError {
     BuildRequest(String),
     Channel(String),
     Deserialize(serde_json::Error),
-    IpBanned,
-    InternalServer,
+    Http(ErrorContext),
+    IpBanned(ErrorContext),
+    InternalServer(ErrorContext),
     Request(reqwest::Error),
-    RequestTimeout,
+    RequestTimeout(ErrorContext),
     Signature(InvalidLength),
-    TooManyRequest,
+    TooManyRequest(ErrorContext),
+    /// Failure of a transport other than reqwest, which reports `Request`.
+    Transport(String),
     Unknown(String),
     WebSocket(ApiError),
     WebSocketInternal(String),
@@ -23,13 +30,141 @@ pub enum BinanceError {
 pub struct ApiError {
     pub code: i16,
     pub msg: String,
+
+    #[serde(skip)]
+    pub context: Option<ErrorContext>,
+}
+
+impl ApiError {
+    pub fn new(code: i16, msg: impl Into<String>) -> Self {
+        ApiError {
+            code,
+            msg: msg.into(),
+            context: None,
+        }
+    }
+
+    pub fn error_code(&self) -> ErrorCode {
+        ErrorCode::from(self.code)
+    }
+}
+
+/// Where a failed REST call went and what came back, minus the query string
+/// so signatures and parameters never end up in logs.
+#[derive(Clone, Debug, Default)]
+pub struct ErrorContext {
+    pub route: String,
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+}
+
+impl ErrorContext {
+    pub fn new(route: impl Into<String>, status: u16, headers: Vec<(String, String)>) -> Self {
+        ErrorContext {
+            route: route.into(),
+            status,
+            headers,
+        }
+    }
+
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// `Retry-After` sent with 418 and 429 responses.
+    pub fn retry_after(&self) -> Option<Duration> {
+        self.header("Retry-After")
+            .and_then(|value| value.trim().parse::<u64>().ok())
+            .map(Duration::from_secs)
+    }
+}
+
+impl Display for ErrorContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "route: {}, status: {}", self.route, self.status)
+    }
+}
+
+impl BinanceError {
+    pub fn code(&self) -> Option<ErrorCode> {
+        match self {
+            BinanceError::Api(api_error) | BinanceError::WebSocket(api_error) => {
+                Some(api_error.error_code())
+            }
+            _ => None,
+        }
+    }
+
+    pub fn context(&self) -> Option<&ErrorContext> {
+        match self {
+            BinanceError::Api(api_error) => api_error.context.as_ref(),
+            BinanceError::Http(context)
+            | BinanceError::IpBanned(context)
+            | BinanceError::InternalServer(context)
+            | BinanceError::RequestTimeout(context)
+            | BinanceError::TooManyRequest(context) => Some(context),
+            _ => None,
+        }
+    }
+
+    pub fn retry_after(&self) -> Option<Duration> {
+        self.context().and_then(ErrorContext::retry_after)
+    }
+
+    /// Request weight, order count or IP ban limits were hit.
+    pub fn is_rate_limit(&self) -> bool {
+        match self {
+            BinanceError::TooManyRequest(_) | BinanceError::IpBanned(_) => true,
+            _ => self.code().is_some_and(|code| code.is_rate_limit()),
+        }
+    }
+
+    /// The same request may succeed if sent again, after `retry_after` when
+    /// given. An IP ban is not retryable, it can last for days. Server errors
+    /// and timeouts are, but see `is_unknown_outcome` before resending an order.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            BinanceError::TooManyRequest(_)
+            | BinanceError::InternalServer(_)
+            | BinanceError::RequestTimeout(_) => true,
+            BinanceError::Request(error) => error.is_timeout() || error.is_connect(),
+            _ => self.code().is_some_and(|code| code.is_retryable()),
+        }
+    }
+
+    /// The request may have been executed despite the error, as after a 5xx,
+    /// a timeout or the `Unknown` and `Timeout` codes. Reconcile before
+    /// resending anything that is not idempotent, such as a new order.
+    pub fn is_unknown_outcome(&self) -> bool {
+        match self {
+            BinanceError::InternalServer(_) | BinanceError::RequestTimeout(_) => true,
+            BinanceError::Request(error) => error.is_timeout(),
+            _ => self.code().is_some_and(|code| code.is_unknown_outcome()),
+        }
+    }
+
+    /// The API key, its permissions or the signature were rejected.
+    pub fn is_auth(&self) -> bool {
+        match self {
+            BinanceError::Http(context) => context.status == 401,
+            _ => self.code().is_some_and(|code| code.is_auth()),
+        }
+    }
 }
 
 impl Display for BinanceError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            BinanceError::Api(ApiError { code, msg }) => {
-                write!(f, "[BinanceError::Api] code: {}, message: {}", code, msg)
+            BinanceError::Api(ApiError { code, msg, context }) => {
+                write!(f, "[BinanceError::Api] code: {}, message: {}", code, msg)?;
+
+                match context {
+                    Some(context) => write!(f, " ({})", context),
+                    None => Ok(()),
+                }
             }
 
             BinanceError::BuildRequest(msg) => {
@@ -41,37 +176,50 @@ impl Display for BinanceError {
             BinanceError::Deserialize(e) => {
                 write!(f, "[BinanceError::Deserialize] {}", e)
             }
-            BinanceError::IpBanned => {
+            BinanceError::Http(context) => {
+                write!(f, "[BinanceError::Http] Unexpected response ({})", context)
+            }
+            BinanceError::IpBanned(context) => {
                 write!(
                     f,
-                    "[BinanceError::IpBanned] IP Auto-banned due to repeated 429 responses."
+                    "[BinanceError::IpBanned] IP Auto-banned due to repeated 429 responses ({}).",
+                    context
                 )
             }
-            BinanceError::InternalServer => {
+            BinanceError::InternalServer(context) => {
                 write!(
                     f,
-                    "[BinanceError::InternalServer] Binance internal server error."
+                    "[BinanceError::InternalServer] Binance internal server error ({}).",
+                    context
                 )
             }
             BinanceError::Request(e) => {
                 write!(f, "[BinanceError::Request] {}", e)
             }
-            BinanceError::RequestTimeout => {
-                write!(f, "[BinanceError::RequestTimeout] Request timed out.")
+            BinanceError::RequestTimeout(context) => {
+                write!(
+                    f,
+                    "[BinanceError::RequestTimeout] Request timed out ({}).",
+                    context
+                )
             }
             BinanceError::Signature(e) => {
                 write!(f, "[BinanceError::Signature] Signature error: {}", e)
             }
-            BinanceError::TooManyRequest => {
+            BinanceError::TooManyRequest(context) => {
                 write!(
                     f,
-                    "[BinanceError::TooManyRequest] Rate limit exceeded (HTTP 429)."
+                    "[BinanceError::TooManyRequest] Rate limit exceeded ({}).",
+                    context
                 )
             }
+            BinanceError::Transport(msg) => {
+                write!(f, "[BinanceError::Transport] {}", msg)
+            }
             BinanceError::Unknown(e) => {
                 write!(f, "[BinanceError::Unknown] {}", e)
             }
-            BinanceError::WebSocket(ApiError { code, msg }) => {
+            BinanceError::WebSocket(ApiError { code, msg, .. }) => {
                 write!(
                     f,
                     "[BinanceError::WebSocket] code: {}, message: {}",
@@ -85,6 +233,17 @@ impl Display for BinanceError {
     }
 }
 
+impl std::error::Error for BinanceError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            BinanceError::Deserialize(error) => Some(error),
+            BinanceError::Request(error) => Some(error),
+            BinanceError::Signature(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
 impl From<InvalidLength> for BinanceError {
     fn from(value: InvalidLength) -> Self {
         BinanceError::Signature(value)
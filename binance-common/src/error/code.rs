@@ -0,0 +1,305 @@
+/// Documented Binance error codes, shared by Spot and Futures where the
+/// number means the same thing. The table is not exhaustive, anything not
+/// listed is kept as `Other` and never classified as retryable.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ErrorCode {
+    // 10xx - General server or network issues
+    Unknown,
+    Disconnected,
+    Unauthorized,
+    TooManyRequests,
+    UnexpectedResponse,
+    Timeout,
+    ServerBusy,
+    InvalidMessage,
+    UnknownOrderComposition,
+    TooManyOrders,
+    ServiceShuttingDown,
+    UnsupportedOperation,
+    InvalidTimestamp,
+    InvalidSignature,
+    NotFound,
+
+    // 11xx - Request issues
+    IllegalChars,
+    TooManyParameters,
+    MandatoryParamEmptyOrMalformed,
+    UnknownParam,
+    UnreadParameters,
+    ParamEmpty,
+    ParamNotRequired,
+    ParamOverflow,
+    BadPrecision,
+    NoDepth,
+    TifNotRequired,
+    InvalidTif,
+    InvalidOrderType,
+    InvalidSide,
+    EmptyNewClientOrderId,
+    EmptyOrigClientOrderId,
+    BadInterval,
+    BadSymbol,
+    InvalidSymbolStatus,
+    InvalidListenKey,
+    MoreThanXxHours,
+    OptionalParamsBadCombo,
+    InvalidParameter,
+    BadRecvWindow,
+    BadStrategyType,
+
+    // 20xx - Order, cancel and API key issues
+    NewOrderRejected,
+    CancelRejected,
+    NoSuchOrder,
+    BadApiKeyFormat,
+    RejectedApiKey,
+    NoTradingWindow,
+    BalanceNotSufficient,
+    MarginNotSufficient,
+    UnableToFill,
+    OrderWouldImmediatelyTrigger,
+    ReduceOnlyReject,
+    UserInLiquidation,
+    PositionNotSufficient,
+    MaxOpenOrderExceeded,
+    ReduceOnlyOrderTypeNotSupported,
+    MaxLeverageRatio,
+    MinLeverageRatio,
+
+    // 4xxx - Futures filters and other issues
+    InvalidOrderStatus,
+    PriceLessThanZero,
+    PriceGreaterThanMaxPrice,
+    QuantityLessThanZero,
+    QuantityLessThanMinQuantity,
+    QuantityGreaterThanMaxQuantity,
+    StopPriceLessThanZero,
+    StopPriceGreaterThanMaxPrice,
+    TickSizeLessThanZero,
+    PriceNotIncreasedByTickSize,
+    InvalidClientOrderIdLength,
+    PriceHigherThanMultiplierUp,
+    QuantityNotIncreasedByStepSize,
+    PriceLowerThanMultiplierDown,
+    InvalidLeverage,
+    NoNeedToChangeMarginType,
+    NoNeedToChangePositionSide,
+    PositionSideNotMatch,
+    MarketOrderReject,
+    MinNotional,
+
+    // 50xx - Futures order execution issues
+    FokOrderReject,
+    GtxOrderReject,
+
+    Other(i16),
+}
+
+impl ErrorCode {
+    pub fn code(&self) -> i16 {
+        match self {
+            ErrorCode::Unknown => -1000,
+            ErrorCode::Disconnected => -1001,
+            ErrorCode::Unauthorized => -1002,
+            ErrorCode::TooManyRequests => -1003,
+            ErrorCode::UnexpectedResponse => -1006,
+            ErrorCode::Timeout => -1007,
+            ErrorCode::ServerBusy => -1008,
+            ErrorCode::InvalidMessage => -1013,
+            ErrorCode::UnknownOrderComposition => -1014,
+            ErrorCode::TooManyOrders => -1015,
+            ErrorCode::ServiceShuttingDown => -1016,
+            ErrorCode::UnsupportedOperation => -1020,
+            ErrorCode::InvalidTimestamp => -1021,
+            ErrorCode::InvalidSignature => -1022,
+            ErrorCode::NotFound => -1099,
+            ErrorCode::IllegalChars => -1100,
+            ErrorCode::TooManyParameters => -1101,
+            ErrorCode::MandatoryParamEmptyOrMalformed => -1102,
+            ErrorCode::UnknownParam => -1103,
+            ErrorCode::UnreadParameters => -1104,
+            ErrorCode::ParamEmpty => -1105,
+            ErrorCode::ParamNotRequired => -1106,
+            ErrorCode::ParamOverflow => -1108,
+            ErrorCode::BadPrecision => -1111,
+            ErrorCode::NoDepth => -1112,
+            ErrorCode::TifNotRequired => -1114,
+            ErrorCode::InvalidTif => -1115,
+            ErrorCode::InvalidOrderType => -1116,
+            ErrorCode::InvalidSide => -1117,
+            ErrorCode::EmptyNewClientOrderId => -1118,
+            ErrorCode::EmptyOrigClientOrderId => -1119,
+            ErrorCode::BadInterval => -1120,
+            ErrorCode::BadSymbol => -1121,
+            ErrorCode::InvalidSymbolStatus => -1122,
+            ErrorCode::InvalidListenKey => -1125,
+            ErrorCode::MoreThanXxHours => -1127,
+            ErrorCode::OptionalParamsBadCombo => -1128,
+            ErrorCode::InvalidParameter => -1130,
+            ErrorCode::BadRecvWindow => -1131,
+            ErrorCode::BadStrategyType => -1134,
+            ErrorCode::NewOrderRejected => -2010,
+            ErrorCode::CancelRejected => -2011,
+            ErrorCode::NoSuchOrder => -2013,
+            ErrorCode::BadApiKeyFormat => -2014,
+            ErrorCode::RejectedApiKey => -2015,
+            ErrorCode::NoTradingWindow => -2016,
+            ErrorCode::BalanceNotSufficient => -2018,
+            ErrorCode::MarginNotSufficient => -2019,
+            ErrorCode::UnableToFill => -2020,
+            ErrorCode::OrderWouldImmediatelyTrigger => -2021,
+            ErrorCode::ReduceOnlyReject => -2022,
+            ErrorCode::UserInLiquidation => -2023,
+            ErrorCode::PositionNotSufficient => -2024,
+            ErrorCode::MaxOpenOrderExceeded => -2025,
+            ErrorCode::ReduceOnlyOrderTypeNotSupported => -2026,
+            ErrorCode::MaxLeverageRatio => -2027,
+            ErrorCode::MinLeverageRatio => -2028,
+            ErrorCode::InvalidOrderStatus => -4000,
+            ErrorCode::PriceLessThanZero => -4001,
+            ErrorCode::PriceGreaterThanMaxPrice => -4002,
+            ErrorCode::QuantityLessThanZero => -4003,
+            ErrorCode::QuantityLessThanMinQuantity => -4004,
+            ErrorCode::QuantityGreaterThanMaxQuantity => -4005,
+            ErrorCode::StopPriceLessThanZero => -4006,
+            ErrorCode::StopPriceGreaterThanMaxPrice => -4007,
+            ErrorCode::TickSizeLessThanZero => -4008,
+            ErrorCode::PriceNotIncreasedByTickSize => -4014,
+            ErrorCode::InvalidClientOrderIdLength => -4015,
+            ErrorCode::PriceHigherThanMultiplierUp => -4016,
+            ErrorCode::QuantityNotIncreasedByStepSize => -4023,
+            ErrorCode::PriceLowerThanMultiplierDown => -4024,
+            ErrorCode::InvalidLeverage => -4028,
+            ErrorCode::NoNeedToChangeMarginType => -4046,
+            ErrorCode::NoNeedToChangePositionSide => -4059,
+            ErrorCode::PositionSideNotMatch => -4061,
+            ErrorCode::MarketOrderReject => -4131,
+            ErrorCode::MinNotional => -4164,
+            ErrorCode::FokOrderReject => -5021,
+            ErrorCode::GtxOrderReject => -5022,
+            ErrorCode::Other(code) => *code,
+        }
+    }
+
+    /// Transient server side conditions, the same request may succeed later.
+    /// `Other` codes are never retried.
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            ErrorCode::Disconnected
+                | ErrorCode::TooManyRequests
+                | ErrorCode::ServerBusy
+                | ErrorCode::TooManyOrders
+                | ErrorCode::InvalidTimestamp
+        )
+    }
+
+    /// `Unknown` and `Timeout` leave the execution status unknown, the order
+    /// may have gone through. Check it before sending it again.
+    pub fn is_unknown_outcome(&self) -> bool {
+        matches!(self, ErrorCode::Unknown | ErrorCode::Timeout)
+    }
+
+    pub fn is_rate_limit(&self) -> bool {
+        matches!(self, ErrorCode::TooManyRequests | ErrorCode::TooManyOrders)
+    }
+
+    pub fn is_auth(&self) -> bool {
+        matches!(
+            self,
+            ErrorCode::Unauthorized
+                | ErrorCode::InvalidSignature
+                | ErrorCode::NotFound
+                | ErrorCode::InvalidListenKey
+                | ErrorCode::BadApiKeyFormat
+                | ErrorCode::RejectedApiKey
+        )
+    }
+}
+
+impl From<i16> for ErrorCode {
+    fn from(code: i16) -> Self {
+        match code {
+            -1000 => ErrorCode::Unknown,
+            -1001 => ErrorCode::Disconnected,
+            -1002 => ErrorCode::Unauthorized,
+            -1003 => ErrorCode::TooManyRequests,
+            -1006 => ErrorCode::UnexpectedResponse,
+            -1007 => ErrorCode::Timeout,
+            -1008 => ErrorCode::ServerBusy,
+            -1013 => ErrorCode::InvalidMessage,
+            -1014 => ErrorCode::UnknownOrderComposition,
+            -1015 => ErrorCode::TooManyOrders,
+            -1016 => ErrorCode::ServiceShuttingDown,
+            -1020 => ErrorCode::UnsupportedOperation,
+            -1021 => ErrorCode::InvalidTimestamp,
+            -1022 => ErrorCode::InvalidSignature,
+            -1099 => ErrorCode::NotFound,
+            -1100 => ErrorCode::IllegalChars,
+            -1101 => ErrorCode::TooManyParameters,
+            -1102 => ErrorCode::MandatoryParamEmptyOrMalformed,
+            -1103 => ErrorCode::UnknownParam,
+            -1104 => ErrorCode::UnreadParameters,
+            -1105 => ErrorCode::ParamEmpty,
+            -1106 => ErrorCode::ParamNotRequired,
+            -1108 => ErrorCode::ParamOverflow,
+            -1111 => ErrorCode::BadPrecision,
+            -1112 => ErrorCode::NoDepth,
+            -1114 => ErrorCode::TifNotRequired,
+            -1115 => ErrorCode::InvalidTif,
+            -1116 => ErrorCode::InvalidOrderType,
+            -1117 => ErrorCode::InvalidSide,
+            -1118 => ErrorCode::EmptyNewClientOrderId,
+            -1119 => ErrorCode::EmptyOrigClientOrderId,
+            -1120 => ErrorCode::BadInterval,
+            -1121 => ErrorCode::BadSymbol,
+            -1122 => ErrorCode::InvalidSymbolStatus,
+            -1125 => ErrorCode::InvalidListenKey,
+            -1127 => ErrorCode::MoreThanXxHours,
+            -1128 => ErrorCode::OptionalParamsBadCombo,
+            -1130 => ErrorCode::InvalidParameter,
+            -1131 => ErrorCode::BadRecvWindow,
+            -1134 => ErrorCode::BadStrategyType,
+            -2010 => ErrorCode::NewOrderRejected,
+            -2011 => ErrorCode::CancelRejected,
+            -2013 => ErrorCode::NoSuchOrder,
+            -2014 => ErrorCode::BadApiKeyFormat,
+            -2015 => ErrorCode::RejectedApiKey,
+            -2016 => ErrorCode::NoTradingWindow,
+            -2018 => ErrorCode::BalanceNotSufficient,
+            -2019 => ErrorCode::MarginNotSufficient,
+            -2020 => ErrorCode::UnableToFill,
+            -2021 => ErrorCode::OrderWouldImmediatelyTrigger,
+            -2022 => ErrorCode::ReduceOnlyReject,
+            -2023 => ErrorCode::UserInLiquidation,
+            -2024 => ErrorCode::PositionNotSufficient,
+            -2025 => ErrorCode::MaxOpenOrderExceeded,
+            -2026 => ErrorCode::ReduceOnlyOrderTypeNotSupported,
+            -2027 => ErrorCode::MaxLeverageRatio,
+            -2028 => ErrorCode::MinLeverageRatio,
+            -4000 => ErrorCode::InvalidOrderStatus,
+            -4001 => ErrorCode::PriceLessThanZero,
+            -4002 => ErrorCode::PriceGreaterThanMaxPrice,
+            -4003 => ErrorCode::QuantityLessThanZero,
+            -4004 => ErrorCode::QuantityLessThanMinQuantity,
+            -4005 => ErrorCode::QuantityGreaterThanMaxQuantity,
+            -4006 => ErrorCode::StopPriceLessThanZero,
+            -4007 => ErrorCode::StopPriceGreaterThanMaxPrice,
+            -4008 => ErrorCode::TickSizeLessThanZero,
+            -4014 => ErrorCode::PriceNotIncreasedByTickSize,
+            -4015 => ErrorCode::InvalidClientOrderIdLength,
+            -4016 => ErrorCode::PriceHigherThanMultiplierUp,
+            -4023 => ErrorCode::QuantityNotIncreasedByStepSize,
+            -4024 => ErrorCode::PriceLowerThanMultiplierDown,
+            -4028 => ErrorCode::InvalidLeverage,
+            -4046 => ErrorCode::NoNeedToChangeMarginType,
+            -4059 => ErrorCode::NoNeedToChangePositionSide,
+            -4061 => ErrorCode::PositionSideNotMatch,
+            -4131 => ErrorCode::MarketOrderReject,
+            -4164 => ErrorCode::MinNotional,
+            -5021 => ErrorCode::FokOrderReject,
+            -5022 => ErrorCode::GtxOrderReject,
+            code => ErrorCode::Other(code),
+        }
+    }
+}
@@ -19,6 +19,8 @@ pub enum WebSocketHost {
     CombinedStreamsPublic,
     CombinedStreamsMarket,
     CombinedStreamsPrivate,
+    SingleStreamTest,
+    CombinedStreamsTest,
 }
 
 impl AsRef<str> for WebSocketHost {
@@ -36,6 +38,8 @@ impl AsRef<str> for WebSocketHost {
             WebSocketHost::CombinedStreamsPrivate => {
                 "wss://fstream.binance.com/private/stream?streams="
             }
+            WebSocketHost::SingleStreamTest => "wss://fstream.binancefuture.com/ws/",
+            WebSocketHost::CombinedStreamsTest => "wss://fstream.binancefuture.com/stream?streams=",
         }
     }
 }
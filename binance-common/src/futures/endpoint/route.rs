@@ -113,7 +113,6 @@ impl AsRef<str> for Trade {
 }
 
 pub enum Account {
-    NewTransfer,
     FuturesBalance,
     FuturesBalanceV3,
     FuturesAccount,
@@ -131,7 +130,6 @@ pub enum Account {
 impl AsRef<str> for Account {
     fn as_ref(&self) -> &'static str {
         match self {
-            Account::NewTransfer => "/fapi/v1/transfer?",
             Account::FuturesBalance => "/fapi/v2/balance?",
             Account::FuturesBalanceV3 => "/fapi/v3/balance?",
             Account::FuturesAccount => "/fapi/v3/account?",
@@ -1,9 +1,11 @@
 use serde::Serialize;
+use serde_json::{Map, Value};
 
 use crate::enums::futures::{
     OrderResponseType, OrderSide, OrderType, PositionSide, PriceMatch, StpModes, TimeInForce,
     WorkingType,
 };
+use crate::error::BinanceError;
 
 #[derive(Clone, Debug, Default, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -244,6 +246,49 @@ impl<'a> NewOrderParams<'a> {
     }
 }
 
+/// Up to five orders placed at once. Binance takes them as a JSON list with
+/// every value a string, long enough to be better sent as a form body.
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchOrdersParams {
+    pub batch_orders: String,
+    pub recv_window: Option<u16>,
+}
+
+impl BatchOrdersParams {
+    pub fn new(orders: &[NewOrderParams<'_>]) -> Result<Self, BinanceError> {
+        let orders = orders
+            .iter()
+            .map(|order| match serde_json::to_value(order) {
+                Ok(Value::Object(fields)) => Ok(Value::Object(
+                    fields
+                        .into_iter()
+                        .filter(|(name, value)| name != "recvWindow" && !value.is_null())
+                        .map(|(name, value)| match value {
+                            Value::String(value) => (name, Value::String(value)),
+                            value => (name, Value::String(value.to_string())),
+                        })
+                        .collect::<Map<_, _>>(),
+                )),
+                Ok(_) => Err(BinanceError::BuildRequest(
+                    "An order did not serialize to an object!".to_string(),
+                )),
+                Err(error) => Err(BinanceError::BuildRequest(error.to_string())),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(BatchOrdersParams {
+            batch_orders: Value::Array(orders).to_string(),
+            recv_window: None,
+        })
+    }
+
+    pub fn recv_window(mut self, recv_window: u16) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
 #[derive(Clone, Debug, Default, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SetLeverageParams<'a> {
@@ -303,6 +348,71 @@ impl<'a> CancelAllOrdersParams<'a> {
     }
 }
 
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenOrdersParams<'a> {
+    pub symbol: Option<&'a str>,
+    pub recv_window: Option<u16>,
+}
+
+impl<'a> OpenOrdersParams<'a> {
+    pub fn new(symbol: &'a str) -> Self {
+        OpenOrdersParams {
+            symbol: Some(symbol),
+            ..Default::default()
+        }
+    }
+
+    /// Open orders of every symbol.
+    pub fn all() -> Self {
+        Self::default()
+    }
+
+    pub fn symbol(mut self, symbol: &'a str) -> Self {
+        self.symbol = Some(symbol);
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: u16) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AutoCancelAllOpenOrdersParams<'a> {
+    pub symbol: &'a str,
+    /// Milliseconds until every open order of the symbol is canceled, `0` stops the countdown.
+    pub countdown_time: u64,
+    pub recv_window: Option<u16>,
+}
+
+impl<'a> AutoCancelAllOpenOrdersParams<'a> {
+    pub fn new(symbol: &'a str, countdown_time: u64) -> Self {
+        AutoCancelAllOpenOrdersParams {
+            symbol,
+            countdown_time,
+            ..Default::default()
+        }
+    }
+
+    pub fn symbol(mut self, symbol: &'a str) -> Self {
+        self.symbol = symbol;
+        self
+    }
+
+    pub fn countdown_time(mut self, countdown_time: u64) -> Self {
+        self.countdown_time = countdown_time;
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: u16) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
 #[derive(Clone, Debug, Default, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PositionRiskV3Params<'a> {
@@ -442,3 +552,59 @@ impl<'a> GetOpenOrderParams<'a> {
         self
     }
 }
+
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserTradesParams<'a> {
+    pub symbol: &'a str,
+    pub order_id: Option<u64>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub from_id: Option<u64>,
+    pub limit: Option<u16>,
+    pub recv_window: Option<u16>,
+}
+
+impl<'a> UserTradesParams<'a> {
+    pub fn new(symbol: &'a str) -> Self {
+        UserTradesParams {
+            symbol,
+            ..Default::default()
+        }
+    }
+
+    pub fn symbol(mut self, symbol: &'a str) -> Self {
+        self.symbol = symbol;
+        self
+    }
+
+    pub fn order_id(mut self, order_id: u64) -> Self {
+        self.order_id = Some(order_id);
+        self
+    }
+
+    pub fn start_time(mut self, start_time: u64) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    pub fn end_time(mut self, end_time: u64) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    pub fn from_id(mut self, from_id: u64) -> Self {
+        self.from_id = Some(from_id);
+        self
+    }
+
+    pub fn limit(mut self, limit: u16) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: u16) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
@@ -1,11 +1,16 @@
-use std::fmt;
+use std::{collections::HashSet, fmt, sync::Arc};
 
 use crate::{
     enums::{Interval, WebSocketStreamRate},
-    futures::endpoint::host::WebSocketHost,
+    error::BinanceError,
+    futures::model::response::market::ExchangeInformationResponse,
 };
 
-#[derive(Debug)]
+/// Binance refuses connections subscribing to more streams than this.
+pub const MAX_STREAMS_PER_CONNECTION: usize = 1024;
+
+/// Shortcuts for common pairs, any symbol can be passed as a string instead.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum WebSocketSymbol {
     AdaUsdc,
     AdaUsdt,
@@ -41,9 +46,9 @@ pub enum WebSocketSymbol {
     PenguUsdt,
 }
 
-impl fmt::Display for WebSocketSymbol {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let symbol: &'static str = match self {
+impl AsRef<str> for WebSocketSymbol {
+    fn as_ref(&self) -> &'static str {
+        match self {
             WebSocketSymbol::AdaUsdc => "adausdc",
             WebSocketSymbol::AdaUsdt => "adausdt",
             WebSocketSymbol::AvaxUsdc => "avaxusdc",
@@ -76,91 +81,211 @@ impl fmt::Display for WebSocketSymbol {
             WebSocketSymbol::XrpUsdc => "xrpusdc",
             WebSocketSymbol::XrpUsdt => "xrpusdt",
             WebSocketSymbol::PenguUsdt => "penguusdt",
-        };
-        write!(f, "{}", symbol)
+        }
+    }
+}
+
+impl fmt::Display for WebSocketSymbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
+/// Symbols listed by `exchangeInfo`, fetched once and shared between params
+/// to validate stream names before connecting.
+#[derive(Clone, Debug, Default)]
+pub struct WebSocketSymbols {
+    symbols: HashSet<String>,
+}
+
+impl WebSocketSymbols {
+    pub fn new<S: AsRef<str>>(symbols: impl IntoIterator<Item = S>) -> Self {
+        WebSocketSymbols {
+            symbols: symbols
+                .into_iter()
+                .map(|symbol| symbol.as_ref().to_ascii_lowercase())
+                .collect(),
+        }
+    }
+
+    pub fn contains(&self, symbol: impl AsRef<str>) -> bool {
+        self.symbols.contains(&symbol.as_ref().to_ascii_lowercase())
+    }
+
+    pub fn len(&self) -> usize {
+        self.symbols.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.symbols.is_empty()
     }
 }
 
+impl From<&ExchangeInformationResponse> for WebSocketSymbols {
+    fn from(exchange_information: &ExchangeInformationResponse) -> Self {
+        WebSocketSymbols::new(
+            exchange_information
+                .symbols
+                .iter()
+                .map(|symbol| symbol.symbol.as_str()),
+        )
+    }
+}
+
+/// Builder of stream routes. Symbols are lowercased, duplicated streams are
+/// dropped and `routes` splits the streams into as many connections as the
+/// per-connection limit requires.
+#[derive(Clone, Debug)]
 pub struct WebSocketParams {
-    pub route: String,
+    host: String,
+    streams: Vec<String>,
+    /// Streams already pushed, keeping duplicate checks constant time.
+    seen: HashSet<String>,
+    symbols: Option<Arc<WebSocketSymbols>>,
+    max_streams: usize,
 }
 
 impl WebSocketParams {
-    pub fn new(host: WebSocketHost) -> Self {
+    /// `host` is a `WebSocketHost` or any other url, such as a testnet or mock server.
+    pub fn new(host: impl AsRef<str>) -> Self {
         WebSocketParams {
-            route: host.as_ref().to_string(),
+            host: host.as_ref().to_string(),
+            streams: Vec::new(),
+            seen: HashSet::new(),
+            symbols: None,
+            max_streams: MAX_STREAMS_PER_CONNECTION,
         }
     }
 
-    pub fn agg_trade(mut self, symbol: WebSocketSymbol) -> Self {
-        self.route
-            .push_str(format!("{}@aggTrade/", symbol).as_str());
+    /// Rejects streams of symbols missing from `symbols` when building routes.
+    pub fn symbols(mut self, symbols: Arc<WebSocketSymbols>) -> Self {
+        self.symbols = Some(symbols);
         self
     }
 
-    pub fn mark_price(mut self, symbol: WebSocketSymbol, rate: WebSocketStreamRate) -> Self {
-        self.route
-            .push_str(format!("{}@markPrice@{}/", symbol, rate).as_str());
+    /// Streams per connection, capped at `MAX_STREAMS_PER_CONNECTION`.
+    pub fn max_streams(mut self, max_streams: usize) -> Self {
+        self.max_streams = max_streams.clamp(1, MAX_STREAMS_PER_CONNECTION);
         self
     }
 
-    pub fn mark_price_all(mut self, rate: WebSocketStreamRate) -> Self {
-        self.route
-            .push_str(format!("!@markPrice@{}/", rate).as_str());
-        self
+    pub fn streams(&self) -> &[String] {
+        &self.streams
     }
 
-    pub fn kline_candlesticks(mut self, symbol: WebSocketSymbol, interval: Interval) -> Self {
-        self.route
-            .push_str(format!("{}@kline_{}/", symbol, interval.as_ref()).as_str());
-        self
+    /// One route per connection needed to carry every stream.
+    pub fn routes(&self) -> Result<Vec<String>, BinanceError> {
+        if self.streams.is_empty() {
+            return Err(BinanceError::BuildRequest(
+                "No WebSocket stream was requested.".to_string(),
+            ));
+        }
+
+        if let Some(symbols) = &self.symbols {
+            let unknown: Vec<&str> = self
+                .streams
+                .iter()
+                .filter_map(|stream| stream.split_once('@').map(|(symbol, _)| symbol))
+                .filter(|symbol| !symbol.starts_with('!') && !symbols.contains(symbol))
+                .collect();
+
+            if !unknown.is_empty() {
+                return Err(BinanceError::BuildRequest(format!(
+                    "Unknown symbols for WebSocket streams: {}",
+                    unknown.join(", ")
+                )));
+            }
+        }
+
+        Ok(self
+            .streams
+            .chunks(self.max_streams)
+            .map(|streams| format!("{}{}", self.host, streams.join("/")))
+            .collect())
     }
 
-    pub fn symbol_ticker_mini(mut self, symbol: WebSocketSymbol) -> Self {
-        self.route
-            .push_str(format!("{}@miniTicker/", symbol).as_str());
-        self
+    /// The route of a single connection, fails if the streams need several.
+    pub fn route(&self) -> Result<String, BinanceError> {
+        let mut routes = self.routes()?;
+
+        match routes.len() {
+            1 => Ok(routes.remove(0)),
+            count => Err(BinanceError::BuildRequest(format!(
+                "The streams need {} connections, use routes instead.",
+                count
+            ))),
+        }
     }
 
-    pub fn symbol_ticker_all(mut self) -> Self {
-        self.route.push_str("!ticker@arr/");
+    fn push(mut self, stream: String) -> Self {
+        if self.seen.insert(stream.clone()) {
+            self.streams.push(stream);
+        }
+
         self
     }
 
-    pub fn symbol_ticker(mut self, symbol: WebSocketSymbol) -> Self {
-        self.route.push_str(format!("{}@ticker/", symbol).as_str());
-        self
+    pub fn agg_trade(self, symbol: impl AsRef<str>) -> Self {
+        let stream = format!("{}@aggTrade", normalize(symbol));
+        self.push(stream)
     }
 
-    pub fn symbol_ticker_all_mini(mut self) -> Self {
-        self.route.push_str("!miniTicker@arr/");
-        self
+    pub fn mark_price(self, symbol: impl AsRef<str>, rate: WebSocketStreamRate) -> Self {
+        let stream = format!("{}@markPrice@{}", normalize(symbol), rate);
+        self.push(stream)
     }
 
-    pub fn all_book_tickers(mut self) -> Self {
-        self.route.push_str("!bookTicker/");
-        self
+    pub fn mark_price_all(self, rate: WebSocketStreamRate) -> Self {
+        self.push(format!("!markPrice@arr@{}", rate))
     }
 
-    pub fn liquidation_order(mut self, symbol: WebSocketSymbol) -> Self {
-        self.route
-            .push_str(format!("{}@forceOrder/", symbol).as_str());
-        self
+    pub fn kline_candlesticks(self, symbol: impl AsRef<str>, interval: Interval) -> Self {
+        let stream = format!("{}@kline_{}", normalize(symbol), interval.as_str());
+        self.push(stream)
     }
 
-    pub fn all_market_liquidation_order(mut self) -> Self {
-        self.route.push_str("!forceOrder@arr/");
-        self
+    pub fn symbol_ticker_mini(self, symbol: impl AsRef<str>) -> Self {
+        let stream = format!("{}@miniTicker", normalize(symbol));
+        self.push(stream)
+    }
+
+    pub fn symbol_ticker_all(self) -> Self {
+        self.push("!ticker@arr".to_string())
+    }
+
+    pub fn symbol_ticker(self, symbol: impl AsRef<str>) -> Self {
+        let stream = format!("{}@ticker", normalize(symbol));
+        self.push(stream)
+    }
+
+    pub fn symbol_ticker_all_mini(self) -> Self {
+        self.push("!miniTicker@arr".to_string())
+    }
+
+    pub fn all_book_tickers(self) -> Self {
+        self.push("!bookTicker".to_string())
+    }
+
+    pub fn liquidation_order(self, symbol: impl AsRef<str>) -> Self {
+        let stream = format!("{}@forceOrder", normalize(symbol));
+        self.push(stream)
+    }
+
+    pub fn all_market_liquidation_order(self) -> Self {
+        self.push("!forceOrder@arr".to_string())
     }
 
     pub fn partial_book_depth(
-        mut self,
-        symbol: WebSocketSymbol,
+        self,
+        symbol: impl AsRef<str>,
         levels: u8,
         milliseconds: WebSocketStreamRate,
     ) -> Self {
-        self.route
-            .push_str(format!("{}@depth{}@{}/", symbol, levels, milliseconds).as_str());
-        self
+        let stream = format!("{}@depth{}@{}", normalize(symbol), levels, milliseconds);
+        self.push(stream)
     }
 }
+
+fn normalize(symbol: impl AsRef<str>) -> String {
+    symbol.as_ref().trim().to_ascii_lowercase()
+}
@@ -178,5 +178,9 @@ pub struct IncomeHistoryResponse {
     pub time: u64,
     pub tran_id: u64,
 
+    /// Trade behind `REALIZED_PNL` and `COMMISSION` entries, empty otherwise.
+    #[serde(default)]
+    pub trade_id: String,
+
     pub taker_commission_rate: Option<String>,
 }
@@ -1,4 +1,4 @@
-pub use crate::spot::model::response::general::{EmptyResponse, ServerTimeResponse};
+pub use crate::spot::model::response::general::{EmptyResponse, ServerTimeResponse, SymbolFilter};
 pub use crate::spot::model::response::market::{Asks, Bids, KlinesResponse};
 pub use serde::{Deserialize, Serialize};
 use serde_with::{DisplayFromStr, serde_as};
@@ -30,6 +30,9 @@ pub struct SymbolResponse {
     pub base_asset_precision: u64,
     pub quote_asset: String,
     pub order_types: Vec<String>,
+
+    #[serde(default)]
+    pub filters: Vec<SymbolFilter>,
 }
 
 #[serde_as]
@@ -0,0 +1,196 @@
+use serde::Deserialize;
+use serde_with::{DisplayFromStr, serde_as};
+
+use crate::enums::futures::{OrderSide, OrderStatus, PositionSide, TimeInForce};
+
+/// Events pushed on a USDT-M futures user data stream.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(tag = "e")]
+pub enum UserDataEvent {
+    #[serde(rename = "ACCOUNT_UPDATE")]
+    AccountUpdate(AccountUpdateEvent),
+
+    #[serde(rename = "ORDER_TRADE_UPDATE")]
+    OrderTradeUpdate(Box<OrderTradeUpdateEvent>),
+
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct AccountUpdateEvent {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+
+    #[serde(rename = "T")]
+    pub transaction_time: u64,
+
+    #[serde(rename = "a")]
+    pub account: AccountUpdate,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct AccountUpdate {
+    /// What caused the update, e.g. `ORDER` or `FUNDING_FEE`.
+    #[serde(rename = "m")]
+    pub reason: String,
+
+    #[serde(rename = "B")]
+    pub balances: Vec<BalanceUpdate>,
+
+    /// Only the positions the update changed.
+    #[serde(rename = "P", default)]
+    pub positions: Vec<PositionUpdate>,
+}
+
+#[serde_as]
+#[derive(Clone, Debug, Deserialize)]
+pub struct BalanceUpdate {
+    #[serde(rename = "a")]
+    pub asset: String,
+
+    #[serde(rename = "wb")]
+    #[serde_as(as = "DisplayFromStr")]
+    pub wallet_balance: f64,
+
+    #[serde(rename = "cw")]
+    #[serde_as(as = "DisplayFromStr")]
+    pub cross_wallet_balance: f64,
+
+    #[serde(rename = "bc")]
+    #[serde_as(as = "DisplayFromStr")]
+    pub balance_change: f64,
+}
+
+#[serde_as]
+#[derive(Clone, Debug, Deserialize)]
+pub struct PositionUpdate {
+    #[serde(rename = "s")]
+    pub symbol: String,
+
+    #[serde(rename = "pa")]
+    #[serde_as(as = "DisplayFromStr")]
+    pub position_amount: f64,
+
+    #[serde(rename = "ep")]
+    #[serde_as(as = "DisplayFromStr")]
+    pub entry_price: f64,
+
+    #[serde(rename = "bep")]
+    #[serde_as(as = "DisplayFromStr")]
+    pub break_even_price: f64,
+
+    #[serde(rename = "cr")]
+    #[serde_as(as = "DisplayFromStr")]
+    pub accumulated_realized: f64,
+
+    #[serde(rename = "up")]
+    #[serde_as(as = "DisplayFromStr")]
+    pub unrealized_pnl: f64,
+
+    /// `cross` or `isolated`.
+    #[serde(rename = "mt")]
+    pub margin_type: String,
+
+    #[serde(rename = "iw")]
+    #[serde_as(as = "DisplayFromStr")]
+    pub isolated_wallet: f64,
+
+    #[serde(rename = "ps")]
+    pub position_side: PositionSide,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct OrderTradeUpdateEvent {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+
+    #[serde(rename = "T")]
+    pub transaction_time: u64,
+
+    #[serde(rename = "o")]
+    pub order: OrderUpdate,
+}
+
+#[serde_as]
+#[derive(Clone, Debug, Deserialize)]
+pub struct OrderUpdate {
+    #[serde(rename = "s")]
+    pub symbol: String,
+
+    #[serde(rename = "c")]
+    pub client_order_id: String,
+
+    #[serde(rename = "S")]
+    pub side: OrderSide,
+
+    #[serde(rename = "o")]
+    pub order_type: String,
+
+    #[serde(rename = "f")]
+    pub time_in_force: TimeInForce,
+
+    #[serde(rename = "q")]
+    #[serde_as(as = "DisplayFromStr")]
+    pub quantity: f64,
+
+    #[serde(rename = "p")]
+    #[serde_as(as = "DisplayFromStr")]
+    pub price: f64,
+
+    #[serde(rename = "ap")]
+    #[serde_as(as = "DisplayFromStr")]
+    pub average_price: f64,
+
+    #[serde(rename = "sp")]
+    #[serde_as(as = "DisplayFromStr")]
+    pub stop_price: f64,
+
+    #[serde(rename = "x")]
+    pub execution_type: String,
+
+    #[serde(rename = "X")]
+    pub status: OrderStatus,
+
+    #[serde(rename = "i")]
+    pub order_id: u64,
+
+    #[serde(rename = "l")]
+    #[serde_as(as = "DisplayFromStr")]
+    pub last_quantity: f64,
+
+    #[serde(rename = "z")]
+    #[serde_as(as = "DisplayFromStr")]
+    pub cumulative_quantity: f64,
+
+    #[serde(rename = "L")]
+    #[serde_as(as = "DisplayFromStr")]
+    pub last_price: f64,
+
+    /// Only sent with trades.
+    #[serde(rename = "N", default)]
+    pub commission_asset: Option<String>,
+
+    #[serde(rename = "n", default)]
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    pub commission: Option<f64>,
+
+    #[serde(rename = "T")]
+    pub trade_time: u64,
+
+    #[serde(rename = "t")]
+    pub trade_id: u64,
+
+    #[serde(rename = "m")]
+    pub is_maker: bool,
+
+    #[serde(rename = "R")]
+    pub reduce_only: bool,
+
+    #[serde(rename = "ps")]
+    pub position_side: PositionSide,
+
+    #[serde(rename = "rp")]
+    #[serde_as(as = "DisplayFromStr")]
+    pub realized_profit: f64,
+}
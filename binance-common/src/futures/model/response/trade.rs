@@ -108,6 +108,20 @@ pub struct SetLeverageResponse {
     pub symbol: String,
 }
 
+/// Each order of a batch is placed or rejected on its own.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+pub enum BatchOrderResponse {
+    Order(Box<OrderResponse>),
+    Rejected(BatchOrderError),
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct BatchOrderError {
+    pub code: i16,
+    pub msg: String,
+}
+
 #[derive(Clone, Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CancelAllOrdersResponse {
@@ -115,6 +129,16 @@ pub struct CancelAllOrdersResponse {
     pub msg: String,
 }
 
+#[serde_as]
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AutoCancelAllOpenOrdersResponse {
+    pub symbol: String,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub countdown_time: u64,
+}
+
 #[serde_as]
 #[derive(Clone, Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -225,3 +249,34 @@ pub struct GetOrderResponse {
     pub self_trade_prevention_mode: StpModes,
     pub good_till_date: u64,
 }
+
+#[serde_as]
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserTradesResponse {
+    pub symbol: String,
+    pub id: u64,
+    pub order_id: u64,
+    pub side: OrderSide,
+    pub position_side: PositionSide,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub price: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub qty: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub quote_qty: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub realized_pnl: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub commission: f64,
+
+    pub commission_asset: String,
+    pub time: u64,
+    pub buyer: bool,
+    pub maker: bool,
+}
@@ -1,6 +1,10 @@
+pub mod borrowed;
+
 use serde::Deserialize;
 use serde_with::{DisplayFromStr, serde_as};
 
+use crate::spot::model::response::market::{Asks, Bids};
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct CombinedStreamResponse {
     pub stream: String,
@@ -22,6 +26,12 @@ pub enum WebSocketResponse {
     #[serde(rename = "24hrMiniTicker")]
     MiniTicker(MiniTickerResponse),
 
+    #[serde(rename = "bookTicker")]
+    BookTicker(BookTickerResponse),
+
+    #[serde(rename = "depthUpdate")]
+    DepthUpdate(DepthUpdateResponse),
+
     #[serde(other)]
     Init,
 }
@@ -197,6 +207,65 @@ pub struct MiniTickerResponse {
     pub quote_volume: f64,
 }
 
+#[serde_as]
+#[derive(Clone, Debug, Deserialize)]
+pub struct BookTickerResponse {
+    #[serde(rename = "u")]
+    pub update_id: u64,
+
+    #[serde(rename = "E")]
+    pub event_time: u64,
+
+    #[serde(rename = "T")]
+    pub transaction_time: u64,
+
+    #[serde(rename = "s")]
+    pub symbol: String,
+
+    #[serde(rename = "b")]
+    #[serde_as(as = "DisplayFromStr")]
+    pub best_bid_price: f64,
+
+    #[serde(rename = "B")]
+    #[serde_as(as = "DisplayFromStr")]
+    pub best_bid_quantity: f64,
+
+    #[serde(rename = "a")]
+    #[serde_as(as = "DisplayFromStr")]
+    pub best_ask_price: f64,
+
+    #[serde(rename = "A")]
+    #[serde_as(as = "DisplayFromStr")]
+    pub best_ask_quantity: f64,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct DepthUpdateResponse {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+
+    #[serde(rename = "T")]
+    pub transaction_time: u64,
+
+    #[serde(rename = "s")]
+    pub symbol: String,
+
+    #[serde(rename = "U")]
+    pub first_update_id: u64,
+
+    #[serde(rename = "u")]
+    pub final_update_id: u64,
+
+    #[serde(rename = "pu")]
+    pub previous_final_update_id: u64,
+
+    #[serde(rename = "b")]
+    pub bids: Vec<Bids>,
+
+    #[serde(rename = "a")]
+    pub asks: Vec<Asks>,
+}
+
 #[derive(Clone, Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ListenKeyResponse {
@@ -0,0 +1,317 @@
+//! Borrowed counterparts of the hot stream payloads.
+//!
+//! Symbols and prices stay `&str` slices of the frame and numbers are only
+//! parsed when asked for, so decoding a `bookTicker` or `depthUpdate` frame
+//! allocates nothing beyond the price levels of a depth update.
+
+use serde::Deserialize;
+
+use crate::{enums::WebSocketType, error::BinanceError};
+
+/// Number kept as sent by Binance, parsed on demand.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(transparent)]
+pub struct Decimal<'a>(#[serde(borrow)] &'a str);
+
+impl<'a> Decimal<'a> {
+    pub fn new(value: &'a str) -> Self {
+        Decimal(value)
+    }
+
+    pub fn as_str(&self) -> &'a str {
+        self.0
+    }
+
+    pub fn to_f64(&self) -> Option<f64> {
+        self.0.parse().ok()
+    }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize)]
+pub struct Level<'a> {
+    #[serde(borrow)]
+    pub price: Decimal<'a>,
+
+    #[serde(borrow)]
+    pub quantity: Decimal<'a>,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize)]
+pub struct AggTradeRef<'a> {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+
+    #[serde(rename = "s")]
+    pub symbol: &'a str,
+
+    #[serde(rename = "a")]
+    pub aggregate_trade_id: u64,
+
+    #[serde(rename = "p", borrow)]
+    pub price: Decimal<'a>,
+
+    #[serde(rename = "q", borrow)]
+    pub quantity: Decimal<'a>,
+
+    #[serde(rename = "f")]
+    pub first_trade_id: u64,
+
+    #[serde(rename = "l")]
+    pub last_trade_id: u64,
+
+    #[serde(rename = "T")]
+    pub trade_time: u64,
+
+    #[serde(rename = "m")]
+    pub is_buyer_market_maker: bool,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize)]
+pub struct BookTickerRef<'a> {
+    #[serde(rename = "u")]
+    pub update_id: u64,
+
+    #[serde(rename = "E")]
+    pub event_time: u64,
+
+    #[serde(rename = "T")]
+    pub transaction_time: u64,
+
+    #[serde(rename = "s")]
+    pub symbol: &'a str,
+
+    #[serde(rename = "b", borrow)]
+    pub best_bid_price: Decimal<'a>,
+
+    #[serde(rename = "B", borrow)]
+    pub best_bid_quantity: Decimal<'a>,
+
+    #[serde(rename = "a", borrow)]
+    pub best_ask_price: Decimal<'a>,
+
+    #[serde(rename = "A", borrow)]
+    pub best_ask_quantity: Decimal<'a>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct DepthUpdateRef<'a> {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+
+    #[serde(rename = "T")]
+    pub transaction_time: u64,
+
+    #[serde(rename = "s")]
+    pub symbol: &'a str,
+
+    #[serde(rename = "U")]
+    pub first_update_id: u64,
+
+    #[serde(rename = "u")]
+    pub final_update_id: u64,
+
+    #[serde(rename = "pu")]
+    pub previous_final_update_id: u64,
+
+    #[serde(rename = "b", borrow)]
+    pub bids: Vec<Level<'a>>,
+
+    #[serde(rename = "a", borrow)]
+    pub asks: Vec<Level<'a>>,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize)]
+pub struct MarkPriceRef<'a> {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+
+    #[serde(rename = "s")]
+    pub symbol: &'a str,
+
+    #[serde(rename = "p", borrow)]
+    pub mark_price: Decimal<'a>,
+
+    #[serde(rename = "i", borrow)]
+    pub index_price: Decimal<'a>,
+
+    #[serde(rename = "P", borrow)]
+    pub estimated_settle_price: Decimal<'a>,
+
+    #[serde(rename = "r", borrow)]
+    pub funding_rate: Decimal<'a>,
+
+    #[serde(rename = "T")]
+    pub next_funding_time: u64,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize)]
+pub struct MiniTickerRef<'a> {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+
+    #[serde(rename = "s")]
+    pub symbol: &'a str,
+
+    #[serde(rename = "c", borrow)]
+    pub close_price: Decimal<'a>,
+
+    #[serde(rename = "o", borrow)]
+    pub open_price: Decimal<'a>,
+
+    #[serde(rename = "h", borrow)]
+    pub high_price: Decimal<'a>,
+
+    #[serde(rename = "l", borrow)]
+    pub low_price: Decimal<'a>,
+
+    #[serde(rename = "v", borrow)]
+    pub base_volume: Decimal<'a>,
+
+    #[serde(rename = "q", borrow)]
+    pub quote_volume: Decimal<'a>,
+}
+
+/// Borrowed `WebSocketResponse`. Events without a borrowed model, such as
+/// klines, decode to `Unsupported` and should go through the owned path.
+#[derive(Clone, Debug)]
+pub enum WebSocketResponseRef<'a> {
+    AggTrade(AggTradeRef<'a>),
+    BookTicker(BookTickerRef<'a>),
+    DepthUpdate(DepthUpdateRef<'a>),
+    MarkPrice(MarkPriceRef<'a>),
+    MiniTicker(MiniTickerRef<'a>),
+    Unsupported,
+}
+
+impl<'a> WebSocketResponseRef<'a> {
+    /// Decodes a frame with `serde_json` without copying strings out of it.
+    pub fn decode(message: &'a str, websocket_type: WebSocketType) -> Result<Self, BinanceError> {
+        decode_event(Event::of(message.as_bytes()), Json(message), websocket_type)
+    }
+
+    /// Decodes a frame with `simd-json`, which parses `message` in place.
+    #[cfg(feature = "simd")]
+    pub fn decode_simd(
+        message: &'a mut [u8],
+        websocket_type: WebSocketType,
+    ) -> Result<Self, BinanceError> {
+        let event = Event::of(message);
+        decode_event(event, Simd(message), websocket_type)
+    }
+}
+
+/// Reusable buffer for `decode_simd`, frames are copied into it since the
+/// parser needs them mutable.
+#[cfg(feature = "simd")]
+#[derive(Debug, Default)]
+pub struct SimdDecoder {
+    buffer: Vec<u8>,
+}
+
+#[cfg(feature = "simd")]
+impl SimdDecoder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn decode<'a>(
+        &'a mut self,
+        message: &str,
+        websocket_type: WebSocketType,
+    ) -> Result<WebSocketResponseRef<'a>, BinanceError> {
+        self.buffer.clear();
+        self.buffer.extend_from_slice(message.as_bytes());
+
+        WebSocketResponseRef::decode_simd(&mut self.buffer, websocket_type)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Event {
+    AggTrade,
+    BookTicker,
+    DepthUpdate,
+    MarkPrice,
+    MiniTicker,
+    Unsupported,
+}
+
+impl Event {
+    /// Binance writes the event type first, so the first `"e":"` names it for
+    /// raw and combined frames alike, without parsing the frame twice.
+    fn of(message: &[u8]) -> Event {
+        const KEY: &[u8] = b"\"e\":\"";
+
+        let event = message
+            .windows(KEY.len())
+            .position(|window| window == KEY)
+            .map(|start| &message[start + KEY.len()..])
+            .and_then(|rest| {
+                rest.iter()
+                    .position(|byte| *byte == b'"')
+                    .map(|end| &rest[..end])
+            });
+
+        match event {
+            Some(b"aggTrade") => Event::AggTrade,
+            Some(b"bookTicker") => Event::BookTicker,
+            Some(b"depthUpdate") => Event::DepthUpdate,
+            Some(b"markPriceUpdate") => Event::MarkPrice,
+            Some(b"24hrMiniTicker") => Event::MiniTicker,
+            _ => Event::Unsupported,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct Combined<T> {
+    data: T,
+}
+
+trait Parser<'a> {
+    fn parse<T: Deserialize<'a>>(self) -> Result<T, BinanceError>;
+}
+
+struct Json<'a>(&'a str);
+
+impl<'a> Parser<'a> for Json<'a> {
+    fn parse<T: Deserialize<'a>>(self) -> Result<T, BinanceError> {
+        serde_json::from_str(self.0).map_err(BinanceError::Deserialize)
+    }
+}
+
+#[cfg(feature = "simd")]
+struct Simd<'a>(&'a mut [u8]);
+
+#[cfg(feature = "simd")]
+impl<'a> Parser<'a> for Simd<'a> {
+    fn parse<T: Deserialize<'a>>(self) -> Result<T, BinanceError> {
+        simd_json::serde::from_slice(self.0)
+            .map_err(|error| BinanceError::Deserialize(serde::de::Error::custom(error)))
+    }
+}
+
+fn decode_event<'a>(
+    event: Event,
+    parser: impl Parser<'a>,
+    websocket_type: WebSocketType,
+) -> Result<WebSocketResponseRef<'a>, BinanceError> {
+    Ok(match event {
+        Event::AggTrade => WebSocketResponseRef::AggTrade(parse(parser, websocket_type)?),
+        Event::BookTicker => WebSocketResponseRef::BookTicker(parse(parser, websocket_type)?),
+        Event::DepthUpdate => WebSocketResponseRef::DepthUpdate(parse(parser, websocket_type)?),
+        Event::MarkPrice => WebSocketResponseRef::MarkPrice(parse(parser, websocket_type)?),
+        Event::MiniTicker => WebSocketResponseRef::MiniTicker(parse(parser, websocket_type)?),
+        Event::Unsupported => WebSocketResponseRef::Unsupported,
+    })
+}
+
+fn parse<'a, T: Deserialize<'a>>(
+    parser: impl Parser<'a>,
+    websocket_type: WebSocketType,
+) -> Result<T, BinanceError> {
+    match websocket_type {
+        WebSocketType::SingleStream => parser.parse(),
+        WebSocketType::MultiStream => parser.parse::<Combined<T>>().map(|combined| combined.data),
+    }
+}
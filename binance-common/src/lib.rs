@@ -1,5 +1,10 @@
 pub mod enums;
 pub mod error;
 pub mod futures;
+pub mod margin;
+pub mod portfolio_margin;
 pub mod spot;
+pub mod sub_account;
+pub mod symbol;
 pub mod url;
+pub mod wallet;
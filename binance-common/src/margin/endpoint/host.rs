@@ -0,0 +1,11 @@
+pub enum WebSocketHost {
+    UserData,
+}
+
+impl AsRef<str> for WebSocketHost {
+    fn as_ref(&self) -> &'static str {
+        match self {
+            WebSocketHost::UserData => "wss://stream.binance.com:9443/ws/",
+        }
+    }
+}
@@ -0,0 +1,65 @@
+pub enum Account {
+    BorrowRepay,
+    CrossAccount,
+    IsolatedAccount,
+    InterestHistory,
+    MaxBorrowable,
+    MaxTransferable,
+}
+
+impl AsRef<str> for Account {
+    fn as_ref(&self) -> &'static str {
+        match self {
+            Account::BorrowRepay => "/sapi/v1/margin/borrow-repay?",
+            Account::CrossAccount => "/sapi/v1/margin/account?",
+            Account::IsolatedAccount => "/sapi/v1/margin/isolated/account?",
+            Account::InterestHistory => "/sapi/v1/margin/interestHistory?",
+            Account::MaxBorrowable => "/sapi/v1/margin/maxBorrowable?",
+            Account::MaxTransferable => "/sapi/v1/margin/maxTransferable?",
+        }
+    }
+}
+
+pub enum Trade {
+    NewOrder,
+    GetOrder,
+    CancelOrder,
+    CancelAllOrders,
+    OpenOrders,
+    AllOrders,
+    NewOco,
+    GetOco,
+    CancelOco,
+    MyTrades,
+}
+
+impl AsRef<str> for Trade {
+    fn as_ref(&self) -> &'static str {
+        match self {
+            Trade::NewOrder => "/sapi/v1/margin/order?",
+            Trade::GetOrder => "/sapi/v1/margin/order?",
+            Trade::CancelOrder => "/sapi/v1/margin/order?",
+            Trade::CancelAllOrders => "/sapi/v1/margin/openOrders?",
+            Trade::OpenOrders => "/sapi/v1/margin/openOrders?",
+            Trade::AllOrders => "/sapi/v1/margin/allOrders?",
+            Trade::NewOco => "/sapi/v1/margin/order/oco?",
+            Trade::GetOco => "/sapi/v1/margin/orderList?",
+            Trade::CancelOco => "/sapi/v1/margin/orderList?",
+            Trade::MyTrades => "/sapi/v1/margin/myTrades?",
+        }
+    }
+}
+
+pub enum UserStream {
+    Cross,
+    Isolated,
+}
+
+impl AsRef<str> for UserStream {
+    fn as_ref(&self) -> &'static str {
+        match self {
+            UserStream::Cross => "/sapi/v1/userDataStream?",
+            UserStream::Isolated => "/sapi/v1/userDataStream/isolated?",
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use serde::Serializer;
+
+pub mod account;
+pub mod stream;
+pub mod trade;
+
+/// Margin endpoints take booleans as `TRUE` and `FALSE`.
+pub(crate) fn flag<S: Serializer>(value: &Option<bool>, serializer: S) -> Result<S::Ok, S::Error> {
+    match value {
+        Some(true) => serializer.serialize_str("TRUE"),
+        Some(false) => serializer.serialize_str("FALSE"),
+        None => serializer.serialize_none(),
+    }
+}
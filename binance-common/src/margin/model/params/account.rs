@@ -0,0 +1,242 @@
+use serde::Serialize;
+
+use crate::enums::margin::BorrowRepayType;
+
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BorrowRepayParams<'a> {
+    pub asset: &'a str,
+
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "super::flag"
+    )]
+    pub is_isolated: Option<bool>,
+    pub symbol: Option<&'a str>,
+    pub amount: f64,
+    pub r#type: BorrowRepayType,
+    pub recv_window: Option<u16>,
+}
+
+impl<'a> BorrowRepayParams<'a> {
+    pub fn new(asset: &'a str, amount: f64, r#type: BorrowRepayType) -> Self {
+        BorrowRepayParams {
+            asset,
+            amount,
+            r#type,
+            ..Default::default()
+        }
+    }
+
+    pub fn borrow(asset: &'a str, amount: f64) -> Self {
+        Self::new(asset, amount, BorrowRepayType::Borrow)
+    }
+
+    pub fn repay(asset: &'a str, amount: f64) -> Self {
+        Self::new(asset, amount, BorrowRepayType::Repay)
+    }
+
+    /// Borrows or repays in the isolated account of `symbol`.
+    pub fn isolated(mut self, symbol: &'a str) -> Self {
+        self.is_isolated = Some(true);
+        self.symbol = Some(symbol);
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: u16) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BorrowRepayRecordsParams<'a> {
+    pub r#type: BorrowRepayType,
+
+    pub asset: Option<&'a str>,
+    pub isolated_symbol: Option<&'a str>,
+    pub tx_id: Option<u64>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub current: Option<u32>,
+    pub size: Option<u8>,
+    pub recv_window: Option<u16>,
+}
+
+impl<'a> BorrowRepayRecordsParams<'a> {
+    pub fn new(r#type: BorrowRepayType) -> Self {
+        BorrowRepayRecordsParams {
+            r#type,
+            ..Default::default()
+        }
+    }
+
+    pub fn asset(mut self, asset: &'a str) -> Self {
+        self.asset = Some(asset);
+        self
+    }
+
+    pub fn isolated_symbol(mut self, isolated_symbol: &'a str) -> Self {
+        self.isolated_symbol = Some(isolated_symbol);
+        self
+    }
+
+    pub fn tx_id(mut self, tx_id: u64) -> Self {
+        self.tx_id = Some(tx_id);
+        self
+    }
+
+    pub fn start_time(mut self, start_time: u64) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    pub fn end_time(mut self, end_time: u64) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    pub fn current(mut self, current: u32) -> Self {
+        self.current = Some(current);
+        self
+    }
+
+    pub fn size(mut self, size: u8) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: u16) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CrossAccountParams {
+    pub recv_window: Option<u16>,
+}
+
+impl CrossAccountParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn recv_window(mut self, recv_window: u16) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IsolatedAccountParams {
+    /// Comma separated, at most 5 symbols.
+    pub symbols: Option<String>,
+    pub recv_window: Option<u16>,
+}
+
+impl IsolatedAccountParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn symbols<S: AsRef<str>>(mut self, symbols: impl IntoIterator<Item = S>) -> Self {
+        self.symbols = Some(
+            symbols
+                .into_iter()
+                .map(|symbol| symbol.as_ref().to_ascii_uppercase())
+                .collect::<Vec<_>>()
+                .join(","),
+        );
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: u16) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InterestHistoryParams<'a> {
+    pub asset: Option<&'a str>,
+    pub isolated_symbol: Option<&'a str>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub current: Option<u32>,
+    pub size: Option<u8>,
+    pub recv_window: Option<u16>,
+}
+
+impl<'a> InterestHistoryParams<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn asset(mut self, asset: &'a str) -> Self {
+        self.asset = Some(asset);
+        self
+    }
+
+    pub fn isolated_symbol(mut self, isolated_symbol: &'a str) -> Self {
+        self.isolated_symbol = Some(isolated_symbol);
+        self
+    }
+
+    pub fn start_time(mut self, start_time: u64) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    pub fn end_time(mut self, end_time: u64) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    pub fn current(mut self, current: u32) -> Self {
+        self.current = Some(current);
+        self
+    }
+
+    pub fn size(mut self, size: u8) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: u16) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+/// Used by both the max borrowable and the max transferable endpoints.
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MaxAmountParams<'a> {
+    pub asset: &'a str,
+    pub isolated_symbol: Option<&'a str>,
+    pub recv_window: Option<u16>,
+}
+
+impl<'a> MaxAmountParams<'a> {
+    pub fn new(asset: &'a str) -> Self {
+        MaxAmountParams {
+            asset,
+            ..Default::default()
+        }
+    }
+
+    pub fn isolated_symbol(mut self, isolated_symbol: &'a str) -> Self {
+        self.isolated_symbol = Some(isolated_symbol);
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: u16) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
@@ -0,0 +1,25 @@
+use serde::Serialize;
+
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListenKeyParams<'a> {
+    pub symbol: Option<&'a str>,
+    pub listen_key: Option<&'a str>,
+}
+
+impl<'a> ListenKeyParams<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Isolated margin streams are opened per symbol.
+    pub fn symbol(mut self, symbol: &'a str) -> Self {
+        self.symbol = Some(symbol);
+        self
+    }
+
+    pub fn listen_key(mut self, listen_key: &'a str) -> Self {
+        self.listen_key = Some(listen_key);
+        self
+    }
+}
@@ -0,0 +1,559 @@
+use crate::enums::{margin::SideEffectType, spot::*};
+use serde::Serialize;
+
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewOrderParams<'a> {
+    pub symbol: &'a str,
+
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "super::flag"
+    )]
+    pub is_isolated: Option<bool>,
+
+    pub side: OrderSide,
+    pub r#type: OrderType,
+    pub time_in_force: Option<TimeInForce>,
+    pub quantity: Option<f64>,
+    pub quote_order_qty: Option<f64>,
+    pub price: Option<f64>,
+    pub stop_price: Option<f64>,
+    pub new_client_order_id: Option<&'a str>,
+    pub iceberg_qty: Option<f64>,
+    pub new_order_resp_type: Option<OrderResponseType>,
+    pub side_effect_type: Option<SideEffectType>,
+    pub self_trade_prevention_mode: Option<StpModes>,
+    pub auto_repay_at_cancel: Option<bool>,
+    pub recv_window: Option<u16>,
+}
+
+impl<'a> NewOrderParams<'a> {
+    pub fn new(symbol: &'a str, side: OrderSide, r#type: OrderType) -> Self {
+        NewOrderParams {
+            symbol,
+            side,
+            r#type,
+            ..Default::default()
+        }
+    }
+
+    pub fn symbol(mut self, symbol: &'a str) -> Self {
+        self.symbol = symbol;
+        self
+    }
+
+    /// Places the order against the isolated account of `symbol`.
+    pub fn isolated(mut self) -> Self {
+        self.is_isolated = Some(true);
+        self
+    }
+
+    pub fn side(mut self, side: OrderSide) -> Self {
+        self.side = side;
+        self
+    }
+
+    pub fn r#type(mut self, r#type: OrderType) -> Self {
+        self.r#type = r#type;
+        self
+    }
+
+    pub fn time_in_force(mut self, time_in_force: TimeInForce) -> Self {
+        self.time_in_force = Some(time_in_force);
+        self
+    }
+
+    pub fn quantity(mut self, quantity: f64) -> Self {
+        self.quantity = Some(quantity);
+        self
+    }
+
+    pub fn quote_order_qty(mut self, quote_order_qty: f64) -> Self {
+        self.quote_order_qty = Some(quote_order_qty);
+        self
+    }
+
+    pub fn price(mut self, price: f64) -> Self {
+        self.price = Some(price);
+        self
+    }
+
+    pub fn stop_price(mut self, stop_price: f64) -> Self {
+        self.stop_price = Some(stop_price);
+        self
+    }
+
+    pub fn new_client_order_id(mut self, new_client_order_id: &'a str) -> Self {
+        self.new_client_order_id = Some(new_client_order_id);
+        self
+    }
+
+    pub fn iceberg_qty(mut self, iceberg_qty: f64) -> Self {
+        self.iceberg_qty = Some(iceberg_qty);
+        self
+    }
+
+    pub fn new_order_resp_type(mut self, new_order_resp_type: OrderResponseType) -> Self {
+        self.new_order_resp_type = Some(new_order_resp_type);
+        self
+    }
+
+    pub fn side_effect_type(mut self, side_effect_type: SideEffectType) -> Self {
+        self.side_effect_type = Some(side_effect_type);
+        self
+    }
+
+    pub fn self_trade_prevention_mode(mut self, self_trade_prevention_mode: StpModes) -> Self {
+        self.self_trade_prevention_mode = Some(self_trade_prevention_mode);
+        self
+    }
+
+    /// Repays the borrowed amount when an `AUTO_BORROW_REPAY` or `MARGIN_BUY` order is canceled.
+    pub fn auto_repay_at_cancel(mut self, auto_repay_at_cancel: bool) -> Self {
+        self.auto_repay_at_cancel = Some(auto_repay_at_cancel);
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: u16) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+
+    pub fn limit(symbol: &'a str, side: OrderSide, price: f64, quantity: f64) -> Self {
+        NewOrderParams {
+            symbol,
+            side,
+            r#type: OrderType::Limit,
+            time_in_force: Some(TimeInForce::Gtc),
+            price: Some(price),
+            quantity: Some(quantity),
+            ..Default::default()
+        }
+    }
+
+    pub fn market(symbol: &'a str, side: OrderSide, quantity: f64) -> Self {
+        NewOrderParams {
+            symbol,
+            side,
+            r#type: OrderType::Market,
+            quantity: Some(quantity),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetOrderParams<'a> {
+    pub symbol: &'a str,
+
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "super::flag"
+    )]
+    pub is_isolated: Option<bool>,
+
+    pub order_id: Option<u64>,
+    pub orig_client_order_id: Option<&'a str>,
+    pub recv_window: Option<u16>,
+}
+
+impl<'a> GetOrderParams<'a> {
+    pub fn new(symbol: &'a str) -> Self {
+        GetOrderParams {
+            symbol,
+            ..Default::default()
+        }
+    }
+
+    pub fn isolated(mut self) -> Self {
+        self.is_isolated = Some(true);
+        self
+    }
+
+    pub fn order_id(mut self, order_id: u64) -> Self {
+        self.order_id = Some(order_id);
+        self
+    }
+
+    pub fn orig_client_order_id(mut self, orig_client_order_id: &'a str) -> Self {
+        self.orig_client_order_id = Some(orig_client_order_id);
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: u16) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelOrderParams<'a> {
+    pub symbol: &'a str,
+
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "super::flag"
+    )]
+    pub is_isolated: Option<bool>,
+
+    pub order_id: Option<u64>,
+    pub orig_client_order_id: Option<&'a str>,
+    pub new_client_order_id: Option<&'a str>,
+    pub recv_window: Option<u16>,
+}
+
+impl<'a> CancelOrderParams<'a> {
+    pub fn new(symbol: &'a str) -> Self {
+        CancelOrderParams {
+            symbol,
+            ..Default::default()
+        }
+    }
+
+    pub fn isolated(mut self) -> Self {
+        self.is_isolated = Some(true);
+        self
+    }
+
+    pub fn order_id(mut self, order_id: u64) -> Self {
+        self.order_id = Some(order_id);
+        self
+    }
+
+    pub fn orig_client_order_id(mut self, orig_client_order_id: &'a str) -> Self {
+        self.orig_client_order_id = Some(orig_client_order_id);
+        self
+    }
+
+    pub fn new_client_order_id(mut self, new_client_order_id: &'a str) -> Self {
+        self.new_client_order_id = Some(new_client_order_id);
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: u16) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+/// Used by both the open orders and the cancel all orders endpoints.
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenOrdersParams<'a> {
+    pub symbol: Option<&'a str>,
+
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "super::flag"
+    )]
+    pub is_isolated: Option<bool>,
+
+    pub recv_window: Option<u16>,
+}
+
+impl<'a> OpenOrdersParams<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn symbol(mut self, symbol: &'a str) -> Self {
+        self.symbol = Some(symbol);
+        self
+    }
+
+    pub fn isolated(mut self) -> Self {
+        self.is_isolated = Some(true);
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: u16) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AllOrdersParams<'a> {
+    pub symbol: &'a str,
+
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "super::flag"
+    )]
+    pub is_isolated: Option<bool>,
+
+    pub order_id: Option<u64>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub limit: Option<u16>,
+    pub recv_window: Option<u16>,
+}
+
+impl<'a> AllOrdersParams<'a> {
+    pub fn new(symbol: &'a str) -> Self {
+        AllOrdersParams {
+            symbol,
+            ..Default::default()
+        }
+    }
+
+    pub fn isolated(mut self) -> Self {
+        self.is_isolated = Some(true);
+        self
+    }
+
+    pub fn order_id(mut self, order_id: u64) -> Self {
+        self.order_id = Some(order_id);
+        self
+    }
+
+    pub fn start_time(mut self, start_time: u64) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    pub fn end_time(mut self, end_time: u64) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    pub fn limit(mut self, limit: u16) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: u16) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewOcoParams<'a> {
+    pub symbol: &'a str,
+
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "super::flag"
+    )]
+    pub is_isolated: Option<bool>,
+
+    pub list_client_order_id: Option<&'a str>,
+    pub side: OrderSide,
+    pub quantity: f64,
+    pub limit_client_order_id: Option<&'a str>,
+    pub price: f64,
+    pub limit_iceberg_qty: Option<f64>,
+    pub stop_client_order_id: Option<&'a str>,
+    pub stop_price: f64,
+    pub stop_limit_price: Option<f64>,
+    pub stop_iceberg_qty: Option<f64>,
+    pub stop_limit_time_in_force: Option<TimeInForce>,
+    pub new_order_resp_type: Option<OrderResponseType>,
+    pub side_effect_type: Option<SideEffectType>,
+    pub self_trade_prevention_mode: Option<StpModes>,
+    pub auto_repay_at_cancel: Option<bool>,
+    pub recv_window: Option<u16>,
+}
+
+impl<'a> NewOcoParams<'a> {
+    /// A limit maker leg at `price` and a stop loss leg triggered at `stop_price`.
+    pub fn new(
+        symbol: &'a str,
+        side: OrderSide,
+        quantity: f64,
+        price: f64,
+        stop_price: f64,
+    ) -> Self {
+        NewOcoParams {
+            symbol,
+            side,
+            quantity,
+            price,
+            stop_price,
+            ..Default::default()
+        }
+    }
+
+    pub fn isolated(mut self) -> Self {
+        self.is_isolated = Some(true);
+        self
+    }
+
+    pub fn list_client_order_id(mut self, list_client_order_id: &'a str) -> Self {
+        self.list_client_order_id = Some(list_client_order_id);
+        self
+    }
+
+    pub fn limit_client_order_id(mut self, limit_client_order_id: &'a str) -> Self {
+        self.limit_client_order_id = Some(limit_client_order_id);
+        self
+    }
+
+    pub fn limit_iceberg_qty(mut self, limit_iceberg_qty: f64) -> Self {
+        self.limit_iceberg_qty = Some(limit_iceberg_qty);
+        self
+    }
+
+    pub fn stop_client_order_id(mut self, stop_client_order_id: &'a str) -> Self {
+        self.stop_client_order_id = Some(stop_client_order_id);
+        self
+    }
+
+    /// Turns the stop loss leg into a stop loss limit order.
+    pub fn stop_limit_price(mut self, stop_limit_price: f64, time_in_force: TimeInForce) -> Self {
+        self.stop_limit_price = Some(stop_limit_price);
+        self.stop_limit_time_in_force = Some(time_in_force);
+        self
+    }
+
+    pub fn stop_iceberg_qty(mut self, stop_iceberg_qty: f64) -> Self {
+        self.stop_iceberg_qty = Some(stop_iceberg_qty);
+        self
+    }
+
+    pub fn new_order_resp_type(mut self, new_order_resp_type: OrderResponseType) -> Self {
+        self.new_order_resp_type = Some(new_order_resp_type);
+        self
+    }
+
+    pub fn side_effect_type(mut self, side_effect_type: SideEffectType) -> Self {
+        self.side_effect_type = Some(side_effect_type);
+        self
+    }
+
+    pub fn self_trade_prevention_mode(mut self, self_trade_prevention_mode: StpModes) -> Self {
+        self.self_trade_prevention_mode = Some(self_trade_prevention_mode);
+        self
+    }
+
+    pub fn auto_repay_at_cancel(mut self, auto_repay_at_cancel: bool) -> Self {
+        self.auto_repay_at_cancel = Some(auto_repay_at_cancel);
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: u16) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+/// Used by both the query and the cancel OCO endpoints.
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OcoParams<'a> {
+    pub symbol: Option<&'a str>,
+
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "super::flag"
+    )]
+    pub is_isolated: Option<bool>,
+
+    pub order_list_id: Option<u64>,
+    pub list_client_order_id: Option<&'a str>,
+    pub new_client_order_id: Option<&'a str>,
+    pub recv_window: Option<u16>,
+}
+
+impl<'a> OcoParams<'a> {
+    pub fn new(symbol: &'a str) -> Self {
+        OcoParams {
+            symbol: Some(symbol),
+            ..Default::default()
+        }
+    }
+
+    pub fn isolated(mut self) -> Self {
+        self.is_isolated = Some(true);
+        self
+    }
+
+    pub fn order_list_id(mut self, order_list_id: u64) -> Self {
+        self.order_list_id = Some(order_list_id);
+        self
+    }
+
+    pub fn list_client_order_id(mut self, list_client_order_id: &'a str) -> Self {
+        self.list_client_order_id = Some(list_client_order_id);
+        self
+    }
+
+    pub fn new_client_order_id(mut self, new_client_order_id: &'a str) -> Self {
+        self.new_client_order_id = Some(new_client_order_id);
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: u16) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MyTradesParams<'a> {
+    pub symbol: &'a str,
+
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "super::flag"
+    )]
+    pub is_isolated: Option<bool>,
+
+    pub order_id: Option<u64>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub from_id: Option<u64>,
+    pub limit: Option<u16>,
+    pub recv_window: Option<u16>,
+}
+
+impl<'a> MyTradesParams<'a> {
+    pub fn new(symbol: &'a str) -> Self {
+        MyTradesParams {
+            symbol,
+            ..Default::default()
+        }
+    }
+
+    pub fn isolated(mut self) -> Self {
+        self.is_isolated = Some(true);
+        self
+    }
+
+    pub fn order_id(mut self, order_id: u64) -> Self {
+        self.order_id = Some(order_id);
+        self
+    }
+
+    pub fn start_time(mut self, start_time: u64) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    pub fn end_time(mut self, end_time: u64) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    pub fn from_id(mut self, from_id: u64) -> Self {
+        self.from_id = Some(from_id);
+        self
+    }
+
+    pub fn limit(mut self, limit: u16) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: u16) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
@@ -0,0 +1,219 @@
+use serde::{Deserialize, Serialize};
+use serde_with::{DisplayFromStr, serde_as};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionResponse {
+    pub tran_id: u64,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BorrowRepayRecordsResponse {
+    pub rows: Vec<BorrowRepayRecord>,
+    pub total: u64,
+}
+
+#[serde_as]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BorrowRepayRecord {
+    pub asset: String,
+
+    #[serde(default)]
+    pub isolated_symbol: Option<String>,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub amount: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(default)]
+    pub interest: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(default)]
+    pub principal: f64,
+
+    pub status: String,
+    pub timestamp: u64,
+    pub tx_id: u64,
+}
+
+#[serde_as]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CrossAccountResponse {
+    pub borrow_enabled: bool,
+    pub trade_enabled: bool,
+    pub transfer_in_enabled: bool,
+    pub transfer_out_enabled: bool,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub margin_level: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub total_asset_of_btc: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub total_liability_of_btc: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub total_net_asset_of_btc: f64,
+
+    #[serde(default)]
+    pub account_type: Option<String>,
+
+    pub user_assets: Vec<CrossAssetResponse>,
+}
+
+#[serde_as]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CrossAssetResponse {
+    pub asset: String,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub borrowed: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub free: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub interest: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub locked: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub net_asset: f64,
+}
+
+/// Totals are only returned when no symbols are requested.
+#[serde_as]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IsolatedAccountResponse {
+    pub assets: Vec<IsolatedPairResponse>,
+
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub total_asset_of_btc: Option<f64>,
+
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub total_liability_of_btc: Option<f64>,
+
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub total_net_asset_of_btc: Option<f64>,
+}
+
+#[serde_as]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IsolatedPairResponse {
+    pub symbol: String,
+    pub base_asset: IsolatedAssetResponse,
+    pub quote_asset: IsolatedAssetResponse,
+    pub isolated_created: bool,
+    pub enabled: bool,
+    pub trade_enabled: bool,
+    pub margin_level_status: String,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub margin_level: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub margin_ratio: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub index_price: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub liquidate_price: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub liquidate_rate: f64,
+}
+
+#[serde_as]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IsolatedAssetResponse {
+    pub asset: String,
+    pub borrow_enabled: bool,
+    pub repay_enabled: bool,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub borrowed: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub free: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub interest: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub locked: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub net_asset: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub net_asset_of_btc: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub total_asset: f64,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InterestHistoryResponse {
+    pub rows: Vec<InterestRecord>,
+    pub total: u64,
+}
+
+#[serde_as]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InterestRecord {
+    pub tx_id: u64,
+    pub interest_accured_time: u64,
+    pub asset: String,
+
+    #[serde(default)]
+    pub raw_asset: Option<String>,
+
+    #[serde(default)]
+    pub isolated_symbol: Option<String>,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub principal: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub interest: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub interest_rate: f64,
+
+    pub r#type: String,
+}
+
+#[serde_as]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MaxBorrowableResponse {
+    #[serde_as(as = "DisplayFromStr")]
+    pub amount: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub borrow_limit: f64,
+}
+
+#[serde_as]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MaxTransferableResponse {
+    #[serde_as(as = "DisplayFromStr")]
+    pub amount: f64,
+}
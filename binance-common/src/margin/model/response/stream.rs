@@ -0,0 +1,179 @@
+use serde::Deserialize;
+use serde_with::{DisplayFromStr, serde_as};
+
+pub use crate::futures::model::response::websocket::ListenKeyResponse;
+
+/// Events pushed on a cross or isolated margin user data stream.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(tag = "e")]
+pub enum UserDataEvent {
+    #[serde(rename = "outboundAccountPosition")]
+    AccountPosition(AccountPositionEvent),
+
+    #[serde(rename = "balanceUpdate")]
+    BalanceUpdate(BalanceUpdateEvent),
+
+    #[serde(rename = "executionReport")]
+    ExecutionReport(ExecutionReportEvent),
+
+    #[serde(rename = "listStatus")]
+    ListStatus(ListStatusEvent),
+
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct AccountPositionEvent {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+
+    #[serde(rename = "u")]
+    pub last_update_time: u64,
+
+    #[serde(rename = "B")]
+    pub balances: Vec<BalanceEvent>,
+}
+
+#[serde_as]
+#[derive(Clone, Debug, Deserialize)]
+pub struct BalanceEvent {
+    #[serde(rename = "a")]
+    pub asset: String,
+
+    #[serde(rename = "f")]
+    #[serde_as(as = "DisplayFromStr")]
+    pub free: f64,
+
+    #[serde(rename = "l")]
+    #[serde_as(as = "DisplayFromStr")]
+    pub locked: f64,
+}
+
+#[serde_as]
+#[derive(Clone, Debug, Deserialize)]
+pub struct BalanceUpdateEvent {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+
+    #[serde(rename = "a")]
+    pub asset: String,
+
+    #[serde(rename = "d")]
+    #[serde_as(as = "DisplayFromStr")]
+    pub delta: f64,
+
+    #[serde(rename = "T")]
+    pub clear_time: u64,
+}
+
+#[serde_as]
+#[derive(Clone, Debug, Deserialize)]
+pub struct ExecutionReportEvent {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+
+    #[serde(rename = "s")]
+    pub symbol: String,
+
+    #[serde(rename = "c")]
+    pub client_order_id: String,
+
+    #[serde(rename = "S")]
+    pub side: String,
+
+    #[serde(rename = "o")]
+    pub order_type: String,
+
+    #[serde(rename = "f")]
+    pub time_in_force: String,
+
+    #[serde(rename = "q")]
+    #[serde_as(as = "DisplayFromStr")]
+    pub quantity: f64,
+
+    #[serde(rename = "p")]
+    #[serde_as(as = "DisplayFromStr")]
+    pub price: f64,
+
+    #[serde(rename = "P")]
+    #[serde_as(as = "DisplayFromStr")]
+    pub stop_price: f64,
+
+    #[serde(rename = "x")]
+    pub execution_type: String,
+
+    #[serde(rename = "X")]
+    pub status: String,
+
+    #[serde(rename = "r")]
+    pub reject_reason: String,
+
+    #[serde(rename = "i")]
+    pub order_id: u64,
+
+    #[serde(rename = "l")]
+    #[serde_as(as = "DisplayFromStr")]
+    pub last_quantity: f64,
+
+    #[serde(rename = "z")]
+    #[serde_as(as = "DisplayFromStr")]
+    pub cumulative_quantity: f64,
+
+    #[serde(rename = "L")]
+    #[serde_as(as = "DisplayFromStr")]
+    pub last_price: f64,
+
+    #[serde(rename = "Z")]
+    #[serde_as(as = "DisplayFromStr")]
+    pub cumulative_quote_quantity: f64,
+
+    #[serde(rename = "n")]
+    #[serde_as(as = "DisplayFromStr")]
+    pub commission: f64,
+
+    #[serde(rename = "N")]
+    pub commission_asset: Option<String>,
+
+    #[serde(rename = "T")]
+    pub transaction_time: u64,
+
+    #[serde(rename = "t")]
+    pub trade_id: i64,
+
+    #[serde(rename = "m")]
+    pub is_maker: bool,
+
+    #[serde(rename = "g")]
+    pub order_list_id: i64,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ListStatusEvent {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+
+    #[serde(rename = "s")]
+    pub symbol: String,
+
+    #[serde(rename = "g")]
+    pub order_list_id: i64,
+
+    #[serde(rename = "c")]
+    pub contingency_type: String,
+
+    #[serde(rename = "l")]
+    pub list_status_type: String,
+
+    #[serde(rename = "L")]
+    pub list_order_status: String,
+
+    #[serde(rename = "r")]
+    pub reject_reason: String,
+
+    #[serde(rename = "C")]
+    pub list_client_order_id: String,
+
+    #[serde(rename = "T")]
+    pub transaction_time: u64,
+}
@@ -0,0 +1,223 @@
+use serde::{Deserialize, Serialize};
+use serde_with::{DisplayFromStr, serde_as};
+
+pub use crate::spot::model::response::trade::Fill;
+
+/// `ACK` responses only carry the identifiers, `RESULT` and `FULL` fill in the rest.
+#[serde_as]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewOrderResponse {
+    pub symbol: String,
+    pub order_id: u64,
+    pub client_order_id: String,
+    pub transact_time: u64,
+
+    #[serde(default)]
+    pub is_isolated: bool,
+
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub price: Option<f64>,
+
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub orig_qty: Option<f64>,
+
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub executed_qty: Option<f64>,
+
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub cummulative_quote_qty: Option<f64>,
+
+    #[serde(default)]
+    pub status: Option<String>,
+
+    #[serde(default)]
+    pub time_in_force: Option<String>,
+
+    #[serde(default)]
+    pub r#type: Option<String>,
+
+    #[serde(default)]
+    pub side: Option<String>,
+
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub margin_buy_borrow_amount: Option<f64>,
+
+    #[serde(default)]
+    pub margin_buy_borrow_asset: Option<String>,
+
+    #[serde(default)]
+    pub self_trade_prevention_mode: Option<String>,
+
+    #[serde(default)]
+    pub fills: Vec<Fill>,
+}
+
+#[serde_as]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderResponse {
+    pub symbol: String,
+    pub order_id: u64,
+    pub client_order_id: String,
+
+    #[serde(default)]
+    pub is_isolated: bool,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub price: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub orig_qty: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub executed_qty: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub cummulative_quote_qty: f64,
+
+    pub status: String,
+    pub time_in_force: String,
+    pub r#type: String,
+    pub side: String,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub stop_price: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub iceberg_qty: f64,
+
+    pub time: u64,
+    pub update_time: u64,
+    pub is_working: bool,
+}
+
+#[serde_as]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelOrderResponse {
+    pub symbol: String,
+    pub order_id: u64,
+    pub orig_client_order_id: String,
+    pub client_order_id: String,
+
+    #[serde(default)]
+    pub is_isolated: bool,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub price: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub orig_qty: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub executed_qty: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub cummulative_quote_qty: f64,
+
+    pub status: String,
+    pub time_in_force: String,
+    pub r#type: String,
+    pub side: String,
+}
+
+/// `order_reports` is only populated when the list is placed or canceled.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OcoResponse {
+    pub order_list_id: u64,
+    pub contingency_type: String,
+    pub list_status_type: String,
+    pub list_order_status: String,
+    pub list_client_order_id: String,
+    pub transaction_time: u64,
+    pub symbol: String,
+
+    #[serde(default)]
+    pub is_isolated: bool,
+
+    pub orders: Vec<OcoOrder>,
+
+    #[serde(default)]
+    pub order_reports: Vec<OcoOrderReport>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OcoOrder {
+    pub symbol: String,
+    pub order_id: u64,
+    pub client_order_id: String,
+}
+
+#[serde_as]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OcoOrderReport {
+    pub symbol: String,
+    pub order_id: u64,
+    pub order_list_id: i64,
+    pub client_order_id: String,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub price: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub orig_qty: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub executed_qty: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub cummulative_quote_qty: f64,
+
+    pub status: String,
+    pub time_in_force: String,
+    pub r#type: String,
+    pub side: String,
+
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub stop_price: Option<f64>,
+}
+
+/// Canceling all open orders of a symbol returns both plain orders and order lists.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum CanceledResponse {
+    OrderList(OcoResponse),
+    Order(CancelOrderResponse),
+}
+
+#[serde_as]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MyTradesResponse {
+    pub symbol: String,
+    pub id: u64,
+    pub order_id: u64,
+
+    #[serde(default)]
+    pub is_isolated: bool,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub price: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub qty: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub commission: f64,
+
+    pub commission_asset: String,
+    pub time: u64,
+    pub is_buyer: bool,
+    pub is_maker: bool,
+    pub is_best_match: bool,
+}
@@ -0,0 +1,23 @@
+pub enum Host {
+    Api,
+}
+
+impl AsRef<str> for Host {
+    fn as_ref(&self) -> &str {
+        match self {
+            Host::Api => "https://papi.binance.com",
+        }
+    }
+}
+
+pub enum WebSocketHost {
+    UserData,
+}
+
+impl AsRef<str> for WebSocketHost {
+    fn as_ref(&self) -> &str {
+        match self {
+            WebSocketHost::UserData => "wss://fstream.binance.com/pm/ws/",
+        }
+    }
+}
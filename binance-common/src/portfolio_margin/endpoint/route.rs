@@ -0,0 +1,75 @@
+pub enum Um {
+    NewOrder,
+    CancelOrder,
+    GetOrder,
+    CancelAllOrders,
+    OpenOrders,
+    PositionRisk,
+}
+
+impl AsRef<str> for Um {
+    fn as_ref(&self) -> &'static str {
+        match self {
+            Um::NewOrder => "/papi/v1/um/order?",
+            Um::CancelOrder => "/papi/v1/um/order?",
+            Um::GetOrder => "/papi/v1/um/order?",
+            Um::CancelAllOrders => "/papi/v1/um/allOpenOrders?",
+            Um::OpenOrders => "/papi/v1/um/openOrders?",
+            Um::PositionRisk => "/papi/v1/um/positionRisk?",
+        }
+    }
+}
+
+pub enum Cm {
+    NewOrder,
+    CancelOrder,
+    GetOrder,
+    CancelAllOrders,
+    OpenOrders,
+    PositionRisk,
+}
+
+impl AsRef<str> for Cm {
+    fn as_ref(&self) -> &'static str {
+        match self {
+            Cm::NewOrder => "/papi/v1/cm/order?",
+            Cm::CancelOrder => "/papi/v1/cm/order?",
+            Cm::GetOrder => "/papi/v1/cm/order?",
+            Cm::CancelAllOrders => "/papi/v1/cm/allOpenOrders?",
+            Cm::OpenOrders => "/papi/v1/cm/openOrders?",
+            Cm::PositionRisk => "/papi/v1/cm/positionRisk?",
+        }
+    }
+}
+
+pub enum Account {
+    Balance,
+    Account,
+    AutoCollection,
+    AssetCollection,
+    RepayFuturesNegativeBalance,
+}
+
+impl AsRef<str> for Account {
+    fn as_ref(&self) -> &'static str {
+        match self {
+            Account::Balance => "/papi/v1/balance?",
+            Account::Account => "/papi/v1/account?",
+            Account::AutoCollection => "/papi/v1/auto-collection?",
+            Account::AssetCollection => "/papi/v1/asset-collection?",
+            Account::RepayFuturesNegativeBalance => "/papi/v1/repay-futures-negative-balance?",
+        }
+    }
+}
+
+pub enum UserStream {
+    ListenKey,
+}
+
+impl AsRef<str> for UserStream {
+    fn as_ref(&self) -> &'static str {
+        match self {
+            UserStream::ListenKey => "/papi/v1/listenKey?",
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use serde::Serialize;
+
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountParams {
+    pub recv_window: Option<u16>,
+}
+
+impl AccountParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn recv_window(mut self, recv_window: u16) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetCollectionParams<'a> {
+    pub asset: &'a str,
+    pub recv_window: Option<u16>,
+}
+
+impl<'a> AssetCollectionParams<'a> {
+    pub fn new(asset: &'a str) -> Self {
+        AssetCollectionParams {
+            asset,
+            ..Default::default()
+        }
+    }
+
+    pub fn recv_window(mut self, recv_window: u16) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
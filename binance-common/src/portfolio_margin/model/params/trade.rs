@@ -0,0 +1,123 @@
+use serde::Serialize;
+
+use crate::enums::futures::{
+    OrderResponseType, OrderSide, OrderType, PositionSide, PriceMatch, StpModes, TimeInForce,
+};
+
+pub use crate::futures::model::params::trade::{
+    CancelAllOrdersParams, CancelOrderParams, GetOrderParams,
+};
+
+/// UM and CM orders, only `LIMIT` and `MARKET` are accepted.
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewOrderParams<'a> {
+    pub symbol: &'a str,
+    pub side: OrderSide,
+    pub position_side: Option<PositionSide>,
+    pub r#type: OrderType,
+    pub time_in_force: Option<TimeInForce>,
+    pub quantity: Option<f64>,
+    pub reduce_only: Option<bool>,
+    pub price: Option<f64>,
+    pub new_client_order_id: Option<&'a str>,
+    pub new_order_resp_type: Option<OrderResponseType>,
+    pub price_match: Option<PriceMatch>,
+    pub self_trade_prevention_mode: Option<StpModes>,
+    pub good_till_date: Option<u64>,
+    pub recv_window: Option<u16>,
+}
+
+impl<'a> NewOrderParams<'a> {
+    pub fn limit(symbol: &'a str, side: OrderSide, price: f64, quantity: f64) -> Self {
+        NewOrderParams {
+            symbol,
+            side,
+            r#type: OrderType::Limit,
+            time_in_force: Some(TimeInForce::Gtc),
+            price: Some(price),
+            quantity: Some(quantity),
+            ..Default::default()
+        }
+    }
+
+    pub fn market(symbol: &'a str, side: OrderSide, quantity: f64) -> Self {
+        NewOrderParams {
+            symbol,
+            side,
+            r#type: OrderType::Market,
+            quantity: Some(quantity),
+            ..Default::default()
+        }
+    }
+
+    pub fn position_side(mut self, position_side: PositionSide) -> Self {
+        self.position_side = Some(position_side);
+        self
+    }
+
+    pub fn time_in_force(mut self, time_in_force: TimeInForce) -> Self {
+        self.time_in_force = Some(time_in_force);
+        self
+    }
+
+    pub fn reduce_only(mut self, reduce_only: bool) -> Self {
+        self.reduce_only = Some(reduce_only);
+        self
+    }
+
+    pub fn new_client_order_id(mut self, new_client_order_id: &'a str) -> Self {
+        self.new_client_order_id = Some(new_client_order_id);
+        self
+    }
+
+    pub fn new_order_resp_type(mut self, new_order_resp_type: OrderResponseType) -> Self {
+        self.new_order_resp_type = Some(new_order_resp_type);
+        self
+    }
+
+    /// UM only.
+    pub fn price_match(mut self, price_match: PriceMatch) -> Self {
+        self.price_match = Some(price_match);
+        self
+    }
+
+    pub fn self_trade_prevention_mode(mut self, self_trade_prevention_mode: StpModes) -> Self {
+        self.self_trade_prevention_mode = Some(self_trade_prevention_mode);
+        self
+    }
+
+    pub fn good_till_date(mut self, good_till_date: u64) -> Self {
+        self.good_till_date = Some(good_till_date);
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: u16) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+/// Used by the open orders and position risk endpoints, no symbol means all symbols.
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SymbolParams<'a> {
+    pub symbol: Option<&'a str>,
+    pub recv_window: Option<u16>,
+}
+
+impl<'a> SymbolParams<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn symbol(mut self, symbol: &'a str) -> Self {
+        self.symbol = Some(symbol);
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: u16) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
@@ -0,0 +1,88 @@
+use serde::Deserialize;
+use serde_with::{DisplayFromStr, NoneAsEmptyString, serde_as};
+
+/// Unrealized PnL fields are empty strings when the wallet holds no positions.
+#[serde_as]
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BalanceResponse {
+    pub asset: String,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub total_wallet_balance: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub cross_margin_asset: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub cross_margin_borrowed: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub cross_margin_free: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub cross_margin_interest: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub cross_margin_locked: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub um_wallet_balance: f64,
+
+    #[serde(rename = "umUnrealizedPNL")]
+    #[serde_as(as = "NoneAsEmptyString")]
+    pub um_unrealized_pnl: Option<f64>,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub cm_wallet_balance: f64,
+
+    #[serde(rename = "cmUnrealizedPNL")]
+    #[serde_as(as = "NoneAsEmptyString")]
+    pub cm_unrealized_pnl: Option<f64>,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub negative_balance: f64,
+
+    pub update_time: u64,
+}
+
+#[serde_as]
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountResponse {
+    /// Unified maintenance margin ratio, liquidation starts below 1.05.
+    #[serde(rename = "uniMMR")]
+    #[serde_as(as = "DisplayFromStr")]
+    pub uni_mmr: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub account_equity: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub actual_equity: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub account_initial_margin: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub account_maint_margin: f64,
+
+    pub account_status: String,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub virtual_max_withdraw_amount: f64,
+
+    #[serde_as(as = "NoneAsEmptyString")]
+    pub total_available_balance: Option<f64>,
+
+    #[serde_as(as = "NoneAsEmptyString")]
+    pub total_margin_open_loss: Option<f64>,
+
+    pub update_time: u64,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MessageResponse {
+    pub msg: String,
+}
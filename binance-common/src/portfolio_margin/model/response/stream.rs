@@ -0,0 +1 @@
+pub use crate::futures::model::response::websocket::ListenKeyResponse;
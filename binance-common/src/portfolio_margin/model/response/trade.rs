@@ -0,0 +1,105 @@
+use serde::Deserialize;
+use serde_with::{DisplayFromStr, serde_as};
+
+use crate::enums::futures::{OrderSide, OrderStatus, OrderType, PositionSide, TimeInForce};
+
+pub use crate::futures::model::response::trade::CancelAllOrdersResponse;
+
+/// UM orders report `cum_quote`, CM orders report `cum_base` and `pair`.
+#[serde_as]
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderResponse {
+    pub symbol: String,
+    pub order_id: u64,
+    pub client_order_id: String,
+
+    #[serde(default)]
+    pub pair: Option<String>,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub price: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub avg_price: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub orig_qty: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub executed_qty: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub cum_qty: f64,
+
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub cum_quote: Option<f64>,
+
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub cum_base: Option<f64>,
+
+    pub reduce_only: bool,
+    pub side: OrderSide,
+    pub position_side: PositionSide,
+    pub status: OrderStatus,
+    pub time_in_force: TimeInForce,
+    pub r#type: OrderType,
+
+    #[serde(default)]
+    pub time: Option<u64>,
+
+    pub update_time: u64,
+
+    #[serde(default)]
+    pub self_trade_prevention_mode: Option<String>,
+
+    #[serde(default)]
+    pub good_till_date: Option<u64>,
+}
+
+/// UM positions carry `notional`, CM positions carry `notional_value` and `max_qty`.
+#[serde_as]
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionRiskResponse {
+    pub symbol: String,
+    pub position_side: PositionSide,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub position_amt: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub entry_price: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub mark_price: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub un_realized_profit: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub liquidation_price: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub leverage: f64,
+
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub notional: Option<f64>,
+
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub notional_value: Option<f64>,
+
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub max_notional_value: Option<f64>,
+
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub max_qty: Option<f64>,
+
+    pub update_time: u64,
+}
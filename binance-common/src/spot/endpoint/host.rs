@@ -5,6 +5,7 @@ pub enum Host {
     Api2,
     Api3,
     Api4,
+    Test,
 }
 
 impl AsRef<str> for Host {
@@ -16,6 +17,7 @@ impl AsRef<str> for Host {
             Host::Api2 => "https://api2.binance.com",
             Host::Api3 => "https://api3.binance.com",
             Host::Api4 => "https://api4.binance.com",
+            Host::Test => "https://testnet.binance.vision",
         }
     }
 }
@@ -3,6 +3,7 @@ use serde::Serialize;
 pub mod account;
 pub mod general;
 pub mod market;
+pub mod symbols;
 pub mod trade;
 
 #[derive(Serialize)]
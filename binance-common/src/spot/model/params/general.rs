@@ -1,12 +1,14 @@
 use serde::Serialize;
 
+use super::symbols::Symbols;
+
 #[derive(Clone, Debug, Default, Serialize)]
 pub struct ExchangeInformationParams<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub symbol: Option<&'a str>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub symbols: Option<&'a str>,
+    pub symbols: Option<Symbols>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub permissions: Option<&'a str>,
@@ -22,8 +24,8 @@ impl<'a> ExchangeInformationParams<'a> {
         self
     }
 
-    pub fn symbols(mut self, symbols: &'a str) -> Self {
-        self.symbols = Some(symbols);
+    pub fn symbols<S: AsRef<str>>(mut self, symbols: impl IntoIterator<Item = S>) -> Self {
+        self.symbols = Some(Symbols::new(symbols));
         self
     }
 
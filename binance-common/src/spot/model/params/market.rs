@@ -1,5 +1,7 @@
 use serde::Serialize;
 
+use super::symbols::{SymbolBatches, Symbols};
+
 #[derive(Clone, Debug, Default, Serialize)]
 pub struct DepthParams<'a> {
     pub symbol: &'a str,
@@ -171,7 +173,7 @@ pub struct Ticker24hParams<'a> {
     pub symbol: Option<&'a str>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub symbols: Option<&'a str>,
+    pub symbols: Option<Symbols>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub r#type: Option<&'a str>,
@@ -187,8 +189,8 @@ impl<'a> Ticker24hParams<'a> {
         self
     }
 
-    pub fn symbols(mut self, symbols: &'a str) -> Self {
-        self.symbols = Some(symbols);
+    pub fn symbols<S: AsRef<str>>(mut self, symbols: impl IntoIterator<Item = S>) -> Self {
+        self.symbols = Some(Symbols::new(symbols));
         self
     }
 
@@ -204,7 +206,7 @@ pub struct TickerDayParams<'a> {
     pub symbol: Option<&'a str>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub symbols: Option<&'a str>,
+    pub symbols: Option<Symbols>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub time_zone: Option<&'a str>,
@@ -223,8 +225,8 @@ impl<'a> TickerDayParams<'a> {
         self
     }
 
-    pub fn symbols(mut self, symbols: &'a str) -> Self {
-        self.symbols = Some(symbols);
+    pub fn symbols<S: AsRef<str>>(mut self, symbols: impl IntoIterator<Item = S>) -> Self {
+        self.symbols = Some(Symbols::new(symbols));
         self
     }
 
@@ -245,7 +247,7 @@ pub struct PriceTickerParams<'a> {
     pub symbol: Option<&'a str>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub symbols: Option<&'a str>,
+    pub symbols: Option<Symbols>,
 }
 
 impl<'a> PriceTickerParams<'a> {
@@ -258,8 +260,8 @@ impl<'a> PriceTickerParams<'a> {
         self
     }
 
-    pub fn symbols(mut self, symbols: &'a str) -> Self {
-        self.symbols = Some(symbols);
+    pub fn symbols<S: AsRef<str>>(mut self, symbols: impl IntoIterator<Item = S>) -> Self {
+        self.symbols = Some(Symbols::new(symbols));
         self
     }
 }
@@ -270,7 +272,7 @@ pub struct BookTickerParams<'a> {
     pub symbol: Option<&'a str>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub symbols: Option<&'a str>,
+    pub symbols: Option<Symbols>,
 }
 
 impl<'a> BookTickerParams<'a> {
@@ -283,8 +285,8 @@ impl<'a> BookTickerParams<'a> {
         self
     }
 
-    pub fn symbols(mut self, symbols: &'a str) -> Self {
-        self.symbols = Some(symbols);
+    pub fn symbols<S: AsRef<str>>(mut self, symbols: impl IntoIterator<Item = S>) -> Self {
+        self.symbols = Some(Symbols::new(symbols));
         self
     }
 }
@@ -295,7 +297,7 @@ pub struct RollingTickerParams<'a> {
     pub symbol: Option<&'a str>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub symbols: Option<&'a str>,
+    pub symbols: Option<Symbols>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub window_size: Option<&'a str>,
@@ -314,8 +316,8 @@ impl<'a> RollingTickerParams<'a> {
         self
     }
 
-    pub fn symbols(mut self, symbols: &'a str) -> Self {
-        self.symbols = Some(symbols);
+    pub fn symbols<S: AsRef<str>>(mut self, symbols: impl IntoIterator<Item = S>) -> Self {
+        self.symbols = Some(Symbols::new(symbols));
         self
     }
 
@@ -329,3 +331,48 @@ impl<'a> RollingTickerParams<'a> {
         self
     }
 }
+
+impl SymbolBatches for Ticker24hParams<'_> {
+    /// Not documented, keeps the query string well below the URL length limit.
+    const MAX_SYMBOLS: usize = 200;
+
+    fn symbols_mut(&mut self) -> &mut Option<Symbols> {
+        &mut self.symbols
+    }
+}
+
+impl SymbolBatches for TickerDayParams<'_> {
+    /// Documented maximum of the endpoint.
+    const MAX_SYMBOLS: usize = 100;
+
+    fn symbols_mut(&mut self) -> &mut Option<Symbols> {
+        &mut self.symbols
+    }
+}
+
+impl SymbolBatches for PriceTickerParams<'_> {
+    /// Not documented, keeps the query string well below the URL length limit.
+    const MAX_SYMBOLS: usize = 200;
+
+    fn symbols_mut(&mut self) -> &mut Option<Symbols> {
+        &mut self.symbols
+    }
+}
+
+impl SymbolBatches for BookTickerParams<'_> {
+    /// Not documented, keeps the query string well below the URL length limit.
+    const MAX_SYMBOLS: usize = 200;
+
+    fn symbols_mut(&mut self) -> &mut Option<Symbols> {
+        &mut self.symbols
+    }
+}
+
+impl SymbolBatches for RollingTickerParams<'_> {
+    /// Documented maximum of the endpoint.
+    const MAX_SYMBOLS: usize = 100;
+
+    fn symbols_mut(&mut self) -> &mut Option<Symbols> {
+        &mut self.symbols
+    }
+}
@@ -0,0 +1,75 @@
+use serde::{Serialize, Serializer};
+
+/// List of symbols sent as the JSON array Binance expects in the `symbols`
+/// parameter, e.g. `["BTCUSDT","ETHUSDT"]`. Symbols are uppercased.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Symbols(Vec<String>);
+
+impl Symbols {
+    pub fn new<S: AsRef<str>>(symbols: impl IntoIterator<Item = S>) -> Self {
+        Symbols(
+            symbols
+                .into_iter()
+                .map(|symbol| symbol.as_ref().trim().to_ascii_uppercase())
+                .collect(),
+        )
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.0.iter().map(String::as_str)
+    }
+
+    /// Splits the list into lists of at most `size` symbols.
+    pub fn batches(&self, size: usize) -> Vec<Symbols> {
+        self.0
+            .chunks(size.max(1))
+            .map(|symbols| Symbols(symbols.to_vec()))
+            .collect()
+    }
+}
+
+impl<S: AsRef<str>> FromIterator<S> for Symbols {
+    fn from_iter<I: IntoIterator<Item = S>>(symbols: I) -> Self {
+        Symbols::new(symbols)
+    }
+}
+
+impl Serialize for Symbols {
+    fn serialize<Z: Serializer>(&self, serializer: Z) -> Result<Z::Ok, Z::Error> {
+        let symbols = serde_json::to_string(&self.0).map_err(serde::ser::Error::custom)?;
+        serializer.serialize_str(&symbols)
+    }
+}
+
+/// Params carrying a `symbols` list the endpoint only accepts in limited
+/// amounts. `batches` returns one params per request needed.
+pub trait SymbolBatches: Clone {
+    const MAX_SYMBOLS: usize;
+
+    fn symbols_mut(&mut self) -> &mut Option<Symbols>;
+
+    fn batches(&self) -> Vec<Self> {
+        let mut params = self.clone();
+
+        match params.symbols_mut().take() {
+            Some(symbols) if symbols.len() > Self::MAX_SYMBOLS => symbols
+                .batches(Self::MAX_SYMBOLS)
+                .into_iter()
+                .map(|symbols| {
+                    let mut params = params.clone();
+                    *params.symbols_mut() = Some(symbols);
+                    params
+                })
+                .collect(),
+            _ => vec![self.clone()],
+        }
+    }
+}
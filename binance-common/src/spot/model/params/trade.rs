@@ -16,7 +16,7 @@ pub struct NewOrderParams<'a> {
     pub strategy_type: Option<u64>,
     pub stop_price: Option<f64>,
     pub trailing_delta: Option<u64>,
-    pub icerberg_qty: Option<f64>,
+    pub iceberg_qty: Option<f64>,
     pub new_order_resp_type: Option<OrderResponseType>,
     pub self_trade_prevention_mode: Option<StpModes>,
     pub recv_window: Option<u16>,
@@ -92,11 +92,16 @@ impl<'a> NewOrderParams<'a> {
         self
     }
 
-    pub fn icerberg_qty(mut self, icerberg_qty: f64) -> Self {
-        self.icerberg_qty = Some(icerberg_qty);
+    pub fn iceberg_qty(mut self, iceberg_qty: f64) -> Self {
+        self.iceberg_qty = Some(iceberg_qty);
         self
     }
 
+    #[deprecated(note = "misspelled, use `iceberg_qty`")]
+    pub fn icerberg_qty(self, iceberg_qty: f64) -> Self {
+        self.iceberg_qty(iceberg_qty)
+    }
+
     pub fn new_order_resp_type(mut self, new_order_resp_type: OrderResponseType) -> Self {
         self.new_order_resp_type = Some(new_order_resp_type);
         self
@@ -255,6 +260,45 @@ pub struct CancelOrderParams<'a> {
     pub recv_window: Option<u16>,
 }
 
+impl<'a> CancelOrderParams<'a> {
+    pub fn new(symbol: &'a str) -> Self {
+        CancelOrderParams {
+            symbol,
+            ..Default::default()
+        }
+    }
+
+    pub fn symbol(mut self, symbol: &'a str) -> Self {
+        self.symbol = symbol;
+        self
+    }
+
+    pub fn order_id(mut self, order_id: u64) -> Self {
+        self.order_id = Some(order_id);
+        self
+    }
+
+    pub fn orig_client_order_id(mut self, orig_client_order_id: &'a str) -> Self {
+        self.orig_client_order_id = Some(orig_client_order_id);
+        self
+    }
+
+    pub fn new_client_order_id(mut self, new_client_order_id: &'a str) -> Self {
+        self.new_client_order_id = Some(new_client_order_id);
+        self
+    }
+
+    pub fn cancel_restrictions(mut self, cancel_restrictions: CancelRestrictions) -> Self {
+        self.cancel_restrictions = Some(cancel_restrictions);
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: u16) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
 #[derive(Clone, Debug, Default, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CancelAllOrdersParms<'a> {
@@ -284,20 +328,25 @@ impl<'a> CancelAllOrdersParms<'a> {
 #[derive(Clone, Debug, Default, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct OpenOrdersParams<'a> {
-    pub symbol: &'a str,
+    pub symbol: Option<&'a str>,
     pub recv_window: Option<u16>,
 }
 
 impl<'a> OpenOrdersParams<'a> {
     pub fn new(symbol: &'a str) -> Self {
         OpenOrdersParams {
-            symbol,
+            symbol: Some(symbol),
             ..Default::default()
         }
     }
 
+    /// Open orders of every symbol.
+    pub fn all() -> Self {
+        Self::default()
+    }
+
     pub fn symbol(mut self, symbol: &'a str) -> Self {
-        self.symbol = symbol;
+        self.symbol = Some(symbol);
         self
     }
 
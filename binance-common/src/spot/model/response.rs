@@ -1,4 +1,5 @@
 pub mod account;
 pub mod general;
 pub mod market;
+pub mod stream;
 pub mod trade;
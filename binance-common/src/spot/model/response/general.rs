@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use serde_with::{DisplayFromStr, serde_as};
 
 #[derive(Clone, Debug, Deserialize, PartialEq)]
 pub struct EmptyResponse {}
@@ -39,4 +40,44 @@ pub struct SymbolResponse {
     pub iceberg_allowed: bool,
     pub is_spot_trading_allowed: bool,
     pub is_margin_trading_allowed: bool,
+
+    #[serde(default)]
+    pub filters: Vec<SymbolFilter>,
+}
+
+/// Trading rule of a symbol, only the ones sizing orders are decoded.
+#[serde_as]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(
+    tag = "filterType",
+    rename_all = "SCREAMING_SNAKE_CASE",
+    rename_all_fields = "camelCase"
+)]
+pub enum SymbolFilter {
+    PriceFilter {
+        #[serde_as(as = "DisplayFromStr")]
+        min_price: f64,
+        #[serde_as(as = "DisplayFromStr")]
+        max_price: f64,
+        #[serde_as(as = "DisplayFromStr")]
+        tick_size: f64,
+    },
+    LotSize {
+        #[serde_as(as = "DisplayFromStr")]
+        min_qty: f64,
+        #[serde_as(as = "DisplayFromStr")]
+        max_qty: f64,
+        #[serde_as(as = "DisplayFromStr")]
+        step_size: f64,
+    },
+    MarketLotSize {
+        #[serde_as(as = "DisplayFromStr")]
+        min_qty: f64,
+        #[serde_as(as = "DisplayFromStr")]
+        max_qty: f64,
+        #[serde_as(as = "DisplayFromStr")]
+        step_size: f64,
+    },
+    #[serde(other)]
+    Other,
 }
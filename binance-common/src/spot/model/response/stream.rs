@@ -0,0 +1,6 @@
+//! Spot user data streams push the same events as margin ones.
+
+pub use crate::margin::model::response::stream::{
+    AccountPositionEvent, BalanceEvent, BalanceUpdateEvent, ExecutionReportEvent, ListStatusEvent,
+    UserDataEvent,
+};
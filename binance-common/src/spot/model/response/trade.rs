@@ -4,11 +4,11 @@ use serde_with::{DisplayFromStr, serde_as};
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AckResponse {
-    symbol: String,
-    order_id: u64,
-    order_list_id: i64,
-    client_order_id: String,
-    transact_time: u64,
+    pub symbol: String,
+    pub order_id: u64,
+    pub order_list_id: i64,
+    pub client_order_id: String,
+    pub transact_time: u64,
 }
 
 #[derive(Clone, Debug)]
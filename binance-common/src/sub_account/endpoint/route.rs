@@ -0,0 +1,51 @@
+pub enum Account {
+    List,
+    Create,
+    SpotAssets,
+    FuturesAccount,
+    EnableFutures,
+}
+
+impl AsRef<str> for Account {
+    fn as_ref(&self) -> &'static str {
+        match self {
+            Account::List => "/sapi/v1/sub-account/list?",
+            Account::Create => "/sapi/v1/sub-account/virtualSubAccount?",
+            Account::SpotAssets => "/sapi/v4/sub-account/assets?",
+            Account::FuturesAccount => "/sapi/v2/sub-account/futures/account?",
+            Account::EnableFutures => "/sapi/v1/sub-account/futures/enable?",
+        }
+    }
+}
+
+pub enum Transfer {
+    UniversalTransfer,
+    UniversalTransferHistory,
+}
+
+impl AsRef<str> for Transfer {
+    fn as_ref(&self) -> &'static str {
+        match self {
+            Transfer::UniversalTransfer => "/sapi/v1/sub-account/universalTransfer?",
+            Transfer::UniversalTransferHistory => "/sapi/v1/sub-account/universalTransfer?",
+        }
+    }
+}
+
+pub enum ApiKey {
+    IpRestriction,
+    AddIpRestriction,
+    DeleteIpRestriction,
+}
+
+impl AsRef<str> for ApiKey {
+    fn as_ref(&self) -> &'static str {
+        match self {
+            ApiKey::IpRestriction => "/sapi/v1/sub-account/subAccountApi/ipRestriction?",
+            ApiKey::AddIpRestriction => "/sapi/v2/sub-account/subAccountApi/ipRestriction?",
+            ApiKey::DeleteIpRestriction => {
+                "/sapi/v1/sub-account/subAccountApi/ipRestriction/ipList?"
+            }
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use serde::Serialize;
+
+use crate::enums::sub_account::FuturesType;
+
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountListParams<'a> {
+    pub email: Option<&'a str>,
+    pub is_freeze: Option<bool>,
+    pub page: Option<u32>,
+    pub limit: Option<u16>,
+    pub recv_window: Option<u16>,
+}
+
+impl<'a> SubAccountListParams<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn email(mut self, email: &'a str) -> Self {
+        self.email = Some(email);
+        self
+    }
+
+    pub fn is_freeze(mut self, is_freeze: bool) -> Self {
+        self.is_freeze = Some(is_freeze);
+        self
+    }
+
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn limit(mut self, limit: u16) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: u16) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateSubAccountParams<'a> {
+    /// Prefix of the generated virtual email.
+    pub sub_account_string: &'a str,
+    pub recv_window: Option<u16>,
+}
+
+impl<'a> CreateSubAccountParams<'a> {
+    pub fn new(sub_account_string: &'a str) -> Self {
+        CreateSubAccountParams {
+            sub_account_string,
+            ..Default::default()
+        }
+    }
+
+    pub fn recv_window(mut self, recv_window: u16) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+/// Used by the spot assets and enable futures endpoints.
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmailParams<'a> {
+    pub email: &'a str,
+    pub recv_window: Option<u16>,
+}
+
+impl<'a> EmailParams<'a> {
+    pub fn new(email: &'a str) -> Self {
+        EmailParams {
+            email,
+            ..Default::default()
+        }
+    }
+
+    pub fn recv_window(mut self, recv_window: u16) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FuturesAccountParams<'a> {
+    pub email: &'a str,
+    pub futures_type: FuturesType,
+    pub recv_window: Option<u16>,
+}
+
+impl<'a> FuturesAccountParams<'a> {
+    pub fn new(email: &'a str, futures_type: FuturesType) -> Self {
+        FuturesAccountParams {
+            email,
+            futures_type,
+            ..Default::default()
+        }
+    }
+
+    pub fn recv_window(mut self, recv_window: u16) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
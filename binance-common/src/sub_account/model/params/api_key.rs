@@ -0,0 +1,84 @@
+use serde::Serialize;
+
+/// Used by the query and delete IP restriction endpoints.
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IpRestrictionParams<'a> {
+    pub email: &'a str,
+    pub sub_account_api_key: &'a str,
+    pub ip_address: Option<String>,
+    pub recv_window: Option<u16>,
+}
+
+impl<'a> IpRestrictionParams<'a> {
+    pub fn new(email: &'a str, sub_account_api_key: &'a str) -> Self {
+        IpRestrictionParams {
+            email,
+            sub_account_api_key,
+            ..Default::default()
+        }
+    }
+
+    pub fn ip_addresses<S: AsRef<str>>(
+        mut self,
+        ip_addresses: impl IntoIterator<Item = S>,
+    ) -> Self {
+        self.ip_address = Some(join(ip_addresses));
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: u16) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddIpRestrictionParams<'a> {
+    pub email: &'a str,
+    pub sub_account_api_key: &'a str,
+    /// `1` unrestricted, `2` trusted IPs only.
+    pub status: &'static str,
+    pub ip_address: Option<String>,
+    pub recv_window: Option<u16>,
+}
+
+impl<'a> AddIpRestrictionParams<'a> {
+    /// Restricts the key to `ip_addresses`.
+    pub fn restricted<S: AsRef<str>>(
+        email: &'a str,
+        sub_account_api_key: &'a str,
+        ip_addresses: impl IntoIterator<Item = S>,
+    ) -> Self {
+        AddIpRestrictionParams {
+            email,
+            sub_account_api_key,
+            status: "2",
+            ip_address: Some(join(ip_addresses)),
+            ..Default::default()
+        }
+    }
+
+    pub fn unrestricted(email: &'a str, sub_account_api_key: &'a str) -> Self {
+        AddIpRestrictionParams {
+            email,
+            sub_account_api_key,
+            status: "1",
+            ..Default::default()
+        }
+    }
+
+    pub fn recv_window(mut self, recv_window: u16) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+fn join<S: AsRef<str>>(values: impl IntoIterator<Item = S>) -> String {
+    values
+        .into_iter()
+        .map(|value| value.as_ref().to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
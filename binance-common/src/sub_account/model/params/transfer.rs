@@ -0,0 +1,121 @@
+use serde::Serialize;
+
+use crate::enums::sub_account::AccountType;
+
+/// Leaving either email unset means the master account.
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UniversalTransferParams<'a> {
+    pub from_email: Option<&'a str>,
+    pub to_email: Option<&'a str>,
+    pub from_account_type: AccountType,
+    pub to_account_type: AccountType,
+    pub client_tran_id: Option<&'a str>,
+    pub symbol: Option<&'a str>,
+    pub asset: &'a str,
+    pub amount: f64,
+    pub recv_window: Option<u16>,
+}
+
+impl<'a> UniversalTransferParams<'a> {
+    pub fn new(
+        from_account_type: AccountType,
+        to_account_type: AccountType,
+        asset: &'a str,
+        amount: f64,
+    ) -> Self {
+        UniversalTransferParams {
+            from_account_type,
+            to_account_type,
+            asset,
+            amount,
+            ..Default::default()
+        }
+    }
+
+    pub fn from_email(mut self, from_email: &'a str) -> Self {
+        self.from_email = Some(from_email);
+        self
+    }
+
+    pub fn to_email(mut self, to_email: &'a str) -> Self {
+        self.to_email = Some(to_email);
+        self
+    }
+
+    /// Must be unique, makes retries idempotent.
+    pub fn client_tran_id(mut self, client_tran_id: &'a str) -> Self {
+        self.client_tran_id = Some(client_tran_id);
+        self
+    }
+
+    /// Required for isolated margin transfers.
+    pub fn symbol(mut self, symbol: &'a str) -> Self {
+        self.symbol = Some(symbol);
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: u16) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferHistoryParams<'a> {
+    pub from_email: Option<&'a str>,
+    pub to_email: Option<&'a str>,
+    pub client_tran_id: Option<&'a str>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub page: Option<u32>,
+    pub limit: Option<u16>,
+    pub recv_window: Option<u16>,
+}
+
+impl<'a> TransferHistoryParams<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn from_email(mut self, from_email: &'a str) -> Self {
+        self.from_email = Some(from_email);
+        self
+    }
+
+    pub fn to_email(mut self, to_email: &'a str) -> Self {
+        self.to_email = Some(to_email);
+        self
+    }
+
+    pub fn client_tran_id(mut self, client_tran_id: &'a str) -> Self {
+        self.client_tran_id = Some(client_tran_id);
+        self
+    }
+
+    pub fn start_time(mut self, start_time: u64) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    pub fn end_time(mut self, end_time: u64) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn limit(mut self, limit: u16) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: u16) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
@@ -0,0 +1,3 @@
+pub mod account;
+pub mod api_key;
+pub mod transfer;
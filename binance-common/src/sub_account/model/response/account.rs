@@ -0,0 +1,118 @@
+use serde::{Deserialize, Serialize};
+use serde_with::{DisplayFromStr, serde_as};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountListResponse {
+    pub sub_accounts: Vec<SubAccountResponse>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountResponse {
+    pub email: String,
+    pub is_freeze: bool,
+    pub create_time: u64,
+    pub is_managed_sub_account: bool,
+    pub is_asset_management_sub_account: bool,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateSubAccountResponse {
+    pub email: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpotAssetsResponse {
+    pub balances: Vec<AssetBalanceResponse>,
+}
+
+#[serde_as]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetBalanceResponse {
+    pub asset: String,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub free: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub locked: f64,
+}
+
+/// USDⓈ-M accounts come back as `futureAccountResp`, COIN-M as `deliveryAccountResp`.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FuturesAccountResponse {
+    #[serde(rename = "futureAccountResp", alias = "deliveryAccountResp")]
+    pub account: FuturesAccount,
+}
+
+#[serde_as]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FuturesAccount {
+    pub email: String,
+    pub assets: Vec<FuturesAssetResponse>,
+    pub can_deposit: bool,
+    pub can_trade: bool,
+    pub can_withdraw: bool,
+    pub fee_tier: u8,
+    pub update_time: u64,
+
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub total_wallet_balance: Option<f64>,
+
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub total_margin_balance: Option<f64>,
+
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub total_unrealized_profit: Option<f64>,
+
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub max_withdraw_amount: Option<f64>,
+}
+
+#[serde_as]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FuturesAssetResponse {
+    pub asset: String,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub initial_margin: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub maintenance_margin: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub margin_balance: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub max_withdraw_amount: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub open_order_initial_margin: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub position_initial_margin: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub unrealized_profit: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub wallet_balance: f64,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EnableFuturesResponse {
+    pub email: String,
+    pub is_futures_enabled: bool,
+}
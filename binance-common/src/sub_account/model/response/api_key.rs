@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+use serde_with::{DisplayFromStr, serde_as};
+
+#[serde_as]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IpRestrictionResponse {
+    #[serde_as(as = "DisplayFromStr")]
+    pub ip_restrict: bool,
+
+    #[serde(default)]
+    pub ip_list: Vec<String>,
+
+    pub update_time: u64,
+    pub api_key: String,
+}
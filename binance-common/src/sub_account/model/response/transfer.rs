@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+use serde_with::{DisplayFromStr, serde_as};
+
+use crate::enums::sub_account::AccountType;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferResponse {
+    pub tran_id: u64,
+
+    #[serde(default)]
+    pub client_tran_id: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferHistoryResponse {
+    pub result: Vec<TransferRecordResponse>,
+    pub total_count: u64,
+}
+
+#[serde_as]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferRecordResponse {
+    pub tran_id: u64,
+    pub from_email: String,
+    pub to_email: String,
+    pub asset: String,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub amount: f64,
+
+    pub create_time_stamp: u64,
+    pub from_account_type: AccountType,
+    pub to_account_type: AccountType,
+    pub status: String,
+
+    #[serde(default)]
+    pub client_tran_id: Option<String>,
+}
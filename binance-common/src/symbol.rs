@@ -0,0 +1,15 @@
+/// Quote assets tried in order to split a symbol into base and quote when its
+/// market is not known.
+pub const QUOTE_ASSETS: [&str; 10] = [
+    "FDUSD", "USDT", "USDC", "BUSD", "TUSD", "BTC", "ETH", "BNB", "EUR", "TRY",
+];
+
+/// Splits `symbol` into base and quote by the first matching [`QUOTE_ASSETS`] suffix.
+pub fn split_symbol(symbol: &str) -> Option<(&str, &str)> {
+    QUOTE_ASSETS.iter().find_map(|quote| {
+        symbol
+            .strip_suffix(quote)
+            .filter(|base| !base.is_empty())
+            .map(|base| (base, *quote))
+    })
+}
@@ -0,0 +1,55 @@
+pub enum Capital {
+    CoinInfo,
+    DepositAddress,
+    DepositHistory,
+    Withdraw,
+    WithdrawHistory,
+}
+
+impl AsRef<str> for Capital {
+    fn as_ref(&self) -> &'static str {
+        match self {
+            Capital::CoinInfo => "/sapi/v1/capital/config/getall?",
+            Capital::DepositAddress => "/sapi/v1/capital/deposit/address?",
+            Capital::DepositHistory => "/sapi/v1/capital/deposit/hisrec?",
+            Capital::Withdraw => "/sapi/v1/capital/withdraw/apply?",
+            Capital::WithdrawHistory => "/sapi/v1/capital/withdraw/history?",
+        }
+    }
+}
+
+pub enum Asset {
+    UniversalTransfer,
+    TransferHistory,
+    DustConvertible,
+    Dust,
+    DustLog,
+    TradeFee,
+    AssetDetail,
+}
+
+impl AsRef<str> for Asset {
+    fn as_ref(&self) -> &'static str {
+        match self {
+            Asset::UniversalTransfer => "/sapi/v1/asset/transfer?",
+            Asset::TransferHistory => "/sapi/v1/asset/transfer?",
+            Asset::DustConvertible => "/sapi/v1/asset/dust-btc?",
+            Asset::Dust => "/sapi/v1/asset/dust?",
+            Asset::DustLog => "/sapi/v1/asset/dribblet?",
+            Asset::TradeFee => "/sapi/v1/asset/tradeFee?",
+            Asset::AssetDetail => "/sapi/v1/asset/assetDetail?",
+        }
+    }
+}
+
+pub enum Account {
+    ApiRestrictions,
+}
+
+impl AsRef<str> for Account {
+    fn as_ref(&self) -> &'static str {
+        match self {
+            Account::ApiRestrictions => "/sapi/v1/account/apiRestrictions?",
+        }
+    }
+}
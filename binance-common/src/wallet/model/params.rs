@@ -0,0 +1,21 @@
+use serde::Serialize;
+
+pub mod asset;
+pub mod capital;
+
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecvWindowParams {
+    pub recv_window: Option<u16>,
+}
+
+impl RecvWindowParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn recv_window(mut self, recv_window: u16) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
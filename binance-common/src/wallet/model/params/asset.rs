@@ -0,0 +1,229 @@
+use serde::Serialize;
+
+use crate::enums::wallet::{DustAccountType, UniversalTransferType};
+
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UniversalTransferParams<'a> {
+    pub r#type: UniversalTransferType,
+    pub asset: &'a str,
+    pub amount: f64,
+    pub from_symbol: Option<&'a str>,
+    pub to_symbol: Option<&'a str>,
+    pub recv_window: Option<u16>,
+}
+
+impl<'a> UniversalTransferParams<'a> {
+    pub fn new(r#type: UniversalTransferType, asset: &'a str, amount: f64) -> Self {
+        UniversalTransferParams {
+            r#type,
+            asset,
+            amount,
+            ..Default::default()
+        }
+    }
+
+    /// Required when transferring out of an isolated margin account.
+    pub fn from_symbol(mut self, from_symbol: &'a str) -> Self {
+        self.from_symbol = Some(from_symbol);
+        self
+    }
+
+    /// Required when transferring into an isolated margin account.
+    pub fn to_symbol(mut self, to_symbol: &'a str) -> Self {
+        self.to_symbol = Some(to_symbol);
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: u16) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferHistoryParams<'a> {
+    pub r#type: UniversalTransferType,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub current: Option<u32>,
+    pub size: Option<u8>,
+    pub from_symbol: Option<&'a str>,
+    pub to_symbol: Option<&'a str>,
+    pub recv_window: Option<u16>,
+}
+
+impl<'a> TransferHistoryParams<'a> {
+    pub fn new(r#type: UniversalTransferType) -> Self {
+        TransferHistoryParams {
+            r#type,
+            ..Default::default()
+        }
+    }
+
+    pub fn start_time(mut self, start_time: u64) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    pub fn end_time(mut self, end_time: u64) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    pub fn current(mut self, current: u32) -> Self {
+        self.current = Some(current);
+        self
+    }
+
+    pub fn size(mut self, size: u8) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    pub fn from_symbol(mut self, from_symbol: &'a str) -> Self {
+        self.from_symbol = Some(from_symbol);
+        self
+    }
+
+    pub fn to_symbol(mut self, to_symbol: &'a str) -> Self {
+        self.to_symbol = Some(to_symbol);
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: u16) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DustConvertibleParams {
+    pub account_type: Option<DustAccountType>,
+    pub recv_window: Option<u16>,
+}
+
+impl DustConvertibleParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn account_type(mut self, account_type: DustAccountType) -> Self {
+        self.account_type = Some(account_type);
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: u16) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DustParams {
+    /// Comma separated assets to convert to BNB.
+    pub asset: String,
+    pub account_type: Option<DustAccountType>,
+    pub recv_window: Option<u16>,
+}
+
+impl DustParams {
+    pub fn new<S: AsRef<str>>(assets: impl IntoIterator<Item = S>) -> Self {
+        DustParams {
+            asset: assets
+                .into_iter()
+                .map(|asset| asset.as_ref().to_ascii_uppercase())
+                .collect::<Vec<_>>()
+                .join(","),
+            ..Default::default()
+        }
+    }
+
+    pub fn account_type(mut self, account_type: DustAccountType) -> Self {
+        self.account_type = Some(account_type);
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: u16) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DustLogParams {
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub recv_window: Option<u16>,
+}
+
+impl DustLogParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn start_time(mut self, start_time: u64) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    pub fn end_time(mut self, end_time: u64) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: u16) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TradeFeeParams<'a> {
+    pub symbol: Option<&'a str>,
+    pub recv_window: Option<u16>,
+}
+
+impl<'a> TradeFeeParams<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn symbol(mut self, symbol: &'a str) -> Self {
+        self.symbol = Some(symbol);
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: u16) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetDetailParams<'a> {
+    pub asset: Option<&'a str>,
+    pub recv_window: Option<u16>,
+}
+
+impl<'a> AssetDetailParams<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn asset(mut self, asset: &'a str) -> Self {
+        self.asset = Some(asset);
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: u16) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
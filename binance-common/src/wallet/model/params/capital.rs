@@ -0,0 +1,229 @@
+use serde::Serialize;
+
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DepositAddressParams<'a> {
+    pub coin: &'a str,
+    pub network: Option<&'a str>,
+    pub amount: Option<f64>,
+    pub recv_window: Option<u16>,
+}
+
+impl<'a> DepositAddressParams<'a> {
+    pub fn new(coin: &'a str) -> Self {
+        DepositAddressParams {
+            coin,
+            ..Default::default()
+        }
+    }
+
+    pub fn network(mut self, network: &'a str) -> Self {
+        self.network = Some(network);
+        self
+    }
+
+    pub fn amount(mut self, amount: f64) -> Self {
+        self.amount = Some(amount);
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: u16) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DepositHistoryParams<'a> {
+    pub coin: Option<&'a str>,
+    pub status: Option<u8>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub offset: Option<u32>,
+    pub limit: Option<u16>,
+    pub tx_id: Option<&'a str>,
+    pub recv_window: Option<u16>,
+}
+
+impl<'a> DepositHistoryParams<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn coin(mut self, coin: &'a str) -> Self {
+        self.coin = Some(coin);
+        self
+    }
+
+    /// 0 pending, 6 credited but cannot withdraw, 7 wrong deposit, 8 waiting user confirm, 1 success.
+    pub fn status(mut self, status: u8) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    pub fn start_time(mut self, start_time: u64) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    pub fn end_time(mut self, end_time: u64) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    pub fn offset(mut self, offset: u32) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    pub fn limit(mut self, limit: u16) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn tx_id(mut self, tx_id: &'a str) -> Self {
+        self.tx_id = Some(tx_id);
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: u16) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WithdrawParams<'a> {
+    pub coin: &'a str,
+    pub withdraw_order_id: Option<&'a str>,
+    pub network: Option<&'a str>,
+    pub address: &'a str,
+    pub address_tag: Option<&'a str>,
+    pub amount: f64,
+    pub transaction_fee_flag: Option<bool>,
+    pub name: Option<&'a str>,
+    pub wallet_type: Option<u8>,
+    pub recv_window: Option<u16>,
+}
+
+impl<'a> WithdrawParams<'a> {
+    pub fn new(coin: &'a str, address: &'a str, amount: f64) -> Self {
+        WithdrawParams {
+            coin,
+            address,
+            amount,
+            ..Default::default()
+        }
+    }
+
+    /// Client id used to look the withdrawal up in the history.
+    pub fn withdraw_order_id(mut self, withdraw_order_id: &'a str) -> Self {
+        self.withdraw_order_id = Some(withdraw_order_id);
+        self
+    }
+
+    pub fn network(mut self, network: &'a str) -> Self {
+        self.network = Some(network);
+        self
+    }
+
+    pub fn address_tag(mut self, address_tag: &'a str) -> Self {
+        self.address_tag = Some(address_tag);
+        self
+    }
+
+    /// When true the fee is charged to the destination for internal transfers.
+    pub fn transaction_fee_flag(mut self, transaction_fee_flag: bool) -> Self {
+        self.transaction_fee_flag = Some(transaction_fee_flag);
+        self
+    }
+
+    pub fn name(mut self, name: &'a str) -> Self {
+        self.name = Some(name);
+        self
+    }
+
+    /// 0 spot wallet, 1 funding wallet.
+    pub fn wallet_type(mut self, wallet_type: u8) -> Self {
+        self.wallet_type = Some(wallet_type);
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: u16) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WithdrawHistoryParams<'a> {
+    pub coin: Option<&'a str>,
+    pub withdraw_order_id: Option<&'a str>,
+    pub status: Option<u8>,
+    pub offset: Option<u32>,
+    pub limit: Option<u16>,
+    pub id_list: Option<String>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub recv_window: Option<u16>,
+}
+
+impl<'a> WithdrawHistoryParams<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn coin(mut self, coin: &'a str) -> Self {
+        self.coin = Some(coin);
+        self
+    }
+
+    pub fn withdraw_order_id(mut self, withdraw_order_id: &'a str) -> Self {
+        self.withdraw_order_id = Some(withdraw_order_id);
+        self
+    }
+
+    /// 0 email sent, 2 awaiting approval, 3 rejected, 4 processing, 6 completed.
+    pub fn status(mut self, status: u8) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    pub fn offset(mut self, offset: u32) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    pub fn limit(mut self, limit: u16) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn id_list<S: AsRef<str>>(mut self, ids: impl IntoIterator<Item = S>) -> Self {
+        self.id_list = Some(
+            ids.into_iter()
+                .map(|id| id.as_ref().to_string())
+                .collect::<Vec<_>>()
+                .join(","),
+        );
+        self
+    }
+
+    pub fn start_time(mut self, start_time: u64) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    pub fn end_time(mut self, end_time: u64) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: u16) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+
+/// Permissions granted to the API key making the request.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiRestrictionsResponse {
+    pub ip_restrict: bool,
+    pub create_time: u64,
+    pub enable_reading: bool,
+    pub enable_withdrawals: bool,
+    pub enable_internal_transfer: bool,
+    pub enable_margin: bool,
+    pub enable_futures: bool,
+    pub permits_universal_transfer: bool,
+    pub enable_vanilla_options: bool,
+    pub enable_spot_and_margin_trading: bool,
+
+    #[serde(default)]
+    pub enable_portfolio_margin_trading: bool,
+
+    #[serde(default)]
+    pub trading_authority_expiration_time: Option<u64>,
+}
@@ -0,0 +1,184 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use serde_with::{DisplayFromStr, serde_as};
+
+use crate::enums::wallet::UniversalTransferType;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferResponse {
+    pub tran_id: u64,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferHistoryResponse {
+    pub total: u64,
+
+    /// Missing from the response when `total` is 0.
+    #[serde(default)]
+    pub rows: Vec<TransferRecord>,
+}
+
+#[serde_as]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferRecord {
+    pub asset: String,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub amount: f64,
+
+    pub r#type: UniversalTransferType,
+    pub status: String,
+    pub tran_id: u64,
+    pub timestamp: u64,
+}
+
+#[serde_as]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DustConvertibleResponse {
+    pub details: Vec<DustAssetResponse>,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub total_transfer_btc: f64,
+
+    #[serde(rename = "totalTransferBNB")]
+    #[serde_as(as = "DisplayFromStr")]
+    pub total_transfer_bnb: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub dribblet_percentage: f64,
+}
+
+#[serde_as]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DustAssetResponse {
+    pub asset: String,
+    pub asset_full_name: String,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub amount_free: f64,
+
+    #[serde(rename = "toBTC")]
+    #[serde_as(as = "DisplayFromStr")]
+    pub to_btc: f64,
+
+    #[serde(rename = "toBNB")]
+    #[serde_as(as = "DisplayFromStr")]
+    pub to_bnb: f64,
+
+    #[serde(rename = "toBNBOffExchange")]
+    #[serde_as(as = "DisplayFromStr")]
+    pub to_bnb_off_exchange: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub exchange: f64,
+}
+
+#[serde_as]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DustResponse {
+    #[serde_as(as = "DisplayFromStr")]
+    pub total_service_charge: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub total_transfered: f64,
+
+    pub transfer_result: Vec<DustTransferResponse>,
+}
+
+#[serde_as]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DustTransferResponse {
+    #[serde_as(as = "DisplayFromStr")]
+    pub amount: f64,
+
+    pub from_asset: String,
+    pub operate_time: u64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub service_charge_amount: f64,
+
+    pub tran_id: u64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub transfered_amount: f64,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DustLogResponse {
+    pub total: u64,
+    pub user_asset_dribblets: Vec<DustLogEntry>,
+}
+
+#[serde_as]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DustLogEntry {
+    pub operate_time: u64,
+    pub trans_id: u64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub total_transfered_amount: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub total_service_charge_amount: f64,
+
+    pub user_asset_dribblet_details: Vec<DustLogDetail>,
+}
+
+#[serde_as]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DustLogDetail {
+    pub trans_id: u64,
+    pub from_asset: String,
+    pub operate_time: u64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub amount: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub service_charge_amount: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub transfered_amount: f64,
+}
+
+#[serde_as]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TradeFeeResponse {
+    pub symbol: String,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub maker_commission: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub taker_commission: f64,
+}
+
+/// Keyed by asset.
+pub type AssetDetailResponse = HashMap<String, AssetDetail>;
+
+#[serde_as]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetDetail {
+    #[serde_as(as = "DisplayFromStr")]
+    pub min_withdraw_amount: f64,
+
+    pub deposit_status: bool,
+    pub withdraw_fee: f64,
+    pub withdraw_status: bool,
+
+    #[serde(default)]
+    pub deposit_tip: Option<String>,
+}
@@ -0,0 +1,136 @@
+use serde::{Deserialize, Serialize};
+use serde_with::{DisplayFromStr, serde_as};
+
+#[serde_as]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CoinInfoResponse {
+    pub coin: String,
+    pub name: String,
+    pub deposit_all_enable: bool,
+    pub withdraw_all_enable: bool,
+    pub is_legal_money: bool,
+    pub trading: bool,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub free: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub locked: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub freeze: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub withdrawing: f64,
+
+    pub network_list: Vec<NetworkResponse>,
+}
+
+#[serde_as]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NetworkResponse {
+    pub network: String,
+    pub coin: String,
+    pub name: String,
+    pub is_default: bool,
+    pub deposit_enable: bool,
+    pub withdraw_enable: bool,
+
+    #[serde(default)]
+    pub deposit_desc: String,
+
+    #[serde(default)]
+    pub withdraw_desc: String,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub withdraw_fee: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub withdraw_min: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub withdraw_max: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub withdraw_integer_multiple: f64,
+
+    pub min_confirm: u32,
+    pub un_lock_confirm: u32,
+    pub same_address: bool,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DepositAddressResponse {
+    pub address: String,
+    pub coin: String,
+    pub tag: String,
+    pub url: String,
+}
+
+#[serde_as]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DepositResponse {
+    pub id: String,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub amount: f64,
+
+    pub coin: String,
+    pub network: String,
+    pub status: u8,
+    pub address: String,
+    pub address_tag: String,
+    pub tx_id: String,
+    pub insert_time: u64,
+    pub transfer_type: u8,
+    pub confirm_times: String,
+    pub wallet_type: u8,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WithdrawResponse {
+    pub id: String,
+}
+
+#[serde_as]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WithdrawRecordResponse {
+    pub id: String,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub amount: f64,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub transaction_fee: f64,
+
+    pub coin: String,
+    pub status: u8,
+    pub address: String,
+
+    #[serde(default)]
+    pub tx_id: String,
+
+    /// UTC time formatted as `yyyy-MM-dd HH:mm:ss`.
+    pub apply_time: String,
+
+    pub network: String,
+    pub transfer_type: u8,
+
+    #[serde(default)]
+    pub withdraw_order_id: Option<String>,
+
+    #[serde(default)]
+    pub info: String,
+
+    pub confirm_no: u32,
+    pub wallet_type: u8,
+
+    #[serde(default)]
+    pub complete_time: Option<String>,
+}
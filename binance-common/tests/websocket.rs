@@ -0,0 +1,84 @@
+#[cfg(test)]
+mod websocket_integration_tests {
+    use binance_common::{
+        enums::WebSocketType,
+        futures::model::response::websocket::{
+            WebSocketResponse,
+            borrowed::{Decimal, WebSocketResponseRef},
+        },
+    };
+
+    const BOOK_TICKER: &str = r#"{"e":"bookTicker","u":400900217,"E":1568014460893,"T":1568014460891,"s":"BNBUSDT","b":"25.35190000","B":"31.21000000","a":"25.36520000","A":"40.66000000"}"#;
+    const DEPTH: &str = r#"{"stream":"btcusdt@depth5@100ms","data":{"e":"depthUpdate","E":1571889248277,"T":1571889248276,"s":"BTCUSDT","U":390497796,"u":390497878,"pu":390497794,"b":[["7403.89","0.002"],["7403.90","3.906"]],"a":[["7405.96","3.340"]]}}"#;
+    const KLINE: &str = r#"{"e":"kline","E":1638747660000,"s":"BTCUSDT","k":{}}"#;
+
+    #[test]
+    fn test_borrowed_book_ticker() {
+        let response = WebSocketResponseRef::decode(BOOK_TICKER, WebSocketType::SingleStream);
+
+        let Ok(WebSocketResponseRef::BookTicker(ticker)) = response else {
+            panic!("unexpected response: {:?}", response);
+        };
+
+        assert_eq!(ticker.symbol, "BNBUSDT");
+        assert_eq!(ticker.best_bid_price, Decimal::new("25.35190000"));
+        assert_eq!(ticker.best_ask_quantity.to_f64(), Some(40.66));
+
+        let owned: WebSocketResponse = serde_json::from_str(BOOK_TICKER).unwrap();
+        assert!(matches!(
+            owned,
+            WebSocketResponse::BookTicker(owned) if owned.best_bid_price == 25.3519
+        ));
+    }
+
+    #[test]
+    fn test_borrowed_combined_depth() {
+        let response = WebSocketResponseRef::decode(DEPTH, WebSocketType::MultiStream);
+
+        let Ok(WebSocketResponseRef::DepthUpdate(depth)) = response else {
+            panic!("unexpected response: {:?}", response);
+        };
+
+        assert_eq!(depth.symbol, "BTCUSDT");
+        assert_eq!(depth.previous_final_update_id, 390497794);
+        assert_eq!(depth.bids.len(), 2);
+        assert_eq!(depth.bids[1].price.as_str(), "7403.90");
+        assert_eq!(depth.asks[0].quantity.to_f64(), Some(3.34));
+    }
+
+    #[test]
+    fn test_borrowed_unsupported_and_invalid() {
+        assert!(matches!(
+            WebSocketResponseRef::decode(KLINE, WebSocketType::SingleStream),
+            Ok(WebSocketResponseRef::Unsupported)
+        ));
+        assert!(
+            WebSocketResponseRef::decode(
+                r#"{"e":"bookTicker","s":1}"#,
+                WebSocketType::SingleStream
+            )
+            .is_err()
+        );
+    }
+
+    #[cfg(feature = "simd")]
+    #[test]
+    fn test_simd_matches_serde_json() {
+        use binance_common::futures::model::response::websocket::borrowed::SimdDecoder;
+
+        let mut decoder = SimdDecoder::new();
+
+        let Ok(WebSocketResponseRef::DepthUpdate(depth)) =
+            decoder.decode(DEPTH, WebSocketType::MultiStream)
+        else {
+            panic!("simd decoding failed");
+        };
+
+        assert_eq!(depth.final_update_id, 390497878);
+        assert_eq!(depth.bids[0].price.as_str(), "7403.89");
+        assert!(matches!(
+            decoder.decode(BOOK_TICKER, WebSocketType::SingleStream),
+            Ok(WebSocketResponseRef::BookTicker(ticker)) if ticker.symbol == "BNBUSDT"
+        ));
+    }
+}
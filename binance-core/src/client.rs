@@ -1,2 +1,51 @@
 pub mod asynchronous;
 pub mod synchronous;
+
+use binance_common::error::{ApiError, BinanceError, ErrorContext};
+use reqwest::{StatusCode, header::HeaderMap};
+use serde::de::DeserializeOwned;
+
+/// Captures what is needed to explain a failed call before the body is read.
+pub(crate) fn error_context(route: &str, status: StatusCode, headers: &HeaderMap) -> ErrorContext {
+    let headers = headers
+        .iter()
+        .filter_map(|(name, value)| {
+            value
+                .to_str()
+                .ok()
+                .map(|value| (name.as_str().to_string(), value.to_string()))
+        })
+        .collect();
+
+    ErrorContext::new(route, status.as_u16(), headers)
+}
+
+/// Status mapping shared by the synchronous and asynchronous clients.
+pub(crate) fn decode<T: DeserializeOwned>(
+    context: ErrorContext,
+    body: &[u8],
+) -> Result<T, BinanceError> {
+    let status = StatusCode::from_u16(context.status).unwrap_or(StatusCode::OK);
+
+    match status {
+        StatusCode::OK => Ok(serde_json::from_slice::<T>(body).map_err(BinanceError::Deserialize)?),
+        StatusCode::IM_A_TEAPOT => Err(BinanceError::IpBanned(context)),
+        StatusCode::TOO_MANY_REQUESTS => Err(BinanceError::TooManyRequest(context)),
+        StatusCode::REQUEST_TIMEOUT => Err(BinanceError::RequestTimeout(context)),
+        status if status.is_server_error() => Err(BinanceError::InternalServer(context)),
+        StatusCode::BAD_REQUEST | StatusCode::UNAUTHORIZED => {
+            let mut api_error: ApiError =
+                serde_json::from_slice(body).map_err(BinanceError::Deserialize)?;
+            api_error.context = Some(context);
+
+            Err(BinanceError::Api(api_error))
+        }
+        _ => match serde_json::from_slice::<ApiError>(body) {
+            Ok(mut api_error) => {
+                api_error.context = Some(context);
+                Err(BinanceError::Api(api_error))
+            }
+            Err(_) => Err(BinanceError::Http(context)),
+        },
+    }
+}
@@ -1,16 +1,21 @@
-use crate::signer::signature::Signature;
 use binance_common::error::BinanceError;
 use binance_common::url::UrlEncoded;
-use reqwest::{Method, RequestBuilder, Response, StatusCode};
+use reqwest::Method;
 use serde::de::DeserializeOwned;
 
-pub struct Client<'a, S>
+use crate::connection::ConnectionConfig;
+use crate::endpoint::{Encoding, Endpoint, Security};
+use crate::signer::signature::Signature;
+use crate::transport::{Request, asynchronous::Transport};
+
+pub struct Client<'a, S, T = reqwest::Client>
 where
     S: Signature<'a>,
+    T: Transport,
 {
     host: &'a str,
     signature: S,
-    inner_client: reqwest::Client,
+    transport: T,
 }
 
 impl<'a, S> Client<'a, S>
@@ -18,80 +23,147 @@ where
     S: Signature<'a>,
 {
     pub fn new(host: &'a impl AsRef<str>, signature: S) -> Client<'a, S> {
+        Client::with_transport(host, signature, reqwest::Client::new())
+    }
+
+    /// A client whose connections follow `config`.
+    pub fn with_config(
+        host: &'a impl AsRef<str>,
+        signature: S,
+        config: &ConnectionConfig,
+    ) -> Result<Client<'a, S>, BinanceError> {
+        Ok(Client::with_transport(host, signature, config.client()?))
+    }
+}
+
+impl<'a, S, T> Client<'a, S, T>
+where
+    S: Signature<'a>,
+    T: Transport,
+{
+    pub fn with_transport(host: &'a impl AsRef<str>, signature: S, transport: T) -> Self {
         Client {
             host: host.as_ref(),
             signature,
-            inner_client: reqwest::Client::new(),
+            transport,
         }
     }
 
-    pub async fn get<T>(
+    /// Calls the endpoint `E` describes, authenticated as it requires.
+    pub async fn call<E: Endpoint>(
+        &self,
+        params: &E::Params<'_>,
+    ) -> Result<E::Response, BinanceError> {
+        self.call_as::<E, E::Response>(params).await
+    }
+
+    /// Like `call`, decoding the response as `R` for endpoints whose response
+    /// shape depends on the params.
+    pub async fn call_as<E: Endpoint, R: DeserializeOwned>(
+        &self,
+        params: &E::Params<'_>,
+    ) -> Result<R, BinanceError> {
+        match E::SECURITY {
+            Security::None => self.public(E::ROUTE, params, E::METHOD).await,
+            Security::ApiKey => self.send_keyed(E::ROUTE, params, E::METHOD).await,
+            Security::Signed => self.signed(E::ROUTE, params, E::METHOD, E::ENCODING).await,
+        }
+    }
+
+    pub async fn get<R>(
         &self,
         path: impl AsRef<str>,
         params: impl UrlEncoded,
-    ) -> Result<T, BinanceError>
+    ) -> Result<R, BinanceError>
     where
-        T: DeserializeOwned,
+        R: DeserializeOwned,
+    {
+        self.public(path, params, Method::GET).await
+    }
+
+    async fn public<R>(
+        &self,
+        path: impl AsRef<str>,
+        params: impl UrlEncoded,
+        method: Method,
+    ) -> Result<R, BinanceError>
+    where
+        R: DeserializeOwned,
     {
         let endpoint = format!("{}{}{}", self.host, path.as_ref(), params.to_url_encoded());
 
-        let response = self.inner_client.get(endpoint).send().await?;
+        self.execute(Request::new(method, endpoint)).await
+    }
 
-        self.handle::<T>(response).await
+    pub async fn send<R>(
+        &self,
+        path: impl AsRef<str>,
+        params: impl UrlEncoded,
+        method: Method,
+    ) -> Result<R, BinanceError>
+    where
+        R: DeserializeOwned,
+    {
+        self.signed(path, params, method, Encoding::Query).await
+    }
+
+    /// Like `send`, with `params` signed in a form body instead of the query.
+    pub async fn send_form<R>(
+        &self,
+        path: impl AsRef<str>,
+        params: impl UrlEncoded,
+        method: Method,
+    ) -> Result<R, BinanceError>
+    where
+        R: DeserializeOwned,
+    {
+        self.signed(path, params, method, Encoding::Form).await
     }
 
-    pub async fn send<T>(
+    async fn signed<R>(
         &self,
         path: impl AsRef<str>,
         params: impl UrlEncoded,
         method: Method,
-    ) -> Result<T, BinanceError>
+        encoding: Encoding,
+    ) -> Result<R, BinanceError>
     where
-        T: DeserializeOwned,
+        R: DeserializeOwned,
     {
-        let request = self.signature.build_request(
-            &self.inner_client,
+        let request = self.signature.sign_request(
             self.host,
             path.as_ref(),
             params.to_url_encoded().as_str(),
             method,
+            encoding,
         )?;
 
-        let response = RequestBuilder::send(request).await?;
-        self.handle::<T>(response).await
+        self.execute(request).await
     }
 
-    pub async fn handle<T: DeserializeOwned>(&self, response: Response) -> Result<T, BinanceError> {
-        let status = response.status();
-        let body = response.bytes().await?;
-
-        match status {
-            StatusCode::BAD_REQUEST => {
-                let api_error: binance_common::error::ApiError =
-                    serde_json::from_slice(&body).map_err(BinanceError::Deserialize)?;
-                Err(BinanceError::Api(api_error))
-            }
-
-            StatusCode::IM_A_TEAPOT => Err(BinanceError::IpBanned),
-
-            StatusCode::INTERNAL_SERVER_ERROR => Err(BinanceError::InternalServer),
-
-            StatusCode::OK => {
-                Ok(serde_json::from_slice::<T>(&body).map_err(BinanceError::Deserialize)?)
-            }
-
-            StatusCode::REQUEST_TIMEOUT => Err(BinanceError::RequestTimeout),
-            StatusCode::UNAUTHORIZED => {
-                let api_error: binance_common::error::ApiError =
-                    serde_json::from_slice(&body).map_err(BinanceError::Deserialize)?;
-
-                Err(BinanceError::Api(api_error))
-            }
-            StatusCode::TOO_MANY_REQUESTS => Err(BinanceError::TooManyRequest),
-            status_code => Err(BinanceError::Unknown(format!(
-                "Response Status Code: {}",
-                status_code
-            ))),
-        }
+    /// Sends `params` unsigned with only the API key header, as `USER_STREAM`
+    /// endpoints expect.
+    pub async fn send_keyed<R>(
+        &self,
+        path: impl AsRef<str>,
+        params: impl UrlEncoded,
+        method: Method,
+    ) -> Result<R, BinanceError>
+    where
+        R: DeserializeOwned,
+    {
+        let endpoint = format!("{}{}{}", self.host, path.as_ref(), params.to_url_encoded());
+        let request =
+            Request::new(method, endpoint).header("x-mbx-apikey", self.signature.api_key())?;
+
+        self.execute(request).await
+    }
+
+    async fn execute<R: DeserializeOwned>(&self, request: Request) -> Result<R, BinanceError> {
+        let route = request.path().to_string();
+        let response = self.transport.send(request).await?;
+        let context = super::error_context(&route, response.status, &response.headers);
+
+        super::decode(context, &response.body)
     }
 }
@@ -1,20 +1,21 @@
 use binance_common::error::BinanceError;
 use binance_common::url::UrlEncoded;
-use reqwest::{
-    Method, StatusCode,
-    blocking::{RequestBuilder, Response},
-};
+use reqwest::Method;
 use serde::de::DeserializeOwned;
 
+use crate::connection::ConnectionConfig;
+use crate::endpoint::{Encoding, Endpoint, Security};
 use crate::signer::signature::Signature;
+use crate::transport::{Request, synchronous::Transport};
 
-pub struct Client<'a, S>
+pub struct Client<'a, S, T = reqwest::blocking::Client>
 where
     S: Signature<'a>,
+    T: Transport,
 {
     host: &'a str,
     signature: S,
-    inner_client: reqwest::blocking::Client,
+    transport: T,
 }
 
 impl<'a, S> Client<'a, S>
@@ -22,81 +23,144 @@ where
     S: Signature<'a>,
 {
     pub fn new(host: &'a impl AsRef<str>, signature: S) -> Client<'a, S> {
+        Client::with_transport(host, signature, reqwest::blocking::Client::new())
+    }
+
+    /// A client whose connections follow `config`.
+    pub fn with_config(
+        host: &'a impl AsRef<str>,
+        signature: S,
+        config: &ConnectionConfig,
+    ) -> Result<Client<'a, S>, BinanceError> {
+        Ok(Client::with_transport(
+            host,
+            signature,
+            config.blocking_client()?,
+        ))
+    }
+}
+
+impl<'a, S, T> Client<'a, S, T>
+where
+    S: Signature<'a>,
+    T: Transport,
+{
+    pub fn with_transport(host: &'a impl AsRef<str>, signature: S, transport: T) -> Self {
         Client {
             host: host.as_ref(),
             signature,
-            inner_client: reqwest::blocking::Client::new(),
+            transport,
         }
     }
 
-    pub fn get<T>(
+    /// Calls the endpoint `E` describes, authenticated as it requires.
+    pub fn call<E: Endpoint>(&self, params: &E::Params<'_>) -> Result<E::Response, BinanceError> {
+        self.call_as::<E, E::Response>(params)
+    }
+
+    /// Like `call`, decoding the response as `R` for endpoints whose response
+    /// shape depends on the params.
+    pub fn call_as<E: Endpoint, R: DeserializeOwned>(
+        &self,
+        params: &E::Params<'_>,
+    ) -> Result<R, BinanceError> {
+        match E::SECURITY {
+            Security::None => self.public(E::ROUTE, params, E::METHOD),
+            Security::ApiKey => self.send_keyed(E::ROUTE, params, E::METHOD),
+            Security::Signed => self.signed(E::ROUTE, params, E::METHOD, E::ENCODING),
+        }
+    }
+
+    pub fn get<R>(&self, path: impl AsRef<str>, params: impl UrlEncoded) -> Result<R, BinanceError>
+    where
+        R: DeserializeOwned,
+    {
+        self.public(path, params, Method::GET)
+    }
+
+    fn public<R>(
         &self,
         path: impl AsRef<str>,
         params: impl UrlEncoded,
-    ) -> std::result::Result<T, BinanceError>
+        method: Method,
+    ) -> Result<R, BinanceError>
     where
-        T: DeserializeOwned,
+        R: DeserializeOwned,
     {
         let endpoint = format!("{}{}{}", self.host, path.as_ref(), params.to_url_encoded());
 
-        let response = self.inner_client.get(endpoint).send()?;
+        self.execute(Request::new(method, endpoint))
+    }
+
+    pub fn send<R>(
+        &self,
+        path: impl AsRef<str>,
+        params: impl UrlEncoded,
+        method: Method,
+    ) -> Result<R, BinanceError>
+    where
+        R: DeserializeOwned,
+    {
+        self.signed(path, params, method, Encoding::Query)
+    }
 
-        self.handle::<T>(response)
+    /// Like `send`, with `params` signed in a form body instead of the query.
+    pub fn send_form<R>(
+        &self,
+        path: impl AsRef<str>,
+        params: impl UrlEncoded,
+        method: Method,
+    ) -> Result<R, BinanceError>
+    where
+        R: DeserializeOwned,
+    {
+        self.signed(path, params, method, Encoding::Form)
     }
 
-    pub fn send<T>(
+    fn signed<R>(
         &self,
         path: impl AsRef<str>,
         params: impl UrlEncoded,
         method: Method,
-    ) -> Result<T, BinanceError>
+        encoding: Encoding,
+    ) -> Result<R, BinanceError>
     where
-        T: DeserializeOwned,
+        R: DeserializeOwned,
     {
-        let request = self.signature.build_blocking_request(
-            &self.inner_client,
+        let request = self.signature.sign_request(
             self.host,
             path.as_ref(),
             params.to_url_encoded().as_str(),
             method,
+            encoding,
         )?;
 
-        let response = RequestBuilder::send(request)?;
+        self.execute(request)
+    }
 
-        self.handle(response)
+    /// Sends `params` unsigned with only the API key header, as `USER_STREAM`
+    /// endpoints expect.
+    pub fn send_keyed<R>(
+        &self,
+        path: impl AsRef<str>,
+        params: impl UrlEncoded,
+        method: Method,
+    ) -> Result<R, BinanceError>
+    where
+        R: DeserializeOwned,
+    {
+        let endpoint = format!("{}{}{}", self.host, path.as_ref(), params.to_url_encoded());
+        let request =
+            Request::new(method, endpoint).header("x-mbx-apikey", self.signature.api_key())?;
+
+        self.execute(request)
     }
 
-    pub fn handle<T: DeserializeOwned>(&self, response: Response) -> Result<T, BinanceError> {
-        let status = response.status();
-        let body = response.bytes()?;
-
-        match status {
-            StatusCode::BAD_REQUEST => {
-                let api_error: binance_common::error::ApiError =
-                    serde_json::from_slice(&body).map_err(BinanceError::Deserialize)?;
-                Err(BinanceError::Api(api_error))
-            }
-
-            StatusCode::IM_A_TEAPOT => Err(BinanceError::IpBanned),
-
-            StatusCode::INTERNAL_SERVER_ERROR => Err(BinanceError::InternalServer),
-
-            StatusCode::OK => {
-                Ok(serde_json::from_slice::<T>(&body).map_err(BinanceError::Deserialize)?)
-            }
-
-            StatusCode::REQUEST_TIMEOUT => Err(BinanceError::RequestTimeout),
-            StatusCode::UNAUTHORIZED => {
-                let api_error: binance_common::error::ApiError =
-                    serde_json::from_slice(&body).map_err(BinanceError::Deserialize)?;
-
-                Err(BinanceError::Api(api_error))
-            }
-            StatusCode::TOO_MANY_REQUESTS => Err(BinanceError::TooManyRequest),
-            status_code => Err(BinanceError::Unknown(format!(
-                "Response Status Code: {}",
-                status_code
-            ))),
-        }
+    fn execute<R: DeserializeOwned>(&self, request: Request) -> Result<R, BinanceError> {
+        let route = request.path().to_string();
+        let response = self.transport.send(request)?;
+        let context = super::error_context(&route, response.status, &response.headers);
+
+        super::decode(context, &response.body)
     }
 }
@@ -0,0 +1,307 @@
+mod tunnel;
+
+use std::{
+    io,
+    net::{IpAddr, SocketAddr},
+    time::Duration,
+};
+
+use binance_common::error::BinanceError;
+use reqwest::{Proxy, Url};
+use socket2::{SockRef, TcpKeepalive};
+use tokio::net::{TcpSocket, TcpStream};
+use tokio_tungstenite::{
+    MaybeTlsStream, WebSocketStream, client_async_tls_with_config,
+    tungstenite::{client::IntoClientRequest, http::HeaderValue},
+};
+
+pub type WebSocket = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+/// Socket and HTTP settings shared by the REST clients and the WebSocket
+/// engines, everything unset is left to the library defaults.
+#[derive(Clone, Debug)]
+pub struct ConnectionConfig {
+    /// Bounds connecting and, for WebSockets behind a proxy, the tunnel handshake.
+    pub connect_timeout: Option<Duration>,
+    /// Longest wait for a response read. The blocking client has no read
+    /// timeout and bounds the whole request with it instead.
+    pub read_timeout: Option<Duration>,
+    /// `http://`, `socks5://` or `socks5h://` url, credentials included.
+    pub proxy: Option<String>,
+    pub pool_idle_timeout: Option<Duration>,
+    pub pool_max_idle_per_host: Option<usize>,
+    pub tcp_keepalive: Option<Duration>,
+    pub tcp_nodelay: bool,
+    /// Speaks HTTP/2 to the REST hosts without negotiating it.
+    pub http2_prior_knowledge: bool,
+    pub user_agent: Option<String>,
+    /// Source address of every connection, to pin traffic to one interface.
+    pub local_address: Option<IpAddr>,
+}
+
+impl Default for ConnectionConfig {
+    fn default() -> Self {
+        ConnectionConfig {
+            connect_timeout: None,
+            read_timeout: None,
+            proxy: None,
+            pool_idle_timeout: None,
+            pool_max_idle_per_host: None,
+            tcp_keepalive: None,
+            tcp_nodelay: true,
+            http2_prior_knowledge: false,
+            user_agent: None,
+            local_address: None,
+        }
+    }
+}
+
+impl ConnectionConfig {
+    pub fn new() -> Self {
+        ConnectionConfig::default()
+    }
+
+    pub fn connect_timeout(mut self, connect_timeout: Duration) -> Self {
+        self.connect_timeout = Some(connect_timeout);
+        self
+    }
+
+    pub fn read_timeout(mut self, read_timeout: Duration) -> Self {
+        self.read_timeout = Some(read_timeout);
+        self
+    }
+
+    pub fn proxy(mut self, proxy: impl Into<String>) -> Self {
+        self.proxy = Some(proxy.into());
+        self
+    }
+
+    pub fn pool_idle_timeout(mut self, pool_idle_timeout: Duration) -> Self {
+        self.pool_idle_timeout = Some(pool_idle_timeout);
+        self
+    }
+
+    pub fn pool_max_idle_per_host(mut self, pool_max_idle_per_host: usize) -> Self {
+        self.pool_max_idle_per_host = Some(pool_max_idle_per_host);
+        self
+    }
+
+    pub fn tcp_keepalive(mut self, tcp_keepalive: Duration) -> Self {
+        self.tcp_keepalive = Some(tcp_keepalive);
+        self
+    }
+
+    pub fn tcp_nodelay(mut self, tcp_nodelay: bool) -> Self {
+        self.tcp_nodelay = tcp_nodelay;
+        self
+    }
+
+    pub fn http2_prior_knowledge(mut self, http2_prior_knowledge: bool) -> Self {
+        self.http2_prior_knowledge = http2_prior_knowledge;
+        self
+    }
+
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    pub fn local_address(mut self, local_address: IpAddr) -> Self {
+        self.local_address = Some(local_address);
+        self
+    }
+
+    /// An HTTP client with these settings, for the asynchronous `Client`.
+    pub fn client(&self) -> Result<reqwest::Client, BinanceError> {
+        let mut builder = reqwest::Client::builder()
+            .tcp_nodelay(self.tcp_nodelay)
+            .tcp_keepalive(self.tcp_keepalive)
+            .local_address(self.local_address);
+
+        if let Some(connect_timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(connect_timeout);
+        }
+        if let Some(read_timeout) = self.read_timeout {
+            builder = builder.read_timeout(read_timeout);
+        }
+        if let Some(proxy) = &self.proxy {
+            builder = builder.proxy(Proxy::all(proxy)?);
+        }
+        if let Some(pool_idle_timeout) = self.pool_idle_timeout {
+            builder = builder.pool_idle_timeout(pool_idle_timeout);
+        }
+        if let Some(pool_max_idle_per_host) = self.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(pool_max_idle_per_host);
+        }
+        if self.http2_prior_knowledge {
+            builder = builder.http2_prior_knowledge();
+        }
+        if let Some(user_agent) = &self.user_agent {
+            builder = builder.user_agent(user_agent);
+        }
+
+        Ok(builder.build()?)
+    }
+
+    /// An HTTP client with these settings, for the synchronous `Client`. Not
+    /// to be built or dropped inside an asynchronous runtime.
+    pub fn blocking_client(&self) -> Result<reqwest::blocking::Client, BinanceError> {
+        let mut builder = reqwest::blocking::Client::builder()
+            .tcp_nodelay(self.tcp_nodelay)
+            .tcp_keepalive(self.tcp_keepalive)
+            .local_address(self.local_address)
+            .connect_timeout(self.connect_timeout)
+            .timeout(self.read_timeout);
+
+        if let Some(proxy) = &self.proxy {
+            builder = builder.proxy(Proxy::all(proxy)?);
+        }
+        if let Some(pool_idle_timeout) = self.pool_idle_timeout {
+            builder = builder.pool_idle_timeout(pool_idle_timeout);
+        }
+        if let Some(pool_max_idle_per_host) = self.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(pool_max_idle_per_host);
+        }
+        if self.http2_prior_knowledge {
+            builder = builder.http2_prior_knowledge();
+        }
+        if let Some(user_agent) = &self.user_agent {
+            builder = builder.user_agent(user_agent);
+        }
+
+        Ok(builder.build()?)
+    }
+
+    /// Opens a WebSocket to `url` with these settings. Frames are read without
+    /// a timeout and the pool and HTTP/2 settings do not apply.
+    pub async fn connect_websocket(&self, url: &str) -> Result<WebSocket, BinanceError> {
+        let mut request = url
+            .into_client_request()
+            .map_err(|error| BinanceError::WebSocketInternal(error.to_string()))?;
+
+        if let Some(user_agent) = &self.user_agent {
+            let user_agent = HeaderValue::from_str(user_agent)
+                .map_err(|error| BinanceError::BuildRequest(error.to_string()))?;
+            request.headers_mut().insert("user-agent", user_agent);
+        }
+
+        let uri = request.uri();
+        let host = uri
+            .host()
+            .map(|host| {
+                host.trim_start_matches('[')
+                    .trim_end_matches(']')
+                    .to_string()
+            })
+            .ok_or_else(|| BinanceError::WebSocketInternal(format!("{} has no host!", url)))?;
+        let port = uri
+            .port_u16()
+            .unwrap_or(if uri.scheme_str() == Some("wss") {
+                443
+            } else {
+                80
+            });
+
+        let stream = match &self.proxy {
+            Some(proxy) => {
+                let proxy = Url::parse(proxy)
+                    .map_err(|error| BinanceError::BuildRequest(error.to_string()))?;
+                let stream = self
+                    .tcp(
+                        proxy.host_str().unwrap_or_default(),
+                        proxy.port_or_known_default().unwrap_or(1080),
+                    )
+                    .await?;
+
+                self.within_connect_timeout(tunnel::open(stream, &proxy, &host, port))
+                    .await
+                    .map_err(|error| {
+                        BinanceError::WebSocketInternal(format!(
+                            "The proxy has failed to open a tunnel to {}: {}",
+                            host, error
+                        ))
+                    })?
+            }
+            None => self.tcp(&host, port).await?,
+        };
+
+        let (socket, _) = client_async_tls_with_config(request, stream, None, None)
+            .await
+            .map_err(|error| BinanceError::WebSocketInternal(error.to_string()))?;
+
+        Ok(socket)
+    }
+
+    /// A TCP connection to the first address of `host` that answers, within
+    /// the connect timeout.
+    async fn tcp(&self, host: &str, port: u16) -> Result<TcpStream, BinanceError> {
+        let connect = async {
+            let mut last_error = None;
+
+            for address in tokio::net::lookup_host((host, port)).await? {
+                match self.tcp_to(address).await {
+                    Ok(stream) => return Ok(stream),
+                    Err(error) => last_error = Some(error),
+                }
+            }
+
+            Err(last_error.unwrap_or_else(|| {
+                io::Error::new(io::ErrorKind::NotFound, "no address to connect to")
+            }))
+        };
+
+        let stream = self
+            .within_connect_timeout(connect)
+            .await
+            .map_err(|error| {
+                BinanceError::WebSocketInternal(format!(
+                    "Failed to connect to {}:{}: {}",
+                    host, port, error
+                ))
+            })?;
+
+        stream
+            .set_nodelay(self.tcp_nodelay)
+            .map_err(|error| BinanceError::WebSocketInternal(error.to_string()))?;
+
+        Ok(stream)
+    }
+
+    /// Fails with `TimedOut` once the connect timeout has passed.
+    async fn within_connect_timeout<T>(
+        &self,
+        future: impl Future<Output = io::Result<T>>,
+    ) -> io::Result<T> {
+        match self.connect_timeout {
+            Some(connect_timeout) => tokio::time::timeout(connect_timeout, future)
+                .await
+                .unwrap_or_else(|_| Err(io::ErrorKind::TimedOut.into())),
+            None => future.await,
+        }
+    }
+
+    async fn tcp_to(&self, address: SocketAddr) -> io::Result<TcpStream> {
+        let socket = match address {
+            SocketAddr::V4(_) => TcpSocket::new_v4()?,
+            SocketAddr::V6(_) => TcpSocket::new_v6()?,
+        };
+
+        if let Some(local_address) = self.local_address {
+            if local_address.is_ipv4() != address.is_ipv4() {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "the local address is of another family",
+                ));
+            }
+
+            socket.bind(SocketAddr::new(local_address, 0))?;
+        }
+
+        if let Some(tcp_keepalive) = self.tcp_keepalive {
+            SockRef::from(&socket)
+                .set_tcp_keepalive(&TcpKeepalive::new().with_time(tcp_keepalive))?;
+        }
+
+        socket.connect(address).await
+    }
+}
@@ -0,0 +1,188 @@
+use std::{io, net::IpAddr};
+
+use base64::Engine;
+use percent_encoding::percent_decode_str;
+use reqwest::Url;
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpStream,
+};
+
+/// Asks the proxy `stream` is connected to for a tunnel to `host`.
+pub(super) async fn open(
+    stream: TcpStream,
+    proxy: &Url,
+    host: &str,
+    port: u16,
+) -> io::Result<TcpStream> {
+    match proxy.scheme() {
+        "http" => http(stream, proxy, host, port).await,
+        "socks5" => {
+            let address = tokio::net::lookup_host((host, port))
+                .await?
+                .next()
+                .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no address"))?;
+
+            socks5(stream, proxy, &address.ip().to_string(), port).await
+        }
+        "socks5h" => socks5(stream, proxy, host, port).await,
+        scheme => Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            format!("{} proxies are not supported", scheme),
+        )),
+    }
+}
+
+async fn http(mut stream: TcpStream, proxy: &Url, host: &str, port: u16) -> io::Result<TcpStream> {
+    let authority = match host.parse::<IpAddr>() {
+        Ok(IpAddr::V6(_)) => format!("[{}]:{}", host, port),
+        _ => format!("{}:{}", host, port),
+    };
+    let mut request = format!("CONNECT {0} HTTP/1.1\r\nHost: {0}\r\n", authority);
+
+    if let Some((username, password)) = credentials(proxy)? {
+        let credentials = format!("{}:{}", username, password);
+        request.push_str(&format!(
+            "Proxy-Authorization: Basic {}\r\n",
+            base64::engine::general_purpose::STANDARD.encode(credentials)
+        ));
+    }
+
+    request.push_str("\r\n");
+    stream.write_all(request.as_bytes()).await?;
+
+    // Read byte by byte so nothing past the headers is taken from the tunnel.
+    let mut response = Vec::new();
+
+    while !response.ends_with(b"\r\n\r\n") {
+        if response.len() > 8192 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "the proxy response is too long",
+            ));
+        }
+
+        response.push(stream.read_u8().await?);
+    }
+
+    match response.split(|byte| *byte == b' ').nth(1) {
+        Some(b"200") => Ok(stream),
+        _ => Err(io::Error::new(
+            io::ErrorKind::ConnectionRefused,
+            String::from_utf8_lossy(
+                response
+                    .split(|byte| *byte == b'\r')
+                    .next()
+                    .unwrap_or_default(),
+            )
+            .to_string(),
+        )),
+    }
+}
+
+/// RFC 1928 handshake, with the RFC 1929 username and password when the url has one.
+async fn socks5(
+    mut stream: TcpStream,
+    proxy: &Url,
+    host: &str,
+    port: u16,
+) -> io::Result<TcpStream> {
+    let credentials = credentials(proxy)?;
+    let method = if credentials.is_some() { 0x02 } else { 0x00 };
+
+    stream.write_all(&[0x05, 0x01, method]).await?;
+
+    let mut reply = [0u8; 2];
+    stream.read_exact(&mut reply).await?;
+
+    if reply != [0x05, method] {
+        return Err(refused(
+            "the SOCKS5 proxy refused the authentication method",
+        ));
+    }
+
+    if let Some((username, password)) = credentials {
+        let mut authentication = vec![0x01];
+        authentication.extend(length_prefixed(&username)?);
+        authentication.extend(length_prefixed(&password)?);
+        stream.write_all(&authentication).await?;
+        stream.read_exact(&mut reply).await?;
+
+        if reply[1] != 0x00 {
+            return Err(refused("the SOCKS5 proxy refused the credentials"));
+        }
+    }
+
+    let mut connect = vec![0x05, 0x01, 0x00];
+
+    match host.parse::<IpAddr>() {
+        Ok(IpAddr::V4(ip)) => {
+            connect.push(0x01);
+            connect.extend(ip.octets());
+        }
+        Ok(IpAddr::V6(ip)) => {
+            connect.push(0x04);
+            connect.extend(ip.octets());
+        }
+        Err(_) => {
+            connect.push(0x03);
+            connect.extend(length_prefixed(host)?);
+        }
+    }
+
+    connect.extend(port.to_be_bytes());
+    stream.write_all(&connect).await?;
+
+    let mut reply = [0u8; 4];
+    stream.read_exact(&mut reply).await?;
+
+    if reply[1] != 0x00 {
+        return Err(refused(&format!("the SOCKS5 proxy replied {}", reply[1])));
+    }
+
+    // Skips the address the proxy bound, which is of no use here.
+    let bound = match reply[3] {
+        0x01 => 4,
+        0x04 => 16,
+        0x03 => stream.read_u8().await? as usize,
+        _ => return Err(refused("the SOCKS5 proxy replied an unknown address type")),
+    };
+    let mut address = vec![0u8; bound + 2];
+    stream.read_exact(&mut address).await?;
+
+    Ok(stream)
+}
+
+/// Username and password of the proxy url, percent-decoded as the url keeps them encoded.
+fn credentials(proxy: &Url) -> io::Result<Option<(String, String)>> {
+    if proxy.username().is_empty() {
+        return Ok(None);
+    }
+
+    let decode = |value: &str| {
+        percent_decode_str(value)
+            .decode_utf8()
+            .map(|value| value.into_owned())
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidInput, error))
+    };
+
+    Ok(Some((
+        decode(proxy.username())?,
+        decode(proxy.password().unwrap_or_default())?,
+    )))
+}
+
+fn length_prefixed(value: &str) -> io::Result<Vec<u8>> {
+    let length = u8::try_from(value.len()).map_err(|_| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "longer than 255 bytes for SOCKS5",
+        )
+    })?;
+
+    Ok([&[length], value.as_bytes()].concat())
+}
+
+fn refused(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::ConnectionRefused, message.to_string())
+}
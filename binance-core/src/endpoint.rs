@@ -0,0 +1,214 @@
+use serde::{Serialize, de::DeserializeOwned};
+
+pub use reqwest::Method;
+
+/// How a request proves who sends it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Security {
+    None,
+    /// Only the `X-MBX-APIKEY` header, as `MARKET_DATA` and `USER_STREAM` endpoints expect.
+    ApiKey,
+    /// API key header and a signed query, for `TRADE` and `USER_DATA` endpoints.
+    Signed,
+}
+
+/// Where the params of a signed request are sent. Binance signs `totalParams`,
+/// the query string followed by the body.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Encoding {
+    /// Params, timestamp and signature in the query string.
+    #[default]
+    Query,
+    /// Params, timestamp and signature as an `application/x-www-form-urlencoded`
+    /// body, for payloads too long for a URL.
+    Form,
+}
+
+/// Everything needed to call one REST endpoint. Descriptors are declared with
+/// [`endpoints!`](crate::endpoints) and called through `Client::call`.
+pub trait Endpoint {
+    type Route: AsRef<str>;
+    type Params<'p>: Serialize;
+    type Response: DeserializeOwned;
+
+    const ROUTE: Self::Route;
+    const METHOD: Method;
+    /// Request weight with the default parameters, as documented by Binance.
+    const WEIGHT: u16;
+    const SECURITY: Security;
+    /// Only used by `Signed` endpoints.
+    const ENCODING: Encoding = Encoding::Query;
+}
+
+#[doc(hidden)]
+pub mod __private {
+    pub use binance_common::error::BinanceError;
+}
+
+/// Declares endpoint descriptors and the methods calling them on a pair of
+/// synchronous and asynchronous APIs. Both take `<'a, S, T>` like `Client` and
+/// need a `client` field visible to the invoking module.
+///
+/// ```ignore
+/// binance_core::endpoints! {
+///     crate::synchronous::market::MarketApi, crate::asynchronous::market::MarketApi;
+///
+///     pub fn get_depth(DepthParams<'p>) -> DepthResponse = Depth {
+///         GET Market::Depth, weight: 5, security: None
+///     }
+///
+///     // Signed params sent as a form body rather than in the query.
+///     pub fn send_batch_orders(BatchOrdersParams) -> Vec<BatchOrderResponse> = BatchOrders {
+///         POST Trade::MultipleOrders, weight: 5, security: Signed, encoding: Form
+///     }
+///
+///     // A descriptor alone, for methods written by hand.
+///     PriceTicker(PriceTickerParams<'p>) -> PriceTickerResponse {
+///         GET Market::TickerPrice, weight: 2, security: None
+///     }
+/// }
+/// ```
+///
+/// Params take no lifetime or one named `'p`, endpoints without params are
+/// sent with `()`.
+#[macro_export]
+macro_rules! endpoints {
+    ($($sync:ident)::+, $($async:ident)::+; $($items:tt)*) => {
+        $crate::endpoints!(@munch [$($sync)::+] [$($async)::+] [] $($items)*);
+    };
+
+    (
+        @munch $sync:tt $async:tt [$($methods:tt)*]
+        $(#[$meta:meta])*
+        pub fn $method:ident($($params:ident $(<$lt:lifetime>)?)?) -> $response:ty = $endpoint:ident {
+            $http:ident $group:ident::$route:ident, weight: $weight:expr, security: $security:ident
+            $(, encoding: $encoding:ident)? $(,)?
+        }
+        $($rest:tt)*
+    ) => {
+        $crate::endpoints!(
+            @descriptor [$(#[$meta])*] $endpoint($($params $(<$lt>)?)?) -> $response {
+                $http $group::$route, $weight, $security $(, $encoding)?
+            }
+        );
+        $crate::endpoints!(
+            @munch $sync $async [
+                $($methods)*
+                {[$(#[$meta])*] $method($($params $(<$lt>)?)?) -> $response = $endpoint}
+            ]
+            $($rest)*
+        );
+    };
+
+    (
+        @munch $sync:tt $async:tt $methods:tt
+        $(#[$meta:meta])*
+        $endpoint:ident($($params:ident $(<$lt:lifetime>)?)?) -> $response:ty {
+            $http:ident $group:ident::$route:ident, weight: $weight:expr, security: $security:ident
+            $(, encoding: $encoding:ident)? $(,)?
+        }
+        $($rest:tt)*
+    ) => {
+        $crate::endpoints!(
+            @descriptor [$(#[$meta])*] $endpoint($($params $(<$lt>)?)?) -> $response {
+                $http $group::$route, $weight, $security $(, $encoding)?
+            }
+        );
+        $crate::endpoints!(@munch $sync $async $methods $($rest)*);
+    };
+
+    (@munch [$($sync:tt)*] [$($async:tt)*] [$($methods:tt)*]) => {
+        impl<'a, S, T> $($sync)*<'a, S, T>
+        where
+            S: $crate::signer::signature::Signature<'a>,
+            T: $crate::transport::synchronous::Transport,
+        {
+            $($crate::endpoints!(@synchronous $methods);)*
+        }
+
+        impl<'a, S, T> $($async)*<'a, S, T>
+        where
+            S: $crate::signer::signature::Signature<'a>,
+            T: $crate::transport::asynchronous::Transport,
+        {
+            $($crate::endpoints!(@asynchronous $methods);)*
+        }
+    };
+
+    (
+        @descriptor [$($meta:tt)*] $endpoint:ident($($params:tt)*) -> $response:ty {
+            $http:ident $group:ident::$route:ident, $weight:expr, $security:ident
+            $(, $encoding:ident)?
+        }
+    ) => {
+        $($meta)*
+        #[derive(Clone, Copy, Debug)]
+        pub struct $endpoint;
+
+        impl $crate::endpoint::Endpoint for $endpoint {
+            type Route = $group;
+            $crate::endpoints!(@params $($params)*);
+            type Response = $response;
+
+            const ROUTE: $group = $group::$route;
+            const METHOD: $crate::endpoint::Method = $crate::endpoint::Method::$http;
+            const WEIGHT: u16 = $weight;
+            const SECURITY: $crate::endpoint::Security = $crate::endpoint::Security::$security;
+            $(const ENCODING: $crate::endpoint::Encoding = $crate::endpoint::Encoding::$encoding;)?
+        }
+    };
+
+    (@params) => {
+        type Params<'p> = ();
+    };
+    (@params $params:ident) => {
+        type Params<'p> = $params;
+    };
+    (@params $params:ident<$lt:lifetime>) => {
+        type Params<$lt> = $params<$lt>;
+    };
+
+    (@synchronous {[$($meta:tt)*] $method:ident() -> $response:ty = $endpoint:ident}) => {
+        $($meta)*
+        pub fn $method(
+            &self,
+        ) -> ::core::result::Result<$response, $crate::endpoint::__private::BinanceError> {
+            self.client.call::<$endpoint>(&())
+        }
+    };
+    (
+        @synchronous {
+            [$($meta:tt)*] $method:ident($params:ident $(<$lt:lifetime>)?) -> $response:ty = $endpoint:ident
+        }
+    ) => {
+        $($meta)*
+        pub fn $method<$($lt)?>(
+            &self,
+            params: &$params$(<$lt>)?,
+        ) -> ::core::result::Result<$response, $crate::endpoint::__private::BinanceError> {
+            self.client.call::<$endpoint>(params)
+        }
+    };
+
+    (@asynchronous {[$($meta:tt)*] $method:ident() -> $response:ty = $endpoint:ident}) => {
+        $($meta)*
+        pub async fn $method(
+            &self,
+        ) -> ::core::result::Result<$response, $crate::endpoint::__private::BinanceError> {
+            self.client.call::<$endpoint>(&()).await
+        }
+    };
+    (
+        @asynchronous {
+            [$($meta:tt)*] $method:ident($params:ident $(<$lt:lifetime>)?) -> $response:ty = $endpoint:ident
+        }
+    ) => {
+        $($meta)*
+        pub async fn $method<$($lt)?>(
+            &self,
+            params: &$params$(<$lt>)?,
+        ) -> ::core::result::Result<$response, $crate::endpoint::__private::BinanceError> {
+            self.client.call::<$endpoint>(params).await
+        }
+    };
+}
@@ -0,0 +1,628 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    time::Duration,
+};
+
+use binance_common::{
+    enums::Interval,
+    error::BinanceError,
+    spot::model::{
+        params::{account::MyTradesParams, market::KlinesParams},
+        response::{account::MyTradesResponse, market::KlinesResponse},
+    },
+};
+use chrono::Utc;
+use futures_util::Stream;
+
+/// What to do with candles the exchange did not return inside the requested range.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Gaps {
+    Skip,
+    Report,
+    Fill,
+}
+
+#[derive(Clone, Debug)]
+pub enum KlineEvent {
+    Kline(KlinesResponse),
+    /// Flat, zero volume candle at the previous close standing in for a missing one.
+    Filled(KlinesResponse),
+    /// `count` candles opening between `start_time` and `end_time` are missing.
+    Missing {
+        start_time: u64,
+        end_time: u64,
+        count: u64,
+    },
+}
+
+/// Download of every candle of `symbol` opening between `start_time` and `end_time`.
+///
+/// The range is requested in pages of `limit` candles with `pause` between
+/// requests. Rate limited requests are retried up to `retries` times, waiting
+/// for `Retry-After` or else doubling `backoff` each time. Candles already seen are dropped, holes
+/// anywhere in the range are handled according to `gaps` (not available for `1M`). Holes before
+/// the first candle are filled at its open, a range without any candle is only ever reported.
+#[derive(Clone, Debug)]
+pub struct KlineHistory<'a> {
+    pub symbol: &'a str,
+    pub interval: Interval,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub limit: u16,
+    pub pause: Duration,
+    pub retries: u32,
+    pub backoff: Duration,
+    pub gaps: Gaps,
+}
+
+impl<'a> KlineHistory<'a> {
+    pub fn new(symbol: &'a str, interval: Interval, start_time: u64, end_time: u64) -> Self {
+        KlineHistory {
+            symbol,
+            interval,
+            start_time,
+            end_time,
+            limit: 1000,
+            pause: Duration::from_millis(250),
+            retries: 3,
+            backoff: Duration::from_secs(1),
+            gaps: Gaps::Report,
+        }
+    }
+
+    pub fn limit(mut self, limit: u16) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    pub fn pause(mut self, pause: Duration) -> Self {
+        self.pause = pause;
+        self
+    }
+
+    pub fn retries(mut self, retries: u32) -> Self {
+        self.retries = retries;
+        self
+    }
+
+    pub fn backoff(mut self, backoff: Duration) -> Self {
+        self.backoff = backoff;
+        self
+    }
+
+    pub fn gaps(mut self, gaps: Gaps) -> Self {
+        self.gaps = gaps;
+        self
+    }
+
+    /// Drives the download with a blocking `fetch`, usually a `get_klines` call.
+    pub fn iter<F>(
+        self,
+        mut fetch: F,
+    ) -> impl Iterator<Item = Result<KlineEvent, BinanceError>> + 'a
+    where
+        F: FnMut(&KlinesParams<'a>) -> Result<Vec<KlinesResponse>, BinanceError> + 'a,
+    {
+        let mut pager = KlinePager::new(self);
+        let mut wait = Duration::ZERO;
+
+        std::iter::from_fn(move || {
+            loop {
+                if let Some(event) = pager.pop() {
+                    return Some(Ok(event));
+                }
+
+                let params = pager.next_params()?;
+
+                if !wait.is_zero() {
+                    std::thread::sleep(wait);
+                }
+
+                match pager.push(fetch(&params)) {
+                    Ok(next) => wait = next,
+                    Err(error) => return Some(Err(error)),
+                }
+            }
+        })
+    }
+
+    /// Drives the download with an asynchronous `fetch`, usually a `get_klines` call.
+    pub fn stream<F, Fut>(
+        self,
+        fetch: F,
+    ) -> impl Stream<Item = Result<KlineEvent, BinanceError>> + 'a
+    where
+        F: FnMut(KlinesParams<'a>) -> Fut + 'a,
+        Fut: Future<Output = Result<Vec<KlinesResponse>, BinanceError>> + 'a,
+    {
+        futures_util::stream::unfold(
+            (KlinePager::new(self), fetch, Duration::ZERO),
+            |(mut pager, mut fetch, mut wait)| async move {
+                loop {
+                    if let Some(event) = pager.pop() {
+                        return Some((Ok(event), (pager, fetch, wait)));
+                    }
+
+                    let params = pager.next_params()?;
+
+                    if !wait.is_zero() {
+                        tokio::time::sleep(wait).await;
+                    }
+
+                    match pager.push(fetch(params).await) {
+                        Ok(next) => wait = next,
+                        Err(error) => return Some((Err(error), (pager, fetch, wait))),
+                    }
+                }
+            },
+        )
+    }
+}
+
+/// Transport independent state of a [`KlineHistory`] download.
+///
+/// Ask for the next request with `next_params`, hand its result to `push`
+/// and drain the resulting events with `pop`.
+pub struct KlinePager<'a> {
+    history: KlineHistory<'a>,
+    cursor: u64,
+    last: Option<(u64, f64)>,
+    events: VecDeque<KlineEvent>,
+    attempts: u32,
+    done: bool,
+}
+
+impl<'a> KlinePager<'a> {
+    pub fn new(history: KlineHistory<'a>) -> Self {
+        KlinePager {
+            cursor: history.start_time,
+            history,
+            last: None,
+            events: VecDeque::new(),
+            attempts: 0,
+            done: false,
+        }
+    }
+
+    pub fn next_params(&self) -> Option<KlinesParams<'a>> {
+        if self.done || self.cursor > self.history.end_time {
+            return None;
+        }
+
+        Some(KlinesParams {
+            symbol: self.history.symbol,
+            interval: self.history.interval.as_str(),
+            start_time: Some(self.cursor),
+            end_time: Some(self.history.end_time),
+            limit: Some(self.history.limit),
+            ..Default::default()
+        })
+    }
+
+    pub fn pop(&mut self) -> Option<KlineEvent> {
+        self.events.pop_front()
+    }
+
+    /// Consumes the result of the last request and returns how long to wait before the next one.
+    pub fn push(
+        &mut self,
+        page: Result<Vec<KlinesResponse>, BinanceError>,
+    ) -> Result<Duration, BinanceError> {
+        let page = match page {
+            Ok(page) => page,
+            Err(error) => {
+                return match retry(
+                    &error,
+                    &mut self.attempts,
+                    self.history.retries,
+                    self.history.backoff,
+                ) {
+                    Some(wait) => Ok(wait),
+                    None => {
+                        self.done = true;
+                        Err(error)
+                    }
+                };
+            }
+        };
+
+        self.attempts = 0;
+
+        let cursor = self.cursor;
+        let full = page.len() >= self.history.limit as usize;
+
+        for kline in page {
+            if kline.open_time < self.history.start_time || kline.open_time > self.history.end_time
+            {
+                continue;
+            }
+
+            match self.last {
+                Some((last_open_time, _)) if kline.open_time <= last_open_time => continue,
+                Some((last_open_time, last_close)) => self.gap(
+                    last_open_time + self.history.interval.milliseconds().unwrap_or_default(),
+                    kline.open_time,
+                    Some(last_close),
+                ),
+                None => self.leading(&kline),
+            }
+
+            self.cursor = kline.open_time + 1;
+            self.last = Some((kline.open_time, kline.close));
+            self.events.push_back(KlineEvent::Kline(kline));
+        }
+
+        self.done = !full || self.cursor == cursor || self.cursor > self.history.end_time;
+
+        if self.done {
+            self.trailing();
+        }
+
+        Ok(self.history.pause)
+    }
+
+    /// Candles missing between `start_time` and the first one received.
+    fn leading(&mut self, kline: &KlinesResponse) {
+        let Some(step) = self.history.interval.milliseconds() else {
+            return;
+        };
+
+        let start_time =
+            kline.open_time - (kline.open_time - self.history.start_time) / step * step;
+
+        self.gap(start_time, kline.open_time, Some(kline.open));
+    }
+
+    /// Candles missing after the last one received, up to `end_time` or now if earlier.
+    fn trailing(&mut self) {
+        let Some(step) = self.history.interval.milliseconds() else {
+            return;
+        };
+
+        let end_time = self
+            .history
+            .end_time
+            .min(Utc::now().timestamp_millis() as u64);
+
+        match self.last {
+            Some((last_open_time, last_close)) => {
+                if end_time >= last_open_time {
+                    let open_time =
+                        last_open_time + ((end_time - last_open_time) / step + 1) * step;
+                    self.gap(last_open_time + step, open_time, Some(last_close));
+                }
+            }
+            None => {
+                // Nothing to line up with, weekly candles open on Mondays, the rest on the epoch.
+                let origin = if self.history.interval == Interval::Weeks1 {
+                    4 * DAY
+                } else {
+                    0
+                };
+                let start_time =
+                    (self.history.start_time.max(origin) - origin).div_ceil(step) * step + origin;
+
+                if end_time >= start_time {
+                    let open_time = start_time + ((end_time - start_time) / step + 1) * step;
+                    self.gap(start_time, open_time, None);
+                }
+            }
+        }
+    }
+
+    /// Handles the candles opening from `start_time` up to, not including, `open_time`.
+    /// Without a `price` to fill them with they are reported as missing.
+    fn gap(&mut self, start_time: u64, open_time: u64, price: Option<f64>) {
+        let Some(step) = self.history.interval.milliseconds() else {
+            return;
+        };
+
+        if open_time <= start_time {
+            return;
+        }
+
+        match (self.history.gaps, price) {
+            (Gaps::Skip, _) => {}
+            (Gaps::Fill, Some(price)) => {
+                for time in (start_time..open_time).step_by(step as usize) {
+                    self.events
+                        .push_back(KlineEvent::Filled(flat(time, step, price)));
+                }
+            }
+            _ => self.events.push_back(KlineEvent::Missing {
+                start_time,
+                end_time: open_time - step,
+                count: (open_time - start_time) / step,
+            }),
+        }
+    }
+}
+
+fn flat(open_time: u64, step: u64, price: f64) -> KlinesResponse {
+    KlinesResponse {
+        open_time,
+        open: price,
+        high: price,
+        low: price,
+        close: price,
+        volume: 0.0,
+        close_time: open_time + step - 1,
+        quote_asset_volume: 0.0,
+        number_of_trades: 0,
+        taker_buy_base_asset_volume: 0.0,
+        taker_buy_quote_asset_volume: 0.0,
+        ignore_field: 0,
+    }
+}
+
+/// Wait before retrying a rate limited request, `None` once `retries` are used up
+/// or for any other error.
+fn retry(
+    error: &BinanceError,
+    attempts: &mut u32,
+    retries: u32,
+    backoff: Duration,
+) -> Option<Duration> {
+    if !error.is_rate_limit() || !error.is_retryable() || *attempts >= retries {
+        return None;
+    }
+
+    *attempts += 1;
+
+    Some(
+        error
+            .retry_after()
+            .unwrap_or(backoff * 2u32.pow(*attempts - 1)),
+    )
+}
+
+/// Longest window `myTrades` accepts between `startTime` and `endTime`.
+const DAY: u64 = 86_400_000;
+
+/// Download of the account's trades of every symbol in `symbols` executed between
+/// `start_time` and `end_time`, symbol after symbol in ascending trade id.
+///
+/// A symbol is searched one day window at a time until its first trade shows up,
+/// then paged by `fromId`, or straight from the id given to `from_id`. Pages of
+/// `limit` trades are requested with `pause` between requests, the default keeps
+/// the request weight of 20 well under the limit of 6000 per minute. Rate limited
+/// requests are retried like in [`KlineHistory`].
+#[derive(Clone, Debug)]
+pub struct TradeHistory<'a> {
+    pub symbols: Vec<&'a str>,
+    pub start_time: u64,
+    pub end_time: u64,
+    /// Trade id to resume each symbol from, usually the last one seen plus one.
+    pub from_ids: HashMap<&'a str, u64>,
+    pub limit: u16,
+    pub pause: Duration,
+    pub retries: u32,
+    pub backoff: Duration,
+}
+
+impl<'a> TradeHistory<'a> {
+    pub fn new(symbols: &[&'a str], start_time: u64, end_time: u64) -> Self {
+        TradeHistory {
+            symbols: symbols.to_vec(),
+            start_time,
+            end_time,
+            from_ids: HashMap::new(),
+            limit: 1000,
+            pause: Duration::from_millis(250),
+            retries: 3,
+            backoff: Duration::from_secs(1),
+        }
+    }
+
+    pub fn from_id(mut self, symbol: &'a str, from_id: u64) -> Self {
+        self.from_ids.insert(symbol, from_id);
+        self
+    }
+
+    pub fn limit(mut self, limit: u16) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    pub fn pause(mut self, pause: Duration) -> Self {
+        self.pause = pause;
+        self
+    }
+
+    pub fn retries(mut self, retries: u32) -> Self {
+        self.retries = retries;
+        self
+    }
+
+    pub fn backoff(mut self, backoff: Duration) -> Self {
+        self.backoff = backoff;
+        self
+    }
+
+    /// Drives the download with a blocking `fetch`, usually a `get_my_trades` call.
+    pub fn iter<F>(
+        self,
+        mut fetch: F,
+    ) -> impl Iterator<Item = Result<MyTradesResponse, BinanceError>> + 'a
+    where
+        F: FnMut(&MyTradesParams<'a>) -> Result<Vec<MyTradesResponse>, BinanceError> + 'a,
+    {
+        let mut pager = TradePager::new(self);
+        let mut wait = Duration::ZERO;
+
+        std::iter::from_fn(move || {
+            loop {
+                if let Some(trade) = pager.pop() {
+                    return Some(Ok(trade));
+                }
+
+                let params = pager.next_params()?;
+
+                if !wait.is_zero() {
+                    std::thread::sleep(wait);
+                }
+
+                match pager.push(fetch(&params)) {
+                    Ok(next) => wait = next,
+                    Err(error) => return Some(Err(error)),
+                }
+            }
+        })
+    }
+
+    /// Drives the download with an asynchronous `fetch`, usually a `get_my_trades` call.
+    pub fn stream<F, Fut>(
+        self,
+        fetch: F,
+    ) -> impl Stream<Item = Result<MyTradesResponse, BinanceError>> + 'a
+    where
+        F: FnMut(MyTradesParams<'a>) -> Fut + 'a,
+        Fut: Future<Output = Result<Vec<MyTradesResponse>, BinanceError>> + 'a,
+    {
+        futures_util::stream::unfold(
+            (TradePager::new(self), fetch, Duration::ZERO),
+            |(mut pager, mut fetch, mut wait)| async move {
+                loop {
+                    if let Some(trade) = pager.pop() {
+                        return Some((Ok(trade), (pager, fetch, wait)));
+                    }
+
+                    let params = pager.next_params()?;
+
+                    if !wait.is_zero() {
+                        tokio::time::sleep(wait).await;
+                    }
+
+                    match pager.push(fetch(params).await) {
+                        Ok(next) => wait = next,
+                        Err(error) => return Some((Err(error), (pager, fetch, wait))),
+                    }
+                }
+            },
+        )
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum TradeCursor {
+    /// Searching the day starting at this time.
+    Window(u64),
+    FromId(u64),
+}
+
+/// Transport independent state of a [`TradeHistory`] download, used like a [`KlinePager`].
+pub struct TradePager<'a> {
+    history: TradeHistory<'a>,
+    symbol: usize,
+    cursor: TradeCursor,
+    trades: VecDeque<MyTradesResponse>,
+    attempts: u32,
+    done: bool,
+}
+
+impl<'a> TradePager<'a> {
+    pub fn new(history: TradeHistory<'a>) -> Self {
+        let mut pager = TradePager {
+            history,
+            symbol: 0,
+            cursor: TradeCursor::Window(0),
+            trades: VecDeque::new(),
+            attempts: 0,
+            done: false,
+        };
+
+        pager.seek(0);
+        pager
+    }
+
+    pub fn next_params(&self) -> Option<MyTradesParams<'a>> {
+        if self.done {
+            return None;
+        }
+
+        let params =
+            MyTradesParams::new(self.history.symbols[self.symbol]).limit(self.history.limit);
+
+        Some(match self.cursor {
+            TradeCursor::Window(start_time) => params
+                .start_time(start_time)
+                .end_time(self.window_end(start_time)),
+            TradeCursor::FromId(from_id) => params.from_id(from_id),
+        })
+    }
+
+    pub fn pop(&mut self) -> Option<MyTradesResponse> {
+        self.trades.pop_front()
+    }
+
+    /// Consumes the result of the last request and returns how long to wait before the next one.
+    pub fn push(
+        &mut self,
+        page: Result<Vec<MyTradesResponse>, BinanceError>,
+    ) -> Result<Duration, BinanceError> {
+        let mut page = match page {
+            Ok(page) => page,
+            Err(error) => {
+                return match retry(
+                    &error,
+                    &mut self.attempts,
+                    self.history.retries,
+                    self.history.backoff,
+                ) {
+                    Some(wait) => Ok(wait),
+                    None => {
+                        self.done = true;
+                        Err(error)
+                    }
+                };
+            }
+        };
+
+        self.attempts = 0;
+        page.sort_by_key(|trade| trade.id);
+
+        let full = page.len() >= self.history.limit as usize;
+        let next_id = page.last().map(|trade| trade.id + 1);
+        let passed = page.iter().any(|trade| trade.time > self.history.end_time);
+
+        self.trades.extend(page.into_iter().filter(|trade| {
+            trade.time >= self.history.start_time && trade.time <= self.history.end_time
+        }));
+
+        match (self.cursor, next_id) {
+            (_, Some(next_id)) if full && !passed => self.cursor = TradeCursor::FromId(next_id),
+            (TradeCursor::Window(start_time), _) if !full => {
+                match self.window_end(start_time) < self.history.end_time {
+                    true => self.cursor = TradeCursor::Window(start_time + DAY),
+                    false => self.seek(self.symbol + 1),
+                }
+            }
+            _ => self.seek(self.symbol + 1),
+        }
+
+        Ok(self.history.pause)
+    }
+
+    fn window_end(&self, start_time: u64) -> u64 {
+        (start_time + DAY - 1).min(self.history.end_time)
+    }
+
+    /// Moves to the first symbol from `index` on with anything left to request.
+    fn seek(&mut self, index: usize) {
+        for (index, symbol) in self.history.symbols.iter().enumerate().skip(index) {
+            let cursor = match self.history.from_ids.get(symbol) {
+                Some(from_id) => TradeCursor::FromId(*from_id),
+                None if self.history.start_time <= self.history.end_time => {
+                    TradeCursor::Window(self.history.start_time)
+                }
+                None => continue,
+            };
+
+            self.symbol = index;
+            self.cursor = cursor;
+            return;
+        }
+
+        self.done = true;
+    }
+}
@@ -1,4 +1,9 @@
 pub mod client;
+pub mod connection;
+pub mod endpoint;
+pub mod history;
 pub mod signer;
+pub mod trading;
+pub mod transport;
 pub mod utility;
 pub mod websocket;
@@ -1,7 +1,6 @@
 use base64::Engine;
 use binance_common::error::BinanceError;
 use ed25519_dalek::{Signer, SigningKey, pkcs8::DecodePrivateKey};
-use reqwest::Method;
 
 pub struct Ed25519Dalek {
     api_key: String,
@@ -20,72 +19,18 @@ impl Ed25519Dalek {
 }
 
 impl<'a> crate::signer::signature::Signature<'a> for Ed25519Dalek {
-    fn build_blocking_request(
-        &self,
-        client: &reqwest::blocking::Client,
-        host: &str,
-        path: &str,
-        params: &str,
-        method: reqwest::Method,
-    ) -> Result<reqwest::blocking::RequestBuilder, binance_common::error::BinanceError> {
-        let url = self.sign(host, path, params)?;
-
-        match method {
-            Method::DELETE => Ok(client
-                .delete(url)
-                .header("X-MBX-APIKEY", self.api_key.as_str())),
-            Method::GET => Ok(client
-                .get(url)
-                .header("X-MBX-APIKEY", self.api_key.as_str())),
-            Method::POST => Ok(client
-                .post(url)
-                .header("X-MBX-APIKEY", self.api_key.as_str())),
-            _ => Err(binance_common::error::BinanceError::Unknown(
-                "Invalid method to send the request!".to_string(),
-            )),
-        }
+    fn signature(&self, payload: &str) -> Result<String, binance_common::error::BinanceError> {
+        let signature = self.signing_key.sign(payload.as_bytes());
+
+        // Base64 uses `+`, `/` and `=`, which a query string would mangle.
+        serde_urlencoded::to_string([(
+            "signature",
+            base64::engine::general_purpose::STANDARD.encode(signature.to_bytes()),
+        )])
+        .map_err(|error| BinanceError::BuildRequest(error.to_string()))
     }
 
-    fn build_request(
-        &self,
-        client: &reqwest::Client,
-        host: &str,
-        path: &str,
-        params: &str,
-        method: reqwest::Method,
-    ) -> Result<reqwest::RequestBuilder, binance_common::error::BinanceError> {
-        let url = self.sign(host, path, params)?;
-
-        match method {
-            Method::DELETE => Ok(client
-                .delete(url)
-                .header("X-MBX-APIKEY", self.api_key.as_str())),
-            Method::GET => Ok(client
-                .get(url)
-                .header("X-MBX-APIKEY", self.api_key.as_str())),
-            Method::POST => Ok(client
-                .post(url)
-                .header("X-MBX-APIKEY", self.api_key.as_str())),
-            _ => Err(binance_common::error::BinanceError::Unknown(
-                "Invalid method to send the request!".to_string(),
-            )),
-        }
-    }
-
-    fn sign(
-        &self,
-        host: &str,
-        path: &str,
-        params: &str,
-    ) -> Result<String, binance_common::error::BinanceError> {
-        let params = self.add_timestamp(params);
-        let signature = self.signing_key.sign(params.as_bytes());
-        let endpoint = format!(
-            "{}&signature={}",
-            params,
-            base64::engine::general_purpose::STANDARD.encode(signature.to_bytes())
-        );
-
-        Ok(format!("{}{}{}", host, path, endpoint))
+    fn api_key(&self) -> &str {
+        &self.api_key
     }
 }
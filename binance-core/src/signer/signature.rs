@@ -2,28 +2,53 @@ use binance_common::error::BinanceError;
 use chrono::Utc;
 use reqwest::Method;
 
+use crate::endpoint::Encoding;
+use crate::transport::Request;
+
 pub trait Signature<'a> {
     fn add_timestamp(&self, params: &str) -> String {
         format!("{}&timestamp={}", params, Utc::now().timestamp_millis())
     }
 
-    fn build_blocking_request(
+    /// Signs `params` into a request any transport can send, with the params
+    /// in the query string or in a form body as `encoding` says. A query the
+    /// `path` already carries is kept and signed ahead of the body.
+    fn sign_request(
         &self,
-        client: &reqwest::blocking::Client,
         host: &str,
         path: &str,
         params: &str,
         method: Method,
-    ) -> Result<reqwest::blocking::RequestBuilder, BinanceError>;
+        encoding: Encoding,
+    ) -> Result<Request, BinanceError> {
+        match encoding {
+            Encoding::Query => Request::new(method, self.sign(host, path, params)?)
+                .header("x-mbx-apikey", self.api_key()),
+            Encoding::Form => {
+                let query = path.split_once('?').map_or("", |(_, query)| query);
+                let body = self.add_timestamp(params);
+                let body = body.strip_prefix('&').unwrap_or(&body);
+                let signature = self.signature(&format!("{}{}", query, body))?;
 
-    fn build_request(
-        &self,
-        client: &reqwest::Client,
-        host: &str,
-        path: &str,
-        params: &str,
-        method: Method,
-    ) -> Result<reqwest::RequestBuilder, BinanceError>;
+                Ok(Request::new(method, format!("{}{}", host, path))
+                    .header("x-mbx-apikey", self.api_key())?
+                    .header("content-type", "application/x-www-form-urlencoded")?
+                    .body(format!("{}&{}", body, signature)))
+            }
+        }
+    }
+
+    /// The url with `params`, a timestamp and their signature in the query string.
+    fn sign(&self, host: &str, path: &str, params: &str) -> Result<String, BinanceError> {
+        let params = self.add_timestamp(params);
+        let signature = self.signature(&params)?;
+
+        Ok(format!("{}{}{}&{}", host, path, params, signature))
+    }
+
+    /// The url-encoded `signature=` pair for `payload`.
+    fn signature(&self, payload: &str) -> Result<String, BinanceError>;
 
-    fn sign(&self, host: &str, path: &str, params: &str) -> Result<String, BinanceError>;
+    /// Sent alone for `USER_STREAM` endpoints, which take no signature.
+    fn api_key(&self) -> &str;
 }
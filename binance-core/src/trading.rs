@@ -0,0 +1,2 @@
+pub mod futures;
+pub mod spot;
@@ -0,0 +1,74 @@
+use binance_common::{
+    error::BinanceError,
+    futures::model::{
+        params::{
+            market::Symbol,
+            trade::{
+                AutoCancelAllOpenOrdersParams, CancelAllOrdersParams, CancelOrderParams,
+                GetOrderParams, NewOrderParams, OpenOrdersParams, PositionRiskV3Params,
+            },
+        },
+        response::{
+            account::FuturesBalanceResponse,
+            market::MarkPriceResponse,
+            trade::{
+                AutoCancelAllOpenOrdersResponse, CancelAllOrdersResponse, GetOrderResponse,
+                OrderResponse, PositionRiskV3Response,
+            },
+        },
+    },
+};
+
+/// Order entry operations shared by the live futures `TradeApi` and simulated
+/// exchanges, so the same strategy code can run against either of them.
+pub trait FuturesTradingApi {
+    fn send_new_order(
+        &self,
+        params: &NewOrderParams<'_>,
+    ) -> impl Future<Output = Result<OrderResponse, BinanceError>> + Send;
+
+    fn send_cancel_order(
+        &self,
+        params: &CancelOrderParams<'_>,
+    ) -> impl Future<Output = Result<OrderResponse, BinanceError>> + Send;
+
+    fn send_cancel_all_orders(
+        &self,
+        params: &CancelAllOrdersParams<'_>,
+    ) -> impl Future<Output = Result<CancelAllOrdersResponse, BinanceError>> + Send;
+
+    /// Dead man's switch canceling every open order of the symbol once the
+    /// countdown runs out without being renewed.
+    fn send_auto_cancel_all_open_orders(
+        &self,
+        params: &AutoCancelAllOpenOrdersParams<'_>,
+    ) -> impl Future<Output = Result<AutoCancelAllOpenOrdersResponse, BinanceError>> + Send;
+
+    fn get_order(
+        &self,
+        params: &GetOrderParams<'_>,
+    ) -> impl Future<Output = Result<GetOrderResponse, BinanceError>> + Send;
+
+    fn get_all_open_orders(
+        &self,
+        params: &OpenOrdersParams<'_>,
+    ) -> impl Future<Output = Result<Vec<GetOrderResponse>, BinanceError>> + Send;
+
+    fn get_balances(
+        &self,
+    ) -> impl Future<Output = Result<Vec<FuturesBalanceResponse>, BinanceError>> + Send;
+
+    fn get_position_risk_v3(
+        &self,
+        params: &PositionRiskV3Params<'_>,
+    ) -> impl Future<Output = Result<Vec<PositionRiskV3Response>, BinanceError>> + Send;
+}
+
+/// Reference prices for pre-trade checks, served by the live futures `MarketApi`
+/// or a simulated exchange.
+pub trait FuturesPriceApi {
+    fn get_mark_price(
+        &self,
+        params: &Symbol<'_>,
+    ) -> impl Future<Output = Result<MarkPriceResponse, BinanceError>> + Send;
+}
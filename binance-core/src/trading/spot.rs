@@ -0,0 +1,58 @@
+use binance_common::{
+    error::BinanceError,
+    spot::model::{
+        params::{
+            market::AvgPriceParams,
+            trade::{
+                CancelAllOrdersParms, CancelOrderParams, GetOrderParams, NewOrderParams,
+                OpenOrdersParams,
+            },
+        },
+        response::{
+            account::AssetResponse,
+            market::AvgPriceResponse,
+            trade::{CancelOrderResponse, NewOrderResponse, OrderIdResponse, OrderResponse},
+        },
+    },
+};
+
+/// Order entry operations shared by the live spot `TradeApi` and simulated
+/// exchanges, so the same strategy code can run against either of them.
+pub trait SpotTradingApi {
+    fn send_new_order(
+        &self,
+        params: &NewOrderParams<'_>,
+    ) -> impl Future<Output = Result<NewOrderResponse, BinanceError>> + Send;
+
+    fn send_cancel_order(
+        &self,
+        params: &CancelOrderParams<'_>,
+    ) -> impl Future<Output = Result<CancelOrderResponse, BinanceError>> + Send;
+
+    fn send_cancel_open_orders(
+        &self,
+        params: &CancelAllOrdersParms<'_>,
+    ) -> impl Future<Output = Result<Vec<CancelOrderResponse>, BinanceError>> + Send;
+
+    fn get_order(
+        &self,
+        params: &GetOrderParams<'_>,
+    ) -> impl Future<Output = Result<OrderIdResponse, BinanceError>> + Send;
+
+    fn get_open_orders(
+        &self,
+        params: &OpenOrdersParams<'_>,
+    ) -> impl Future<Output = Result<Vec<OrderResponse>, BinanceError>> + Send;
+
+    fn get_balances(&self)
+    -> impl Future<Output = Result<Vec<AssetResponse>, BinanceError>> + Send;
+}
+
+/// Reference prices for pre-trade checks, served by the live spot `MarketApi`
+/// or a simulated exchange.
+pub trait SpotPriceApi {
+    fn get_average_price(
+        &self,
+        params: &AvgPriceParams<'_>,
+    ) -> impl Future<Output = Result<AvgPriceResponse, BinanceError>> + Send;
+}
@@ -0,0 +1,70 @@
+pub mod asynchronous;
+pub mod synchronous;
+
+use binance_common::error::BinanceError;
+use reqwest::{
+    Method, StatusCode,
+    header::{HeaderMap, HeaderName, HeaderValue},
+};
+
+/// A REST request ready to send, independent of the HTTP client sending it.
+#[derive(Clone, Debug)]
+pub struct Request {
+    pub method: Method,
+    pub url: String,
+    pub headers: HeaderMap,
+    pub body: Option<String>,
+}
+
+impl Request {
+    pub fn new(method: Method, url: impl Into<String>) -> Self {
+        Request {
+            method,
+            url: url.into(),
+            headers: HeaderMap::new(),
+            body: None,
+        }
+    }
+
+    pub fn header(mut self, name: &'static str, value: &str) -> Result<Self, BinanceError> {
+        let value = HeaderValue::from_str(value)
+            .map_err(|error| BinanceError::BuildRequest(error.to_string()))?;
+
+        self.headers.insert(HeaderName::from_static(name), value);
+        Ok(self)
+    }
+
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+
+    /// The path of `url`, the route errors are reported with.
+    pub fn path(&self) -> &str {
+        let url = self
+            .url
+            .split_once("://")
+            .map_or(self.url.as_str(), |(_, url)| url);
+        let path = url.find('/').map_or("", |index| &url[index..]);
+
+        path.split(['?', '#']).next().unwrap_or_default()
+    }
+}
+
+/// What a transport got back, the body not yet decoded.
+#[derive(Clone, Debug)]
+pub struct Response {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: Vec<u8>,
+}
+
+impl Response {
+    pub fn new(status: StatusCode, body: impl Into<Vec<u8>>) -> Self {
+        Response {
+            status,
+            headers: HeaderMap::new(),
+            body: body.into(),
+        }
+    }
+}
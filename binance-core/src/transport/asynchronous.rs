@@ -0,0 +1,33 @@
+use binance_common::error::BinanceError;
+
+use super::{Request, Response};
+
+/// Sends requests for the asynchronous `Client`. Implemented for
+/// `reqwest::Client`, the default.
+pub trait Transport {
+    fn send(&self, request: Request)
+    -> impl Future<Output = Result<Response, BinanceError>> + Send;
+}
+
+impl Transport for reqwest::Client {
+    async fn send(&self, request: Request) -> Result<Response, BinanceError> {
+        let mut builder = self
+            .request(request.method, request.url)
+            .headers(request.headers);
+
+        if let Some(body) = request.body {
+            builder = builder.body(body);
+        }
+
+        let response = builder.send().await?;
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = response.bytes().await?;
+
+        Ok(Response {
+            status,
+            headers,
+            body: body.to_vec(),
+        })
+    }
+}
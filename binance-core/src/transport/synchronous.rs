@@ -0,0 +1,32 @@
+use binance_common::error::BinanceError;
+
+use super::{Request, Response};
+
+/// Sends requests for the synchronous `Client`. Implemented for
+/// `reqwest::blocking::Client`, the default.
+pub trait Transport {
+    fn send(&self, request: Request) -> Result<Response, BinanceError>;
+}
+
+impl Transport for reqwest::blocking::Client {
+    fn send(&self, request: Request) -> Result<Response, BinanceError> {
+        let mut builder = self
+            .request(request.method, request.url)
+            .headers(request.headers);
+
+        if let Some(body) = request.body {
+            builder = builder.body(body);
+        }
+
+        let response = builder.send()?;
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = response.bytes()?;
+
+        Ok(Response {
+            status,
+            headers,
+            body: body.to_vec(),
+        })
+    }
+}
@@ -1,5 +1,6 @@
 pub mod controller;
 pub mod engine;
 pub mod futures;
+pub mod recorder;
 pub mod spot;
 pub mod supervisor;
@@ -1,3 +1,5 @@
 pub mod controller;
 pub mod engine;
+pub mod replayer;
+pub mod shards;
 pub mod supervisor;
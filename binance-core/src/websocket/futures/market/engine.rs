@@ -4,20 +4,24 @@ use binance_common::{
     futures::model::response::websocket::{CombinedStreamResponse, WebSocketResponse},
 };
 use futures_util::{SinkExt, StreamExt};
-use tokio_tungstenite::{
-    MaybeTlsStream, WebSocketStream, connect_async,
-    tungstenite::{Message, Utf8Bytes},
-};
+use tokio_tungstenite::tungstenite::{Message, Utf8Bytes};
 
-use crate::websocket::engine::WebSocketEngine;
+use crate::connection::{ConnectionConfig, WebSocket};
+use crate::websocket::{
+    engine::WebSocketEngine,
+    recorder::{RecorderWriter, WebSocketRecorder},
+};
 
 pub struct WebSocketMarketEngine {
     rx_controller: tokio::sync::mpsc::Receiver<WebSocketCommand>,
     tx_response: tokio::sync::mpsc::Sender<Result<(), BinanceError>>,
     tx_watch: tokio::sync::watch::Sender<Result<WebSocketResponse, BinanceError>>,
-    socket: Option<WebSocketStream<MaybeTlsStream<tokio::net::TcpStream>>>,
+    socket: Option<WebSocket>,
     state: WebSocketState,
     websocket_type: WebSocketType,
+    recorder: Option<RecorderWriter>,
+    tx_frames: Option<tokio::sync::mpsc::Sender<Utf8Bytes>>,
+    config: ConnectionConfig,
 }
 
 impl WebSocketMarketEngine {
@@ -34,8 +38,66 @@ impl WebSocketMarketEngine {
             socket: None,
             state: WebSocketState::Idle,
             websocket_type,
+            recorder: None,
+            tx_frames: None,
+            config: ConnectionConfig::default(),
+        }
+    }
+
+    /// Connections opened from now on follow `config`.
+    pub fn configure(&mut self, config: ConnectionConfig) {
+        self.config = config;
+    }
+
+    /// Writes every text frame to `recorder` before it is decoded, on a
+    /// thread of its own. A failed write is published once on the watch
+    /// channel and ends the recording, not the stream.
+    pub fn record(&mut self, recorder: WebSocketRecorder) {
+        self.recorder = Some(recorder.spawn());
+    }
+
+    /// Hands every text frame undecoded to the returned receiver instead of
+    /// publishing it on the watch channel, for consumers decoding frames with
+    /// `WebSocketResponseRef`. Unlike the watch channel no frame is dropped, a
+    /// slow consumer slows down the socket instead.
+    pub fn frames(&mut self, capacity: usize) -> tokio::sync::mpsc::Receiver<Utf8Bytes> {
+        let (tx_frames, rx_frames) = tokio::sync::mpsc::channel(capacity.max(1));
+        self.tx_frames = Some(tx_frames);
+        rx_frames
+    }
+
+    /// Records, decodes and publishes one text frame, the path every frame
+    /// from the socket takes. Also used to replay recordings.
+    pub async fn dispatch(&mut self, message: Utf8Bytes) -> Result<(), BinanceError> {
+        if let Some(recorder) = self.recorder.as_mut()
+            && let Err(error) = recorder.record(message.clone())
+        {
+            self.stop_recording(error);
+        }
+
+        if let Some(tx_frames) = self.tx_frames.as_ref() {
+            return tx_frames.send(message).await.map_err(|_| {
+                BinanceError::Channel("The frame receiver has been dropped.".to_string())
+            });
+        }
+
+        self.tx_watch.send(self.handle(message).await).map_err(|_| {
+            BinanceError::Channel("Failed to send the response through watch channel.".to_string())
+        })
+    }
+
+    async fn flush_recording(&mut self) {
+        if let Some(recorder) = self.recorder.as_mut()
+            && let Err(error) = recorder.flush().await
+        {
+            self.stop_recording(error);
         }
     }
+
+    fn stop_recording(&mut self, error: BinanceError) {
+        self.recorder = None;
+        let _ = self.tx_watch.send(Err(error));
+    }
 }
 
 impl WebSocketEngine for WebSocketMarketEngine {
@@ -44,11 +106,16 @@ impl WebSocketEngine for WebSocketMarketEngine {
     type Response = WebSocketResponse;
 
     async fn connect(&mut self, stream: String) -> Result<(), BinanceError> {
-        let (socket, _) = connect_async(stream).await.map_err(|_| {
-            BinanceError::WebSocketInternal(
-                "WebSocketMarketEngine has failed to connect to the stream!".to_string(),
-            )
-        })?;
+        let socket = self
+            .config
+            .connect_websocket(&stream)
+            .await
+            .map_err(|error| {
+                BinanceError::WebSocketInternal(format!(
+                    "WebSocketMarketEngine has failed to connect to the stream! {}",
+                    error
+                ))
+            })?;
 
         self.socket = Some(socket);
         self.state = WebSocketState::Connected;
@@ -66,6 +133,9 @@ impl WebSocketEngine for WebSocketMarketEngine {
 
             self.socket = None;
             self.state = WebSocketState::Closed;
+            self.tx_frames = None;
+
+            self.flush_recording().await;
 
             return Ok(());
         }
@@ -86,6 +156,8 @@ impl WebSocketEngine for WebSocketMarketEngine {
             self.socket = None;
             self.state = WebSocketState::Idle;
 
+            self.flush_recording().await;
+
             return Ok(());
         }
 
@@ -123,7 +195,7 @@ impl WebSocketEngine for WebSocketMarketEngine {
                         self.tx_response.send(response).await.map_err(|_|BinanceError::Channel("Failed to send the close error through response channel.".to_string()))
                     },
 
-                Message::Text(msg) => {self.tx_watch.send(self.handle(msg).await).map_err(|_| BinanceError::Channel("Failed to send the close error through watch channel.".to_string()))},
+                Message::Text(msg) => {self.dispatch(msg).await},
 
                 Message::Ping(payload) => {
                     self.socket.as_mut().ok_or(BinanceError::WebSocketInternal("Unable to get a mutable reference to socket".to_string()))?.send(Message::Pong(payload)).await.map_err(|_| BinanceError::Channel("Failed to send the Pong response back to server with socket channel".to_string()))
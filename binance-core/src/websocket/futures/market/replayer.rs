@@ -0,0 +1,129 @@
+use std::path::Path;
+
+use binance_common::{
+    enums::WebSocketCommand, error::BinanceError,
+    futures::model::response::websocket::WebSocketResponse,
+};
+use futures_util::Stream;
+use tokio::time::{Duration, Instant};
+
+use crate::websocket::{
+    engine::WebSocketEngine,
+    futures::market::engine::WebSocketMarketEngine,
+    recorder::{RecordedFrame, WebSocketRecording},
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReplaySpeed {
+    /// Keeps the gaps between receive times of the recording.
+    Original,
+    Fastest,
+}
+
+/// Plays a [`WebSocketRecording`] back through `WebSocketMarketEngine::handle`.
+///
+/// `run` publishes into the same watch channel a live supervisor hands out,
+/// so consumers of `watch` cannot tell a replay from a live session. Like a
+/// live stream a slow consumer only sees the latest response; `stream` yields
+/// every response and is the deterministic choice for tests.
+pub struct WebSocketReplayer {
+    recording: WebSocketRecording,
+    engine: WebSocketMarketEngine,
+    rx_watch: tokio::sync::watch::Receiver<Result<WebSocketResponse, BinanceError>>,
+    speed: ReplaySpeed,
+}
+
+impl WebSocketReplayer {
+    pub fn new(recording: WebSocketRecording, speed: ReplaySpeed) -> Self {
+        let (_, rx_controller) = tokio::sync::mpsc::channel::<WebSocketCommand>(1);
+        let (tx_response, _) = tokio::sync::mpsc::channel(1);
+        let (tx_watch, rx_watch) = tokio::sync::watch::channel::<
+            Result<WebSocketResponse, BinanceError>,
+        >(Ok(WebSocketResponse::Init));
+
+        let engine = WebSocketMarketEngine::new(
+            rx_controller,
+            tx_response,
+            tx_watch,
+            recording.websocket_type(),
+        );
+
+        WebSocketReplayer {
+            recording,
+            engine,
+            rx_watch,
+            speed,
+        }
+    }
+
+    pub fn open(path: impl AsRef<Path>, speed: ReplaySpeed) -> Result<Self, BinanceError> {
+        Ok(Self::new(WebSocketRecording::open(path)?, speed))
+    }
+
+    pub fn watch(&self) -> tokio::sync::watch::Receiver<Result<WebSocketResponse, BinanceError>> {
+        self.rx_watch.clone()
+    }
+
+    /// Publishes every frame, the watch channel closes once the recording ends.
+    pub async fn run(self) -> Result<(), BinanceError> {
+        let WebSocketReplayer {
+            recording,
+            mut engine,
+            rx_watch: _rx_watch,
+            speed,
+        } = self;
+
+        let mut pace = Pace::new(speed);
+
+        for frame in recording {
+            let frame = frame?;
+
+            pace.wait(&frame).await;
+            engine.dispatch(frame.message).await?;
+            tokio::task::yield_now().await;
+        }
+
+        Ok(())
+    }
+
+    pub fn stream(self) -> impl Stream<Item = Result<WebSocketResponse, BinanceError>> {
+        futures_util::stream::unfold(
+            (self.recording, self.engine, Pace::new(self.speed)),
+            |(mut recording, engine, mut pace)| async move {
+                let response = match recording.next()? {
+                    Ok(frame) => {
+                        pace.wait(&frame).await;
+                        engine.handle(frame.message).await
+                    }
+                    Err(error) => Err(error),
+                };
+
+                Some((response, (recording, engine, pace)))
+            },
+        )
+    }
+}
+
+struct Pace {
+    speed: ReplaySpeed,
+    start: Option<(Instant, u64)>,
+}
+
+impl Pace {
+    fn new(speed: ReplaySpeed) -> Self {
+        Pace { speed, start: None }
+    }
+
+    async fn wait(&mut self, frame: &RecordedFrame) {
+        if self.speed == ReplaySpeed::Fastest {
+            return;
+        }
+
+        let (started, first) = *self
+            .start
+            .get_or_insert((Instant::now(), frame.received_at));
+
+        let offset = Duration::from_micros(frame.received_at.saturating_sub(first));
+        tokio::time::sleep_until(started + offset).await;
+    }
+}
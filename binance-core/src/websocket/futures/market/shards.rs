@@ -0,0 +1,93 @@
+use binance_common::{
+    enums::WebSocketType, error::BinanceError, futures::model::params::websocket::WebSocketParams,
+    futures::model::response::websocket::WebSocketResponse,
+};
+
+use crate::connection::ConnectionConfig;
+use crate::websocket::{
+    futures::market::{
+        controller::WebSocketMarketController, engine::WebSocketMarketEngine,
+        supervisor::WebSocketMarketSupervisor,
+    },
+    supervisor::WebSocketSupervisor,
+};
+
+type MarketSupervisor = WebSocketMarketSupervisor<WebSocketMarketController, WebSocketMarketEngine>;
+
+/// Runs one supervisor per route of a `WebSocketParams`, so subscriptions
+/// beyond the per-connection stream limit are spread over several
+/// connections.
+pub struct WebSocketMarketShards {
+    websocket_type: WebSocketType,
+    supervisors: Vec<MarketSupervisor>,
+    config: ConnectionConfig,
+}
+
+impl WebSocketMarketShards {
+    pub fn new(websocket_type: WebSocketType) -> Self {
+        WebSocketMarketShards {
+            websocket_type,
+            supervisors: Vec::new(),
+            config: ConnectionConfig::default(),
+        }
+    }
+
+    /// Every connection `start` opens follows `config`.
+    pub fn configure(&mut self, config: ConnectionConfig) {
+        self.config = config;
+    }
+
+    pub fn len(&self) -> usize {
+        self.supervisors.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.supervisors.is_empty()
+    }
+
+    pub async fn start(&mut self, params: &WebSocketParams) -> Result<(), BinanceError> {
+        if !self.supervisors.is_empty() {
+            return Err(BinanceError::Unknown(
+                "Engine or handler bad state".to_string(),
+            ));
+        }
+
+        for route in params.routes()? {
+            let mut supervisor = MarketSupervisor::new(self.websocket_type);
+            supervisor.configure(self.config.clone())?;
+
+            if let Err(error) = supervisor.start(route).await {
+                self.stop().await?;
+                return Err(error);
+            }
+
+            self.supervisors.push(supervisor);
+        }
+
+        Ok(())
+    }
+
+    pub async fn stop(&mut self) -> Result<(), BinanceError> {
+        for mut supervisor in self.supervisors.drain(..) {
+            supervisor.stop().await?;
+        }
+
+        Ok(())
+    }
+
+    /// One receiver per connection, in the order of `WebSocketParams::routes`.
+    pub async fn watch(
+        &mut self,
+    ) -> Result<
+        Vec<tokio::sync::watch::Receiver<Result<WebSocketResponse, BinanceError>>>,
+        BinanceError,
+    > {
+        let mut receivers = Vec::with_capacity(self.supervisors.len());
+
+        for supervisor in self.supervisors.iter_mut() {
+            receivers.push(supervisor.watch().await?);
+        }
+
+        Ok(receivers)
+    }
+}
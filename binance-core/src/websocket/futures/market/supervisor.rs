@@ -7,11 +7,14 @@ use binance_common::{
     futures::model::response::websocket::WebSocketResponse,
 };
 use tokio::task::JoinHandle;
+use tokio_tungstenite::tungstenite::Utf8Bytes;
 
 use super::controller::WebSocketMarketController;
+use crate::connection::ConnectionConfig;
 use crate::websocket::{
     controller::WebSocketController, engine::WebSocketEngine,
-    futures::market::engine::WebSocketMarketEngine, supervisor::WebSocketSupervisor,
+    futures::market::engine::WebSocketMarketEngine, recorder::WebSocketRecorder,
+    supervisor::WebSocketSupervisor,
 };
 
 pub struct WebSocketMarketSupervisor<C, E>
@@ -24,6 +27,48 @@ where
     handler: Option<JoinHandle<E>>,
 }
 
+impl WebSocketMarketSupervisor<WebSocketMarketController, WebSocketMarketEngine> {
+    /// Taps the engine with `recorder`, only possible before `start`.
+    pub fn record(&mut self, recorder: WebSocketRecorder) -> Result<(), BinanceError> {
+        match self.engine.as_mut() {
+            Some(engine) => {
+                engine.record(recorder);
+                Ok(())
+            }
+            None => Err(BinanceError::Unknown(
+                "Engine or handler bad state".to_string(),
+            )),
+        }
+    }
+
+    /// Connects the engine as `config` says, only possible before `start`.
+    pub fn configure(&mut self, config: ConnectionConfig) -> Result<(), BinanceError> {
+        match self.engine.as_mut() {
+            Some(engine) => {
+                engine.configure(config);
+                Ok(())
+            }
+            None => Err(BinanceError::Unknown(
+                "Engine or handler bad state".to_string(),
+            )),
+        }
+    }
+
+    /// Receiver of the raw text frames, see `WebSocketMarketEngine::frames`.
+    /// Only possible before `start`, the watch channel stays silent afterwards.
+    pub fn frames(
+        &mut self,
+        capacity: usize,
+    ) -> Result<tokio::sync::mpsc::Receiver<Utf8Bytes>, BinanceError> {
+        match self.engine.as_mut() {
+            Some(engine) => Ok(engine.frames(capacity)),
+            None => Err(BinanceError::Unknown(
+                "Engine or handler bad state".to_string(),
+            )),
+        }
+    }
+}
+
 impl WebSocketSupervisor
     for WebSocketMarketSupervisor<WebSocketMarketController, WebSocketMarketEngine>
 {
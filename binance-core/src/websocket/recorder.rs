@@ -0,0 +1,295 @@
+use std::{
+    fs::{File, OpenOptions},
+    io::{self, BufReader, BufWriter, ErrorKind, Read, Write},
+    path::Path,
+    sync::mpsc,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use binance_common::{enums::WebSocketType, error::BinanceError};
+use tokio_tungstenite::tungstenite::Utf8Bytes;
+
+const MAGIC: &[u8; 8] = b"BNWSREC\0";
+const VERSION: u8 = 1;
+
+/// Raw text frame as it came off the socket.
+#[derive(Clone, Debug)]
+pub struct RecordedFrame {
+    /// Receive time in microseconds since the Unix epoch.
+    pub received_at: u64,
+    pub message: Utf8Bytes,
+}
+
+/// Append-only writer of raw WebSocket frames.
+///
+/// The file starts with a header naming the stream type, followed by one
+/// record per frame: the receive time (`u64`, microseconds), the payload
+/// length (`u32`) and the payload, all little endian. Opening an existing
+/// recording appends to it, after cutting off a frame left incomplete by a
+/// crash. Frames are buffered, call `flush` to force them to disk; dropping
+/// the recorder flushes as well.
+pub struct WebSocketRecorder {
+    writer: BufWriter<File>,
+    websocket_type: WebSocketType,
+}
+
+impl WebSocketRecorder {
+    pub fn create(
+        path: impl AsRef<Path>,
+        websocket_type: WebSocketType,
+    ) -> Result<Self, BinanceError> {
+        let mut file = OpenOptions::new()
+            .read(true)
+            .append(true)
+            .create(true)
+            .open(path)
+            .map_err(io_error)?;
+
+        match file.metadata().map_err(io_error)?.len() {
+            0 => file.write_all(&header(websocket_type)).map_err(io_error)?,
+            _ => {
+                let mut reader = BufReader::new(&mut file);
+                let recorded = read_header(&mut reader)?;
+
+                if recorded != websocket_type {
+                    return Err(BinanceError::WebSocketInternal(format!(
+                        "The recording holds {:?} frames, not {:?}.",
+                        recorded, websocket_type
+                    )));
+                }
+
+                let end = complete_frames(&mut reader).map_err(io_error)?;
+                file.set_len(end).map_err(io_error)?;
+            }
+        }
+
+        Ok(WebSocketRecorder {
+            writer: BufWriter::new(file),
+            websocket_type,
+        })
+    }
+
+    pub fn websocket_type(&self) -> WebSocketType {
+        self.websocket_type
+    }
+
+    /// Appends `message` stamped with the current time.
+    pub fn record(&mut self, message: &str) -> Result<(), BinanceError> {
+        let received_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|time| time.as_micros() as u64)
+            .unwrap_or_default();
+
+        self.record_at(received_at, message)
+    }
+
+    pub fn record_at(&mut self, received_at: u64, message: &str) -> Result<(), BinanceError> {
+        let length = u32::try_from(message.len()).map_err(|_| {
+            BinanceError::WebSocketInternal("Frame is too large to be recorded.".to_string())
+        })?;
+
+        self.writer
+            .write_all(&received_at.to_le_bytes())
+            .and_then(|_| self.writer.write_all(&length.to_le_bytes()))
+            .and_then(|_| self.writer.write_all(message.as_bytes()))
+            .map_err(io_error)
+    }
+
+    pub fn flush(&mut self) -> Result<(), BinanceError> {
+        self.writer.flush().map_err(io_error)
+    }
+
+    /// Moves the recorder to its own thread so the caller never waits on the disk.
+    pub(crate) fn spawn(mut self) -> RecorderWriter {
+        let (tx_command, rx_command) = mpsc::channel();
+        let (tx_failure, rx_failure) = tokio::sync::oneshot::channel();
+
+        std::thread::spawn(move || {
+            for command in rx_command {
+                let result = match command {
+                    RecorderCommand::Frame(received_at, message) => {
+                        self.record_at(received_at, message.as_str())
+                    }
+                    RecorderCommand::Flush(tx_flushed) => self.flush().map(|_| {
+                        let _ = tx_flushed.send(());
+                    }),
+                };
+
+                if let Err(error) = result {
+                    let _ = tx_failure.send(error);
+                    return;
+                }
+            }
+        });
+
+        RecorderWriter {
+            tx_command,
+            rx_failure,
+        }
+    }
+}
+
+enum RecorderCommand {
+    Frame(u64, Utf8Bytes),
+    Flush(tokio::sync::oneshot::Sender<()>),
+}
+
+/// Handle of a [`WebSocketRecorder`] writing on its own thread. Frames queue
+/// up without bound, the first failed write stops the recording.
+pub(crate) struct RecorderWriter {
+    tx_command: mpsc::Sender<RecorderCommand>,
+    rx_failure: tokio::sync::oneshot::Receiver<BinanceError>,
+}
+
+impl RecorderWriter {
+    /// Queues `message` stamped with the current time, or returns why the
+    /// recording has stopped.
+    pub(crate) fn record(&mut self, message: Utf8Bytes) -> Result<(), BinanceError> {
+        self.failure()?;
+
+        let received_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|time| time.as_micros() as u64)
+            .unwrap_or_default();
+
+        self.tx_command
+            .send(RecorderCommand::Frame(received_at, message))
+            .map_err(|_| self.stopped())
+    }
+
+    /// Waits for every queued frame to reach the file.
+    pub(crate) async fn flush(&mut self) -> Result<(), BinanceError> {
+        self.failure()?;
+
+        let (tx_flushed, rx_flushed) = tokio::sync::oneshot::channel();
+
+        self.tx_command
+            .send(RecorderCommand::Flush(tx_flushed))
+            .map_err(|_| self.stopped())?;
+
+        match rx_flushed.await {
+            Ok(()) => Ok(()),
+            Err(_) => Err(self.stopped()),
+        }
+    }
+
+    fn failure(&mut self) -> Result<(), BinanceError> {
+        match self.rx_failure.try_recv() {
+            Ok(error) => Err(error),
+            Err(_) => Ok(()),
+        }
+    }
+
+    fn stopped(&mut self) -> BinanceError {
+        self.failure().err().unwrap_or_else(|| {
+            BinanceError::WebSocketInternal("The recording has stopped.".to_string())
+        })
+    }
+}
+
+/// Reader of a file written by [`WebSocketRecorder`], yielding frames in the
+/// order they were received. A frame cut short by a crash ends the recording.
+pub struct WebSocketRecording {
+    reader: BufReader<File>,
+    websocket_type: WebSocketType,
+}
+
+impl WebSocketRecording {
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, BinanceError> {
+        let mut reader = BufReader::new(File::open(path).map_err(io_error)?);
+        let websocket_type = read_header(&mut reader)?;
+
+        Ok(WebSocketRecording {
+            reader,
+            websocket_type,
+        })
+    }
+
+    pub fn websocket_type(&self) -> WebSocketType {
+        self.websocket_type
+    }
+
+    fn read_frame(&mut self) -> Result<RecordedFrame, std::io::Error> {
+        let mut received_at = [0u8; 8];
+        let mut length = [0u8; 4];
+
+        self.reader.read_exact(&mut received_at)?;
+        self.reader.read_exact(&mut length)?;
+
+        let mut message = vec![0u8; u32::from_le_bytes(length) as usize];
+        self.reader.read_exact(&mut message)?;
+
+        let message = String::from_utf8(message)
+            .map_err(|error| std::io::Error::new(ErrorKind::InvalidData, error))?;
+
+        Ok(RecordedFrame {
+            received_at: u64::from_le_bytes(received_at),
+            message: Utf8Bytes::from(message),
+        })
+    }
+}
+
+impl Iterator for WebSocketRecording {
+    type Item = Result<RecordedFrame, BinanceError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.read_frame() {
+            Ok(frame) => Some(Ok(frame)),
+            Err(error) if error.kind() == ErrorKind::UnexpectedEof => None,
+            Err(error) => Some(Err(io_error(error))),
+        }
+    }
+}
+
+fn header(websocket_type: WebSocketType) -> [u8; 10] {
+    let mut header = [0u8; 10];
+
+    header[..8].copy_from_slice(MAGIC);
+    header[8] = VERSION;
+    header[9] = match websocket_type {
+        WebSocketType::SingleStream => 0,
+        WebSocketType::MultiStream => 1,
+    };
+
+    header
+}
+
+fn read_header(reader: &mut impl Read) -> Result<WebSocketType, BinanceError> {
+    let mut header = [0u8; 10];
+    reader.read_exact(&mut header).map_err(io_error)?;
+
+    match (&header[..8] == MAGIC, header[8], header[9]) {
+        (true, VERSION, 0) => Ok(WebSocketType::SingleStream),
+        (true, VERSION, 1) => Ok(WebSocketType::MultiStream),
+        _ => Err(BinanceError::WebSocketInternal(
+            "The file is not a WebSocket recording.".to_string(),
+        )),
+    }
+}
+
+/// Length of the header and every complete frame after it, `reader` being
+/// positioned right after the header.
+fn complete_frames(reader: &mut impl Read) -> io::Result<u64> {
+    let mut end = header(WebSocketType::SingleStream).len() as u64;
+    let mut prefix = [0u8; 12];
+
+    loop {
+        match reader.read_exact(&mut prefix) {
+            Ok(()) => {}
+            Err(error) if error.kind() == ErrorKind::UnexpectedEof => return Ok(end),
+            Err(error) => return Err(error),
+        }
+
+        let length = u32::from_le_bytes([prefix[8], prefix[9], prefix[10], prefix[11]]) as u64;
+
+        if io::copy(&mut reader.take(length), &mut io::sink())? < length {
+            return Ok(end);
+        }
+
+        end += prefix.len() as u64 + length;
+    }
+}
+
+fn io_error(error: std::io::Error) -> BinanceError {
+    BinanceError::WebSocketInternal(format!("Recording I/O failed: {}", error))
+}
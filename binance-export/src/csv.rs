@@ -0,0 +1,90 @@
+use std::{fs::File, io::Write, path::Path};
+
+use chrono::DateTime;
+
+use crate::{
+    error::ExportError,
+    record::{Record, Value},
+    streams::StreamTables,
+};
+
+/// Timestamps are written as RFC 3339 UTC with millisecond precision, which
+/// `pandas.read_csv(parse_dates=...)` and `polars.read_csv(try_parse_dates=True)`
+/// read back as timezone aware datetimes.
+pub fn write<'a, R, W>(
+    writer: W,
+    records: impl IntoIterator<Item = &'a R>,
+) -> Result<(), ExportError>
+where
+    R: Record + 'a,
+    W: Write,
+{
+    let mut writer = ::csv::Writer::from_writer(writer);
+
+    writer.write_record(R::COLUMNS.iter().map(|column| column.name))?;
+
+    for record in records {
+        writer.write_record(record.values().iter().map(field))?;
+    }
+
+    writer.flush()?;
+
+    Ok(())
+}
+
+pub fn write_file<'a, R>(
+    path: impl AsRef<Path>,
+    records: impl IntoIterator<Item = &'a R>,
+) -> Result<(), ExportError>
+where
+    R: Record + 'a,
+{
+    write(File::create(path)?, records)
+}
+
+/// Writes `agg_trades.csv`, `klines.csv`, `mark_prices.csv`, `mini_tickers.csv`,
+/// `book_tickers.csv` and `depth_levels.csv` into `directory`, skipping empty tables.
+pub fn write_streams(
+    directory: impl AsRef<Path>,
+    tables: &StreamTables,
+) -> Result<(), ExportError> {
+    let directory = directory.as_ref();
+
+    if !tables.agg_trades.is_empty() {
+        write_file(directory.join("agg_trades.csv"), &tables.agg_trades)?;
+    }
+
+    if !tables.klines.is_empty() {
+        write_file(directory.join("klines.csv"), &tables.klines)?;
+    }
+
+    if !tables.mark_prices.is_empty() {
+        write_file(directory.join("mark_prices.csv"), &tables.mark_prices)?;
+    }
+
+    if !tables.mini_tickers.is_empty() {
+        write_file(directory.join("mini_tickers.csv"), &tables.mini_tickers)?;
+    }
+
+    if !tables.book_tickers.is_empty() {
+        write_file(directory.join("book_tickers.csv"), &tables.book_tickers)?;
+    }
+
+    if !tables.depth_levels.is_empty() {
+        write_file(directory.join("depth_levels.csv"), &tables.depth_levels)?;
+    }
+
+    Ok(())
+}
+
+fn field(value: &Value<'_>) -> String {
+    match value {
+        Value::Timestamp(time) => DateTime::from_timestamp_millis(*time as i64)
+            .map(|time| time.format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string())
+            .unwrap_or_default(),
+        Value::Float(value) => value.to_string(),
+        Value::Integer(value) => value.to_string(),
+        Value::Text(value) => value.to_string(),
+        Value::Boolean(value) => value.to_string(),
+    }
+}
@@ -0,0 +1,59 @@
+use std::fmt::Display;
+
+#[derive(Debug)]
+pub enum ExportError {
+    #[cfg(feature = "csv")]
+    Csv(::csv::Error),
+    Io(std::io::Error),
+    #[cfg(feature = "parquet")]
+    Arrow(arrow_schema::ArrowError),
+    #[cfg(feature = "parquet")]
+    Parquet(::parquet::errors::ParquetError),
+    Schema(String),
+    Ledger(String),
+}
+
+impl Display for ExportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            #[cfg(feature = "csv")]
+            ExportError::Csv(e) => write!(f, "[ExportError::Csv] {}", e),
+            ExportError::Io(e) => write!(f, "[ExportError::Io] {}", e),
+            #[cfg(feature = "parquet")]
+            ExportError::Arrow(e) => write!(f, "[ExportError::Arrow] {}", e),
+            #[cfg(feature = "parquet")]
+            ExportError::Parquet(e) => write!(f, "[ExportError::Parquet] {}", e),
+            ExportError::Schema(msg) => write!(f, "[ExportError::Schema] {}", msg),
+            ExportError::Ledger(msg) => write!(f, "[ExportError::Ledger] {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for ExportError {}
+
+#[cfg(feature = "csv")]
+impl From<::csv::Error> for ExportError {
+    fn from(value: ::csv::Error) -> Self {
+        ExportError::Csv(value)
+    }
+}
+
+impl From<std::io::Error> for ExportError {
+    fn from(value: std::io::Error) -> Self {
+        ExportError::Io(value)
+    }
+}
+
+#[cfg(feature = "parquet")]
+impl From<arrow_schema::ArrowError> for ExportError {
+    fn from(value: arrow_schema::ArrowError) -> Self {
+        ExportError::Arrow(value)
+    }
+}
+
+#[cfg(feature = "parquet")]
+impl From<::parquet::errors::ParquetError> for ExportError {
+    fn from(value: ::parquet::errors::ParquetError) -> Self {
+        ExportError::Parquet(value)
+    }
+}
@@ -0,0 +1,536 @@
+use std::collections::{HashMap, HashSet};
+
+use binance_common::{
+    enums::{IncomeType, wallet::UniversalTransferType},
+    futures::model::response::{account::IncomeHistoryResponse, trade::UserTradesResponse},
+    spot::model::response::{account::MyTradesResponse, market::KlinesResponse},
+    symbol::split_symbol,
+    wallet::model::response::asset::TransferRecord,
+};
+
+use crate::{
+    error::ExportError,
+    record::{Column, ColumnType, Record, Value, column},
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EntryKind {
+    Trade,
+    Commission,
+    RealizedPnl,
+    FundingFee,
+    Transfer,
+    /// Any other `IncomeType`, such as rebates, insurance clears or delivery settlements.
+    Income,
+}
+
+impl EntryKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EntryKind::Trade => "TRADE",
+            EntryKind::Commission => "COMMISSION",
+            EntryKind::RealizedPnl => "REALIZED_PNL",
+            EntryKind::FundingFee => "FUNDING_FEE",
+            EntryKind::Transfer => "TRANSFER",
+            EntryKind::Income => "INCOME",
+        }
+    }
+}
+
+/// Wallets hold the account's assets, the other accounts are their counterparts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LedgerAccount {
+    Spot,
+    UsdMFutures,
+    CoinMFutures,
+    Margin,
+    IsolatedMargin,
+    Funding,
+    /// Other side of every trade, holding the assets given up and received.
+    Trading,
+    Fees,
+    RealizedPnl,
+    FundingFees,
+    /// Other side of futures transfers without a matching transfer record.
+    External,
+    OtherIncome,
+}
+
+impl LedgerAccount {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LedgerAccount::Spot => "spot",
+            LedgerAccount::UsdMFutures => "usdm_futures",
+            LedgerAccount::CoinMFutures => "coinm_futures",
+            LedgerAccount::Margin => "margin",
+            LedgerAccount::IsolatedMargin => "isolated_margin",
+            LedgerAccount::Funding => "funding",
+            LedgerAccount::Trading => "trading",
+            LedgerAccount::Fees => "fees",
+            LedgerAccount::RealizedPnl => "realized_pnl",
+            LedgerAccount::FundingFees => "funding_fees",
+            LedgerAccount::External => "external",
+            LedgerAccount::OtherIncome => "other_income",
+        }
+    }
+
+    pub fn is_wallet(&self) -> bool {
+        matches!(
+            self,
+            LedgerAccount::Spot
+                | LedgerAccount::UsdMFutures
+                | LedgerAccount::CoinMFutures
+                | LedgerAccount::Margin
+                | LedgerAccount::IsolatedMargin
+                | LedgerAccount::Funding
+        )
+    }
+}
+
+/// One line of the ledger, exported with the columns
+///
+/// | column        | type      | content                                                        |
+/// |---------------|-----------|----------------------------------------------------------------|
+/// | `entry`       | integer   | Event the line belongs to, numbered from 1 in time order       |
+/// | `time`        | timestamp | Time of the event                                              |
+/// | `kind`        | text      | `TRADE`, `COMMISSION`, `REALIZED_PNL`, `FUNDING_FEE`, `TRANSFER` or `INCOME` |
+/// | `account`     | text      | [`LedgerAccount`] in snake case, such as `spot` or `fees`      |
+/// | `asset`       | text      | Asset moved                                                    |
+/// | `amount`      | float     | Signed change of the account, positive is a debit              |
+/// | `symbol`      | text      | Traded symbol, empty for transfers                             |
+/// | `reference`   | text      | Trade id for trades and commissions, else the transaction id   |
+/// | `price`       | float     | Open of the kline of `asset` in `value_asset` holding `time`   |
+/// | `value`       | float     | `amount` times `price`                                         |
+/// | `value_asset` | text      | Valuation currency of the ledger                               |
+/// | `valued`      | boolean   | Whether a price was found, `price` and `value` are 0 otherwise |
+///
+/// The amounts of every entry sum to zero per asset.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LedgerEntry {
+    pub entry: u64,
+    pub time: u64,
+    pub kind: EntryKind,
+    pub account: LedgerAccount,
+    pub asset: String,
+    pub amount: f64,
+    pub symbol: String,
+    pub reference: String,
+    pub price: f64,
+    pub value: f64,
+    pub value_asset: String,
+    pub valued: bool,
+}
+
+impl Record for LedgerEntry {
+    const COLUMNS: &'static [Column] = &[
+        column("entry", ColumnType::Integer),
+        column("time", ColumnType::Timestamp),
+        column("kind", ColumnType::Text),
+        column("account", ColumnType::Text),
+        column("asset", ColumnType::Text),
+        column("amount", ColumnType::Float),
+        column("symbol", ColumnType::Text),
+        column("reference", ColumnType::Text),
+        column("price", ColumnType::Float),
+        column("value", ColumnType::Float),
+        column("value_asset", ColumnType::Text),
+        column("valued", ColumnType::Boolean),
+    ];
+
+    fn values(&self) -> Vec<Value<'_>> {
+        vec![
+            Value::Integer(self.entry),
+            Value::Timestamp(self.time),
+            Value::Text(self.kind.as_str()),
+            Value::Text(self.account.as_str()),
+            Value::Text(&self.asset),
+            Value::Float(self.amount),
+            Value::Text(&self.symbol),
+            Value::Text(&self.reference),
+            Value::Float(self.price),
+            Value::Float(self.value),
+            Value::Text(&self.value_asset),
+            Value::Boolean(self.valued),
+        ]
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Ledger {
+    pub entries: Vec<LedgerEntry>,
+}
+
+impl Ledger {
+    /// Net amount of `asset` booked to `account`.
+    pub fn balance(&self, account: LedgerAccount, asset: &str) -> f64 {
+        self.lines(account)
+            .filter(|line| line.asset == asset)
+            .map(|line| line.amount)
+            .sum()
+    }
+
+    /// Value of everything booked to `account`, lines without a price count as 0.
+    pub fn value(&self, account: LedgerAccount) -> f64 {
+        self.lines(account).map(|line| line.value).sum()
+    }
+
+    fn lines(&self, account: LedgerAccount) -> impl Iterator<Item = &LedgerEntry> {
+        self.entries
+            .iter()
+            .filter(move |line| line.account == account)
+    }
+}
+
+struct Event {
+    time: u64,
+    kind: EntryKind,
+    symbol: String,
+    reference: String,
+    lines: Vec<(LedgerAccount, String, f64)>,
+}
+
+impl Event {
+    fn new(time: u64, kind: EntryKind, symbol: &str, reference: impl ToString) -> Self {
+        Event {
+            time,
+            kind,
+            symbol: symbol.to_string(),
+            reference: reference.to_string(),
+            lines: Vec::new(),
+        }
+    }
+
+    /// Books `amount` of `asset` to `debit` against `credit`.
+    fn book(
+        mut self,
+        debit: LedgerAccount,
+        credit: LedgerAccount,
+        asset: &str,
+        amount: f64,
+    ) -> Self {
+        self.lines.push((debit, asset.to_string(), amount));
+        self.lines.push((credit, asset.to_string(), -amount));
+        self
+    }
+}
+
+/// Builds a double-entry [`Ledger`] out of account history, valued in `value_asset`.
+///
+/// Spot trades move the base and quote asset between the spot wallet and the
+/// trading account, futures trades book their realized PnL and commission to
+/// the USDⓈ-M futures wallet. Income history entries already booked through a
+/// futures trade, matched by symbol and trade id, and `TRANSFER` entries of a
+/// transfer record, matched by transaction id, are skipped, so overlapping
+/// histories can be combined. Only `CONFIRMED` transfer records are booked.
+///
+/// Every line is valued at the open of the latest kline of its asset against
+/// `value_asset` opening at or before its time, in either direction, so no price
+/// from after the line is used. Symbols are
+/// split into base and quote with [`pair`](Self::pair), else by a known quote suffix.
+pub struct LedgerBuilder<'a> {
+    value_asset: &'a str,
+    pairs: HashMap<&'a str, (&'a str, &'a str)>,
+    klines: Vec<(&'a str, &'a [KlinesResponse])>,
+    spot_trades: Vec<&'a MyTradesResponse>,
+    futures_trades: Vec<&'a UserTradesResponse>,
+    income: Vec<&'a IncomeHistoryResponse>,
+    transfers: Vec<&'a TransferRecord>,
+}
+
+impl<'a> LedgerBuilder<'a> {
+    pub fn new(value_asset: &'a str) -> Self {
+        LedgerBuilder {
+            value_asset,
+            pairs: HashMap::new(),
+            klines: Vec::new(),
+            spot_trades: Vec::new(),
+            futures_trades: Vec::new(),
+            income: Vec::new(),
+            transfers: Vec::new(),
+        }
+    }
+
+    pub fn pair(mut self, symbol: &'a str, base: &'a str, quote: &'a str) -> Self {
+        self.pairs.insert(symbol, (base, quote));
+        self
+    }
+
+    pub fn klines(mut self, symbol: &'a str, klines: &'a [KlinesResponse]) -> Self {
+        self.klines.push((symbol, klines));
+        self
+    }
+
+    pub fn spot_trades(mut self, trades: &'a [MyTradesResponse]) -> Self {
+        self.spot_trades.extend(trades);
+        self
+    }
+
+    pub fn futures_trades(mut self, trades: &'a [UserTradesResponse]) -> Self {
+        self.futures_trades.extend(trades);
+        self
+    }
+
+    pub fn income(mut self, income: &'a [IncomeHistoryResponse]) -> Self {
+        self.income.extend(income);
+        self
+    }
+
+    pub fn transfers(mut self, transfers: &'a [TransferRecord]) -> Self {
+        self.transfers.extend(transfers);
+        self
+    }
+
+    /// Fails on a traded symbol that can not be split into base and quote.
+    pub fn build(&self) -> Result<Ledger, ExportError> {
+        let mut events = Vec::new();
+
+        for trade in &self.spot_trades {
+            let (base, quote) = self.split(&trade.symbol)?;
+            let (bought, paid) = match trade.is_buyer {
+                true => ((base, trade.qty), (quote, trade.quote_qty)),
+                false => ((quote, trade.quote_qty), (base, trade.qty)),
+            };
+
+            events.push(
+                Event::new(trade.time, EntryKind::Trade, &trade.symbol, trade.id)
+                    .book(
+                        LedgerAccount::Spot,
+                        LedgerAccount::Trading,
+                        bought.0,
+                        bought.1,
+                    )
+                    .book(LedgerAccount::Trading, LedgerAccount::Spot, paid.0, paid.1),
+            );
+
+            if trade.commission != 0.0 {
+                events.push(
+                    Event::new(trade.time, EntryKind::Commission, &trade.symbol, trade.id).book(
+                        LedgerAccount::Fees,
+                        LedgerAccount::Spot,
+                        &trade.commission_asset,
+                        trade.commission,
+                    ),
+                );
+            }
+        }
+
+        let mut booked = HashSet::new();
+
+        for trade in &self.futures_trades {
+            let (_, quote) = self.split(&trade.symbol)?;
+            let reference = trade.id.to_string();
+
+            if trade.realized_pnl != 0.0 {
+                events.push(
+                    Event::new(
+                        trade.time,
+                        EntryKind::RealizedPnl,
+                        &trade.symbol,
+                        &reference,
+                    )
+                    .book(
+                        LedgerAccount::UsdMFutures,
+                        LedgerAccount::RealizedPnl,
+                        quote,
+                        trade.realized_pnl,
+                    ),
+                );
+            }
+
+            if trade.commission != 0.0 {
+                events.push(
+                    Event::new(trade.time, EntryKind::Commission, &trade.symbol, &reference).book(
+                        LedgerAccount::Fees,
+                        LedgerAccount::UsdMFutures,
+                        &trade.commission_asset,
+                        trade.commission,
+                    ),
+                );
+            }
+
+            booked.insert((trade.symbol.as_str(), reference));
+        }
+
+        let transfers: HashSet<u64> = self
+            .transfers
+            .iter()
+            .filter(|transfer| transfer.status == "CONFIRMED")
+            .map(|transfer| transfer.tran_id)
+            .collect();
+
+        for entry in &self.income {
+            if entry.income == 0.0 {
+                continue;
+            }
+
+            let by_trade = booked.contains(&(entry.symbol.as_str(), entry.trade_id.clone()));
+
+            let (kind, counter) = match entry.income_type {
+                IncomeType::RealizedPnl | IncomeType::Commission if by_trade => continue,
+                IncomeType::Transfer if transfers.contains(&entry.tran_id) => continue,
+                IncomeType::RealizedPnl => (EntryKind::RealizedPnl, LedgerAccount::RealizedPnl),
+                IncomeType::Commission => (EntryKind::Commission, LedgerAccount::Fees),
+                IncomeType::FundingFee => (EntryKind::FundingFee, LedgerAccount::FundingFees),
+                IncomeType::Transfer => (EntryKind::Transfer, LedgerAccount::External),
+                _ => (EntryKind::Income, LedgerAccount::OtherIncome),
+            };
+
+            let reference = match entry.trade_id.is_empty() {
+                true => entry.tran_id.to_string(),
+                false => entry.trade_id.clone(),
+            };
+
+            events.push(Event::new(entry.time, kind, &entry.symbol, reference).book(
+                LedgerAccount::UsdMFutures,
+                counter,
+                &entry.asset,
+                entry.income,
+            ));
+        }
+
+        for transfer in &self.transfers {
+            if !transfers.contains(&transfer.tran_id) {
+                continue;
+            }
+
+            let (from, to) = wallets(transfer.r#type);
+
+            events.push(
+                Event::new(
+                    transfer.timestamp,
+                    EntryKind::Transfer,
+                    "",
+                    transfer.tran_id,
+                )
+                .book(to, from, &transfer.asset, transfer.amount),
+            );
+        }
+
+        events.sort_by_key(|event| event.time);
+
+        let prices = Prices::new(self);
+        let entries = events
+            .into_iter()
+            .enumerate()
+            .flat_map(|(index, event)| {
+                let prices = &prices;
+
+                event
+                    .lines
+                    .into_iter()
+                    .map(move |(account, asset, amount)| {
+                        let price = prices.price(&asset, event.time);
+
+                        LedgerEntry {
+                            entry: index as u64 + 1,
+                            time: event.time,
+                            kind: event.kind,
+                            account,
+                            amount,
+                            symbol: event.symbol.clone(),
+                            reference: event.reference.clone(),
+                            price: price.unwrap_or_default(),
+                            value: price.map(|price| price * amount).unwrap_or_default(),
+                            value_asset: self.value_asset.to_string(),
+                            valued: price.is_some(),
+                            asset,
+                        }
+                    })
+            })
+            .collect();
+
+        Ok(Ledger { entries })
+    }
+
+    fn split<'s>(&'s self, symbol: &'s str) -> Result<(&'s str, &'s str), ExportError> {
+        if let Some((base, quote)) = self.pairs.get(symbol) {
+            return Ok((base, quote));
+        }
+
+        split_symbol(symbol)
+            .ok_or_else(|| ExportError::Ledger(format!("Unknown base and quote of {}", symbol)))
+    }
+}
+
+/// Opens by open time of every asset quoted against the valuation asset, or the inverse.
+struct Prices<'a> {
+    value_asset: &'a str,
+    opens: HashMap<&'a str, Vec<(u64, f64)>>,
+}
+
+impl<'a> Prices<'a> {
+    fn new(builder: &'a LedgerBuilder<'_>) -> Self {
+        let mut opens: HashMap<&str, Vec<(u64, f64)>> = HashMap::new();
+
+        for (symbol, klines) in &builder.klines {
+            let Ok((base, quote)) = builder.split(symbol) else {
+                continue;
+            };
+
+            let (asset, invert) = match (base, quote) {
+                (base, quote) if quote == builder.value_asset => (base, false),
+                (base, quote) if base == builder.value_asset => (quote, true),
+                _ => continue,
+            };
+
+            let series = opens.entry(asset).or_default();
+
+            series.extend(klines.iter().filter(|kline| kline.open > 0.0).map(
+                |kline| match invert {
+                    true => (kline.open_time, 1.0 / kline.open),
+                    false => (kline.open_time, kline.open),
+                },
+            ));
+        }
+
+        for series in opens.values_mut() {
+            series.sort_by_key(|(open_time, _)| *open_time);
+        }
+
+        Prices {
+            value_asset: builder.value_asset,
+            opens,
+        }
+    }
+
+    fn price(&self, asset: &str, time: u64) -> Option<f64> {
+        if asset == self.value_asset {
+            return Some(1.0);
+        }
+
+        let series = self.opens.get(asset)?;
+        let index = series.partition_point(|(open_time, _)| *open_time <= time);
+
+        index.checked_sub(1).map(|index| series[index].1)
+    }
+}
+
+/// Source and destination wallet of a universal transfer.
+fn wallets(transfer_type: UniversalTransferType) -> (LedgerAccount, LedgerAccount) {
+    use LedgerAccount::*;
+    use UniversalTransferType as Type;
+
+    match transfer_type {
+        Type::SpotToUsdMFutures => (Spot, UsdMFutures),
+        Type::SpotToCoinMFutures => (Spot, CoinMFutures),
+        Type::SpotToMargin => (Spot, Margin),
+        Type::SpotToFunding => (Spot, Funding),
+        Type::UsdMFuturesToSpot => (UsdMFutures, Spot),
+        Type::UsdMFuturesToMargin => (UsdMFutures, Margin),
+        Type::UsdMFuturesToFunding => (UsdMFutures, Funding),
+        Type::CoinMFuturesToSpot => (CoinMFutures, Spot),
+        Type::CoinMFuturesToMargin => (CoinMFutures, Margin),
+        Type::CoinMFuturesToFunding => (CoinMFutures, Funding),
+        Type::MarginToSpot => (Margin, Spot),
+        Type::MarginToUsdMFutures => (Margin, UsdMFutures),
+        Type::MarginToCoinMFutures => (Margin, CoinMFutures),
+        Type::MarginToFunding => (Margin, Funding),
+        Type::MarginToIsolatedMargin => (Margin, IsolatedMargin),
+        Type::IsolatedMarginToMargin => (IsolatedMargin, Margin),
+        Type::IsolatedMarginToIsolatedMargin => (IsolatedMargin, IsolatedMargin),
+        Type::FundingToSpot => (Funding, Spot),
+        Type::FundingToUsdMFutures => (Funding, UsdMFutures),
+        Type::FundingToCoinMFutures => (Funding, CoinMFutures),
+        Type::FundingToMargin => (Funding, Margin),
+    }
+}
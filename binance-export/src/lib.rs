@@ -0,0 +1,10 @@
+pub mod error;
+pub mod ledger;
+pub mod record;
+pub mod streams;
+
+#[cfg(feature = "csv")]
+pub mod csv;
+
+#[cfg(feature = "parquet")]
+pub mod parquet;
@@ -0,0 +1,175 @@
+use std::{fs::File, io::Write, path::Path, sync::Arc};
+
+use arrow_array::{
+    ArrayRef, BooleanArray, Float64Array, RecordBatch, StringArray, TimestampMillisecondArray,
+    UInt64Array,
+};
+use arrow_schema::{DataType, Field, Schema, SchemaRef, TimeUnit};
+use parquet::arrow::ArrowWriter;
+
+use crate::{
+    error::ExportError,
+    record::{ColumnType, Record, Value},
+    streams::StreamTables,
+};
+
+/// Arrow schema of `R`. Timestamps are `Timestamp(Millisecond, "UTC")`,
+/// identifiers and counters `UInt64`, and no column is nullable.
+pub fn schema<R: Record>() -> SchemaRef {
+    Arc::new(Schema::new(
+        R::COLUMNS
+            .iter()
+            .map(|column| Field::new(column.name, data_type(column.column_type), false))
+            .collect::<Vec<Field>>(),
+    ))
+}
+
+pub fn record_batch<'a, R>(
+    records: impl IntoIterator<Item = &'a R>,
+) -> Result<RecordBatch, ExportError>
+where
+    R: Record + 'a,
+{
+    let rows: Vec<Vec<Value<'_>>> = records.into_iter().map(Record::values).collect();
+
+    let columns = R::COLUMNS
+        .iter()
+        .enumerate()
+        .map(|(index, column)| {
+            let values = rows.iter().map(|row| {
+                row.get(index)
+                    .filter(|value| value.column_type() == column.column_type)
+                    .ok_or_else(|| {
+                        ExportError::Schema(format!(
+                            "Column {} expects {:?} values",
+                            column.name, column.column_type
+                        ))
+                    })
+            });
+
+            array(column.column_type, values)
+        })
+        .collect::<Result<Vec<ArrayRef>, ExportError>>()?;
+
+    Ok(RecordBatch::try_new(schema::<R>(), columns)?)
+}
+
+pub fn write<'a, R, W>(
+    writer: W,
+    records: impl IntoIterator<Item = &'a R>,
+) -> Result<(), ExportError>
+where
+    R: Record + 'a,
+    W: Write + Send,
+{
+    let batch = record_batch(records)?;
+    let mut writer = ArrowWriter::try_new(writer, batch.schema(), None)?;
+
+    writer.write(&batch)?;
+    writer.close()?;
+
+    Ok(())
+}
+
+pub fn write_file<'a, R>(
+    path: impl AsRef<Path>,
+    records: impl IntoIterator<Item = &'a R>,
+) -> Result<(), ExportError>
+where
+    R: Record + 'a,
+{
+    write(File::create(path)?, records)
+}
+
+/// Writes `agg_trades.parquet`, `klines.parquet`, `mark_prices.parquet`,
+/// `mini_tickers.parquet`, `book_tickers.parquet` and `depth_levels.parquet`
+/// into `directory`, skipping empty tables.
+pub fn write_streams(
+    directory: impl AsRef<Path>,
+    tables: &StreamTables,
+) -> Result<(), ExportError> {
+    let directory = directory.as_ref();
+
+    if !tables.agg_trades.is_empty() {
+        write_file(directory.join("agg_trades.parquet"), &tables.agg_trades)?;
+    }
+
+    if !tables.klines.is_empty() {
+        write_file(directory.join("klines.parquet"), &tables.klines)?;
+    }
+
+    if !tables.mark_prices.is_empty() {
+        write_file(directory.join("mark_prices.parquet"), &tables.mark_prices)?;
+    }
+
+    if !tables.mini_tickers.is_empty() {
+        write_file(directory.join("mini_tickers.parquet"), &tables.mini_tickers)?;
+    }
+
+    if !tables.book_tickers.is_empty() {
+        write_file(directory.join("book_tickers.parquet"), &tables.book_tickers)?;
+    }
+
+    if !tables.depth_levels.is_empty() {
+        write_file(directory.join("depth_levels.parquet"), &tables.depth_levels)?;
+    }
+
+    Ok(())
+}
+
+fn data_type(column_type: ColumnType) -> DataType {
+    match column_type {
+        ColumnType::Timestamp => DataType::Timestamp(TimeUnit::Millisecond, Some("UTC".into())),
+        ColumnType::Float => DataType::Float64,
+        ColumnType::Integer => DataType::UInt64,
+        ColumnType::Text => DataType::Utf8,
+        ColumnType::Boolean => DataType::Boolean,
+    }
+}
+
+fn array<'a, 'b: 'a>(
+    column_type: ColumnType,
+    values: impl Iterator<Item = Result<&'a Value<'b>, ExportError>>,
+) -> Result<ArrayRef, ExportError> {
+    let values = values.collect::<Result<Vec<&Value<'_>>, ExportError>>()?;
+
+    Ok(match column_type {
+        ColumnType::Timestamp => Arc::new(
+            TimestampMillisecondArray::from_iter_values(values.iter().map(|value| match value {
+                Value::Timestamp(time) => *time as i64,
+                _ => 0,
+            }))
+            .with_timezone("UTC"),
+        ),
+        ColumnType::Float => {
+            Arc::new(Float64Array::from_iter_values(values.iter().map(
+                |value| match value {
+                    Value::Float(value) => *value,
+                    _ => 0.0,
+                },
+            )))
+        }
+        ColumnType::Integer => Arc::new(UInt64Array::from_iter_values(values.iter().map(
+            |value| match value {
+                Value::Integer(value) => *value,
+                _ => 0,
+            },
+        ))),
+        ColumnType::Text => {
+            Arc::new(StringArray::from_iter_values(values.iter().map(
+                |value| match value {
+                    Value::Text(value) => *value,
+                    _ => "",
+                },
+            )))
+        }
+        ColumnType::Boolean => {
+            Arc::new(BooleanArray::from_iter(values.iter().map(
+                |value| match value {
+                    Value::Boolean(value) => Some(*value),
+                    _ => None,
+                },
+            )))
+        }
+    })
+}
@@ -0,0 +1,338 @@
+use binance_common::{
+    futures::model::response::{
+        market::{FundingRateHistoryResponse, TradesResponse as FuturesTradesResponse},
+        websocket::{
+            AggTradeResponse, BookTickerResponse, KlineResponse, MarkPriceResponse,
+            MiniTickerResponse,
+        },
+    },
+    spot::model::response::market::{KlinesResponse, TradesResponse},
+};
+
+use crate::streams::DepthLevel;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColumnType {
+    /// Milliseconds since the Unix epoch, written as a UTC timestamp.
+    Timestamp,
+    Float,
+    Integer,
+    Text,
+    Boolean,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Column {
+    pub name: &'static str,
+    pub column_type: ColumnType,
+}
+
+pub(crate) const fn column(name: &'static str, column_type: ColumnType) -> Column {
+    Column { name, column_type }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value<'a> {
+    Timestamp(u64),
+    Float(f64),
+    Integer(u64),
+    Text(&'a str),
+    Boolean(bool),
+}
+
+impl Value<'_> {
+    pub fn column_type(&self) -> ColumnType {
+        match self {
+            Value::Timestamp(_) => ColumnType::Timestamp,
+            Value::Float(_) => ColumnType::Float,
+            Value::Integer(_) => ColumnType::Integer,
+            Value::Text(_) => ColumnType::Text,
+            Value::Boolean(_) => ColumnType::Boolean,
+        }
+    }
+}
+
+/// A response type that can be exported as one row of a fixed table.
+///
+/// `values` must return one value per column, in the order and of the type
+/// given by `COLUMNS`. Column names follow the Rust field names so the same
+/// data has the same schema in every format.
+pub trait Record {
+    const COLUMNS: &'static [Column];
+
+    fn values(&self) -> Vec<Value<'_>>;
+}
+
+impl Record for KlinesResponse {
+    const COLUMNS: &'static [Column] = &[
+        column("open_time", ColumnType::Timestamp),
+        column("open", ColumnType::Float),
+        column("high", ColumnType::Float),
+        column("low", ColumnType::Float),
+        column("close", ColumnType::Float),
+        column("volume", ColumnType::Float),
+        column("close_time", ColumnType::Timestamp),
+        column("quote_asset_volume", ColumnType::Float),
+        column("number_of_trades", ColumnType::Integer),
+        column("taker_buy_base_asset_volume", ColumnType::Float),
+        column("taker_buy_quote_asset_volume", ColumnType::Float),
+    ];
+
+    fn values(&self) -> Vec<Value<'_>> {
+        vec![
+            Value::Timestamp(self.open_time),
+            Value::Float(self.open),
+            Value::Float(self.high),
+            Value::Float(self.low),
+            Value::Float(self.close),
+            Value::Float(self.volume),
+            Value::Timestamp(self.close_time),
+            Value::Float(self.quote_asset_volume),
+            Value::Integer(self.number_of_trades as u64),
+            Value::Float(self.taker_buy_base_asset_volume),
+            Value::Float(self.taker_buy_quote_asset_volume),
+        ]
+    }
+}
+
+impl Record for TradesResponse {
+    const COLUMNS: &'static [Column] = &[
+        column("id", ColumnType::Integer),
+        column("price", ColumnType::Float),
+        column("qty", ColumnType::Float),
+        column("quote_qty", ColumnType::Float),
+        column("time", ColumnType::Timestamp),
+        column("is_buyer_maker", ColumnType::Boolean),
+        column("is_best_match", ColumnType::Boolean),
+    ];
+
+    fn values(&self) -> Vec<Value<'_>> {
+        vec![
+            Value::Integer(self.id as u64),
+            Value::Float(self.price),
+            Value::Float(self.qty),
+            Value::Float(self.quote_qty),
+            Value::Timestamp(self.time),
+            Value::Boolean(self.is_buyer_maker),
+            Value::Boolean(self.is_best_match),
+        ]
+    }
+}
+
+impl Record for FuturesTradesResponse {
+    const COLUMNS: &'static [Column] = &[
+        column("id", ColumnType::Integer),
+        column("price", ColumnType::Float),
+        column("qty", ColumnType::Float),
+        column("quote_qty", ColumnType::Float),
+        column("time", ColumnType::Timestamp),
+        column("is_buyer_maker", ColumnType::Boolean),
+    ];
+
+    fn values(&self) -> Vec<Value<'_>> {
+        vec![
+            Value::Integer(self.id as u64),
+            Value::Float(self.price),
+            Value::Float(self.qty),
+            Value::Float(self.quote_qty),
+            Value::Timestamp(self.time),
+            Value::Boolean(self.is_buyer_maker),
+        ]
+    }
+}
+
+impl Record for FundingRateHistoryResponse {
+    const COLUMNS: &'static [Column] = &[
+        column("symbol", ColumnType::Text),
+        column("funding_rate", ColumnType::Float),
+        column("funding_time", ColumnType::Timestamp),
+        column("mark_price", ColumnType::Float),
+    ];
+
+    fn values(&self) -> Vec<Value<'_>> {
+        vec![
+            Value::Text(&self.symbol),
+            Value::Float(self.funding_rate),
+            Value::Timestamp(self.funding_time),
+            Value::Float(self.mark_price),
+        ]
+    }
+}
+
+impl Record for AggTradeResponse {
+    const COLUMNS: &'static [Column] = &[
+        column("event_time", ColumnType::Timestamp),
+        column("symbol", ColumnType::Text),
+        column("aggregate_trade_id", ColumnType::Integer),
+        column("price", ColumnType::Float),
+        column("quantity", ColumnType::Float),
+        column("first_trade_id", ColumnType::Integer),
+        column("last_trade_id", ColumnType::Integer),
+        column("trade_time", ColumnType::Timestamp),
+        column("is_buyer_market_maker", ColumnType::Boolean),
+    ];
+
+    fn values(&self) -> Vec<Value<'_>> {
+        vec![
+            Value::Timestamp(self.event_time),
+            Value::Text(&self.symbol),
+            Value::Integer(self.aggregate_trade_id),
+            Value::Float(self.price),
+            Value::Float(self.quantity),
+            Value::Integer(self.first_trade_id),
+            Value::Integer(self.last_trade_id),
+            Value::Timestamp(self.trade_time),
+            Value::Boolean(self.is_buyer_market_maker),
+        ]
+    }
+}
+
+impl Record for KlineResponse {
+    const COLUMNS: &'static [Column] = &[
+        column("event_time", ColumnType::Timestamp),
+        column("symbol", ColumnType::Text),
+        column("interval", ColumnType::Text),
+        column("start_time", ColumnType::Timestamp),
+        column("close_time", ColumnType::Timestamp),
+        column("first_trade_id", ColumnType::Integer),
+        column("last_trade_id", ColumnType::Integer),
+        column("open_price", ColumnType::Float),
+        column("high_price", ColumnType::Float),
+        column("low_price", ColumnType::Float),
+        column("close_price", ColumnType::Float),
+        column("base_asset_volume", ColumnType::Float),
+        column("number_of_trades", ColumnType::Integer),
+        column("is_closed", ColumnType::Boolean),
+        column("quote_asset_volume", ColumnType::Float),
+        column("taker_buy_base_asset_volume", ColumnType::Float),
+        column("taker_buy_quote_asset_volume", ColumnType::Float),
+    ];
+
+    fn values(&self) -> Vec<Value<'_>> {
+        let kline = &self.kline;
+
+        vec![
+            Value::Timestamp(self.event_time),
+            Value::Text(&self.symbol),
+            Value::Text(&kline.interval),
+            Value::Timestamp(kline.start_time),
+            Value::Timestamp(kline.close_time),
+            Value::Integer(kline.first_trade_id),
+            Value::Integer(kline.last_trade_id),
+            Value::Float(kline.open_price),
+            Value::Float(kline.high_price),
+            Value::Float(kline.low_price),
+            Value::Float(kline.close_price),
+            Value::Float(kline.base_asset_volume),
+            Value::Integer(kline.number_of_trades),
+            Value::Boolean(kline.is_closed),
+            Value::Float(kline.quote_asset_volume),
+            Value::Float(kline.taker_buy_base_asset_volume),
+            Value::Float(kline.taker_buy_quote_asset_volume),
+        ]
+    }
+}
+
+impl Record for MarkPriceResponse {
+    const COLUMNS: &'static [Column] = &[
+        column("event_time", ColumnType::Timestamp),
+        column("symbol", ColumnType::Text),
+        column("mark_price", ColumnType::Float),
+        column("index_price", ColumnType::Float),
+        column("estimated_settle_price", ColumnType::Float),
+        column("funding_rate", ColumnType::Float),
+        column("next_funding_time", ColumnType::Timestamp),
+    ];
+
+    fn values(&self) -> Vec<Value<'_>> {
+        vec![
+            Value::Timestamp(self.event_time),
+            Value::Text(&self.symbol),
+            Value::Float(self.mark_price),
+            Value::Float(self.index_price),
+            Value::Float(self.estimated_settle_price),
+            Value::Float(self.funding_rate),
+            Value::Timestamp(self.next_funding_time),
+        ]
+    }
+}
+
+impl Record for MiniTickerResponse {
+    const COLUMNS: &'static [Column] = &[
+        column("event_time", ColumnType::Timestamp),
+        column("symbol", ColumnType::Text),
+        column("close_price", ColumnType::Float),
+        column("open_price", ColumnType::Float),
+        column("high_price", ColumnType::Float),
+        column("low_price", ColumnType::Float),
+        column("base_volume", ColumnType::Float),
+        column("quote_volume", ColumnType::Float),
+    ];
+
+    fn values(&self) -> Vec<Value<'_>> {
+        vec![
+            Value::Timestamp(self.event_time),
+            Value::Text(&self.symbol),
+            Value::Float(self.close_price),
+            Value::Float(self.open_price),
+            Value::Float(self.high_price),
+            Value::Float(self.low_price),
+            Value::Float(self.base_volume),
+            Value::Float(self.quote_volume),
+        ]
+    }
+}
+
+impl Record for BookTickerResponse {
+    const COLUMNS: &'static [Column] = &[
+        column("event_time", ColumnType::Timestamp),
+        column("transaction_time", ColumnType::Timestamp),
+        column("symbol", ColumnType::Text),
+        column("update_id", ColumnType::Integer),
+        column("best_bid_price", ColumnType::Float),
+        column("best_bid_quantity", ColumnType::Float),
+        column("best_ask_price", ColumnType::Float),
+        column("best_ask_quantity", ColumnType::Float),
+    ];
+
+    fn values(&self) -> Vec<Value<'_>> {
+        vec![
+            Value::Timestamp(self.event_time),
+            Value::Timestamp(self.transaction_time),
+            Value::Text(&self.symbol),
+            Value::Integer(self.update_id),
+            Value::Float(self.best_bid_price),
+            Value::Float(self.best_bid_quantity),
+            Value::Float(self.best_ask_price),
+            Value::Float(self.best_ask_quantity),
+        ]
+    }
+}
+
+impl Record for DepthLevel {
+    const COLUMNS: &'static [Column] = &[
+        column("event_time", ColumnType::Timestamp),
+        column("transaction_time", ColumnType::Timestamp),
+        column("symbol", ColumnType::Text),
+        column("first_update_id", ColumnType::Integer),
+        column("final_update_id", ColumnType::Integer),
+        column("previous_final_update_id", ColumnType::Integer),
+        column("is_bid", ColumnType::Boolean),
+        column("price", ColumnType::Float),
+        column("quantity", ColumnType::Float),
+    ];
+
+    fn values(&self) -> Vec<Value<'_>> {
+        vec![
+            Value::Timestamp(self.event_time),
+            Value::Timestamp(self.transaction_time),
+            Value::Text(&self.symbol),
+            Value::Integer(self.first_update_id),
+            Value::Integer(self.final_update_id),
+            Value::Integer(self.previous_final_update_id),
+            Value::Boolean(self.is_bid),
+            Value::Float(self.price),
+            Value::Float(self.quantity),
+        ]
+    }
+}
@@ -0,0 +1,91 @@
+use binance_common::futures::model::response::websocket::{
+    AggTradeResponse, BookTickerResponse, DepthUpdateResponse, KlineResponse, MarkPriceResponse,
+    MiniTickerResponse, WebSocketResponse,
+};
+
+/// One price level of a `depthUpdate`, the row of the depth table. A zero
+/// `quantity` removes the level from the book.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DepthLevel {
+    pub event_time: u64,
+    pub transaction_time: u64,
+    pub symbol: String,
+    pub first_update_id: u64,
+    pub final_update_id: u64,
+    pub previous_final_update_id: u64,
+    pub is_bid: bool,
+    pub price: f64,
+    pub quantity: f64,
+}
+
+/// A recorded `WebSocketResponse` stream split into one table per event type,
+/// so every table keeps a fixed schema. Depth updates are flattened into one
+/// row per level, an update without levels leaves no row. `Init` messages are dropped.
+#[derive(Clone, Debug, Default)]
+pub struct StreamTables {
+    pub agg_trades: Vec<AggTradeResponse>,
+    pub klines: Vec<KlineResponse>,
+    pub mark_prices: Vec<MarkPriceResponse>,
+    pub mini_tickers: Vec<MiniTickerResponse>,
+    pub book_tickers: Vec<BookTickerResponse>,
+    pub depth_levels: Vec<DepthLevel>,
+}
+
+impl StreamTables {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, response: WebSocketResponse) {
+        match response {
+            WebSocketResponse::AggTrade(agg_trade) => self.agg_trades.push(agg_trade),
+            WebSocketResponse::Kline(kline) => self.klines.push(kline),
+            WebSocketResponse::MarkPrice(mark_price) => self.mark_prices.push(mark_price),
+            WebSocketResponse::MiniTicker(mini_ticker) => self.mini_tickers.push(mini_ticker),
+            WebSocketResponse::BookTicker(book_ticker) => self.book_tickers.push(book_ticker),
+            WebSocketResponse::DepthUpdate(depth_update) => self.push_depth(depth_update),
+            WebSocketResponse::Init => {}
+        }
+    }
+
+    fn push_depth(&mut self, depth_update: DepthUpdateResponse) {
+        let level = |is_bid: bool, price: f64, quantity: f64| DepthLevel {
+            event_time: depth_update.event_time,
+            transaction_time: depth_update.transaction_time,
+            symbol: depth_update.symbol.clone(),
+            first_update_id: depth_update.first_update_id,
+            final_update_id: depth_update.final_update_id,
+            previous_final_update_id: depth_update.previous_final_update_id,
+            is_bid,
+            price,
+            quantity,
+        };
+
+        let bids = depth_update
+            .bids
+            .iter()
+            .map(|bid| level(true, bid.price, bid.quantity));
+        let asks = depth_update
+            .asks
+            .iter()
+            .map(|ask| level(false, ask.price, ask.quantity));
+
+        self.depth_levels.extend(bids.chain(asks));
+    }
+}
+
+impl Extend<WebSocketResponse> for StreamTables {
+    fn extend<T: IntoIterator<Item = WebSocketResponse>>(&mut self, responses: T) {
+        responses
+            .into_iter()
+            .for_each(|response| self.push(response));
+    }
+}
+
+impl FromIterator<WebSocketResponse> for StreamTables {
+    fn from_iter<T: IntoIterator<Item = WebSocketResponse>>(responses: T) -> Self {
+        let mut tables = StreamTables::new();
+        tables.extend(responses);
+        tables
+    }
+}
@@ -0,0 +1,178 @@
+#[cfg(test)]
+mod export_integration_tests {
+    use binance_common::{
+        futures::model::response::{
+            market::FundingRateHistoryResponse, websocket::WebSocketResponse,
+        },
+        spot::model::response::market::KlinesResponse,
+    };
+    use binance_export::{record::Record, streams::StreamTables};
+
+    fn klines() -> Vec<KlinesResponse> {
+        serde_json::from_str(
+            r#"[
+                [1499040000000,"0.01634790","0.80000000","0.01575800","0.01577100","148976.11427815",1499644799999,"2434.19055334",308,"1756.87402397","28.46694368","0"],
+                [1499644800000,"0.01577100","0.01600000","0.01570000","0.01590000","1000.00000000",1500249599999,"15.90000000",12,"500.00000000","7.95000000","0"]
+            ]"#,
+        )
+        .unwrap()
+    }
+
+    fn stream() -> Vec<WebSocketResponse> {
+        [
+            r#"{"e":"aggTrade","E":1700000000100,"s":"BTCUSDT","a":5933014,"p":"37000.10","q":"0.002","f":100,"l":105,"T":1700000000099,"m":true}"#,
+            r#"{"e":"markPriceUpdate","E":1700000000200,"s":"BTCUSDT","p":"37001.00","i":"37000.50","P":"37000.70","r":"0.00010000","T":1700006400000}"#,
+            r#"{"e":"aggTrade","E":1700000000300,"s":"BTCUSDT","a":5933015,"p":"37000.20","q":"0.010","f":106,"l":106,"T":1700000000299,"m":false}"#,
+            r#"{"e":"bookTicker","u":400900217,"E":1700000000400,"T":1700000000399,"s":"BTCUSDT","b":"37000.10","B":"1.5","a":"37000.20","A":"0.7"}"#,
+            r#"{"e":"depthUpdate","E":1700000000500,"T":1700000000499,"s":"BTCUSDT","U":157,"u":160,"pu":149,"b":[["37000.00","2.0"],["36999.90","0"]],"a":[["37000.30","1.1"]]}"#,
+        ]
+        .iter()
+        .map(|message| serde_json::from_str(message).unwrap())
+        .collect()
+    }
+
+    #[test]
+    fn test_stream_tables_split_by_event() {
+        let tables: StreamTables = stream().into_iter().collect();
+
+        assert_eq!(tables.agg_trades.len(), 2);
+        assert_eq!(tables.mark_prices.len(), 1);
+        assert!(tables.klines.is_empty());
+        assert!(tables.mini_tickers.is_empty());
+        assert_eq!(tables.book_tickers.len(), 1);
+        assert_eq!(tables.depth_levels.len(), 3);
+        assert!(tables.depth_levels[0].is_bid);
+        assert_eq!(tables.depth_levels[1].quantity, 0.0);
+        assert!(!tables.depth_levels[2].is_bid);
+        assert_eq!(tables.depth_levels[2].price, 37000.30);
+        assert!(
+            tables
+                .depth_levels
+                .iter()
+                .all(|level| level.final_update_id == 160)
+        );
+    }
+
+    #[test]
+    fn test_columns_match_values() {
+        let funding = FundingRateHistoryResponse {
+            symbol: "BTCUSDT".to_string(),
+            funding_rate: 0.0001,
+            funding_time: 1700006400000,
+            mark_price: 37000.0,
+        };
+
+        let values = funding.values();
+
+        assert_eq!(values.len(), FundingRateHistoryResponse::COLUMNS.len());
+        assert!(
+            values
+                .iter()
+                .zip(FundingRateHistoryResponse::COLUMNS)
+                .all(|(value, column)| value.column_type() == column.column_type)
+        );
+        assert_eq!(klines()[0].values().len(), KlinesResponse::COLUMNS.len());
+    }
+
+    #[cfg(feature = "csv")]
+    #[test]
+    fn test_csv_klines() {
+        let mut output = Vec::new();
+        binance_export::csv::write(&mut output, &klines()).unwrap();
+
+        let output = String::from_utf8(output).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+
+        assert_eq!(lines.len(), 3);
+        assert_eq!(
+            lines[0],
+            "open_time,open,high,low,close,volume,close_time,quote_asset_volume,number_of_trades,taker_buy_base_asset_volume,taker_buy_quote_asset_volume"
+        );
+        assert_eq!(
+            lines[1],
+            "2017-07-03T00:00:00.000Z,0.0163479,0.8,0.015758,0.015771,148976.11427815,2017-07-09T23:59:59.999Z,2434.19055334,308,1756.87402397,28.46694368"
+        );
+    }
+
+    #[cfg(feature = "csv")]
+    #[test]
+    fn test_csv_streams() {
+        let directory =
+            std::env::temp_dir().join(format!("binance-export-csv-{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+
+        let tables: StreamTables = stream().into_iter().collect();
+        binance_export::csv::write_streams(&directory, &tables).unwrap();
+
+        let agg_trades = std::fs::read_to_string(directory.join("agg_trades.csv")).unwrap();
+
+        assert!(agg_trades.starts_with("event_time,symbol,aggregate_trade_id,"));
+        assert_eq!(agg_trades.lines().count(), 3);
+        assert!(directory.join("mark_prices.csv").exists());
+        assert!(directory.join("book_tickers.csv").exists());
+
+        let depth_levels = std::fs::read_to_string(directory.join("depth_levels.csv")).unwrap();
+
+        assert!(depth_levels.starts_with("event_time,transaction_time,symbol,first_update_id,"));
+        assert_eq!(depth_levels.lines().count(), 4);
+        assert!(!directory.join("klines.csv").exists());
+
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[cfg(feature = "parquet")]
+    #[test]
+    fn test_parquet_round_trip() {
+        use arrow_array::{Array, TimestampMillisecondArray, UInt64Array};
+        use arrow_schema::{DataType, TimeUnit};
+        use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+
+        let directory =
+            std::env::temp_dir().join(format!("binance-export-parquet-{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+
+        let path = directory.join("klines.parquet");
+        binance_export::parquet::write_file(&path, &klines()).unwrap();
+
+        let reader = ParquetRecordBatchReaderBuilder::try_new(std::fs::File::open(&path).unwrap())
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let batches: Vec<_> = reader.map(Result::unwrap).collect();
+        let batch = &batches[0];
+
+        assert_eq!(
+            batch.schema(),
+            binance_export::parquet::schema::<KlinesResponse>()
+        );
+        assert_eq!(batch.num_rows(), 2);
+        assert_eq!(
+            batch.schema().field(0).data_type(),
+            &DataType::Timestamp(TimeUnit::Millisecond, Some("UTC".into()))
+        );
+
+        let open_time = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<TimestampMillisecondArray>()
+            .unwrap();
+        let number_of_trades = batch
+            .column(8)
+            .as_any()
+            .downcast_ref::<UInt64Array>()
+            .unwrap();
+
+        assert_eq!(open_time.value(1), 1499644800000);
+        assert_eq!(number_of_trades.value(0), 308);
+        assert_eq!(number_of_trades.null_count(), 0);
+
+        let tables: StreamTables = stream().into_iter().collect();
+        binance_export::parquet::write_streams(&directory, &tables).unwrap();
+
+        assert!(directory.join("agg_trades.parquet").exists());
+        assert!(!directory.join("mini_tickers.parquet").exists());
+
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}
@@ -0,0 +1,197 @@
+#[cfg(test)]
+mod ledger_integration_tests {
+    use std::collections::HashMap;
+
+    use binance_common::{
+        futures::model::response::{account::IncomeHistoryResponse, trade::UserTradesResponse},
+        spot::model::response::{account::MyTradesResponse, market::KlinesResponse},
+        wallet::model::response::asset::TransferRecord,
+    };
+    use binance_export::ledger::{EntryKind, Ledger, LedgerAccount, LedgerBuilder};
+
+    fn klines() -> Vec<KlinesResponse> {
+        serde_json::from_str(
+            r#"[
+                [0,"100","106","99","105","1",59999,"100",1,"0","0","0"],
+                [60000,"110","121","109","120","1",119999,"110",1,"0","0","0"]
+            ]"#,
+        )
+        .unwrap()
+    }
+
+    fn spot_trades() -> Vec<MyTradesResponse> {
+        serde_json::from_str(
+            r#"[
+                {"symbol":"BTCUSDT","id":1,"orderId":1,"orderListId":-1,"price":"100","qty":"1","quoteQty":"100","commission":"0.001","commissionAsset":"BTC","time":1000,"isBuyer":true,"isMaker":false,"isBestMatch":true},
+                {"symbol":"BTCUSDT","id":2,"orderId":2,"orderListId":-1,"price":"110","qty":"0.999","quoteQty":"109.89","commission":"0.11","commissionAsset":"USDT","time":61000,"isBuyer":false,"isMaker":true,"isBestMatch":true}
+            ]"#,
+        )
+        .unwrap()
+    }
+
+    fn futures_trades() -> Vec<UserTradesResponse> {
+        serde_json::from_str(
+            r#"[
+                {"buyer":false,"commission":"0.2","commissionAsset":"USDT","id":7,"maker":false,"orderId":70,"price":"110","qty":"1","quoteQty":"110","realizedPnl":"5","side":"SELL","positionSide":"BOTH","symbol":"BTCUSDT","time":70000}
+            ]"#,
+        )
+        .unwrap()
+    }
+
+    fn income() -> Vec<IncomeHistoryResponse> {
+        let entry = |income_type: &str,
+                     income: &str,
+                     asset: &str,
+                     time: u64,
+                     tran_id: u64,
+                     trade_id: &str| {
+            format!(
+                r#"{{"symbol":"BTCUSDT","incomeType":"{income_type}","income":"{income}","asset":"{asset}","info":"","time":{time},"tranId":{tran_id},"tradeId":"{trade_id}"}}"#
+            )
+        };
+
+        let entries = [
+            // Already booked through the futures trade.
+            entry("REALIZED_PNL", "5", "USDT", 70000, 100, "7"),
+            entry("COMMISSION", "-0.2", "USDT", 70000, 101, "7"),
+            entry("FUNDING_FEE", "-0.5", "USDT", 80000, 102, ""),
+            // Booked through the transfer record.
+            entry("TRANSFER", "1000", "USDT", 500, 42, ""),
+            entry("TRANSFER", "10", "USDT", 90000, 43, ""),
+            entry("COMMISSION_REBATE", "0.05", "BNB", 95000, 104, ""),
+        ];
+
+        serde_json::from_str(&format!("[{}]", entries.join(","))).unwrap()
+    }
+
+    fn transfers() -> Vec<TransferRecord> {
+        serde_json::from_str(
+            r#"[
+                {"asset":"USDT","amount":"1000","type":"MAIN_UMFUTURE","status":"CONFIRMED","tranId":42,"timestamp":500},
+                {"asset":"USDT","amount":"5000","type":"MAIN_UMFUTURE","status":"FAILED","tranId":44,"timestamp":600}
+            ]"#,
+        )
+        .unwrap()
+    }
+
+    fn assert_close(left: f64, right: f64) {
+        assert!((left - right).abs() < 1e-9, "{} != {}", left, right);
+    }
+
+    fn ledger() -> Ledger {
+        let (klines, spot_trades, futures_trades, income, transfers) = (
+            klines(),
+            spot_trades(),
+            futures_trades(),
+            income(),
+            transfers(),
+        );
+
+        LedgerBuilder::new("USDT")
+            .klines("BTCUSDT", &klines)
+            .spot_trades(&spot_trades)
+            .futures_trades(&futures_trades)
+            .income(&income)
+            .transfers(&transfers)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_entries_balance_and_overlaps_are_booked_once() {
+        let ledger = ledger();
+
+        let mut sums: HashMap<(u64, &str), f64> = HashMap::new();
+
+        for line in &ledger.entries {
+            *sums.entry((line.entry, line.asset.as_str())).or_default() += line.amount;
+        }
+
+        assert!(sums.values().all(|sum| sum.abs() < 1e-9));
+        assert_eq!(ledger.entries.len(), 24);
+        assert_eq!(ledger.entries.last().unwrap().entry, 10);
+        assert!(
+            ledger
+                .entries
+                .windows(2)
+                .all(|pair| pair[0].time <= pair[1].time)
+        );
+
+        assert_close(ledger.balance(LedgerAccount::Spot, "BTC"), 0.0);
+        assert_close(ledger.balance(LedgerAccount::Spot, "USDT"), -990.22);
+        assert_close(ledger.balance(LedgerAccount::UsdMFutures, "USDT"), 1014.3);
+        assert_close(ledger.balance(LedgerAccount::UsdMFutures, "BNB"), 0.05);
+        assert_close(ledger.balance(LedgerAccount::RealizedPnl, "USDT"), -5.0);
+        assert_close(ledger.balance(LedgerAccount::External, "USDT"), -10.0);
+
+        let rebate = ledger
+            .entries
+            .iter()
+            .find(|line| line.asset == "BNB")
+            .unwrap();
+
+        assert_eq!(rebate.kind, EntryKind::Income);
+        assert_eq!(rebate.reference, "104");
+        assert!(!rebate.valued);
+    }
+
+    #[test]
+    fn test_lines_are_valued_at_the_kline_open() {
+        let ledger = ledger();
+
+        // 0.001 BTC at 100, then 0.11 and 0.2 USDT. The closes lie after the trades.
+        assert_close(ledger.value(LedgerAccount::Fees), 0.41);
+
+        let sold = ledger
+            .entries
+            .iter()
+            .find(|line| line.reference == "2" && line.asset == "BTC")
+            .unwrap();
+
+        assert_eq!(sold.kind, EntryKind::Trade);
+        assert_eq!(sold.account, LedgerAccount::Trading);
+        assert_eq!(sold.price, 110.0);
+        assert_close(sold.value, 109.89);
+
+        let unknown: Vec<MyTradesResponse> = serde_json::from_str(
+            &serde_json::to_string(&spot_trades())
+                .unwrap()
+                .replace("BTCUSDT", "FOOBAR"),
+        )
+        .unwrap();
+
+        assert!(
+            LedgerBuilder::new("USDT")
+                .spot_trades(&unknown)
+                .build()
+                .is_err()
+        );
+        assert!(
+            LedgerBuilder::new("USDT")
+                .pair("FOOBAR", "FOO", "BAR")
+                .spot_trades(&unknown)
+                .build()
+                .is_ok()
+        );
+    }
+
+    #[cfg(feature = "csv")]
+    #[test]
+    fn test_csv_ledger() {
+        let mut output = Vec::new();
+        binance_export::csv::write(&mut output, &ledger().entries).unwrap();
+
+        let output = String::from_utf8(output).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+
+        assert_eq!(lines.len(), 25);
+        assert_eq!(
+            lines[0],
+            "entry,time,kind,account,asset,amount,symbol,reference,price,value,value_asset,valued"
+        );
+        assert_eq!(
+            lines[1],
+            "1,1970-01-01T00:00:00.500Z,TRANSFER,usdm_futures,USDT,1000,,42,1,1000,USDT,true"
+        );
+    }
+}
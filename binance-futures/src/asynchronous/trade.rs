@@ -1,115 +1,105 @@
 use binance_common::{
     error::BinanceError,
-    futures::{
-        endpoint::route::Trade,
-        model::{
-            params::{
-                market::Symbol,
-                trade::{
-                    CancelAllOrdersParams, CancelOrderParams, GetOpenOrderParams, GetOrderParams,
-                    NewOrderParams, PositionRiskV3Params, SetLeverageParams,
-                },
+    futures::model::{
+        params::{
+            account::FuturesBalanceParams,
+            trade::{
+                AutoCancelAllOpenOrdersParams, CancelAllOrdersParams, CancelOrderParams,
+                GetOrderParams, NewOrderParams, OpenOrdersParams, PositionRiskV3Params,
             },
-            response::trade::{
-                CancelAllOrdersResponse, GetOrderResponse, OrderResponse, PositionRiskV3Response,
-                SetLeverageResponse, TestOrderResponse,
+        },
+        response::{
+            account::FuturesBalanceResponse,
+            trade::{
+                AutoCancelAllOpenOrdersResponse, CancelAllOrdersResponse, GetOrderResponse,
+                OrderResponse, PositionRiskV3Response,
             },
         },
     },
 };
-use binance_core::{client::asynchronous::Client, signer::signature::Signature};
-use reqwest::Method;
+use binance_core::{
+    client::asynchronous::Client, signer::signature::Signature, trading::futures::FuturesTradingApi,
+};
 
-pub struct TradeApi<'a, S>
+use crate::endpoint::account::FuturesBalanceV3;
+use binance_core::transport::asynchronous::Transport;
+
+/// Endpoints are declared in [`crate::endpoint::trade`].
+pub struct TradeApi<'a, S, T = reqwest::Client>
 where
     S: Signature<'a>,
+    T: Transport,
 {
-    pub client: Client<'a, S>,
+    pub client: Client<'a, S, T>,
 }
 
-impl<'a, S> TradeApi<'a, S>
+impl<'a, S, T> TradeApi<'a, S, T>
 where
     S: Signature<'a>,
+    T: Transport,
 {
-    pub fn new(client: Client<'a, S>) -> Self {
+    pub fn new(client: Client<'a, S, T>) -> Self {
         TradeApi { client }
     }
+}
 
-    pub async fn send_new_test_order(
-        &self,
-        params: &NewOrderParams<'a>,
-    ) -> Result<TestOrderResponse, BinanceError> {
-        self.client
-            .send(Trade::TestNewOrder, params, Method::POST)
-            .await
-    }
-
-    pub async fn send_new_order(
+impl<'a, S, T> FuturesTradingApi for TradeApi<'a, S, T>
+where
+    S: Signature<'a> + Sync,
+    T: Transport + Sync,
+{
+    async fn send_new_order(
         &self,
-        params: &NewOrderParams<'a>,
+        params: &NewOrderParams<'_>,
     ) -> Result<OrderResponse, BinanceError> {
-        self.client
-            .send(Trade::NewOrder, params, Method::POST)
-            .await
-    }
-
-    pub async fn send_set_leverage(
-        &self,
-        params: &SetLeverageParams<'a>,
-    ) -> Result<SetLeverageResponse, BinanceError> {
-        self.client
-            .send(Trade::SetLeverage, params, Method::POST)
-            .await
+        TradeApi::send_new_order(self, params).await
     }
 
-    pub async fn send_cancel_order(
+    async fn send_cancel_order(
         &self,
-        params: &CancelOrderParams<'a>,
+        params: &CancelOrderParams<'_>,
     ) -> Result<OrderResponse, BinanceError> {
-        self.client
-            .send(Trade::CancelOrder, params, Method::DELETE)
-            .await
+        TradeApi::send_cancel_order(self, params).await
     }
 
-    pub async fn send_cancel_all_orders(
+    async fn send_cancel_all_orders(
         &self,
-        params: &CancelAllOrdersParams<'a>,
+        params: &CancelAllOrdersParams<'_>,
     ) -> Result<CancelAllOrdersResponse, BinanceError> {
-        self.client
-            .send(Trade::CancelAllOrders, params, Method::GET)
-            .await
+        TradeApi::send_cancel_all_orders(self, params).await
     }
 
-    pub async fn get_order(
+    async fn send_auto_cancel_all_open_orders(
         &self,
-        params: &GetOrderParams<'a>,
-    ) -> Result<GetOrderResponse, BinanceError> {
-        self.client.send(Trade::GetOrder, params, Method::GET).await
+        params: &AutoCancelAllOpenOrdersParams<'_>,
+    ) -> Result<AutoCancelAllOpenOrdersResponse, BinanceError> {
+        TradeApi::send_auto_cancel_all_open_orders(self, params).await
     }
 
-    pub async fn get_open_order(
+    async fn get_order(
         &self,
-        params: &GetOpenOrderParams<'a>,
+        params: &GetOrderParams<'_>,
     ) -> Result<GetOrderResponse, BinanceError> {
-        self.client
-            .send(Trade::GetOpenOrder, params, Method::GET)
-            .await
+        TradeApi::get_order(self, params).await
     }
 
-    pub async fn get_all_open_orders(
+    async fn get_all_open_orders(
         &self,
-        params: &Symbol<'a>,
+        params: &OpenOrdersParams<'_>,
     ) -> Result<Vec<GetOrderResponse>, BinanceError> {
+        TradeApi::get_all_open_orders(self, params).await
+    }
+
+    async fn get_balances(&self) -> Result<Vec<FuturesBalanceResponse>, BinanceError> {
         self.client
-            .send(Trade::OpenOrders, params, Method::GET)
+            .call::<FuturesBalanceV3>(&FuturesBalanceParams::new(5000))
             .await
     }
-    pub async fn get_position_risk_v3(
+
+    async fn get_position_risk_v3(
         &self,
-        params: &PositionRiskV3Params<'a>,
+        params: &PositionRiskV3Params<'_>,
     ) -> Result<Vec<PositionRiskV3Response>, BinanceError> {
-        self.client
-            .send(Trade::PositionRiskV3, params, Method::GET)
-            .await
+        TradeApi::get_position_risk_v3(self, params).await
     }
 }
@@ -0,0 +1,3 @@
+pub mod account;
+pub mod market;
+pub mod trade;
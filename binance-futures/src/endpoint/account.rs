@@ -0,0 +1,41 @@
+use binance_common::futures::endpoint::route::Account;
+use binance_common::futures::model::params::account::{
+    CommissionRateParams, FuturesAccountParams, FuturesBalanceParams, IncomeHistoryParams,
+    PositionSideParams,
+};
+use binance_common::futures::model::response::account::{
+    CommissionRateResponse, FuturesAccountResponse, FuturesBalanceResponse, IncomeHistoryResponse,
+    PositionSideResponse,
+};
+
+binance_core::endpoints! {
+    crate::synchronous::account::AccountApi, crate::asynchronous::account::AccountApi;
+
+    pub fn get_futures_balance(FuturesBalanceParams)
+        -> Vec<FuturesBalanceResponse> = FuturesBalance {
+        GET Account::FuturesBalance, weight: 5, security: Signed
+    }
+
+    pub fn get_futures_balance_v3(FuturesBalanceParams)
+        -> Vec<FuturesBalanceResponse> = FuturesBalanceV3 {
+        GET Account::FuturesBalanceV3, weight: 5, security: Signed
+    }
+
+    pub fn get_position_side(PositionSideParams) -> PositionSideResponse = PositionSide {
+        GET Account::PositionSide, weight: 30, security: Signed
+    }
+
+    pub fn get_futures_account(FuturesAccountParams) -> FuturesAccountResponse = FuturesAccount {
+        GET Account::FuturesAccount, weight: 5, security: Signed
+    }
+
+    pub fn get_comission_rate(CommissionRateParams<'p>)
+        -> CommissionRateResponse = CommissionRate {
+        GET Account::CommissionRate, weight: 20, security: Signed
+    }
+
+    pub fn get_income_history(IncomeHistoryParams<'p>)
+        -> Vec<IncomeHistoryResponse> = IncomeHistory {
+        GET Account::IncomeHistory, weight: 30, security: Signed
+    }
+}
@@ -0,0 +1,132 @@
+use binance_common::futures::endpoint::route::Market;
+use binance_common::futures::model::params::market::{
+    BasisParams, DepthParams, ExchangeInformationParams, FundingRateHistoryParams,
+    GlobalLongShortAccountRatioParams, HistoricalTradesParams, OpenInterestHistoryParams, Pair,
+    Symbol, TakerBuySellVolumeParams, TopLongShortAccountRatioParams,
+    TopLongShortPositionRatioParams, TradesParams,
+};
+use binance_common::futures::model::response::market::{
+    BasisResponse, BookTickerResponse, DeliveryPriceResponse, DepthResponse, EmptyResponse,
+    ExchangeInformationResponse, FundingRateHistoryResponse, GlobalLongShortAccountRatioResponse,
+    HistoricalTradesResponse, MarkPriceResponse, OpenInterestHistoryResponse, OpenInterestResponse,
+    ServerTimeResponse, TakerBuySellVolumeResponse, Ticker24hResponse, TickerPriceResponse,
+    TopLongShortAccountRatioResponse, TopLongShortPositionRatioResponse, TradesResponse,
+};
+use binance_common::spot::model::params::market::KlinesParams;
+use binance_common::spot::model::response::market::KlinesResponse;
+
+binance_core::endpoints! {
+    crate::synchronous::market::MarketApi, crate::asynchronous::market::MarketApi;
+
+    pub fn ping() -> EmptyResponse = Ping {
+        GET Market::Ping, weight: 1, security: None
+    }
+
+    pub fn get_server_time() -> ServerTimeResponse = ServerTime {
+        GET Market::ServerTime, weight: 1, security: None
+    }
+
+    pub fn get_exchange_info(ExchangeInformationParams<'p>)
+        -> ExchangeInformationResponse = ExchangeInfo {
+        GET Market::ExchangeInfo, weight: 1, security: None
+    }
+
+    pub fn get_depth(DepthParams<'p>) -> DepthResponse = Depth {
+        GET Market::Depth, weight: 5, security: None
+    }
+
+    pub fn get_trades(TradesParams<'p>) -> Vec<TradesResponse> = Trades {
+        GET Market::Trades, weight: 5, security: None
+    }
+
+    pub fn get_historical_trades(HistoricalTradesParams<'p>)
+        -> Vec<HistoricalTradesResponse> = HistoricalTrades {
+        GET Market::HistoricalTrades, weight: 20, security: ApiKey
+    }
+
+    pub fn get_klines(KlinesParams<'p>) -> Vec<KlinesResponse> = Klines {
+        GET Market::Klines, weight: 5, security: None
+    }
+
+    pub fn get_mark_price(Symbol<'p>) -> MarkPriceResponse = MarkPrice {
+        GET Market::MarkPrice, weight: 1, security: None
+    }
+
+    pub fn get_mark_price_list() -> Vec<MarkPriceResponse> = MarkPriceList {
+        GET Market::MarkPrice, weight: 10, security: None
+    }
+
+    pub fn get_funding_rate_history(FundingRateHistoryParams<'p>)
+        -> Vec<FundingRateHistoryResponse> = FundingRateHistory {
+        GET Market::FundingRateHistory, weight: 1, security: None
+    }
+
+    pub fn get_ticker24h(Symbol<'p>) -> Ticker24hResponse = Ticker24h {
+        GET Market::Ticker24h, weight: 1, security: None
+    }
+
+    pub fn get_ticker24h_list() -> Vec<Ticker24hResponse> = Ticker24hList {
+        GET Market::Ticker24h, weight: 40, security: None
+    }
+
+    pub fn get_ticker_price(Symbol<'p>) -> TickerPriceResponse = TickerPrice {
+        GET Market::TickerPrice, weight: 1, security: None
+    }
+
+    pub fn get_ticker_price_list() -> Vec<TickerPriceResponse> = TickerPriceList {
+        GET Market::TickerPrice, weight: 2, security: None
+    }
+
+    pub fn get_ticker_price_v2(Symbol<'p>) -> TickerPriceResponse = TickerPriceV2 {
+        GET Market::TickerPriceV2, weight: 1, security: None
+    }
+
+    pub fn get_ticker_price_v2_list() -> Vec<TickerPriceResponse> = TickerPriceV2List {
+        GET Market::TickerPriceV2, weight: 2, security: None
+    }
+
+    pub fn get_book_ticker(Symbol<'p>) -> BookTickerResponse = BookTicker {
+        GET Market::BookTicker, weight: 2, security: None
+    }
+
+    pub fn get_book_ticker_list() -> Vec<BookTickerResponse> = BookTickerList {
+        GET Market::BookTicker, weight: 5, security: None
+    }
+
+    pub fn get_delivery_price_list(Pair<'p>) -> Vec<DeliveryPriceResponse> = DeliveryPrice {
+        GET Market::DeliveryPrice, weight: 1, security: None
+    }
+
+    pub fn get_open_interest(Symbol<'p>) -> OpenInterestResponse = OpenInterest {
+        GET Market::OpenInterest, weight: 1, security: None
+    }
+
+    pub fn get_open_interest_history_list(OpenInterestHistoryParams<'p>)
+        -> Vec<OpenInterestHistoryResponse> = OpenInterestHistory {
+        GET Market::OpenInterestHistory, weight: 1, security: None
+    }
+
+    pub fn get_top_long_position_ratio_list(TopLongShortPositionRatioParams<'p>)
+        -> Vec<TopLongShortPositionRatioResponse> = TopLongShortPositionRatio {
+        GET Market::TopLongShortPositionRatio, weight: 1, security: None
+    }
+
+    pub fn get_top_long_account_ratio_list(TopLongShortAccountRatioParams<'p>)
+        -> Vec<TopLongShortAccountRatioResponse> = TopLongShortAccountRatio {
+        GET Market::TopLongShortAccountRatio, weight: 1, security: None
+    }
+
+    pub fn get_global_long_account_ratio_list(GlobalLongShortAccountRatioParams<'p>)
+        -> Vec<GlobalLongShortAccountRatioResponse> = GlobalLongShortAccountRatio {
+        GET Market::GlobalLongShortAccountRatio, weight: 1, security: None
+    }
+
+    pub fn get_taker_buy_sell_volume_list(TakerBuySellVolumeParams<'p>)
+        -> Vec<TakerBuySellVolumeResponse> = TakerBuySellVolume {
+        GET Market::TakerBuySellVolume, weight: 1, security: None
+    }
+
+    pub fn get_basis_list(BasisParams<'p>) -> Vec<BasisResponse> = Basis {
+        GET Market::Basis, weight: 1, security: None
+    }
+}
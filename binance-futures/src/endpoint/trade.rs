@@ -0,0 +1,66 @@
+use binance_common::futures::endpoint::route::Trade;
+use binance_common::futures::model::params::trade::{
+    AutoCancelAllOpenOrdersParams, BatchOrdersParams, CancelAllOrdersParams, CancelOrderParams,
+    GetOpenOrderParams, GetOrderParams, NewOrderParams, OpenOrdersParams, PositionRiskV3Params,
+    SetLeverageParams, UserTradesParams,
+};
+use binance_common::futures::model::response::trade::{
+    AutoCancelAllOpenOrdersResponse, BatchOrderResponse, CancelAllOrdersResponse, GetOrderResponse,
+    OrderResponse, PositionRiskV3Response, SetLeverageResponse, TestOrderResponse,
+    UserTradesResponse,
+};
+
+binance_core::endpoints! {
+    crate::synchronous::trade::TradeApi, crate::asynchronous::trade::TradeApi;
+
+    pub fn send_new_test_order(NewOrderParams<'p>) -> TestOrderResponse = TestNewOrder {
+        POST Trade::TestNewOrder, weight: 0, security: Signed
+    }
+
+    pub fn send_new_order(NewOrderParams<'p>) -> OrderResponse = NewOrder {
+        POST Trade::NewOrder, weight: 0, security: Signed
+    }
+
+    pub fn send_batch_orders(BatchOrdersParams) -> Vec<BatchOrderResponse> = BatchOrders {
+        POST Trade::MultipleOrders, weight: 5, security: Signed, encoding: Form
+    }
+
+    pub fn send_set_leverage(SetLeverageParams<'p>) -> SetLeverageResponse = SetLeverage {
+        POST Trade::SetLeverage, weight: 1, security: Signed
+    }
+
+    pub fn send_cancel_order(CancelOrderParams<'p>) -> OrderResponse = CancelOrder {
+        DELETE Trade::CancelOrder, weight: 1, security: Signed
+    }
+
+    pub fn send_cancel_all_orders(CancelAllOrdersParams<'p>)
+        -> CancelAllOrdersResponse = CancelAllOrders {
+        DELETE Trade::CancelAllOrders, weight: 1, security: Signed
+    }
+
+    pub fn send_auto_cancel_all_open_orders(AutoCancelAllOpenOrdersParams<'p>)
+        -> AutoCancelAllOpenOrdersResponse = AutoCancelAllOpenOrders {
+        POST Trade::AutoCancelAllOpenOrders, weight: 10, security: Signed
+    }
+
+    pub fn get_order(GetOrderParams<'p>) -> GetOrderResponse = GetOrder {
+        GET Trade::GetOrder, weight: 1, security: Signed
+    }
+
+    pub fn get_open_order(GetOpenOrderParams<'p>) -> GetOrderResponse = GetOpenOrder {
+        GET Trade::GetOpenOrder, weight: 1, security: Signed
+    }
+
+    pub fn get_all_open_orders(OpenOrdersParams<'p>) -> Vec<GetOrderResponse> = OpenOrders {
+        GET Trade::OpenOrders, weight: 1, security: Signed
+    }
+
+    pub fn get_position_risk_v3(PositionRiskV3Params<'p>)
+        -> Vec<PositionRiskV3Response> = PositionRiskV3 {
+        GET Trade::PositionRiskV3, weight: 5, security: Signed
+    }
+
+    pub fn get_user_trades(UserTradesParams<'p>) -> Vec<UserTradesResponse> = UserTrades {
+        GET Trade::UserTrades, weight: 5, security: Signed
+    }
+}
@@ -1,81 +1,21 @@
-use binance_common::{
-    error::BinanceError,
-    futures::{
-        endpoint::route::Account,
-        model::{
-            params::account::{
-                CommissionRateParams, FuturesAccountParams, FuturesBalanceParams,
-                IncomeHistoryParams, PositionSideParams,
-            },
-            response::account::{
-                CommissionRateResponse, FuturesAccountResponse, FuturesBalanceResponse,
-                IncomeHistoryResponse, PositionSideResponse,
-            },
-        },
-    },
-};
+use binance_core::transport::synchronous::Transport;
 use binance_core::{client::synchronous::Client, signer::signature::Signature};
-use reqwest::Method;
 
-pub struct AccountApi<'a, S>
+/// Endpoints are declared in [`crate::endpoint::account`].
+pub struct AccountApi<'a, S, T = reqwest::blocking::Client>
 where
     S: Signature<'a>,
+    T: Transport,
 {
-    pub client: Client<'a, S>,
+    pub client: Client<'a, S, T>,
 }
 
-impl<'a, S> AccountApi<'a, S>
+impl<'a, S, T> AccountApi<'a, S, T>
 where
     S: Signature<'a>,
+    T: Transport,
 {
-    pub fn new(client: Client<'a, S>) -> Self {
+    pub fn new(client: Client<'a, S, T>) -> Self {
         AccountApi { client }
     }
-
-    pub fn get_futures_balance(
-        &self,
-        params: &FuturesBalanceParams,
-    ) -> Result<Vec<FuturesBalanceResponse>, BinanceError> {
-        self.client
-            .send(Account::FuturesBalance, params, Method::GET)
-    }
-
-    pub fn get_futures_balance_v3(
-        &self,
-        params: &FuturesBalanceParams,
-    ) -> Result<Vec<FuturesBalanceResponse>, BinanceError> {
-        self.client
-            .send(Account::FuturesBalanceV3, params, Method::GET)
-    }
-
-    pub fn get_position_side(
-        &self,
-        params: &PositionSideParams,
-    ) -> Result<PositionSideResponse, BinanceError> {
-        self.client.send(Account::PositionSide, params, Method::GET)
-    }
-
-    pub fn get_futures_account(
-        &self,
-        params: &FuturesAccountParams,
-    ) -> Result<FuturesAccountResponse, BinanceError> {
-        self.client
-            .send(Account::FuturesAccount, params, Method::GET)
-    }
-
-    pub fn get_comission_rate(
-        &self,
-        params: &CommissionRateParams,
-    ) -> Result<CommissionRateResponse, BinanceError> {
-        self.client
-            .send(Account::CommissionRate, params, Method::GET)
-    }
-
-    pub fn get_income_history(
-        &self,
-        params: &IncomeHistoryParams,
-    ) -> Result<Vec<IncomeHistoryResponse>, BinanceError> {
-        self.client
-            .send(Account::IncomeHistory, params, Method::GET)
-    }
 }
@@ -26,7 +26,10 @@ mod futures_websocket_api_integration_test {
 
         let mut websocket_handler = WebSocketMarketSupervisor::new(WebSocketType::MultiStream);
 
-        websocket_handler.start(stream.route).await.unwrap();
+        websocket_handler
+            .start(stream.route().unwrap())
+            .await
+            .unwrap();
 
         let buffer = fill_buffer(websocket_handler.watch().await.unwrap()).await;
 
@@ -0,0 +1,100 @@
+use binance_common::error::BinanceError;
+use binance_common::margin::{
+    endpoint::route::Account,
+    model::{
+        params::account::{
+            BorrowRepayParams, BorrowRepayRecordsParams, CrossAccountParams, InterestHistoryParams,
+            IsolatedAccountParams, MaxAmountParams,
+        },
+        response::account::{
+            BorrowRepayRecordsResponse, CrossAccountResponse, InterestHistoryResponse,
+            IsolatedAccountResponse, MaxBorrowableResponse, MaxTransferableResponse,
+            TransactionResponse,
+        },
+    },
+};
+use reqwest::Method;
+
+use binance_core::transport::asynchronous::Transport;
+use binance_core::{client::asynchronous::Client, signer::signature::Signature};
+
+pub struct AccountApi<'a, S, T = reqwest::Client>
+where
+    S: Signature<'a>,
+    T: Transport,
+{
+    client: Client<'a, S, T>,
+}
+
+impl<'a, S, T> AccountApi<'a, S, T>
+where
+    S: Signature<'a>,
+    T: Transport,
+{
+    pub fn new(client: Client<'a, S, T>) -> Self {
+        AccountApi { client }
+    }
+
+    pub async fn borrow_repay(
+        &self,
+        params: &BorrowRepayParams<'a>,
+    ) -> Result<TransactionResponse, BinanceError> {
+        self.client
+            .send(Account::BorrowRepay, params, Method::POST)
+            .await
+    }
+
+    pub async fn get_borrow_repay_records(
+        &self,
+        params: &BorrowRepayRecordsParams<'a>,
+    ) -> Result<BorrowRepayRecordsResponse, BinanceError> {
+        self.client
+            .send(Account::BorrowRepay, params, Method::GET)
+            .await
+    }
+
+    pub async fn get_cross_account(
+        &self,
+        params: &CrossAccountParams,
+    ) -> Result<CrossAccountResponse, BinanceError> {
+        self.client
+            .send(Account::CrossAccount, params, Method::GET)
+            .await
+    }
+
+    pub async fn get_isolated_account(
+        &self,
+        params: &IsolatedAccountParams,
+    ) -> Result<IsolatedAccountResponse, BinanceError> {
+        self.client
+            .send(Account::IsolatedAccount, params, Method::GET)
+            .await
+    }
+
+    pub async fn get_interest_history(
+        &self,
+        params: &InterestHistoryParams<'a>,
+    ) -> Result<InterestHistoryResponse, BinanceError> {
+        self.client
+            .send(Account::InterestHistory, params, Method::GET)
+            .await
+    }
+
+    pub async fn get_max_borrowable(
+        &self,
+        params: &MaxAmountParams<'a>,
+    ) -> Result<MaxBorrowableResponse, BinanceError> {
+        self.client
+            .send(Account::MaxBorrowable, params, Method::GET)
+            .await
+    }
+
+    pub async fn get_max_transferable(
+        &self,
+        params: &MaxAmountParams<'a>,
+    ) -> Result<MaxTransferableResponse, BinanceError> {
+        self.client
+            .send(Account::MaxTransferable, params, Method::GET)
+            .await
+    }
+}
@@ -0,0 +1,67 @@
+use binance_common::error::BinanceError;
+use binance_common::margin::{
+    endpoint::{host::WebSocketHost, route::UserStream},
+    model::{params::stream::ListenKeyParams, response::stream::ListenKeyResponse},
+};
+use binance_common::spot::model::response::general::EmptyResponse;
+use reqwest::Method;
+
+use binance_core::transport::asynchronous::Transport;
+use binance_core::{client::asynchronous::Client, signer::signature::Signature};
+
+/// Listen key lifecycle for the margin user data stream.
+///
+/// Cross margin streams use the account wide endpoint, isolated streams are opened per symbol.
+pub struct UserStreamApi<'a, S, T = reqwest::Client>
+where
+    S: Signature<'a>,
+    T: Transport,
+{
+    client: Client<'a, S, T>,
+}
+
+impl<'a, S, T> UserStreamApi<'a, S, T>
+where
+    S: Signature<'a>,
+    T: Transport,
+{
+    pub fn new(client: Client<'a, S, T>) -> Self {
+        UserStreamApi { client }
+    }
+
+    pub async fn start(
+        &self,
+        params: &ListenKeyParams<'a>,
+    ) -> Result<ListenKeyResponse, BinanceError> {
+        self.client
+            .send_keyed(route(params), params, Method::POST)
+            .await
+    }
+
+    /// Listen keys expire after 60 minutes unless kept alive.
+    pub async fn keepalive(
+        &self,
+        params: &ListenKeyParams<'a>,
+    ) -> Result<EmptyResponse, BinanceError> {
+        self.client
+            .send_keyed(route(params), params, Method::PUT)
+            .await
+    }
+
+    pub async fn close(&self, params: &ListenKeyParams<'a>) -> Result<EmptyResponse, BinanceError> {
+        self.client
+            .send_keyed(route(params), params, Method::DELETE)
+            .await
+    }
+
+    pub fn websocket_url(listen_key: &str) -> String {
+        format!("{}{}", WebSocketHost::UserData.as_ref(), listen_key)
+    }
+}
+
+fn route(params: &ListenKeyParams) -> UserStream {
+    match params.symbol {
+        Some(_) => UserStream::Isolated,
+        None => UserStream::Cross,
+    }
+}
@@ -0,0 +1,3 @@
+pub mod account;
+pub mod stream;
+pub mod trade;
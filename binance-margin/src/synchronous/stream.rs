@@ -0,0 +1,56 @@
+use binance_common::error::BinanceError;
+use binance_common::margin::{
+    endpoint::{host::WebSocketHost, route::UserStream},
+    model::{params::stream::ListenKeyParams, response::stream::ListenKeyResponse},
+};
+use binance_common::spot::model::response::general::EmptyResponse;
+use reqwest::Method;
+
+use binance_core::transport::synchronous::Transport;
+use binance_core::{client::synchronous::Client, signer::signature::Signature};
+
+/// Listen key lifecycle for the margin user data stream.
+///
+/// Cross margin streams use the account wide endpoint, isolated streams are opened per symbol.
+pub struct UserStreamApi<'a, S, T = reqwest::blocking::Client>
+where
+    S: Signature<'a>,
+    T: Transport,
+{
+    client: Client<'a, S, T>,
+}
+
+impl<'a, S, T> UserStreamApi<'a, S, T>
+where
+    S: Signature<'a>,
+    T: Transport,
+{
+    pub fn new(client: Client<'a, S, T>) -> Self {
+        UserStreamApi { client }
+    }
+
+    pub fn start(&self, params: &ListenKeyParams<'a>) -> Result<ListenKeyResponse, BinanceError> {
+        self.client.send_keyed(route(params), params, Method::POST)
+    }
+
+    /// Listen keys expire after 60 minutes unless kept alive.
+    pub fn keepalive(&self, params: &ListenKeyParams<'a>) -> Result<EmptyResponse, BinanceError> {
+        self.client.send_keyed(route(params), params, Method::PUT)
+    }
+
+    pub fn close(&self, params: &ListenKeyParams<'a>) -> Result<EmptyResponse, BinanceError> {
+        self.client
+            .send_keyed(route(params), params, Method::DELETE)
+    }
+
+    pub fn websocket_url(listen_key: &str) -> String {
+        format!("{}{}", WebSocketHost::UserData.as_ref(), listen_key)
+    }
+}
+
+fn route(params: &ListenKeyParams) -> UserStream {
+    match params.symbol {
+        Some(_) => UserStream::Isolated,
+        None => UserStream::Cross,
+    }
+}
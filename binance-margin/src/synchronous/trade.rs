@@ -0,0 +1,95 @@
+use binance_common::error::BinanceError;
+use binance_common::margin::{
+    endpoint::route::Trade,
+    model::{
+        params::trade::{
+            AllOrdersParams, CancelOrderParams, GetOrderParams, MyTradesParams, NewOcoParams,
+            NewOrderParams, OcoParams, OpenOrdersParams,
+        },
+        response::trade::{
+            CancelOrderResponse, CanceledResponse, MyTradesResponse, NewOrderResponse, OcoResponse,
+            OrderResponse,
+        },
+    },
+};
+use reqwest::Method;
+
+use binance_core::transport::synchronous::Transport;
+use binance_core::{client::synchronous::Client, signer::signature::Signature};
+
+pub struct TradeApi<'a, S, T = reqwest::blocking::Client>
+where
+    S: Signature<'a>,
+    T: Transport,
+{
+    client: Client<'a, S, T>,
+}
+
+impl<'a, S, T> TradeApi<'a, S, T>
+where
+    S: Signature<'a>,
+    T: Transport,
+{
+    pub fn new(client: Client<'a, S, T>) -> Self {
+        TradeApi { client }
+    }
+
+    pub fn send_new_order(
+        &self,
+        params: &NewOrderParams<'a>,
+    ) -> Result<NewOrderResponse, BinanceError> {
+        self.client.send(Trade::NewOrder, params, Method::POST)
+    }
+
+    pub fn get_order(&self, params: &GetOrderParams<'a>) -> Result<OrderResponse, BinanceError> {
+        self.client.send(Trade::GetOrder, params, Method::GET)
+    }
+
+    pub fn send_cancel_order(
+        &self,
+        params: &CancelOrderParams<'a>,
+    ) -> Result<CancelOrderResponse, BinanceError> {
+        self.client.send(Trade::CancelOrder, params, Method::DELETE)
+    }
+
+    pub fn send_cancel_all_orders(
+        &self,
+        params: &OpenOrdersParams<'a>,
+    ) -> Result<Vec<CanceledResponse>, BinanceError> {
+        self.client
+            .send(Trade::CancelAllOrders, params, Method::DELETE)
+    }
+
+    pub fn get_open_orders(
+        &self,
+        params: &OpenOrdersParams<'a>,
+    ) -> Result<Vec<OrderResponse>, BinanceError> {
+        self.client.send(Trade::OpenOrders, params, Method::GET)
+    }
+
+    pub fn get_all_orders(
+        &self,
+        params: &AllOrdersParams<'a>,
+    ) -> Result<Vec<OrderResponse>, BinanceError> {
+        self.client.send(Trade::AllOrders, params, Method::GET)
+    }
+
+    pub fn send_new_oco(&self, params: &NewOcoParams<'a>) -> Result<OcoResponse, BinanceError> {
+        self.client.send(Trade::NewOco, params, Method::POST)
+    }
+
+    pub fn get_oco(&self, params: &OcoParams<'a>) -> Result<OcoResponse, BinanceError> {
+        self.client.send(Trade::GetOco, params, Method::GET)
+    }
+
+    pub fn send_cancel_oco(&self, params: &OcoParams<'a>) -> Result<OcoResponse, BinanceError> {
+        self.client.send(Trade::CancelOco, params, Method::DELETE)
+    }
+
+    pub fn get_my_trades(
+        &self,
+        params: &MyTradesParams<'a>,
+    ) -> Result<Vec<MyTradesResponse>, BinanceError> {
+        self.client.send(Trade::MyTrades, params, Method::GET)
+    }
+}
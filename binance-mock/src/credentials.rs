@@ -0,0 +1,48 @@
+use base64::Engine;
+use binance_common::error::BinanceError;
+use ed25519_dalek::{Signature, Verifier, VerifyingKey, pkcs8::DecodePublicKey};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+#[derive(Clone, Debug)]
+pub enum Credentials {
+    HmacSha256 { secret_key: String },
+    Ed25519 { verifying_key: VerifyingKey },
+}
+
+impl Credentials {
+    pub fn hmac_sha256(secret_key: impl Into<String>) -> Self {
+        Credentials::HmacSha256 {
+            secret_key: secret_key.into(),
+        }
+    }
+
+    pub fn ed25519(public_key: &str) -> Result<Self, BinanceError> {
+        Ok(Credentials::Ed25519 {
+            verifying_key: VerifyingKey::from_public_key_pem(public_key).map_err(|_| {
+                BinanceError::Unknown("Invalid public key pem for ed25519!".to_string())
+            })?,
+        })
+    }
+
+    pub fn verify(&self, payload: &str, signature: &str) -> bool {
+        match self {
+            Credentials::HmacSha256 { secret_key } => {
+                let Ok(mut hasher) = Hmac::<Sha256>::new_from_slice(secret_key.as_bytes()) else {
+                    return false;
+                };
+
+                hasher.update(payload.as_bytes());
+
+                hex::encode(hasher.finalize().into_bytes()).eq_ignore_ascii_case(signature)
+            }
+            Credentials::Ed25519 { verifying_key } => base64::engine::general_purpose::STANDARD
+                .decode(signature)
+                .ok()
+                .and_then(|bytes| Signature::from_slice(&bytes).ok())
+                .is_some_and(|signature| {
+                    verifying_key.verify(payload.as_bytes(), &signature).is_ok()
+                }),
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use hyper::StatusCode;
+
+use crate::request::MockResponse;
+
+#[derive(Clone, Debug)]
+pub enum Failure {
+    BadRequest { code: i16, msg: String },
+    Unauthorized { code: i16, msg: String },
+    IpBanned { until: u64 },
+    TooManyRequests { retry_after: u64 },
+    InternalServer,
+}
+
+impl Failure {
+    pub fn bad_request(code: i16, msg: impl Into<String>) -> Self {
+        Failure::BadRequest {
+            code,
+            msg: msg.into(),
+        }
+    }
+
+    pub fn invalid_symbol() -> Self {
+        Failure::bad_request(-1121, "Invalid symbol.")
+    }
+
+    pub fn mandatory_parameter(name: &str) -> Self {
+        Failure::bad_request(
+            -1102,
+            format!(
+                "Mandatory parameter '{}' was not sent, was empty/null, or malformed.",
+                name
+            ),
+        )
+    }
+
+    pub fn outside_recv_window() -> Self {
+        Failure::bad_request(
+            -1021,
+            "Timestamp for this request is outside of the recvWindow.",
+        )
+    }
+
+    pub fn invalid_signature() -> Self {
+        Failure::bad_request(-1022, "Signature for this request is not valid.")
+    }
+
+    pub fn api_key_format() -> Self {
+        Failure::Unauthorized {
+            code: -2014,
+            msg: "API-key format invalid.".to_string(),
+        }
+    }
+
+    pub fn rejected_api_key() -> Self {
+        Failure::Unauthorized {
+            code: -2015,
+            msg: "Invalid API-key, IP, or permissions for action.".to_string(),
+        }
+    }
+
+    pub fn to_response(&self) -> MockResponse {
+        match self {
+            Failure::BadRequest { code, msg } => {
+                MockResponse::api_error(StatusCode::BAD_REQUEST, *code, msg)
+            }
+            Failure::Unauthorized { code, msg } => {
+                MockResponse::api_error(StatusCode::UNAUTHORIZED, *code, msg)
+            }
+            Failure::IpBanned { until } => MockResponse::api_error(
+                StatusCode::IM_A_TEAPOT,
+                -1003,
+                &format!(
+                    "Way too much request weight used; IP banned until {}. Please use WebSocket Streams for live updates to avoid bans.",
+                    until
+                ),
+            )
+            .header("Retry-After", until.to_string()),
+            Failure::TooManyRequests { retry_after } => MockResponse::api_error(
+                StatusCode::TOO_MANY_REQUESTS,
+                -1003,
+                "Too much request weight used; current limit is 6000 request weight per 1 MINUTE. Please use WebSocket Streams for live updates to avoid polling the API.",
+            )
+            .header("Retry-After", retry_after.to_string()),
+            Failure::InternalServer => MockResponse::api_error(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                -1000,
+                "An unknown error occurred while processing the request.",
+            ),
+        }
+    }
+}
@@ -0,0 +1,217 @@
+pub mod futures;
+pub mod margin;
+pub mod portfolio_margin;
+pub mod spot;
+pub mod sub_account;
+pub mod wallet;
+
+use std::sync::Arc;
+
+use hyper::Method;
+use serde_json::Value;
+
+use crate::{
+    failure::Failure,
+    request::{MockRequest, MockResponse},
+};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Security {
+    None,
+    ApiKey,
+    Signed,
+}
+
+pub type Handler = Arc<dyn Fn(&MockRequest) -> MockResponse + Send + Sync>;
+
+#[derive(Clone)]
+pub enum Responder {
+    Json(String),
+    BySymbol(String),
+    ByParam {
+        name: &'static str,
+        variants: Vec<(&'static str, String)>,
+        default: String,
+    },
+    Custom(Handler),
+}
+
+impl Responder {
+    pub fn json(body: impl Into<String>) -> Self {
+        Responder::Json(body.into())
+    }
+
+    pub fn by_symbol(body: impl Into<String>) -> Self {
+        Responder::BySymbol(body.into())
+    }
+
+    pub fn by_param(
+        name: &'static str,
+        variants: Vec<(&'static str, &'static str)>,
+        default: impl Into<String>,
+    ) -> Self {
+        Responder::ByParam {
+            name,
+            variants: variants
+                .into_iter()
+                .map(|(value, body)| (value, body.to_string()))
+                .collect(),
+            default: default.into(),
+        }
+    }
+
+    pub fn custom(handler: impl Fn(&MockRequest) -> MockResponse + Send + Sync + 'static) -> Self {
+        Responder::Custom(Arc::new(handler))
+    }
+
+    pub fn respond(&self, request: &MockRequest) -> MockResponse {
+        match self {
+            Responder::Json(body) => MockResponse::ok(limit(body, request)),
+            Responder::BySymbol(body) => by_symbol(body, request),
+            Responder::ByParam {
+                name,
+                variants,
+                default,
+            } => {
+                let body = request
+                    .param(name)
+                    .and_then(|value| {
+                        variants
+                            .iter()
+                            .find(|(variant, _)| variant.eq_ignore_ascii_case(value))
+                    })
+                    .map(|(_, body)| body)
+                    .unwrap_or(default);
+
+                by_symbol(body, request)
+            }
+            Responder::Custom(handler) => handler(request),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Fixture {
+    pub method: Method,
+    pub path: String,
+    pub security: Security,
+    pub responder: Responder,
+}
+
+impl Fixture {
+    pub fn new(
+        method: Method,
+        route: impl AsRef<str>,
+        security: Security,
+        responder: Responder,
+    ) -> Self {
+        Fixture {
+            method,
+            path: path(route.as_ref()).to_string(),
+            security,
+            responder,
+        }
+    }
+
+    pub fn matches(&self, method: &Method, path: &str) -> bool {
+        self.method == *method && self.path == path
+    }
+}
+
+/// Strips the query string part that the route enums carry (`?` or `?symbol=`).
+pub fn path(route: &str) -> &str {
+    route.split('?').next().unwrap_or(route)
+}
+
+fn limit(body: &str, request: &MockRequest) -> String {
+    let limit = request
+        .param("limit")
+        .and_then(|limit| limit.parse::<usize>().ok());
+
+    match (limit, serde_json::from_str::<Value>(body)) {
+        (Some(limit), Ok(Value::Array(mut values))) if values.len() > limit => {
+            values.truncate(limit);
+            Value::Array(values).to_string()
+        }
+        _ => body.to_string(),
+    }
+}
+
+/// Narrows list fixtures the way Binance does: `symbol` selects a single object,
+/// `symbols` filters the list and no parameter returns every symbol. Exchange
+/// information keeps its envelope and only narrows the nested `symbols` list.
+fn by_symbol(body: &str, request: &MockRequest) -> MockResponse {
+    match serde_json::from_str::<Value>(body) {
+        Ok(Value::Array(values)) => match select(values, request) {
+            Ok(Selection::One(value)) => MockResponse::ok(value.to_string()),
+            Ok(Selection::Many(values)) => MockResponse::ok(Value::Array(values).to_string()),
+            Err(failure) => failure.to_response(),
+        },
+        Ok(Value::Object(mut object)) if object.get("symbols").is_some_and(Value::is_array) => {
+            let Some(Value::Array(values)) = object.remove("symbols") else {
+                return MockResponse::ok(body);
+            };
+
+            match select(values, request) {
+                Ok(Selection::One(value)) => {
+                    object.insert("symbols".to_string(), Value::Array(vec![value]));
+                }
+                Ok(Selection::Many(values)) => {
+                    object.insert("symbols".to_string(), Value::Array(values));
+                }
+                Err(failure) => return failure.to_response(),
+            }
+
+            MockResponse::ok(Value::Object(object).to_string())
+        }
+        _ => MockResponse::ok(limit(body, request)),
+    }
+}
+
+enum Selection {
+    One(Value),
+    Many(Vec<Value>),
+}
+
+fn select(values: Vec<Value>, request: &MockRequest) -> Result<Selection, Failure> {
+    let has_symbol = |value: &Value, symbol: &str| {
+        value
+            .get("symbol")
+            .and_then(Value::as_str)
+            .is_some_and(|candidate| candidate.eq_ignore_ascii_case(symbol))
+    };
+
+    if let Some(symbol) = request.param("symbol") {
+        return values
+            .into_iter()
+            .find(|value| has_symbol(value, symbol))
+            .map(Selection::One)
+            .ok_or_else(Failure::invalid_symbol);
+    }
+
+    if let Some(symbols) = request.param("symbols") {
+        let symbols: Vec<String> = serde_json::from_str(symbols).unwrap_or_else(|_| {
+            symbols
+                .trim_matches(|c| c == '[' || c == ']')
+                .split(',')
+                .map(|symbol| symbol.trim_matches('"').to_string())
+                .collect()
+        });
+
+        if symbols
+            .iter()
+            .any(|symbol| !values.iter().any(|value| has_symbol(value, symbol)))
+        {
+            return Err(Failure::invalid_symbol());
+        }
+
+        return Ok(Selection::Many(
+            values
+                .into_iter()
+                .filter(|value| symbols.iter().any(|symbol| has_symbol(value, symbol)))
+                .collect(),
+        ));
+    }
+
+    Ok(Selection::Many(values))
+}
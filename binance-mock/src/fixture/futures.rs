@@ -0,0 +1,326 @@
+use binance_common::futures::endpoint::route::{Account, Market, Trade};
+use hyper::Method;
+
+use crate::fixture::{Fixture, Responder, Security};
+
+pub fn fixtures() -> Vec<Fixture> {
+    let mut fixtures = market();
+
+    fixtures.extend(trade());
+    fixtures.extend(account());
+
+    fixtures
+}
+
+pub fn market() -> Vec<Fixture> {
+    vec![
+        Fixture::new(
+            Method::GET,
+            Market::Ping,
+            Security::None,
+            Responder::json(include_str!("../../fixtures/futures/market/ping.json")),
+        ),
+        Fixture::new(
+            Method::GET,
+            Market::ServerTime,
+            Security::None,
+            Responder::json(include_str!(
+                "../../fixtures/futures/market/server_time.json"
+            )),
+        ),
+        Fixture::new(
+            Method::GET,
+            Market::ExchangeInfo,
+            Security::None,
+            Responder::by_symbol(include_str!(
+                "../../fixtures/futures/market/exchange_info.json"
+            )),
+        ),
+        Fixture::new(
+            Method::GET,
+            Market::Depth,
+            Security::None,
+            Responder::json(include_str!("../../fixtures/futures/market/depth.json")),
+        ),
+        Fixture::new(
+            Method::GET,
+            Market::Trades,
+            Security::None,
+            Responder::json(include_str!("../../fixtures/futures/market/trades.json")),
+        ),
+        Fixture::new(
+            Method::GET,
+            Market::HistoricalTrades,
+            Security::ApiKey,
+            Responder::json(include_str!(
+                "../../fixtures/futures/market/historical_trades.json"
+            )),
+        ),
+        Fixture::new(
+            Method::GET,
+            Market::AggregateTrades,
+            Security::None,
+            Responder::json(include_str!(
+                "../../fixtures/futures/market/agg_trades.json"
+            )),
+        ),
+        Fixture::new(
+            Method::GET,
+            Market::Klines,
+            Security::None,
+            Responder::json(include_str!("../../fixtures/futures/market/klines.json")),
+        ),
+        Fixture::new(
+            Method::GET,
+            Market::MarkPrice,
+            Security::None,
+            Responder::by_symbol(include_str!(
+                "../../fixtures/futures/market/mark_price.json"
+            )),
+        ),
+        Fixture::new(
+            Method::GET,
+            Market::FundingRateHistory,
+            Security::None,
+            Responder::json(include_str!(
+                "../../fixtures/futures/market/funding_rate_history.json"
+            )),
+        ),
+        Fixture::new(
+            Method::GET,
+            Market::Ticker24h,
+            Security::None,
+            Responder::by_symbol(include_str!(
+                "../../fixtures/futures/market/ticker_24h.json"
+            )),
+        ),
+        Fixture::new(
+            Method::GET,
+            Market::TickerPrice,
+            Security::None,
+            Responder::by_symbol(include_str!(
+                "../../fixtures/futures/market/ticker_price.json"
+            )),
+        ),
+        Fixture::new(
+            Method::GET,
+            Market::TickerPriceV2,
+            Security::None,
+            Responder::by_symbol(include_str!(
+                "../../fixtures/futures/market/ticker_price.json"
+            )),
+        ),
+        Fixture::new(
+            Method::GET,
+            Market::BookTicker,
+            Security::None,
+            Responder::by_symbol(include_str!(
+                "../../fixtures/futures/market/book_ticker.json"
+            )),
+        ),
+        Fixture::new(
+            Method::GET,
+            Market::DeliveryPrice,
+            Security::None,
+            Responder::json(include_str!(
+                "../../fixtures/futures/market/delivery_price.json"
+            )),
+        ),
+        Fixture::new(
+            Method::GET,
+            Market::OpenInterest,
+            Security::None,
+            Responder::by_symbol(include_str!(
+                "../../fixtures/futures/market/open_interest.json"
+            )),
+        ),
+        Fixture::new(
+            Method::GET,
+            Market::OpenInterestHistory,
+            Security::None,
+            Responder::json(include_str!(
+                "../../fixtures/futures/market/open_interest_history.json"
+            )),
+        ),
+        Fixture::new(
+            Method::GET,
+            Market::TopLongShortPositionRatio,
+            Security::None,
+            Responder::json(include_str!(
+                "../../fixtures/futures/market/top_long_short_position_ratio.json"
+            )),
+        ),
+        Fixture::new(
+            Method::GET,
+            Market::TopLongShortAccountRatio,
+            Security::None,
+            Responder::json(include_str!(
+                "../../fixtures/futures/market/top_long_short_account_ratio.json"
+            )),
+        ),
+        Fixture::new(
+            Method::GET,
+            Market::GlobalLongShortAccountRatio,
+            Security::None,
+            Responder::json(include_str!(
+                "../../fixtures/futures/market/global_long_short_account_ratio.json"
+            )),
+        ),
+        Fixture::new(
+            Method::GET,
+            Market::TakerBuySellVolume,
+            Security::None,
+            Responder::json(include_str!(
+                "../../fixtures/futures/market/taker_buy_sell_volume.json"
+            )),
+        ),
+        Fixture::new(
+            Method::GET,
+            Market::Basis,
+            Security::None,
+            Responder::json(include_str!("../../fixtures/futures/market/basis.json")),
+        ),
+    ]
+}
+
+pub fn trade() -> Vec<Fixture> {
+    vec![
+        Fixture::new(
+            Method::POST,
+            Trade::NewOrder,
+            Security::Signed,
+            Responder::json(include_str!("../../fixtures/futures/trade/new_order.json")),
+        ),
+        Fixture::new(
+            Method::POST,
+            Trade::TestNewOrder,
+            Security::Signed,
+            Responder::json(include_str!("../../fixtures/futures/trade/test_order.json")),
+        ),
+        Fixture::new(
+            Method::POST,
+            Trade::MultipleOrders,
+            Security::Signed,
+            Responder::json(include_str!(
+                "../../fixtures/futures/trade/batch_orders.json"
+            )),
+        ),
+        Fixture::new(
+            Method::POST,
+            Trade::SetLeverage,
+            Security::Signed,
+            Responder::json(include_str!(
+                "../../fixtures/futures/trade/set_leverage.json"
+            )),
+        ),
+        Fixture::new(
+            Method::DELETE,
+            Trade::CancelOrder,
+            Security::Signed,
+            Responder::json(include_str!(
+                "../../fixtures/futures/trade/cancel_order.json"
+            )),
+        ),
+        Fixture::new(
+            Method::DELETE,
+            Trade::CancelAllOrders,
+            Security::Signed,
+            Responder::json(include_str!(
+                "../../fixtures/futures/trade/cancel_all_orders.json"
+            )),
+        ),
+        Fixture::new(
+            Method::POST,
+            Trade::AutoCancelAllOpenOrders,
+            Security::Signed,
+            Responder::json(include_str!(
+                "../../fixtures/futures/trade/auto_cancel_all_open_orders.json"
+            )),
+        ),
+        Fixture::new(
+            Method::GET,
+            Trade::GetOrder,
+            Security::Signed,
+            Responder::json(include_str!("../../fixtures/futures/trade/get_order.json")),
+        ),
+        Fixture::new(
+            Method::GET,
+            Trade::GetOpenOrder,
+            Security::Signed,
+            Responder::json(include_str!("../../fixtures/futures/trade/open_order.json")),
+        ),
+        Fixture::new(
+            Method::GET,
+            Trade::OpenOrders,
+            Security::Signed,
+            Responder::json(include_str!(
+                "../../fixtures/futures/trade/open_orders.json"
+            )),
+        ),
+        Fixture::new(
+            Method::GET,
+            Trade::PositionRiskV3,
+            Security::Signed,
+            Responder::json(include_str!(
+                "../../fixtures/futures/trade/position_risk_v3.json"
+            )),
+        ),
+        Fixture::new(
+            Method::GET,
+            Trade::UserTrades,
+            Security::Signed,
+            Responder::json(include_str!(
+                "../../fixtures/futures/trade/user_trades.json"
+            )),
+        ),
+    ]
+}
+
+pub fn account() -> Vec<Fixture> {
+    vec![
+        Fixture::new(
+            Method::GET,
+            Account::FuturesBalance,
+            Security::Signed,
+            Responder::json(include_str!("../../fixtures/futures/account/balance.json")),
+        ),
+        Fixture::new(
+            Method::GET,
+            Account::FuturesBalanceV3,
+            Security::Signed,
+            Responder::json(include_str!(
+                "../../fixtures/futures/account/balance_v3.json"
+            )),
+        ),
+        Fixture::new(
+            Method::GET,
+            Account::PositionSide,
+            Security::Signed,
+            Responder::json(include_str!(
+                "../../fixtures/futures/account/position_side.json"
+            )),
+        ),
+        Fixture::new(
+            Method::GET,
+            Account::FuturesAccount,
+            Security::Signed,
+            Responder::json(include_str!("../../fixtures/futures/account/account.json")),
+        ),
+        Fixture::new(
+            Method::GET,
+            Account::CommissionRate,
+            Security::Signed,
+            Responder::by_symbol(include_str!(
+                "../../fixtures/futures/account/commission_rate.json"
+            )),
+        ),
+        Fixture::new(
+            Method::GET,
+            Account::IncomeHistory,
+            Security::Signed,
+            Responder::json(include_str!(
+                "../../fixtures/futures/account/income_history.json"
+            )),
+        ),
+    ]
+}
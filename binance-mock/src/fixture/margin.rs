@@ -0,0 +1,172 @@
+use binance_common::margin::endpoint::route::{Account, Trade, UserStream};
+use hyper::Method;
+
+use crate::fixture::{Fixture, Responder, Security};
+
+pub fn fixtures() -> Vec<Fixture> {
+    let mut fixtures = account();
+
+    fixtures.extend(trade());
+    fixtures.extend(stream());
+
+    fixtures
+}
+
+pub fn account() -> Vec<Fixture> {
+    vec![
+        Fixture::new(
+            Method::POST,
+            Account::BorrowRepay,
+            Security::Signed,
+            Responder::json(include_str!(
+                "../../fixtures/margin/account/borrow_repay.json"
+            )),
+        ),
+        Fixture::new(
+            Method::GET,
+            Account::BorrowRepay,
+            Security::Signed,
+            Responder::json(include_str!(
+                "../../fixtures/margin/account/borrow_repay_records.json"
+            )),
+        ),
+        Fixture::new(
+            Method::GET,
+            Account::CrossAccount,
+            Security::Signed,
+            Responder::json(include_str!(
+                "../../fixtures/margin/account/cross_account.json"
+            )),
+        ),
+        Fixture::new(
+            Method::GET,
+            Account::IsolatedAccount,
+            Security::Signed,
+            Responder::json(include_str!(
+                "../../fixtures/margin/account/isolated_account.json"
+            )),
+        ),
+        Fixture::new(
+            Method::GET,
+            Account::InterestHistory,
+            Security::Signed,
+            Responder::json(include_str!(
+                "../../fixtures/margin/account/interest_history.json"
+            )),
+        ),
+        Fixture::new(
+            Method::GET,
+            Account::MaxBorrowable,
+            Security::Signed,
+            Responder::json(include_str!(
+                "../../fixtures/margin/account/max_borrowable.json"
+            )),
+        ),
+        Fixture::new(
+            Method::GET,
+            Account::MaxTransferable,
+            Security::Signed,
+            Responder::json(include_str!(
+                "../../fixtures/margin/account/max_transferable.json"
+            )),
+        ),
+    ]
+}
+
+pub fn trade() -> Vec<Fixture> {
+    vec![
+        Fixture::new(
+            Method::POST,
+            Trade::NewOrder,
+            Security::Signed,
+            Responder::json(include_str!("../../fixtures/margin/trade/new_order.json")),
+        ),
+        Fixture::new(
+            Method::GET,
+            Trade::GetOrder,
+            Security::Signed,
+            Responder::json(include_str!("../../fixtures/margin/trade/order.json")),
+        ),
+        Fixture::new(
+            Method::DELETE,
+            Trade::CancelOrder,
+            Security::Signed,
+            Responder::json(include_str!(
+                "../../fixtures/margin/trade/cancel_order.json"
+            )),
+        ),
+        Fixture::new(
+            Method::DELETE,
+            Trade::CancelAllOrders,
+            Security::Signed,
+            Responder::json(include_str!(
+                "../../fixtures/margin/trade/cancel_all_orders.json"
+            )),
+        ),
+        Fixture::new(
+            Method::GET,
+            Trade::OpenOrders,
+            Security::Signed,
+            Responder::json(include_str!("../../fixtures/margin/trade/orders.json")),
+        ),
+        Fixture::new(
+            Method::GET,
+            Trade::AllOrders,
+            Security::Signed,
+            Responder::json(include_str!("../../fixtures/margin/trade/orders.json")),
+        ),
+        Fixture::new(
+            Method::POST,
+            Trade::NewOco,
+            Security::Signed,
+            Responder::json(include_str!("../../fixtures/margin/trade/new_oco.json")),
+        ),
+        Fixture::new(
+            Method::GET,
+            Trade::GetOco,
+            Security::Signed,
+            Responder::json(include_str!("../../fixtures/margin/trade/oco.json")),
+        ),
+        Fixture::new(
+            Method::DELETE,
+            Trade::CancelOco,
+            Security::Signed,
+            Responder::json(include_str!("../../fixtures/margin/trade/cancel_oco.json")),
+        ),
+        Fixture::new(
+            Method::GET,
+            Trade::MyTrades,
+            Security::Signed,
+            Responder::json(include_str!("../../fixtures/margin/trade/my_trades.json")),
+        ),
+    ]
+}
+
+pub fn stream() -> Vec<Fixture> {
+    let mut fixtures = Vec::new();
+
+    for route in [UserStream::Cross, UserStream::Isolated] {
+        fixtures.extend([
+            Fixture::new(
+                Method::POST,
+                route.as_ref(),
+                Security::ApiKey,
+                Responder::json(include_str!("../../fixtures/margin/stream/listen_key.json")),
+            ),
+            Fixture::new(
+                Method::PUT,
+                route.as_ref(),
+                Security::ApiKey,
+                Responder::json(include_str!("../../fixtures/margin/stream/empty.json")),
+            ),
+            Fixture::new(
+                Method::DELETE,
+                route.as_ref(),
+                Security::ApiKey,
+                Responder::json(include_str!("../../fixtures/margin/stream/empty.json")),
+            ),
+        ]);
+    }
+
+    fixtures
+}
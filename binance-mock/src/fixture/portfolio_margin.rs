@@ -0,0 +1,184 @@
+use binance_common::portfolio_margin::endpoint::route::{Account, Cm, Um, UserStream};
+use hyper::Method;
+
+use crate::fixture::{Fixture, Responder, Security};
+
+pub fn fixtures() -> Vec<Fixture> {
+    let mut fixtures = um();
+
+    fixtures.extend(cm());
+    fixtures.extend(account());
+    fixtures.extend(stream());
+
+    fixtures
+}
+
+pub fn um() -> Vec<Fixture> {
+    vec![
+        Fixture::new(
+            Method::POST,
+            Um::NewOrder,
+            Security::Signed,
+            Responder::json(include_str!(
+                "../../fixtures/portfolio_margin/um/order.json"
+            )),
+        ),
+        Fixture::new(
+            Method::GET,
+            Um::GetOrder,
+            Security::Signed,
+            Responder::json(include_str!(
+                "../../fixtures/portfolio_margin/um/order.json"
+            )),
+        ),
+        Fixture::new(
+            Method::DELETE,
+            Um::CancelOrder,
+            Security::Signed,
+            Responder::json(include_str!(
+                "../../fixtures/portfolio_margin/um/order.json"
+            )),
+        ),
+        Fixture::new(
+            Method::DELETE,
+            Um::CancelAllOrders,
+            Security::Signed,
+            Responder::json(include_str!(
+                "../../fixtures/portfolio_margin/um/cancel_all_orders.json"
+            )),
+        ),
+        Fixture::new(
+            Method::GET,
+            Um::OpenOrders,
+            Security::Signed,
+            Responder::json(include_str!(
+                "../../fixtures/portfolio_margin/um/open_orders.json"
+            )),
+        ),
+        Fixture::new(
+            Method::GET,
+            Um::PositionRisk,
+            Security::Signed,
+            Responder::json(include_str!(
+                "../../fixtures/portfolio_margin/um/position_risk.json"
+            )),
+        ),
+    ]
+}
+
+pub fn cm() -> Vec<Fixture> {
+    vec![
+        Fixture::new(
+            Method::POST,
+            Cm::NewOrder,
+            Security::Signed,
+            Responder::json(include_str!(
+                "../../fixtures/portfolio_margin/cm/order.json"
+            )),
+        ),
+        Fixture::new(
+            Method::GET,
+            Cm::GetOrder,
+            Security::Signed,
+            Responder::json(include_str!(
+                "../../fixtures/portfolio_margin/cm/order.json"
+            )),
+        ),
+        Fixture::new(
+            Method::DELETE,
+            Cm::CancelOrder,
+            Security::Signed,
+            Responder::json(include_str!(
+                "../../fixtures/portfolio_margin/cm/order.json"
+            )),
+        ),
+        Fixture::new(
+            Method::DELETE,
+            Cm::CancelAllOrders,
+            Security::Signed,
+            Responder::json(include_str!(
+                "../../fixtures/portfolio_margin/um/cancel_all_orders.json"
+            )),
+        ),
+        Fixture::new(
+            Method::GET,
+            Cm::OpenOrders,
+            Security::Signed,
+            Responder::json(include_str!(
+                "../../fixtures/portfolio_margin/cm/open_orders.json"
+            )),
+        ),
+        Fixture::new(
+            Method::GET,
+            Cm::PositionRisk,
+            Security::Signed,
+            Responder::json(include_str!(
+                "../../fixtures/portfolio_margin/cm/position_risk.json"
+            )),
+        ),
+    ]
+}
+
+pub fn account() -> Vec<Fixture> {
+    let success = include_str!("../../fixtures/portfolio_margin/account/success.json");
+
+    vec![
+        Fixture::new(
+            Method::GET,
+            Account::Balance,
+            Security::Signed,
+            Responder::json(include_str!(
+                "../../fixtures/portfolio_margin/account/balance.json"
+            )),
+        ),
+        Fixture::new(
+            Method::GET,
+            Account::Account,
+            Security::Signed,
+            Responder::json(include_str!(
+                "../../fixtures/portfolio_margin/account/account.json"
+            )),
+        ),
+        Fixture::new(
+            Method::POST,
+            Account::AutoCollection,
+            Security::Signed,
+            Responder::json(success),
+        ),
+        Fixture::new(
+            Method::POST,
+            Account::AssetCollection,
+            Security::Signed,
+            Responder::json(success),
+        ),
+        Fixture::new(
+            Method::POST,
+            Account::RepayFuturesNegativeBalance,
+            Security::Signed,
+            Responder::json(success),
+        ),
+    ]
+}
+
+pub fn stream() -> Vec<Fixture> {
+    vec![
+        Fixture::new(
+            Method::POST,
+            UserStream::ListenKey,
+            Security::ApiKey,
+            Responder::json(include_str!("../../fixtures/margin/stream/listen_key.json")),
+        ),
+        Fixture::new(
+            Method::PUT,
+            UserStream::ListenKey,
+            Security::ApiKey,
+            Responder::json(include_str!("../../fixtures/margin/stream/empty.json")),
+        ),
+        Fixture::new(
+            Method::DELETE,
+            UserStream::ListenKey,
+            Security::ApiKey,
+            Responder::json(include_str!("../../fixtures/margin/stream/empty.json")),
+        ),
+    ]
+}
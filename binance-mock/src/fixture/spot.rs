@@ -0,0 +1,226 @@
+use binance_common::spot::endpoint::route::{Account, General, Market, Trade};
+use hyper::Method;
+
+use crate::fixture::{Fixture, Responder, Security};
+
+pub fn fixtures() -> Vec<Fixture> {
+    let mut fixtures = general();
+
+    fixtures.extend(market());
+    fixtures.extend(trade());
+    fixtures.extend(account());
+
+    fixtures
+}
+
+pub fn general() -> Vec<Fixture> {
+    vec![
+        Fixture::new(
+            Method::GET,
+            General::Ping,
+            Security::None,
+            Responder::json(include_str!("../../fixtures/spot/general/ping.json")),
+        ),
+        Fixture::new(
+            Method::GET,
+            General::ServerTime,
+            Security::None,
+            Responder::json(include_str!("../../fixtures/spot/general/server_time.json")),
+        ),
+        Fixture::new(
+            Method::GET,
+            General::ExchangeInfo,
+            Security::None,
+            Responder::by_symbol(include_str!(
+                "../../fixtures/spot/general/exchange_info.json"
+            )),
+        ),
+    ]
+}
+
+pub fn market() -> Vec<Fixture> {
+    vec![
+        Fixture::new(
+            Method::GET,
+            Market::Depth,
+            Security::None,
+            Responder::json(include_str!("../../fixtures/spot/market/depth.json")),
+        ),
+        Fixture::new(
+            Method::GET,
+            Market::Trades,
+            Security::None,
+            Responder::json(include_str!("../../fixtures/spot/market/trades.json")),
+        ),
+        Fixture::new(
+            Method::GET,
+            Market::HistoricalTrades,
+            Security::ApiKey,
+            Responder::json(include_str!(
+                "../../fixtures/spot/market/historical_trades.json"
+            )),
+        ),
+        Fixture::new(
+            Method::GET,
+            Market::AggTrades,
+            Security::None,
+            Responder::json(include_str!("../../fixtures/spot/market/agg_trades.json")),
+        ),
+        Fixture::new(
+            Method::GET,
+            Market::Klines,
+            Security::None,
+            Responder::json(include_str!("../../fixtures/spot/market/klines.json")),
+        ),
+        Fixture::new(
+            Method::GET,
+            Market::UIKlines,
+            Security::None,
+            Responder::json(include_str!("../../fixtures/spot/market/ui_klines.json")),
+        ),
+        Fixture::new(
+            Method::GET,
+            Market::AvgPrice,
+            Security::None,
+            Responder::json(include_str!("../../fixtures/spot/market/avg_price.json")),
+        ),
+        Fixture::new(
+            Method::GET,
+            Market::Ticker24h,
+            Security::None,
+            Responder::by_param(
+                "type",
+                vec![(
+                    "MINI",
+                    include_str!("../../fixtures/spot/market/ticker_24h_mini.json"),
+                )],
+                include_str!("../../fixtures/spot/market/ticker_24h_full.json"),
+            ),
+        ),
+        Fixture::new(
+            Method::GET,
+            Market::TickerDay,
+            Security::None,
+            Responder::by_param(
+                "type",
+                vec![(
+                    "MINI",
+                    include_str!("../../fixtures/spot/market/ticker_day_mini.json"),
+                )],
+                include_str!("../../fixtures/spot/market/ticker_day_full.json"),
+            ),
+        ),
+        Fixture::new(
+            Method::GET,
+            Market::TickerPrice,
+            Security::None,
+            Responder::by_symbol(include_str!("../../fixtures/spot/market/ticker_price.json")),
+        ),
+        Fixture::new(
+            Method::GET,
+            Market::BookTicker,
+            Security::None,
+            Responder::by_symbol(include_str!("../../fixtures/spot/market/book_ticker.json")),
+        ),
+        Fixture::new(
+            Method::GET,
+            Market::RollingTicker,
+            Security::None,
+            Responder::by_param(
+                "type",
+                vec![(
+                    "MINI",
+                    include_str!("../../fixtures/spot/market/rolling_ticker_mini.json"),
+                )],
+                include_str!("../../fixtures/spot/market/rolling_ticker_full.json"),
+            ),
+        ),
+    ]
+}
+
+pub fn trade() -> Vec<Fixture> {
+    vec![
+        Fixture::new(
+            Method::POST,
+            Trade::NewOrder,
+            Security::Signed,
+            Responder::by_param(
+                "newOrderRespType",
+                vec![
+                    (
+                        "ACK",
+                        include_str!("../../fixtures/spot/trade/new_order_ack.json"),
+                    ),
+                    (
+                        "RESULT",
+                        include_str!("../../fixtures/spot/trade/new_order_result.json"),
+                    ),
+                ],
+                include_str!("../../fixtures/spot/trade/new_order_full.json"),
+            ),
+        ),
+        Fixture::new(
+            Method::POST,
+            Trade::TestOrder,
+            Security::Signed,
+            Responder::json(include_str!("../../fixtures/spot/trade/test_order.json")),
+        ),
+        Fixture::new(
+            Method::GET,
+            Trade::GetOrder,
+            Security::Signed,
+            Responder::json(include_str!("../../fixtures/spot/trade/get_order.json")),
+        ),
+        Fixture::new(
+            Method::DELETE,
+            Trade::CancelOrder,
+            Security::Signed,
+            Responder::json(include_str!("../../fixtures/spot/trade/cancel_order.json")),
+        ),
+        Fixture::new(
+            Method::DELETE,
+            Trade::CancelAllOrders,
+            Security::Signed,
+            Responder::json(include_str!(
+                "../../fixtures/spot/trade/cancel_open_orders.json"
+            )),
+        ),
+        Fixture::new(
+            Method::GET,
+            Trade::OpenOrders,
+            Security::Signed,
+            Responder::json(include_str!("../../fixtures/spot/trade/open_orders.json")),
+        ),
+        Fixture::new(
+            Method::GET,
+            Trade::AllOrders,
+            Security::Signed,
+            Responder::json(include_str!("../../fixtures/spot/trade/all_orders.json")),
+        ),
+    ]
+}
+
+pub fn account() -> Vec<Fixture> {
+    vec![
+        Fixture::new(
+            Method::GET,
+            Account::Info,
+            Security::Signed,
+            Responder::json(include_str!("../../fixtures/spot/account/info.json")),
+        ),
+        Fixture::new(
+            Method::GET,
+            Account::MyTrades,
+            Security::Signed,
+            Responder::json(include_str!("../../fixtures/spot/account/my_trades.json")),
+        ),
+        Fixture::new(
+            Method::GET,
+            Account::UnfilledOrderCount,
+            Security::Signed,
+            Responder::json(include_str!(
+                "../../fixtures/spot/account/unfilled_order_count.json"
+            )),
+        ),
+    ]
+}
@@ -0,0 +1,106 @@
+use binance_common::sub_account::endpoint::route::{Account, ApiKey, Transfer};
+use hyper::Method;
+
+use crate::fixture::{Fixture, Responder, Security};
+
+pub fn fixtures() -> Vec<Fixture> {
+    let mut fixtures = account();
+
+    fixtures.extend(transfer());
+    fixtures.extend(api_key());
+
+    fixtures
+}
+
+pub fn account() -> Vec<Fixture> {
+    vec![
+        Fixture::new(
+            Method::GET,
+            Account::List,
+            Security::Signed,
+            Responder::json(include_str!("../../fixtures/sub_account/account/list.json")),
+        ),
+        Fixture::new(
+            Method::POST,
+            Account::Create,
+            Security::Signed,
+            Responder::json(include_str!(
+                "../../fixtures/sub_account/account/create.json"
+            )),
+        ),
+        Fixture::new(
+            Method::GET,
+            Account::SpotAssets,
+            Security::Signed,
+            Responder::json(include_str!(
+                "../../fixtures/sub_account/account/spot_assets.json"
+            )),
+        ),
+        Fixture::new(
+            Method::GET,
+            Account::FuturesAccount,
+            Security::Signed,
+            Responder::json(include_str!(
+                "../../fixtures/sub_account/account/futures_account.json"
+            )),
+        ),
+        Fixture::new(
+            Method::POST,
+            Account::EnableFutures,
+            Security::Signed,
+            Responder::json(include_str!(
+                "../../fixtures/sub_account/account/enable_futures.json"
+            )),
+        ),
+    ]
+}
+
+pub fn transfer() -> Vec<Fixture> {
+    vec![
+        Fixture::new(
+            Method::POST,
+            Transfer::UniversalTransfer,
+            Security::Signed,
+            Responder::json(include_str!(
+                "../../fixtures/sub_account/transfer/universal_transfer.json"
+            )),
+        ),
+        Fixture::new(
+            Method::GET,
+            Transfer::UniversalTransferHistory,
+            Security::Signed,
+            Responder::json(include_str!(
+                "../../fixtures/sub_account/transfer/history.json"
+            )),
+        ),
+    ]
+}
+
+pub fn api_key() -> Vec<Fixture> {
+    vec![
+        Fixture::new(
+            Method::GET,
+            ApiKey::IpRestriction,
+            Security::Signed,
+            Responder::json(include_str!(
+                "../../fixtures/sub_account/api_key/ip_restriction.json"
+            )),
+        ),
+        Fixture::new(
+            Method::POST,
+            ApiKey::AddIpRestriction,
+            Security::Signed,
+            Responder::json(include_str!(
+                "../../fixtures/sub_account/api_key/ip_restriction.json"
+            )),
+        ),
+        Fixture::new(
+            Method::DELETE,
+            ApiKey::DeleteIpRestriction,
+            Security::Signed,
+            Responder::json(include_str!(
+                "../../fixtures/sub_account/api_key/ip_restriction.json"
+            )),
+        ),
+    ]
+}
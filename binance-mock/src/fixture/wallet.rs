@@ -0,0 +1,118 @@
+use binance_common::wallet::endpoint::route::{Account, Asset, Capital};
+use hyper::Method;
+
+use crate::fixture::{Fixture, Responder, Security};
+
+pub fn fixtures() -> Vec<Fixture> {
+    let mut fixtures = capital();
+
+    fixtures.extend(asset());
+    fixtures.extend(account());
+
+    fixtures
+}
+
+pub fn capital() -> Vec<Fixture> {
+    vec![
+        Fixture::new(
+            Method::GET,
+            Capital::CoinInfo,
+            Security::Signed,
+            Responder::json(include_str!("../../fixtures/wallet/capital/coin_info.json")),
+        ),
+        Fixture::new(
+            Method::GET,
+            Capital::DepositAddress,
+            Security::Signed,
+            Responder::json(include_str!(
+                "../../fixtures/wallet/capital/deposit_address.json"
+            )),
+        ),
+        Fixture::new(
+            Method::GET,
+            Capital::DepositHistory,
+            Security::Signed,
+            Responder::json(include_str!(
+                "../../fixtures/wallet/capital/deposit_history.json"
+            )),
+        ),
+        Fixture::new(
+            Method::POST,
+            Capital::Withdraw,
+            Security::Signed,
+            Responder::json(include_str!("../../fixtures/wallet/capital/withdraw.json")),
+        ),
+        Fixture::new(
+            Method::GET,
+            Capital::WithdrawHistory,
+            Security::Signed,
+            Responder::json(include_str!(
+                "../../fixtures/wallet/capital/withdraw_history.json"
+            )),
+        ),
+    ]
+}
+
+pub fn asset() -> Vec<Fixture> {
+    vec![
+        Fixture::new(
+            Method::POST,
+            Asset::UniversalTransfer,
+            Security::Signed,
+            Responder::json(include_str!("../../fixtures/wallet/asset/transfer.json")),
+        ),
+        Fixture::new(
+            Method::GET,
+            Asset::TransferHistory,
+            Security::Signed,
+            Responder::json(include_str!(
+                "../../fixtures/wallet/asset/transfer_history.json"
+            )),
+        ),
+        Fixture::new(
+            Method::POST,
+            Asset::DustConvertible,
+            Security::Signed,
+            Responder::json(include_str!(
+                "../../fixtures/wallet/asset/dust_convertible.json"
+            )),
+        ),
+        Fixture::new(
+            Method::POST,
+            Asset::Dust,
+            Security::Signed,
+            Responder::json(include_str!("../../fixtures/wallet/asset/dust.json")),
+        ),
+        Fixture::new(
+            Method::GET,
+            Asset::DustLog,
+            Security::Signed,
+            Responder::json(include_str!("../../fixtures/wallet/asset/dust_log.json")),
+        ),
+        Fixture::new(
+            Method::GET,
+            Asset::TradeFee,
+            Security::Signed,
+            Responder::json(include_str!("../../fixtures/wallet/asset/trade_fee.json")),
+        ),
+        Fixture::new(
+            Method::GET,
+            Asset::AssetDetail,
+            Security::Signed,
+            Responder::json(include_str!(
+                "../../fixtures/wallet/asset/asset_detail.json"
+            )),
+        ),
+    ]
+}
+
+pub fn account() -> Vec<Fixture> {
+    vec![Fixture::new(
+        Method::GET,
+        Account::ApiRestrictions,
+        Security::Signed,
+        Responder::json(include_str!(
+            "../../fixtures/wallet/account/api_restrictions.json"
+        )),
+    )]
+}
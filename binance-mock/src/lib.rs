@@ -0,0 +1,7 @@
+pub mod credentials;
+pub mod failure;
+pub mod fixture;
+pub mod request;
+pub mod server;
+pub mod transport;
+pub mod websocket;
@@ -0,0 +1,89 @@
+use hyper::{HeaderMap, Method, StatusCode, Uri, body::Bytes};
+
+#[derive(Clone, Debug)]
+pub struct MockRequest {
+    pub method: Method,
+    pub path: String,
+    pub query: String,
+    pub body: String,
+    pub api_key: Option<String>,
+    pub params: Vec<(String, String)>,
+}
+
+impl MockRequest {
+    pub fn new(method: Method, uri: &Uri, headers: &HeaderMap, body: &Bytes) -> Self {
+        let query = uri.query().unwrap_or_default().to_string();
+        let body = String::from_utf8_lossy(body).to_string();
+
+        let mut params: Vec<(String, String)> =
+            serde_urlencoded::from_str(&query).unwrap_or_default();
+        params
+            .extend(serde_urlencoded::from_str::<Vec<(String, String)>>(&body).unwrap_or_default());
+
+        MockRequest {
+            method,
+            path: uri.path().to_string(),
+            query,
+            body,
+            api_key: headers
+                .get("X-MBX-APIKEY")
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_string),
+            params,
+        }
+    }
+
+    pub fn param(&self, name: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(key, value)| key == name && !value.is_empty())
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Splits the `totalParams` string (query string followed by the request body) into the
+    /// signed payload and the decoded signature, the same way Binance validates it.
+    pub fn signature(&self) -> Option<(String, &str)> {
+        let total = format!("{}{}", self.query, self.body);
+        let index = total
+            .rmatch_indices("signature=")
+            .map(|(index, _)| index)
+            .find(|index| *index == 0 || total[..*index].ends_with('&'))?;
+
+        let payload = total[..index].strip_suffix('&').unwrap_or(&total[..index]);
+
+        Some((payload.to_string(), self.param("signature")?))
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct MockResponse {
+    pub status: StatusCode,
+    pub headers: Vec<(&'static str, String)>,
+    pub body: String,
+}
+
+impl MockResponse {
+    pub fn new(status: StatusCode, body: impl Into<String>) -> Self {
+        MockResponse {
+            status,
+            headers: Vec::new(),
+            body: body.into(),
+        }
+    }
+
+    pub fn ok(body: impl Into<String>) -> Self {
+        MockResponse::new(StatusCode::OK, body)
+    }
+
+    pub fn api_error(status: StatusCode, code: i16, msg: &str) -> Self {
+        MockResponse::new(
+            status,
+            serde_json::json!({ "code": code, "msg": msg }).to_string(),
+        )
+    }
+
+    pub fn header(mut self, name: &'static str, value: impl Into<String>) -> Self {
+        self.headers.push((name, value.into()));
+        self
+    }
+}
@@ -0,0 +1,378 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    convert::Infallible,
+    net::SocketAddr,
+    sync::{Arc, Mutex},
+    thread::JoinHandle,
+    time::Duration,
+};
+
+use binance_common::enums::WebSocketType;
+use chrono::Utc;
+use http_body_util::{BodyExt, Full};
+use hyper::{
+    Method, Request, Response,
+    body::{Bytes, Incoming},
+    server::conn::http1,
+    service::service_fn,
+};
+use hyper_util::rt::TokioIo;
+use tokio::{net::TcpListener, sync::watch};
+
+use crate::{
+    credentials::Credentials,
+    failure::Failure,
+    fixture::{self, Fixture, Responder, Security},
+    request::{MockRequest, MockResponse},
+    transport::MockTransport,
+    websocket,
+};
+
+pub struct MockServerBuilder {
+    fixtures: Vec<Fixture>,
+    credentials: HashMap<String, Credentials>,
+    recv_window: i64,
+    stream_interval: Duration,
+}
+
+impl Default for MockServerBuilder {
+    fn default() -> Self {
+        let mut fixtures = fixture::spot::fixtures();
+        fixtures.extend(fixture::futures::fixtures());
+        fixtures.extend(fixture::margin::fixtures());
+        fixtures.extend(fixture::portfolio_margin::fixtures());
+        fixtures.extend(fixture::sub_account::fixtures());
+        fixtures.extend(fixture::wallet::fixtures());
+
+        MockServerBuilder {
+            fixtures,
+            credentials: HashMap::new(),
+            recv_window: 5000,
+            stream_interval: Duration::from_millis(50),
+        }
+    }
+}
+
+impl MockServerBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn credentials(mut self, api_key: impl Into<String>, credentials: Credentials) -> Self {
+        self.credentials.insert(api_key.into(), credentials);
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: i64) -> Self {
+        self.recv_window = recv_window;
+        self
+    }
+
+    pub fn stream_interval(mut self, stream_interval: Duration) -> Self {
+        self.stream_interval = stream_interval;
+        self
+    }
+
+    /// Registers a fixture, replacing the built-in one for the same method and route.
+    pub fn fixture(mut self, fixture: Fixture) -> Self {
+        self.fixtures
+            .retain(|existing| !existing.matches(&fixture.method, &fixture.path));
+        self.fixtures.push(fixture);
+        self
+    }
+
+    pub fn respond_with(
+        self,
+        method: Method,
+        route: impl AsRef<str>,
+        security: Security,
+        handler: impl Fn(&MockRequest) -> MockResponse + Send + Sync + 'static,
+    ) -> Self {
+        self.fixture(Fixture::new(
+            method,
+            route,
+            security,
+            Responder::custom(handler),
+        ))
+    }
+
+    pub fn start(self) -> std::io::Result<MockServer> {
+        let http = std::net::TcpListener::bind("127.0.0.1:0")?;
+        let websocket = std::net::TcpListener::bind("127.0.0.1:0")?;
+
+        http.set_nonblocking(true)?;
+        websocket.set_nonblocking(true)?;
+
+        let http_address = http.local_addr()?;
+        let websocket_address = websocket.local_addr()?;
+
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(2)
+            .enable_all()
+            .build()?;
+
+        let (http, websocket) = {
+            let _guard = runtime.enter();
+            (
+                TcpListener::from_std(http)?,
+                TcpListener::from_std(websocket)?,
+            )
+        };
+
+        let state = Arc::new(State {
+            fixtures: self.fixtures,
+            credentials: self.credentials,
+            recv_window: self.recv_window,
+            stream_interval: self.stream_interval,
+            failures: Mutex::new(HashMap::new()),
+            requests: Mutex::new(Vec::new()),
+        });
+
+        let (shutdown, rx_shutdown) = watch::channel(false);
+
+        let handle = std::thread::spawn({
+            let state = state.clone();
+
+            move || {
+                runtime.block_on(async move {
+                    tokio::join!(
+                        serve(state.clone(), http, rx_shutdown.clone()),
+                        websocket::serve(state, websocket, rx_shutdown)
+                    );
+                });
+            }
+        });
+
+        Ok(MockServer {
+            url: format!("http://{}", http_address),
+            websocket_address,
+            state,
+            shutdown,
+            handle: Some(handle),
+        })
+    }
+}
+
+pub struct MockServer {
+    url: String,
+    websocket_address: SocketAddr,
+    state: Arc<State>,
+    shutdown: watch::Sender<bool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl MockServer {
+    pub fn builder() -> MockServerBuilder {
+        MockServerBuilder::new()
+    }
+
+    pub fn start() -> std::io::Result<MockServer> {
+        MockServerBuilder::new().start()
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Returns the host to pass to `WebSocketParams`, mirroring the single and
+    /// combined stream hosts of Binance.
+    pub fn websocket_host(&self, websocket_type: &WebSocketType) -> String {
+        match websocket_type {
+            WebSocketType::SingleStream => format!("ws://{}/market/", self.websocket_address),
+            WebSocketType::MultiStream => {
+                format!("ws://{}/market/stream?streams=", self.websocket_address)
+            }
+        }
+    }
+
+    /// Makes the next request to `route` fail. Failures queue up, so calling
+    /// this twice fails the next two requests.
+    pub fn fail(&self, method: Method, route: impl AsRef<str>, failure: Failure) {
+        self.state
+            .failures
+            .lock()
+            .unwrap()
+            .entry((method, fixture::path(route.as_ref()).to_string()))
+            .or_default()
+            .push_back(failure);
+    }
+
+    /// A transport answering from this server without going through a socket,
+    /// for `Client::with_transport`. Requests still need the `url` host.
+    pub fn transport(&self) -> MockTransport {
+        MockTransport::new(self.state.clone())
+    }
+
+    pub fn requests(&self) -> Vec<MockRequest> {
+        self.state.requests.lock().unwrap().clone()
+    }
+
+    pub fn requests_to(&self, route: impl AsRef<str>) -> Vec<MockRequest> {
+        let path = fixture::path(route.as_ref());
+
+        self.state
+            .requests
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|request| request.path == path)
+            .cloned()
+            .collect()
+    }
+}
+
+impl AsRef<str> for MockServer {
+    fn as_ref(&self) -> &str {
+        &self.url
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        let _ = self.shutdown.send(true);
+
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+pub(crate) struct State {
+    fixtures: Vec<Fixture>,
+    credentials: HashMap<String, Credentials>,
+    recv_window: i64,
+    pub(crate) stream_interval: Duration,
+    failures: Mutex<HashMap<(Method, String), VecDeque<Failure>>>,
+    requests: Mutex<Vec<MockRequest>>,
+}
+
+impl State {
+    /// Answers `request` and records it.
+    pub(crate) fn exchange(&self, request: MockRequest) -> MockResponse {
+        let response = self.respond(&request);
+
+        self.requests.lock().unwrap().push(request);
+        response
+    }
+
+    fn respond(&self, request: &MockRequest) -> MockResponse {
+        let Some(fixture) = self
+            .fixtures
+            .iter()
+            .find(|fixture| fixture.matches(&request.method, &request.path))
+        else {
+            return MockResponse::new(hyper::StatusCode::NOT_FOUND, "");
+        };
+
+        let failure = self
+            .failures
+            .lock()
+            .unwrap()
+            .get_mut(&(request.method.clone(), request.path.clone()))
+            .and_then(VecDeque::pop_front);
+
+        if let Some(failure) = failure {
+            return failure.to_response();
+        }
+
+        match self.authorize(fixture.security, request) {
+            Ok(()) => fixture.responder.respond(request),
+            Err(failure) => failure.to_response(),
+        }
+    }
+
+    fn authorize(&self, security: Security, request: &MockRequest) -> Result<(), Failure> {
+        if security == Security::None {
+            return Ok(());
+        }
+
+        let api_key = request
+            .api_key
+            .as_deref()
+            .ok_or_else(Failure::api_key_format)?;
+        let credentials = self
+            .credentials
+            .get(api_key)
+            .ok_or_else(Failure::rejected_api_key)?;
+
+        if security == Security::ApiKey {
+            return Ok(());
+        }
+
+        let timestamp = request
+            .param("timestamp")
+            .and_then(|timestamp| timestamp.parse::<i64>().ok())
+            .ok_or_else(|| Failure::mandatory_parameter("timestamp"))?;
+
+        let recv_window = request
+            .param("recvWindow")
+            .and_then(|recv_window| recv_window.parse::<i64>().ok())
+            .unwrap_or(self.recv_window);
+
+        let now = Utc::now().timestamp_millis();
+
+        if timestamp >= now + 1000 || now - timestamp > recv_window {
+            return Err(Failure::outside_recv_window());
+        }
+
+        let (payload, signature) = request
+            .signature()
+            .ok_or_else(|| Failure::mandatory_parameter("signature"))?;
+
+        if credentials.verify(&payload, signature) {
+            Ok(())
+        } else {
+            Err(Failure::invalid_signature())
+        }
+    }
+}
+
+async fn serve(state: Arc<State>, listener: TcpListener, mut shutdown: watch::Receiver<bool>) {
+    loop {
+        tokio::select! {
+            accepted = listener.accept() => {
+                let Ok((stream, _)) = accepted else {
+                    continue;
+                };
+
+                let state = state.clone();
+
+                tokio::spawn(async move {
+                    let service = service_fn(move |request| handle(state.clone(), request));
+
+                    let _ = http1::Builder::new()
+                        .serve_connection(TokioIo::new(stream), service)
+                        .await;
+                });
+            }
+            _ = shutdown.changed() => break,
+        }
+    }
+}
+
+async fn handle(
+    state: Arc<State>,
+    request: Request<Incoming>,
+) -> Result<Response<Full<Bytes>>, Infallible> {
+    let (parts, body) = request.into_parts();
+    let body = body
+        .collect()
+        .await
+        .map(|body| body.to_bytes())
+        .unwrap_or_default();
+
+    let request = MockRequest::new(parts.method, &parts.uri, &parts.headers, &body);
+    let response = state.exchange(request);
+
+    let mut builder = Response::builder()
+        .status(response.status)
+        .header("Content-Type", "application/json;charset=UTF-8");
+
+    for (name, value) in response.headers {
+        builder = builder.header(name, value);
+    }
+
+    Ok(builder
+        .body(Full::new(Bytes::from(response.body)))
+        .unwrap_or_default())
+}
@@ -0,0 +1,68 @@
+use std::sync::Arc;
+
+use binance_common::error::BinanceError;
+use binance_core::transport::{Request, Response, asynchronous, synchronous};
+use hyper::{
+    HeaderMap, Uri,
+    body::Bytes,
+    header::{HeaderName, HeaderValue},
+};
+
+use crate::{request::MockRequest, server::State};
+
+/// Serves requests from the fixtures of a `MockServer` in memory, see
+/// `MockServer::transport`.
+#[derive(Clone)]
+pub struct MockTransport {
+    state: Arc<State>,
+}
+
+impl MockTransport {
+    pub(crate) fn new(state: Arc<State>) -> Self {
+        MockTransport { state }
+    }
+
+    fn exchange(&self, request: Request) -> Result<Response, BinanceError> {
+        let uri: Uri = request
+            .url
+            .parse()
+            .map_err(|error| BinanceError::Transport(format!("{}: {}", request.url, error)))?;
+        let body = Bytes::from(request.body.unwrap_or_default());
+
+        let response = self.state.exchange(MockRequest::new(
+            request.method,
+            &uri,
+            &request.headers,
+            &body,
+        ));
+
+        let mut headers = HeaderMap::new();
+
+        for (name, value) in response.headers {
+            let name = HeaderName::from_bytes(name.as_bytes())
+                .map_err(|error| BinanceError::Transport(error.to_string()))?;
+            let value = HeaderValue::from_str(&value)
+                .map_err(|error| BinanceError::Transport(error.to_string()))?;
+
+            headers.insert(name, value);
+        }
+
+        Ok(Response {
+            status: response.status,
+            headers,
+            body: response.body.into_bytes(),
+        })
+    }
+}
+
+impl synchronous::Transport for MockTransport {
+    fn send(&self, request: Request) -> Result<Response, BinanceError> {
+        self.exchange(request)
+    }
+}
+
+impl asynchronous::Transport for MockTransport {
+    async fn send(&self, request: Request) -> Result<Response, BinanceError> {
+        self.exchange(request)
+    }
+}
@@ -0,0 +1,162 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use futures_util::{SinkExt, StreamExt};
+use hyper::Uri;
+use serde_json::{Value, json};
+use tokio::{
+    net::{TcpListener, TcpStream},
+    sync::watch,
+};
+use tokio_tungstenite::{
+    accept_hdr_async,
+    tungstenite::{
+        Bytes, Message,
+        handshake::server::{Callback, ErrorResponse, Request, Response},
+    },
+};
+
+use crate::server::State;
+
+const AGG_TRADE: &str = include_str!("../fixtures/futures/websocket/agg_trade.json");
+const KLINE: &str = include_str!("../fixtures/futures/websocket/kline.json");
+const MARK_PRICE: &str = include_str!("../fixtures/futures/websocket/mark_price.json");
+const MINI_TICKER: &str = include_str!("../fixtures/futures/websocket/mini_ticker.json");
+
+pub(crate) async fn serve(
+    state: Arc<State>,
+    listener: TcpListener,
+    mut shutdown: watch::Receiver<bool>,
+) {
+    loop {
+        tokio::select! {
+            accepted = listener.accept() => {
+                let Ok((stream, _)) = accepted else {
+                    continue;
+                };
+
+                tokio::spawn(connection(state.clone(), stream, shutdown.clone()));
+            }
+            _ = shutdown.changed() => break,
+        }
+    }
+}
+
+async fn connection(state: Arc<State>, stream: TcpStream, mut shutdown: watch::Receiver<bool>) {
+    let mut uri = Uri::default();
+
+    let Ok(mut socket) = accept_hdr_async(stream, Route(&mut uri)).await else {
+        return;
+    };
+
+    let (combined, streams) = streams(&uri);
+    let streams: Vec<(String, Value)> = streams
+        .into_iter()
+        .filter_map(|stream| frame(&stream).map(|frame| (stream, frame)))
+        .collect();
+
+    if socket.send(Message::Ping(Bytes::new())).await.is_err() {
+        return;
+    }
+
+    let mut interval = tokio::time::interval(state.stream_interval);
+    let mut index = 0;
+
+    loop {
+        tokio::select! {
+            _ = interval.tick(), if !streams.is_empty() => {
+                let (stream, frame) = &streams[index % streams.len()];
+                index += 1;
+
+                let mut frame = frame.clone();
+                frame["E"] = json!(Utc::now().timestamp_millis());
+
+                let message = match combined {
+                    true => json!({ "stream": stream, "data": frame }),
+                    false => frame,
+                };
+
+                if socket.send(Message::Text(message.to_string().into())).await.is_err() {
+                    break;
+                }
+            }
+            message = socket.next() => match message {
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                _ => {}
+            },
+            _ = shutdown.changed() => {
+                let _ = socket.close(None).await;
+                break;
+            }
+        }
+    }
+}
+
+/// Captures the request URI of the handshake, which carries the stream names.
+struct Route<'a>(&'a mut Uri);
+
+impl Callback for Route<'_> {
+    fn on_request(self, request: &Request, response: Response) -> Result<Response, ErrorResponse> {
+        *self.0 = request.uri().clone();
+        Ok(response)
+    }
+}
+
+/// Reads the stream names from `/market/stream?streams=a/b/` for combined
+/// streams or from `/market/a/b/` for raw streams.
+pub fn streams(uri: &Uri) -> (bool, Vec<String>) {
+    let combined = uri
+        .query()
+        .and_then(|query| query.strip_prefix("streams="))
+        .map(str::to_string);
+
+    let streams = match &combined {
+        Some(streams) => streams.as_str(),
+        None => uri
+            .path()
+            .trim_start_matches('/')
+            .split_once('/')
+            .map(|(_, streams)| streams)
+            .unwrap_or_default(),
+    };
+
+    (
+        combined.is_some(),
+        streams
+            .split('/')
+            .filter(|stream| !stream.is_empty())
+            .map(str::to_string)
+            .collect(),
+    )
+}
+
+/// Builds the first payload of a stream from the fixtures, rewriting the
+/// symbol (and the interval for klines) to match the subscription.
+pub fn frame(stream: &str) -> Option<Value> {
+    let (symbol, event) = stream.split_once('@')?;
+
+    if symbol.is_empty() || symbol.starts_with('!') {
+        return None;
+    }
+
+    let symbol = symbol.to_uppercase();
+
+    let mut frame: Value = if event == "aggTrade" {
+        serde_json::from_str(AGG_TRADE).ok()?
+    } else if let Some(interval) = event.strip_prefix("kline_") {
+        let mut frame: Value = serde_json::from_str(KLINE).ok()?;
+        frame["k"]["s"] = json!(symbol);
+        frame["k"]["i"] = json!(interval);
+        frame
+    } else if event.starts_with("markPrice") {
+        serde_json::from_str(MARK_PRICE).ok()?
+    } else if event == "miniTicker" {
+        serde_json::from_str(MINI_TICKER).ok()?
+    } else {
+        return None;
+    };
+
+    frame["s"] = json!(symbol);
+
+    Some(frame)
+}
@@ -0,0 +1,325 @@
+#[cfg(test)]
+mod futures_mock_integration_tests {
+    use binance_common::{
+        enums::{Interval, futures::OrderSide},
+        error::{ApiError, BinanceError},
+        futures::{
+            endpoint::route::{Market, Trade},
+            model::{
+                params::{
+                    account::{CommissionRateParams, FuturesAccountParams, IncomeHistoryParams},
+                    market::{KlinesParams, Symbol},
+                    trade::{
+                        AutoCancelAllOpenOrdersParams, BatchOrdersParams, CancelAllOrdersParams,
+                        NewOrderParams, SetLeverageParams, UserTradesParams,
+                    },
+                },
+                response::{
+                    account::{
+                        CommissionRateResponse, FuturesAccountResponse, IncomeHistoryResponse,
+                    },
+                    market::{KlinesResponse, MarkPriceResponse},
+                    trade::{
+                        AutoCancelAllOpenOrdersResponse, BatchOrderResponse,
+                        CancelAllOrdersResponse, OrderResponse, SetLeverageResponse,
+                        TestOrderResponse, UserTradesResponse,
+                    },
+                },
+            },
+        },
+    };
+    use binance_core::{
+        client::asynchronous::Client, connection::ConnectionConfig, signer::hmacsha256::HmacSha256,
+    };
+    use binance_futures::asynchronous::{account::AccountApi, market::MarketApi, trade::TradeApi};
+    use binance_mock::{
+        credentials::Credentials, failure::Failure, request::MockResponse, server::MockServer,
+    };
+    use hyper::Method;
+    use std::{
+        net::{Ipv4Addr, Ipv6Addr},
+        sync::OnceLock,
+        time::Duration,
+    };
+
+    static API_KEY: &str = "api_key";
+    static SECRET_KEY: &str = "secret_key";
+
+    static SERVER: OnceLock<MockServer> = OnceLock::new();
+
+    fn shared_test_server() -> &'static MockServer {
+        SERVER.get_or_init(|| {
+            MockServer::builder()
+                .credentials(API_KEY, Credentials::hmac_sha256(SECRET_KEY))
+                .start()
+                .unwrap()
+        })
+    }
+
+    #[tokio::test]
+    async fn test_get_mark_price() {
+        let market_api = MarketApi::new(Client::new(
+            shared_test_server(),
+            HmacSha256::new(API_KEY, SECRET_KEY),
+        ));
+
+        let mark_price: MarkPriceResponse = market_api
+            .get_mark_price(&Symbol::new("ETHUSDT"))
+            .await
+            .unwrap();
+
+        assert_eq!(mark_price.symbol, "ETHUSDT");
+        assert!(mark_price.mark_price > 0.0);
+
+        let mark_prices: Vec<MarkPriceResponse> = market_api.get_mark_price_list().await.unwrap();
+
+        assert!(mark_prices.len() > 1);
+    }
+
+    #[tokio::test]
+    async fn test_get_klines() {
+        let market_api = MarketApi::new(Client::new(
+            shared_test_server(),
+            HmacSha256::new(API_KEY, SECRET_KEY),
+        ));
+
+        let params = KlinesParams::new("BTCUSDT", &Interval::Minutes1).limit(2);
+
+        let klines: Vec<KlinesResponse> = market_api.get_klines(&params).await.unwrap();
+
+        assert_eq!(klines.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_send_orders() {
+        let trade_api = TradeApi::new(Client::new(
+            shared_test_server(),
+            HmacSha256::new(API_KEY, SECRET_KEY),
+        ));
+
+        let params = NewOrderParams::limit("BTCUSDT", OrderSide::Buy, 100000.0, 0.002);
+
+        let test_order: TestOrderResponse = trade_api.send_new_test_order(&params).await.unwrap();
+        let order: OrderResponse = trade_api.send_new_order(&params).await.unwrap();
+
+        assert_eq!(test_order.order_id, 0);
+        assert!(order.order_id > 0);
+
+        let leverage: SetLeverageResponse = trade_api
+            .send_set_leverage(&SetLeverageParams::new("BTCUSDT", 10))
+            .await
+            .unwrap();
+
+        assert_eq!(leverage.leverage, 10);
+
+        let cancel_all: CancelAllOrdersResponse = trade_api
+            .send_cancel_all_orders(&CancelAllOrdersParams::new("BTCUSDT"))
+            .await
+            .unwrap();
+
+        assert_eq!(cancel_all.code, 200);
+        assert!(
+            shared_test_server()
+                .requests_to(Trade::CancelAllOrders)
+                .iter()
+                .all(|request| request.method == Method::DELETE)
+        );
+
+        let countdown: AutoCancelAllOpenOrdersResponse = trade_api
+            .send_auto_cancel_all_open_orders(&AutoCancelAllOpenOrdersParams::new(
+                "BTCUSDT", 100_000,
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(countdown.countdown_time, 100_000);
+
+        let trades: Vec<UserTradesResponse> = trade_api
+            .get_user_trades(&UserTradesParams::new("BTCUSDT").limit(10))
+            .await
+            .unwrap();
+
+        assert_eq!(trades[0].realized_pnl, 3.41);
+        assert_eq!(trades[0].commission_asset, "USDT");
+    }
+
+    #[tokio::test]
+    async fn test_account() {
+        let account_api = AccountApi::new(Client::new(
+            shared_test_server(),
+            HmacSha256::new(API_KEY, SECRET_KEY),
+        ));
+
+        let account: FuturesAccountResponse = account_api
+            .get_futures_account(&FuturesAccountParams::new(5000))
+            .await
+            .unwrap();
+
+        assert!(account.total_wallet_balance > 0.0);
+
+        let commission: CommissionRateResponse = account_api
+            .get_comission_rate(&CommissionRateParams::new("SOLUSDT"))
+            .await
+            .unwrap();
+
+        assert_eq!(commission.symbol, "SOLUSDT");
+
+        let income: Vec<IncomeHistoryResponse> = account_api
+            .get_income_history(&IncomeHistoryParams::new().symbol("BTCUSDT"))
+            .await
+            .unwrap();
+
+        assert_eq!(income[0].trade_id, "6711800101");
+        assert!(income.iter().any(|entry| entry.trade_id.is_empty()));
+    }
+
+    #[tokio::test]
+    async fn test_batch_orders_in_form_body() {
+        let server = MockServer::builder()
+            .credentials(API_KEY, Credentials::hmac_sha256(SECRET_KEY))
+            .start()
+            .unwrap();
+        let trade_api = TradeApi::new(Client::new(&server, HmacSha256::new(API_KEY, SECRET_KEY)));
+
+        let params = BatchOrdersParams::new(&[
+            NewOrderParams::limit("BTCUSDT", OrderSide::Buy, 100000.0, 0.002),
+            NewOrderParams::market("BTCUSDT", OrderSide::Sell, 0.002).recv_window(5000),
+        ])
+        .unwrap();
+
+        let orders: Vec<BatchOrderResponse> = trade_api.send_batch_orders(&params).await.unwrap();
+
+        assert!(matches!(orders[0], BatchOrderResponse::Order(ref order) if order.order_id > 0));
+        assert!(
+            matches!(orders[1], BatchOrderResponse::Rejected(ref error) if error.code == -2019)
+        );
+
+        let requests = server.requests_to(Trade::MultipleOrders);
+        let batch: serde_json::Value =
+            serde_json::from_str(requests[0].param("batchOrders").unwrap()).unwrap();
+
+        assert!(requests[0].query.is_empty());
+        assert!(requests[0].param("signature").is_some());
+        assert_eq!(batch[0]["price"], "100000.0");
+        assert_eq!(batch[1]["type"], "MARKET");
+        assert!(batch[1].get("recvWindow").is_none() && batch[1].get("price").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_connection_config() {
+        let server = shared_test_server();
+        let config = ConnectionConfig::new()
+            .connect_timeout(Duration::from_secs(1))
+            .read_timeout(Duration::from_secs(5))
+            .pool_idle_timeout(Duration::from_secs(60))
+            .pool_max_idle_per_host(2)
+            .tcp_keepalive(Duration::from_secs(30))
+            .user_agent("binance-rs-colo")
+            .local_address(Ipv4Addr::LOCALHOST.into());
+        let market_api = MarketApi::new(
+            Client::with_config(server, HmacSha256::new(API_KEY, SECRET_KEY), &config).unwrap(),
+        );
+
+        assert!(
+            market_api
+                .get_mark_price(&Symbol::new("BTCUSDT"))
+                .await
+                .is_ok()
+        );
+
+        let market_api = MarketApi::new(
+            Client::with_config(
+                server,
+                HmacSha256::new(API_KEY, SECRET_KEY),
+                &config.local_address(Ipv6Addr::LOCALHOST.into()),
+            )
+            .unwrap(),
+        );
+
+        assert!(matches!(
+            market_api.get_mark_price(&Symbol::new("BTCUSDT")).await,
+            Err(BinanceError::Request(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_transport() {
+        let server = MockServer::builder()
+            .credentials(API_KEY, Credentials::hmac_sha256(SECRET_KEY))
+            .start()
+            .unwrap();
+        let trade_api = TradeApi::new(Client::with_transport(
+            &server,
+            HmacSha256::new(API_KEY, SECRET_KEY),
+            server.transport(),
+        ));
+
+        let order: OrderResponse = trade_api
+            .send_new_order(&NewOrderParams::limit(
+                "BTCUSDT",
+                OrderSide::Buy,
+                100000.0,
+                0.002,
+            ))
+            .await
+            .unwrap();
+
+        assert!(order.order_id > 0);
+        assert_eq!(server.requests_to(Trade::NewOrder).len(), 1);
+
+        let forged_api = TradeApi::new(Client::with_transport(
+            &server,
+            HmacSha256::new(API_KEY, "wrong_secret_key"),
+            server.transport(),
+        ));
+
+        assert!(matches!(
+            forged_api
+                .send_new_order(&NewOrderParams::market("BTCUSDT", OrderSide::Sell, 0.002))
+                .await,
+            Err(BinanceError::Api(ApiError { code: -1022, .. }))
+        ));
+        assert_eq!(server.requests_to(Trade::NewOrder).len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_custom_responder_and_failure() {
+        let server = MockServer::builder()
+            .respond_with(
+                Method::GET,
+                Market::MarkPrice,
+                binance_mock::fixture::Security::None,
+                |request| {
+                    MockResponse::ok(format!(
+                        r#"{{"symbol":"{}","markPrice":"1.5","indexPrice":"1.5","estimatedSettlePrice":"1.5","lastFundingRate":"0","interestRate":"0","nextFundingTime":0,"time":0}}"#,
+                        request.param("symbol").unwrap_or_default()
+                    ))
+                },
+            )
+            .start()
+            .unwrap();
+
+        let market_api = MarketApi::new(Client::new(&server, HmacSha256::new(API_KEY, SECRET_KEY)));
+
+        server.fail(
+            Method::GET,
+            Market::MarkPrice,
+            Failure::bad_request(-1003, "Too many requests queued."),
+        );
+
+        assert!(matches!(
+            market_api.get_mark_price(&Symbol::new("XRPUSDT")).await,
+            Err(BinanceError::Api(ApiError { code: -1003, .. }))
+        ));
+
+        let mark_price = market_api
+            .get_mark_price(&Symbol::new("XRPUSDT"))
+            .await
+            .unwrap();
+
+        assert_eq!(mark_price.symbol, "XRPUSDT");
+        assert_eq!(mark_price.mark_price, 1.5);
+        assert_eq!(server.requests_to(Market::MarkPrice).len(), 2);
+        assert!(server.requests_to(Trade::NewOrder).is_empty());
+    }
+}
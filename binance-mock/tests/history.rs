@@ -0,0 +1,426 @@
+#[cfg(test)]
+mod history_mock_integration_tests {
+    use std::time::Duration;
+
+    use binance_common::{
+        enums::Interval,
+        error::BinanceError,
+        futures::endpoint::route::Market as FuturesMarket,
+        spot::{
+            endpoint::route::{Account, Market},
+            model::response::account::MyTradesResponse,
+        },
+    };
+    use binance_core::{
+        client::{asynchronous, synchronous},
+        history::{Gaps, KlineEvent, KlineHistory, TradeHistory},
+        signer::hmacsha256::HmacSha256,
+    };
+    use binance_mock::{
+        credentials::Credentials,
+        failure::Failure,
+        fixture::Security,
+        request::{MockRequest, MockResponse},
+        server::MockServer,
+    };
+    use futures_util::StreamExt;
+    use hyper::{Method, StatusCode};
+
+    const MINUTE: u64 = 60_000;
+    const HOUR: u64 = 60 * MINUTE;
+    const DAY: u64 = 24 * HOUR;
+
+    /// One minute candles for the requested window, except the ones in `missing`. Every page
+    /// starts one candle before `startTime` so the downloader has to drop duplicates.
+    fn klines(request: &MockRequest, missing: &[u64]) -> MockResponse {
+        let param = |name: &str| {
+            request
+                .param(name)
+                .and_then(|value| value.parse::<u64>().ok())
+        };
+
+        let start_time = param("startTime").unwrap_or_default();
+        let end_time = param("endTime").unwrap_or(u64::MAX);
+        let limit = param("limit").unwrap_or(500) as usize;
+
+        let first = start_time.div_ceil(MINUTE).saturating_sub(1);
+
+        let rows: Vec<String> = (first..)
+            .map(|index| index * MINUTE)
+            .take_while(|open_time| *open_time <= end_time)
+            .filter(|open_time| !missing.contains(&(open_time / MINUTE)))
+            .take(limit)
+            .map(|open_time| {
+                let close = 100.0 + (open_time / MINUTE) as f64;
+                format!(
+                    r#"[{open_time},"{close}","{close}","{close}","{close}","1.0",{},"{close}",1,"0.5","{}","0"]"#,
+                    open_time + MINUTE - 1,
+                    close / 2.0
+                )
+            })
+            .collect();
+
+        MockResponse::ok(format!("[{}]", rows.join(",")))
+    }
+
+    /// BTCUSDT trades every hour from the first hour on, ETHUSDT six times on the third day
+    /// and XRPUSDT never.
+    fn my_trades(request: &MockRequest) -> MockResponse {
+        let param = |name: &str| {
+            request
+                .param(name)
+                .and_then(|value| value.parse::<u64>().ok())
+        };
+
+        let trades: Vec<(u64, u64)> = match request.param("symbol") {
+            Some("BTCUSDT") => (1..=25).map(|id| (id, id * HOUR)).collect(),
+            Some("ETHUSDT") => (0..6).map(|id| (100 + id, 2 * DAY + id * MINUTE)).collect(),
+            _ => Vec::new(),
+        };
+
+        if let (Some(start_time), Some(end_time)) = (param("startTime"), param("endTime"))
+            && end_time - start_time >= DAY
+        {
+            return MockResponse::api_error(
+                StatusCode::BAD_REQUEST,
+                -1127,
+                "More than 24 hours between startTime and endTime.",
+            );
+        }
+
+        let rows: Vec<String> = trades
+            .into_iter()
+            .filter(|(id, time)| match param("fromId") {
+                Some(from_id) => *id >= from_id,
+                None => {
+                    *time >= param("startTime").unwrap_or_default()
+                        && *time <= param("endTime").unwrap_or(u64::MAX)
+                }
+            })
+            .take(param("limit").unwrap_or(500) as usize)
+            .map(|(id, time)| {
+                format!(
+                    r#"{{"symbol":"{}","id":{id},"orderId":{id},"orderListId":-1,"price":"100","qty":"1","quoteQty":"100","commission":"0.1","commissionAsset":"USDT","time":{time},"isBuyer":true,"isMaker":false,"isBestMatch":true}}"#,
+                    request.param("symbol").unwrap_or_default()
+                )
+            })
+            .collect();
+
+        MockResponse::ok(format!("[{}]", rows.join(",")))
+    }
+
+    fn server() -> MockServer {
+        MockServer::builder()
+            .credentials("api_key", Credentials::hmac_sha256("secret_key"))
+            .respond_with(Method::GET, Market::Klines, Security::None, |request| {
+                klines(request, &[30, 31, 32])
+            })
+            .respond_with(
+                Method::GET,
+                FuturesMarket::Klines,
+                Security::None,
+                |request| klines(request, &[30, 31, 32]),
+            )
+            .respond_with(Method::GET, Account::MyTrades, Security::Signed, my_trades)
+            .start()
+            .unwrap()
+    }
+
+    fn history(symbol: &str) -> KlineHistory<'_> {
+        KlineHistory::new(symbol, Interval::Minutes1, 0, 59 * MINUTE)
+            .limit(25)
+            .pause(Duration::ZERO)
+            .backoff(Duration::from_millis(10))
+    }
+
+    #[test]
+    fn test_interval_deserialize() {
+        for interval in ["1s", "3m", "15m", "2h", "12h", "3d", "1w", "1M"] {
+            let parsed: Interval = serde_json::from_str(&format!("\"{}\"", interval)).unwrap();
+            assert_eq!(parsed.as_ref(), interval);
+        }
+
+        assert!(serde_json::from_str::<Interval>("\"2m\"").is_err());
+    }
+
+    #[test]
+    fn test_interval_round_trip() {
+        let intervals = [
+            Interval::Seconds1,
+            Interval::Minutes1,
+            Interval::Minutes3,
+            Interval::Minutes5,
+            Interval::Minutes15,
+            Interval::Minutes30,
+            Interval::Hours1,
+            Interval::Hours2,
+            Interval::Hours4,
+            Interval::Hours6,
+            Interval::Hours8,
+            Interval::Hours12,
+            Interval::Days1,
+            Interval::Days3,
+            Interval::Weeks1,
+            Interval::Months1,
+        ];
+
+        for interval in intervals {
+            let parsed: Interval =
+                serde_json::from_str(&format!("\"{}\"", interval.as_str())).unwrap();
+            assert_eq!(parsed, interval);
+        }
+    }
+
+    fn missing_at_both_ends(gaps: Gaps, missing: Vec<u64>) -> Vec<KlineEvent> {
+        let server = MockServer::builder()
+            .respond_with(
+                Method::GET,
+                Market::Klines,
+                Security::None,
+                move |request| klines(request, &missing),
+            )
+            .start()
+            .unwrap();
+        let market_api = binance_spot::synchronous::market::MarketApi::new(
+            synchronous::Client::new(&server, HmacSha256::new("api_key", "secret_key")),
+        );
+
+        market_api
+            .get_klines_history(history("BTCUSDT").gaps(gaps))
+            .collect::<Result<_, _>>()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_history_reports_gaps_at_both_ends() {
+        let events = missing_at_both_ends(Gaps::Report, vec![0, 1, 58, 59]);
+
+        assert_eq!(events.len(), 58);
+        assert!(matches!(
+            events.first(),
+            Some(KlineEvent::Missing {
+                start_time: 0,
+                end_time,
+                count: 2
+            }) if *end_time == MINUTE
+        ));
+        assert!(matches!(
+            events.last(),
+            Some(KlineEvent::Missing {
+                start_time,
+                end_time,
+                count: 2
+            }) if *start_time == 58 * MINUTE && *end_time == 59 * MINUTE
+        ));
+
+        let events = missing_at_both_ends(Gaps::Fill, vec![0, 1, 58, 59]);
+        let filled: Vec<(u64, f64)> = events
+            .iter()
+            .filter_map(|event| match event {
+                KlineEvent::Filled(kline) => Some((kline.open_time / MINUTE, kline.close)),
+                _ => None,
+            })
+            .collect();
+
+        assert_eq!(events.len(), 60);
+        assert_eq!(
+            filled,
+            vec![(0, 102.0), (1, 102.0), (58, 157.0), (59, 157.0)]
+        );
+
+        let events = missing_at_both_ends(Gaps::Fill, (0..60).collect());
+
+        assert!(matches!(
+            events.as_slice(),
+            [KlineEvent::Missing {
+                start_time: 0,
+                end_time,
+                count: 60
+            }] if *end_time == 59 * MINUTE
+        ));
+    }
+
+    #[test]
+    fn test_spot_history_pages_and_reports_gaps() {
+        let server = server();
+        let market_api = binance_spot::synchronous::market::MarketApi::new(
+            synchronous::Client::new(&server, HmacSha256::new("api_key", "secret_key")),
+        );
+
+        let events: Vec<KlineEvent> = market_api
+            .get_klines_history(history("BTCUSDT"))
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        let open_times: Vec<u64> = events
+            .iter()
+            .filter_map(|event| match event {
+                KlineEvent::Kline(kline) => Some(kline.open_time / MINUTE),
+                _ => None,
+            })
+            .collect();
+
+        assert_eq!(open_times.len(), 57);
+        assert!(open_times.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(open_times.last(), Some(&59));
+
+        let missing: Vec<&KlineEvent> = events
+            .iter()
+            .filter(|event| matches!(event, KlineEvent::Missing { .. }))
+            .collect();
+
+        assert_eq!(missing.len(), 1);
+        assert!(matches!(
+            missing[0],
+            KlineEvent::Missing {
+                start_time,
+                end_time,
+                count: 3
+            } if *start_time == 30 * MINUTE && *end_time == 32 * MINUTE
+        ));
+
+        assert_eq!(server.requests_to(Market::Klines).len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_futures_history_fills_gaps() {
+        let server = server();
+        let market_api = binance_futures::asynchronous::market::MarketApi::new(
+            asynchronous::Client::new(&server, HmacSha256::new("api_key", "secret_key")),
+        );
+
+        let events: Vec<KlineEvent> = market_api
+            .get_klines_history(history("ETHUSDT").gaps(Gaps::Fill))
+            .map(Result::unwrap)
+            .collect()
+            .await;
+
+        assert_eq!(events.len(), 60);
+
+        let filled: Vec<(u64, f64, f64)> = events
+            .iter()
+            .filter_map(|event| match event {
+                KlineEvent::Filled(kline) => {
+                    Some((kline.open_time / MINUTE, kline.close, kline.volume))
+                }
+                _ => None,
+            })
+            .collect();
+
+        assert_eq!(
+            filled,
+            vec![(30, 129.0, 0.0), (31, 129.0, 0.0), (32, 129.0, 0.0)]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_history_retries_rate_limit() {
+        let server = server();
+        let market_api = binance_spot::asynchronous::market::MarketApi::new(
+            asynchronous::Client::new(&server, HmacSha256::new("api_key", "secret_key")),
+        );
+
+        server.fail(
+            Method::GET,
+            Market::Klines,
+            Failure::TooManyRequests { retry_after: 0 },
+        );
+
+        let events: Vec<Result<KlineEvent, BinanceError>> = market_api
+            .get_klines_history(history("BTCUSDT").gaps(Gaps::Skip))
+            .collect()
+            .await;
+
+        assert_eq!(events.len(), 57);
+        assert!(events.iter().all(Result::is_ok));
+
+        for _ in 0..2 {
+            server.fail(
+                Method::GET,
+                Market::Klines,
+                Failure::TooManyRequests { retry_after: 0 },
+            );
+        }
+
+        let mut stream = Box::pin(market_api.get_klines_history(history("BTCUSDT").retries(1)));
+
+        assert!(matches!(
+            stream.next().await,
+            Some(Err(BinanceError::TooManyRequest(_)))
+        ));
+        assert!(stream.next().await.is_none());
+    }
+
+    fn trade_history<'a>(symbols: &[&'a str], end_time: u64) -> TradeHistory<'a> {
+        TradeHistory::new(symbols, 0, end_time)
+            .limit(10)
+            .pause(Duration::ZERO)
+            .backoff(Duration::from_millis(10))
+    }
+
+    #[test]
+    fn test_trade_history_walks_windows_then_ids() {
+        let server = server();
+        let account_api = binance_spot::synchronous::account::AccountApi::new(
+            synchronous::Client::new(&server, HmacSha256::new("api_key", "secret_key")),
+        );
+
+        let trades: Vec<MyTradesResponse> = account_api
+            .get_my_trades_history(trade_history(
+                &["BTCUSDT", "ETHUSDT", "XRPUSDT"],
+                3 * DAY - 1,
+            ))
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        let ids: Vec<u64> = trades.iter().map(|trade| trade.id).collect();
+
+        assert_eq!(ids.len(), 31);
+        assert_eq!(&ids[..25], (1..=25).collect::<Vec<_>>().as_slice());
+        assert_eq!(&ids[25..], &[100, 101, 102, 103, 104, 105]);
+
+        // Three pages of BTCUSDT, a window per day for ETHUSDT and XRPUSDT.
+        let requests = server.requests_to(Account::MyTrades);
+
+        assert_eq!(requests.len(), 9);
+        assert_eq!(requests[1].param("fromId"), Some("11"));
+        assert_eq!(requests[1].param("startTime"), None);
+        assert_eq!(
+            requests[8].param("startTime"),
+            Some((2 * DAY).to_string().as_str())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_trade_history_resumes_and_stops_at_the_end_time() {
+        let server = server();
+        let account_api = binance_spot::asynchronous::account::AccountApi::new(
+            asynchronous::Client::new(&server, HmacSha256::new("api_key", "secret_key")),
+        );
+
+        server.fail(
+            Method::GET,
+            Account::MyTrades,
+            Failure::TooManyRequests { retry_after: 0 },
+        );
+
+        let ids: Vec<u64> = account_api
+            .get_my_trades_history(trade_history(&["BTCUSDT"], 12 * HOUR))
+            .map(|trade| trade.unwrap().id)
+            .collect()
+            .await;
+
+        assert_eq!(ids, (1..=12).collect::<Vec<_>>());
+        assert_eq!(server.requests_to(Account::MyTrades).len(), 3);
+
+        let ids: Vec<u64> = account_api
+            .get_my_trades_history(
+                trade_history(&["BTCUSDT", "ETHUSDT"], 3 * DAY - 1).from_id("BTCUSDT", 24),
+            )
+            .map(|trade| trade.unwrap().id)
+            .collect()
+            .await;
+
+        assert_eq!(ids, vec![24, 25, 100, 101, 102, 103, 104, 105]);
+    }
+}
@@ -0,0 +1,219 @@
+#[cfg(test)]
+mod margin_mock_integration_tests {
+    use binance_common::{
+        enums::{
+            margin::SideEffectType,
+            spot::{OrderSide, TimeInForce},
+        },
+        margin::{
+            endpoint::route::{Account, Trade, UserStream},
+            model::{
+                params::{
+                    account::{BorrowRepayParams, CrossAccountParams, MaxAmountParams},
+                    stream::ListenKeyParams,
+                    trade::{CancelOrderParams, NewOcoParams, NewOrderParams, OpenOrdersParams},
+                },
+                response::{stream::UserDataEvent, trade::CanceledResponse},
+            },
+        },
+    };
+    use binance_core::{
+        client::{asynchronous, synchronous::Client},
+        signer::hmacsha256::HmacSha256,
+    };
+    use binance_margin::synchronous::{
+        account::AccountApi, stream::UserStreamApi, trade::TradeApi,
+    };
+    use binance_mock::{credentials::Credentials, server::MockServer};
+    use hyper::Method;
+    use std::sync::OnceLock;
+
+    static API_KEY: &str = "api_key";
+    static SECRET_KEY: &str = "secret_key";
+
+    static SERVER: OnceLock<MockServer> = OnceLock::new();
+
+    fn shared_test_server() -> &'static MockServer {
+        SERVER.get_or_init(|| {
+            MockServer::builder()
+                .credentials(API_KEY, Credentials::hmac_sha256(SECRET_KEY))
+                .start()
+                .unwrap()
+        })
+    }
+
+    #[test]
+    fn test_borrow_and_repay_isolated() {
+        let server = shared_test_server();
+        let account_api =
+            AccountApi::new(Client::new(server, HmacSha256::new(API_KEY, SECRET_KEY)));
+
+        let borrow = account_api
+            .borrow_repay(&BorrowRepayParams::borrow("USDT", 100.0).isolated("BTCUSDT"))
+            .unwrap();
+        let repay = account_api
+            .borrow_repay(&BorrowRepayParams::repay("USDT", 100.0))
+            .unwrap();
+
+        assert_eq!(borrow.tran_id, 100000001);
+        assert_eq!(repay.tran_id, 100000001);
+
+        let requests = server.requests_to(Account::BorrowRepay);
+
+        assert!(requests.iter().any(|request| {
+            request.param("type") == Some("BORROW")
+                && request.param("isIsolated") == Some("TRUE")
+                && request.param("symbol") == Some("BTCUSDT")
+        }));
+        assert!(requests.iter().any(|request| {
+            request.param("type") == Some("REPAY") && request.param("isIsolated").is_none()
+        }));
+    }
+
+    #[test]
+    fn test_new_order_with_auto_borrow_repay() {
+        let server = shared_test_server();
+        let trade_api = TradeApi::new(Client::new(server, HmacSha256::new(API_KEY, SECRET_KEY)));
+
+        let params = NewOrderParams::market("BTCUSDT", OrderSide::Buy, 0.01)
+            .side_effect_type(SideEffectType::AutoBorrowRepay)
+            .auto_repay_at_cancel(true);
+
+        let response = trade_api.send_new_order(&params).unwrap();
+
+        assert_eq!(response.margin_buy_borrow_asset.as_deref(), Some("USDT"));
+        assert_eq!(response.fills.len(), 1);
+        assert!(server.requests_to(Trade::NewOrder).iter().any(|request| {
+            request.param("sideEffectType") == Some("AUTO_BORROW_REPAY")
+                && request.param("autoRepayAtCancel") == Some("true")
+                && request.param("signature").is_some()
+        }));
+    }
+
+    #[test]
+    fn test_new_oco_and_cancel_all_orders() {
+        let server = shared_test_server();
+        let trade_api = TradeApi::new(Client::new(server, HmacSha256::new(API_KEY, SECRET_KEY)));
+
+        let params = NewOcoParams::new("LTCBTC", OrderSide::Buy, 0.624363, 0.036435, 0.960664)
+            .stop_limit_price(0.97, TimeInForce::Gtc)
+            .isolated();
+
+        let oco = trade_api.send_new_oco(&params).unwrap();
+
+        assert_eq!(oco.contingency_type, "OCO");
+        assert_eq!(oco.order_reports.len(), 2);
+        assert!(server.requests_to(Trade::NewOco).iter().any(|request| {
+            request.param("stopLimitTimeInForce") == Some("GTC")
+                && request.param("isIsolated") == Some("TRUE")
+        }));
+
+        let canceled = trade_api
+            .send_cancel_all_orders(&OpenOrdersParams::new().symbol("BTCUSDT").isolated())
+            .unwrap();
+
+        assert!(matches!(canceled[0], CanceledResponse::Order(_)));
+        assert!(matches!(canceled[1], CanceledResponse::OrderList(_)));
+    }
+
+    #[tokio::test]
+    async fn test_async_cancel_order_and_listen_key() {
+        let server = shared_test_server();
+        let client = || asynchronous::Client::new(server, HmacSha256::new(API_KEY, SECRET_KEY));
+        let trade_api = binance_margin::asynchronous::trade::TradeApi::new(client());
+        let stream_api = binance_margin::asynchronous::stream::UserStreamApi::new(client());
+
+        let canceled = trade_api
+            .send_cancel_order(&CancelOrderParams::new("LTCBTC").order_id(28).isolated())
+            .await
+            .unwrap();
+        let listen_key = stream_api
+            .start(&ListenKeyParams::new())
+            .await
+            .unwrap()
+            .listen_key;
+
+        assert_eq!(canceled.order_id, 28);
+        assert!(!listen_key.is_empty());
+    }
+
+    #[test]
+    fn test_get_cross_account_and_max_borrowable() {
+        let account_api = AccountApi::new(Client::new(
+            shared_test_server(),
+            HmacSha256::new(API_KEY, SECRET_KEY),
+        ));
+
+        let account = account_api
+            .get_cross_account(&CrossAccountParams::new())
+            .unwrap();
+        let max_borrowable = account_api
+            .get_max_borrowable(&MaxAmountParams::new("USDT"))
+            .unwrap();
+
+        assert!(account.borrow_enabled);
+        assert!(
+            account
+                .user_assets
+                .iter()
+                .any(|asset| asset.asset == "USDT" && asset.borrowed == 100.0)
+        );
+        assert_eq!(max_borrowable.borrow_limit, 60.0);
+    }
+
+    #[test]
+    fn test_listen_key_lifecycle_is_keyed_not_signed() {
+        let server = MockServer::builder()
+            .credentials(API_KEY, Credentials::hmac_sha256(SECRET_KEY))
+            .start()
+            .unwrap();
+        let stream_api =
+            UserStreamApi::new(Client::new(&server, HmacSha256::new(API_KEY, SECRET_KEY)));
+
+        let listen_key = stream_api
+            .start(&ListenKeyParams::new().symbol("BTCUSDT"))
+            .unwrap()
+            .listen_key;
+        let params = ListenKeyParams::new()
+            .symbol("BTCUSDT")
+            .listen_key(&listen_key);
+
+        stream_api.keepalive(&params).unwrap();
+        stream_api.close(&params).unwrap();
+
+        let requests = server.requests_to(UserStream::Isolated);
+        let methods: Vec<Method> = requests
+            .iter()
+            .map(|request| request.method.clone())
+            .collect();
+
+        assert_eq!(methods, vec![Method::POST, Method::PUT, Method::DELETE]);
+        assert!(requests.iter().all(|request| {
+            request.api_key.as_deref() == Some(API_KEY)
+                && request.param("signature").is_none()
+                && request.param("timestamp").is_none()
+        }));
+        assert!(server.requests_to(UserStream::Cross).is_empty());
+        assert!(UserStreamApi::<HmacSha256>::websocket_url(&listen_key).ends_with(&listen_key));
+    }
+
+    #[test]
+    fn test_decode_user_data_events() {
+        let execution = r#"{"e":"executionReport","E":1499405658658,"s":"BTCUSDT","c":"mUvoqJxFIILMdfAW5iGSOW","S":"BUY","o":"LIMIT","f":"GTC","q":"1.00000000","p":"0.10264410","P":"0.00000000","F":"0.00000000","g":-1,"C":"","x":"NEW","X":"NEW","r":"NONE","i":4293153,"l":"0.00000000","z":"0.00000000","L":"0.00000000","n":"0","N":null,"T":1499405658657,"t":-1,"I":8641984,"w":true,"m":false,"M":false,"O":1499405658657,"Z":"0.00000000","Y":"0.00000000","Q":"0.00000000","W":1499405658657,"V":"NONE"}"#;
+        let balance = r#"{"e":"balanceUpdate","E":1573200697110,"a":"BTC","d":"100.00000000","T":1573200697068}"#;
+        let unknown = r#"{"e":"externalLockUpdate","E":1581557507324,"a":"NEO","d":"10.00000000","T":1581557507268}"#;
+
+        assert!(matches!(
+            serde_json::from_str::<UserDataEvent>(execution).unwrap(),
+            UserDataEvent::ExecutionReport(report) if report.order_id == 4293153 && report.commission_asset.is_none()
+        ));
+        assert!(matches!(
+            serde_json::from_str::<UserDataEvent>(balance).unwrap(),
+            UserDataEvent::BalanceUpdate(update) if update.delta == 100.0
+        ));
+        assert!(matches!(
+            serde_json::from_str::<UserDataEvent>(unknown).unwrap(),
+            UserDataEvent::Unknown
+        ));
+    }
+}
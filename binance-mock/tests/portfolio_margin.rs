@@ -0,0 +1,175 @@
+#[cfg(test)]
+mod portfolio_margin_mock_integration_tests {
+    use binance_common::{
+        enums::futures::{OrderSide, PositionSide},
+        portfolio_margin::{
+            endpoint::route::{Account, Cm, Um, UserStream},
+            model::params::{
+                account::{AccountParams, AssetCollectionParams},
+                trade::{NewOrderParams, SymbolParams},
+            },
+        },
+    };
+    use binance_core::{client::synchronous::Client, signer::hmacsha256::HmacSha256};
+    use binance_mock::{credentials::Credentials, server::MockServer};
+    use binance_portfolio_margin::synchronous::{
+        account::AccountApi, cm::CmApi, stream::UserStreamApi, um::UmApi,
+    };
+    use hyper::Method;
+    use std::sync::OnceLock;
+
+    static API_KEY: &str = "api_key";
+    static SECRET_KEY: &str = "secret_key";
+
+    static SERVER: OnceLock<MockServer> = OnceLock::new();
+
+    fn shared_test_server() -> &'static MockServer {
+        SERVER.get_or_init(|| {
+            MockServer::builder()
+                .credentials(API_KEY, Credentials::hmac_sha256(SECRET_KEY))
+                .start()
+                .unwrap()
+        })
+    }
+
+    #[test]
+    fn test_um_market_order() {
+        let server = shared_test_server();
+        let um_api = UmApi::new(Client::new(server, HmacSha256::new(API_KEY, SECRET_KEY)));
+
+        let params = NewOrderParams::market("BTCUSDT", OrderSide::Buy, 0.01)
+            .position_side(PositionSide::Long);
+
+        let response = um_api.send_new_order(&params).unwrap();
+
+        assert_eq!(response.symbol, "BTCUSDT");
+
+        let requests = server.requests_to(Um::NewOrder);
+
+        assert!(requests.iter().any(|request| {
+            request.method == Method::POST
+                && request.param("type") == Some("MARKET")
+                && request.param("positionSide") == Some("LONG")
+                && request.param("quantity").map(|q| q.parse::<f64>().unwrap()) == Some(0.01)
+                && request.param("signature").is_some()
+        }));
+    }
+
+    #[test]
+    fn test_cm_order_reports_pair_and_base() {
+        let server = shared_test_server();
+        let cm_api = CmApi::new(Client::new(server, HmacSha256::new(API_KEY, SECRET_KEY)));
+
+        let params = NewOrderParams::limit("BTCUSD_200925", OrderSide::Buy, 9000.0, 10.0);
+
+        let response = cm_api.send_new_order(&params).unwrap();
+
+        assert_eq!(response.pair.as_deref(), Some("BTCUSD"));
+        assert_eq!(response.cum_base, Some(0.0));
+        assert!(response.cum_quote.is_none());
+
+        assert!(
+            server
+                .requests_to(Cm::NewOrder)
+                .iter()
+                .any(|request| request.param("timeInForce") == Some("GTC"))
+        );
+    }
+
+    #[test]
+    fn test_position_risk_for_both_books() {
+        let server = shared_test_server();
+        let um_api = UmApi::new(Client::new(server, HmacSha256::new(API_KEY, SECRET_KEY)));
+        let cm_api = CmApi::new(Client::new(server, HmacSha256::new(API_KEY, SECRET_KEY)));
+
+        let um = um_api
+            .get_position_risk(&SymbolParams::new().symbol("BTCUSDT"))
+            .unwrap();
+        let cm = cm_api.get_position_risk(&SymbolParams::new()).unwrap();
+
+        assert_eq!(um[0].symbol, "BTCUSDT");
+        assert!(um[0].notional.is_some());
+        assert_eq!(cm[0].symbol, "BTCUSD_201225");
+        assert!(cm[0].notional_value.is_some());
+
+        assert!(
+            server
+                .requests_to(Cm::PositionRisk)
+                .iter()
+                .any(|request| request.param("symbol").is_none())
+        );
+    }
+
+    #[test]
+    fn test_balances_and_account() {
+        let server = shared_test_server();
+        let account_api =
+            AccountApi::new(Client::new(server, HmacSha256::new(API_KEY, SECRET_KEY)));
+
+        let balances = account_api.get_balances(&AccountParams::new()).unwrap();
+        let account = account_api.get_account(&AccountParams::new()).unwrap();
+
+        assert_eq!(balances[0].asset, "USDT");
+        assert_eq!(balances[0].um_unrealized_pnl, Some(23.72469206));
+        assert!(balances[0].cm_unrealized_pnl.is_none());
+        assert_eq!(account.uni_mmr, 5167.92171923);
+        assert!(account.total_available_balance.is_none());
+    }
+
+    #[test]
+    fn test_collection_and_negative_balance_repay() {
+        let server = shared_test_server();
+        let account_api =
+            AccountApi::new(Client::new(server, HmacSha256::new(API_KEY, SECRET_KEY)));
+
+        let auto = account_api.auto_collection(&AccountParams::new()).unwrap();
+        let asset = account_api
+            .asset_collection(&AssetCollectionParams::new("USDT"))
+            .unwrap();
+        let repay = account_api
+            .repay_futures_negative_balance(&AccountParams::new())
+            .unwrap();
+
+        assert_eq!(auto.msg, "success");
+        assert_eq!(asset.msg, "success");
+        assert_eq!(repay.msg, "success");
+
+        assert!(
+            server
+                .requests_to(Account::AssetCollection)
+                .iter()
+                .any(|request| request.method == Method::POST
+                    && request.param("asset") == Some("USDT"))
+        );
+        assert!(
+            server
+                .requests_to(Account::RepayFuturesNegativeBalance)
+                .iter()
+                .all(|request| request.method == Method::POST)
+        );
+    }
+
+    #[test]
+    fn test_listen_key_lifecycle_is_keyed_not_signed() {
+        let server = shared_test_server();
+        let stream_api =
+            UserStreamApi::new(Client::new(server, HmacSha256::new(API_KEY, SECRET_KEY)));
+
+        let listen_key = stream_api.start().unwrap().listen_key;
+
+        stream_api.keepalive().unwrap();
+        stream_api.close().unwrap();
+
+        let requests = server.requests_to(UserStream::ListenKey);
+        let methods: Vec<Method> = requests
+            .iter()
+            .map(|request| request.method.clone())
+            .collect();
+
+        assert_eq!(methods, vec![Method::POST, Method::PUT, Method::DELETE]);
+        assert!(requests.iter().all(|request| {
+            request.api_key.as_deref() == Some(API_KEY) && request.param("signature").is_none()
+        }));
+        assert!(UserStreamApi::<HmacSha256>::websocket_url(&listen_key).ends_with(&listen_key));
+    }
+}
@@ -0,0 +1,209 @@
+#[cfg(test)]
+mod recorder_mock_integration_tests {
+    use std::{io::Write, path::PathBuf, time::Duration};
+
+    use binance_common::{
+        enums::{Interval, WebSocketType},
+        error::BinanceError,
+        futures::model::{
+            params::websocket::{WebSocketParams, WebSocketSymbol},
+            response::websocket::WebSocketResponse,
+        },
+    };
+    use binance_core::websocket::{
+        futures::market::{
+            replayer::{ReplaySpeed, WebSocketReplayer},
+            supervisor::WebSocketMarketSupervisor,
+        },
+        recorder::{WebSocketRecorder, WebSocketRecording},
+        supervisor::WebSocketSupervisor,
+    };
+    use binance_mock::server::MockServer;
+    use futures_util::StreamExt;
+    use tokio::sync::watch::Receiver;
+
+    fn recording_path(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "binance-recording-{}-{}.bin",
+            name,
+            std::process::id()
+        ));
+        let _ = std::fs::remove_file(&path);
+        path
+    }
+
+    fn agg_trade(id: u64, price: &str) -> String {
+        format!(
+            r#"{{"e":"aggTrade","E":{id},"s":"BTCUSDT","a":{id},"p":"{price}","q":"0.5","f":{id},"l":{id},"T":{id},"m":false}}"#
+        )
+    }
+
+    #[tokio::test]
+    async fn test_record_live_session_and_replay() {
+        let server = MockServer::builder()
+            .stream_interval(Duration::from_millis(10))
+            .start()
+            .unwrap();
+        let path = recording_path("live");
+
+        let stream = WebSocketParams::new(server.websocket_host(&WebSocketType::MultiStream))
+            .kline_candlesticks(WebSocketSymbol::EthUsdt, Interval::Minutes5)
+            .agg_trade(WebSocketSymbol::SolUsdt);
+
+        let mut websocket_handler = WebSocketMarketSupervisor::new(WebSocketType::MultiStream);
+        websocket_handler
+            .record(WebSocketRecorder::create(&path, WebSocketType::MultiStream).unwrap())
+            .unwrap();
+
+        websocket_handler
+            .start(stream.route().unwrap())
+            .await
+            .unwrap();
+
+        let live = fill_buffer(websocket_handler.watch().await.unwrap(), 10).await;
+
+        websocket_handler.stop().await.unwrap();
+
+        let recording = WebSocketRecording::open(&path).unwrap();
+        assert_eq!(recording.websocket_type(), WebSocketType::MultiStream);
+
+        let frames: Vec<_> = recording.map(Result::unwrap).collect();
+        assert!(frames.len() >= live.len());
+        assert!(
+            frames
+                .windows(2)
+                .all(|pair| pair[0].received_at <= pair[1].received_at)
+        );
+
+        let replayed: Vec<String> = WebSocketReplayer::open(&path, ReplaySpeed::Fastest)
+            .unwrap()
+            .stream()
+            .map(|response| format!("{:?}", response.unwrap()))
+            .collect()
+            .await;
+
+        assert_eq!(replayed.len(), frames.len());
+
+        let mut replayed = replayed.iter();
+        assert!(live.iter().all(|response| {
+            let response = format!("{:?}", response);
+            replayed.any(|replayed| *replayed == response)
+        }));
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_replay_at_original_speed() {
+        let path = recording_path("speed");
+
+        let mut recorder = WebSocketRecorder::create(&path, WebSocketType::SingleStream).unwrap();
+        recorder
+            .record_at(1_000_000, &agg_trade(1, "100.0"))
+            .unwrap();
+        recorder
+            .record_at(1_100_000, &agg_trade(2, "101.0"))
+            .unwrap();
+        recorder
+            .record_at(1_200_000, &agg_trade(3, "102.0"))
+            .unwrap();
+        drop(recorder);
+
+        let replayer = WebSocketReplayer::open(&path, ReplaySpeed::Original).unwrap();
+        let watch = replayer.watch();
+        let consumer = tokio::spawn(fill_buffer(watch, 3));
+
+        let started = tokio::time::Instant::now();
+        replayer.run().await.unwrap();
+
+        assert!(started.elapsed() >= Duration::from_millis(200));
+
+        let prices: Vec<f64> = consumer
+            .await
+            .unwrap()
+            .iter()
+            .filter_map(|response| match response {
+                WebSocketResponse::AggTrade(trade) => Some(trade.price),
+                _ => None,
+            })
+            .collect();
+
+        assert_eq!(prices, vec![100.0, 101.0, 102.0]);
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_recording_appends_and_survives_truncation() {
+        let path = recording_path("append");
+
+        let mut recorder = WebSocketRecorder::create(&path, WebSocketType::SingleStream).unwrap();
+        recorder.record(&agg_trade(1, "100.0")).unwrap();
+        drop(recorder);
+
+        let mut recorder = WebSocketRecorder::create(&path, WebSocketType::SingleStream).unwrap();
+        recorder.record(&agg_trade(2, "101.0")).unwrap();
+        drop(recorder);
+
+        assert!(matches!(
+            WebSocketRecorder::create(&path, WebSocketType::MultiStream),
+            Err(BinanceError::WebSocketInternal(_))
+        ));
+
+        std::fs::OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .unwrap()
+            .write_all(&[7, 0, 0])
+            .unwrap();
+
+        let responses: Vec<WebSocketResponse> =
+            WebSocketReplayer::open(&path, ReplaySpeed::Fastest)
+                .unwrap()
+                .stream()
+                .map(Result::unwrap)
+                .collect()
+                .await;
+
+        assert_eq!(responses.len(), 2);
+        assert!(matches!(
+            &responses[1],
+            WebSocketResponse::AggTrade(trade) if trade.aggregate_trade_id == 2
+        ));
+
+        let mut recorder = WebSocketRecorder::create(&path, WebSocketType::SingleStream).unwrap();
+        recorder.record(&agg_trade(3, "102.0")).unwrap();
+        drop(recorder);
+
+        let ids: Vec<u64> = WebSocketRecording::open(&path)
+            .unwrap()
+            .map(
+                |frame| match serde_json::from_str::<WebSocketResponse>(&frame.unwrap().message) {
+                    Ok(WebSocketResponse::AggTrade(trade)) => trade.aggregate_trade_id,
+                    _ => 0,
+                },
+            )
+            .collect();
+
+        assert_eq!(ids, vec![1, 2, 3]);
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    async fn fill_buffer(
+        mut stream: Receiver<Result<WebSocketResponse, BinanceError>>,
+        count: usize,
+    ) -> Vec<WebSocketResponse> {
+        let mut buffer = Vec::new();
+
+        while buffer.len() < count && stream.changed().await.is_ok() {
+            if let Ok(response) = &*stream.borrow()
+                && !matches!(response, WebSocketResponse::Init)
+            {
+                buffer.push(response.clone());
+            }
+        }
+
+        buffer
+    }
+}
@@ -0,0 +1,447 @@
+#[cfg(test)]
+mod spot_mock_integration_tests {
+    use binance_common::{
+        enums::{
+            Interval,
+            spot::{OrderResponseType, OrderSide},
+        },
+        error::{ApiError, BinanceError, ErrorCode},
+        spot::{
+            endpoint::route::{Market, Trade},
+            model::{
+                params::{
+                    account::InfoParams,
+                    general::ExchangeInformationParams,
+                    market::{HistoricalTradesParams, KlinesParams, Ticker24hParams},
+                    trade::NewOrderParams,
+                },
+                response::{
+                    account::InfoResponse,
+                    general::ExchangeInformationResponse,
+                    market::{KlinesResponse, PriceTickerResponse, Ticker24hMiniResponse},
+                    trade::{AckResponse, NewOrderResponse},
+                },
+            },
+        },
+    };
+    use binance_core::{
+        client::synchronous::Client,
+        endpoint::{self, Endpoint},
+        signer::{ed25519::Ed25519Dalek, hmacsha256::HmacSha256, signature::Signature},
+        transport::{Request, Response, synchronous::Transport},
+    };
+    use binance_mock::{
+        credentials::Credentials,
+        failure::Failure,
+        fixture::Security,
+        request::{MockRequest, MockResponse},
+        server::MockServer,
+        transport::MockTransport,
+    };
+    use binance_spot::{
+        endpoint::market::{Depth, HistoricalTrades},
+        synchronous::{
+            account::AccountApi, general::GeneralApi, market::MarketApi, trade::TradeApi,
+        },
+    };
+    use ed25519_dalek::{
+        SigningKey,
+        pkcs8::{EncodePrivateKey, EncodePublicKey, spki::der::pem::LineEnding},
+    };
+    use hyper::Method;
+    use std::{cell::RefCell, rc::Rc, sync::OnceLock, time::Duration};
+
+    static API_KEY: &str = "api_key";
+    static SECRET_KEY: &str = "secret_key";
+    static ED25519_API_KEY: &str = "ed25519_api_key";
+
+    struct RecordingTransport {
+        inner: MockTransport,
+        sent: Rc<RefCell<Vec<(Method, String)>>>,
+    }
+
+    impl Transport for RecordingTransport {
+        fn send(&self, request: Request) -> Result<Response, BinanceError> {
+            self.sent
+                .borrow_mut()
+                .push((request.method.clone(), request.path().to_string()));
+            self.inner.send(request)
+        }
+    }
+
+    static ED25519_KEYS: OnceLock<(String, String)> = OnceLock::new();
+    static SERVER: OnceLock<MockServer> = OnceLock::new();
+
+    fn ed25519_keys() -> &'static (String, String) {
+        ED25519_KEYS.get_or_init(|| {
+            let signing_key = SigningKey::generate(&mut rand::rngs::OsRng);
+
+            (
+                signing_key
+                    .to_pkcs8_pem(LineEnding::LF)
+                    .unwrap()
+                    .to_string(),
+                signing_key
+                    .verifying_key()
+                    .to_public_key_pem(LineEnding::LF)
+                    .unwrap(),
+            )
+        })
+    }
+
+    fn shared_test_server() -> &'static MockServer {
+        SERVER.get_or_init(|| {
+            MockServer::builder()
+                .credentials(API_KEY, Credentials::hmac_sha256(SECRET_KEY))
+                .credentials(
+                    ED25519_API_KEY,
+                    Credentials::ed25519(&ed25519_keys().1).unwrap(),
+                )
+                .start()
+                .unwrap()
+        })
+    }
+
+    #[test]
+    fn test_ping() {
+        let general_api = GeneralApi::new(Client::new(
+            shared_test_server(),
+            HmacSha256::new(API_KEY, SECRET_KEY),
+        ));
+
+        assert!(general_api.ping().is_ok());
+    }
+
+    #[test]
+    fn test_get_exchange_info_for_symbol() {
+        let general_api = GeneralApi::new(Client::new(
+            shared_test_server(),
+            HmacSha256::new(API_KEY, SECRET_KEY),
+        ));
+
+        let exchange_info: ExchangeInformationResponse = general_api
+            .get_exchange_info(&ExchangeInformationParams::new().symbol("ETHUSDC"))
+            .unwrap();
+
+        assert_eq!(exchange_info.symbols.len(), 1);
+        assert_eq!(exchange_info.symbols[0].symbol, "ETHUSDC");
+    }
+
+    #[test]
+    fn test_get_klines_respects_limit() {
+        let market_api = MarketApi::new(Client::new(
+            shared_test_server(),
+            HmacSha256::new(API_KEY, SECRET_KEY),
+        ));
+
+        let params = KlinesParams::new("BTCUSDC", &Interval::Minutes1).limit(3);
+
+        let klines: Vec<KlinesResponse> = market_api.get_klines(&params).unwrap();
+
+        assert_eq!(klines.len(), 3);
+        assert!(
+            klines
+                .iter()
+                .all(|kline| kline.close_time > kline.open_time)
+        );
+    }
+
+    #[test]
+    fn test_get_ticker24h_mini_list() {
+        let market_api = MarketApi::new(Client::new(
+            shared_test_server(),
+            HmacSha256::new(API_KEY, SECRET_KEY),
+        ));
+
+        let params = Ticker24hParams::new()
+            .symbols(["btcusdc", "ETHUSDC"])
+            .r#type("MINI");
+
+        let tickers: Vec<Ticker24hMiniResponse> =
+            market_api.get_ticker24h_mini_list(&params).unwrap();
+
+        assert_eq!(tickers.len(), 2);
+        assert!(tickers.iter().any(|ticker| ticker.symbol == "ETHUSDC"));
+    }
+
+    #[test]
+    fn test_price_ticker_list_is_batched() {
+        let server = MockServer::builder()
+            .respond_with(
+                Method::GET,
+                Market::TickerPrice,
+                Security::None,
+                |request: &MockRequest| {
+                    let symbols: Vec<String> =
+                        serde_json::from_str(request.param("symbols").unwrap()).unwrap();
+
+                    MockResponse::ok(
+                        serde_json::Value::from_iter(symbols.iter().map(
+                            |symbol| serde_json::json!({ "symbol": symbol, "price": "1.00000000" }),
+                        ))
+                        .to_string(),
+                    )
+                },
+            )
+            .start()
+            .unwrap();
+        let market_api = MarketApi::new(Client::new(&server, HmacSha256::new(API_KEY, SECRET_KEY)));
+
+        let symbols: Vec<String> = (0..300).map(|index| format!("coin{}usdt", index)).collect();
+        let tickers: Vec<PriceTickerResponse> = market_api.get_price_ticker_list(&symbols).unwrap();
+
+        assert_eq!(tickers.len(), 300);
+        assert_eq!(tickers[0].symbol, "COIN0USDT");
+        assert_eq!(tickers[299].symbol, "COIN299USDT");
+
+        let requests = server.requests_to(Market::TickerPrice);
+
+        assert_eq!(requests.len(), 2);
+        assert!(
+            requests[0]
+                .query
+                .starts_with("symbols=%5B%22COIN0USDT%22%2C%22COIN1USDT%22")
+        );
+    }
+
+    #[test]
+    fn test_get_price_ticker_unknown_symbol() {
+        let market_api = MarketApi::new(Client::new(
+            shared_test_server(),
+            HmacSha256::new(API_KEY, SECRET_KEY),
+        ));
+
+        let ticker: PriceTickerResponse = market_api.get_price_ticker("BTCUSDC").unwrap();
+
+        assert_eq!(ticker.symbol, "BTCUSDC");
+        assert!(matches!(
+            market_api.get_price_ticker("NOTASYMBOL"),
+            Err(BinanceError::Api(ApiError { code: -1121, .. }))
+        ));
+    }
+
+    #[test]
+    fn test_endpoint_descriptors() {
+        let client = Client::new(shared_test_server(), HmacSha256::new(API_KEY, SECRET_KEY));
+
+        assert_eq!(HistoricalTrades::SECURITY, endpoint::Security::ApiKey);
+        assert_eq!(Depth::WEIGHT, 5);
+
+        // Sent with the API key header the mock requires, unsigned.
+        let trades = client
+            .call::<HistoricalTrades>(&HistoricalTradesParams::new("BTCUSDC").limit(5))
+            .unwrap();
+        let market_api = MarketApi::new(client);
+
+        assert!(!trades.is_empty());
+        assert!(
+            market_api
+                .get_historical_trades(&HistoricalTradesParams::new("BTCUSDC"))
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_send_new_order_result() {
+        let server = shared_test_server();
+        let trade_api = TradeApi::new(Client::new(server, HmacSha256::new(API_KEY, SECRET_KEY)));
+
+        let params = NewOrderParams::limit("BTCUSDC", OrderSide::Buy, 100000.0, 0.0001)
+            .new_order_resp_type(OrderResponseType::Result);
+
+        let response = trade_api.send_new_order(&params).unwrap();
+
+        assert!(matches!(response, NewOrderResponse::Result(_)));
+        assert!(server.requests_to(Trade::NewOrder).iter().any(|request| {
+            request.param("newOrderRespType") == Some("RESULT")
+                && request.param("signature").is_some()
+        }));
+    }
+
+    #[test]
+    fn test_get_info_with_ed25519() {
+        let account_api = AccountApi::new(Client::new(
+            shared_test_server(),
+            Ed25519Dalek::new(ED25519_API_KEY.to_string(), &ed25519_keys().0).unwrap(),
+        ));
+
+        let info: InfoResponse = account_api.get_info(&InfoParams::new()).unwrap();
+
+        assert!(info.can_trade);
+        assert!(info.balances.iter().any(|balance| balance.asset == "BTC"));
+    }
+
+    #[test]
+    fn test_ed25519_signature_is_url_encoded() {
+        let (private_key, public_key) = ed25519_keys();
+        let signer = Ed25519Dalek::new(ED25519_API_KEY.to_string(), private_key).unwrap();
+        let credentials = Credentials::ed25519(public_key).unwrap();
+
+        for nonce in 0..64 {
+            let payload = format!("symbol=BTCUSDC&nonce={}", nonce);
+            let pair = signer.signature(&payload).unwrap();
+            let encoded = pair.strip_prefix("signature=").unwrap();
+            let decoded: Vec<(String, String)> = serde_urlencoded::from_str(&pair).unwrap();
+
+            assert!(!encoded.contains(['+', '/', '=']));
+            assert!(credentials.verify(&payload, &decoded[0].1));
+        }
+    }
+
+    #[test]
+    fn test_invalid_signature() {
+        let account_api = AccountApi::new(Client::new(
+            shared_test_server(),
+            HmacSha256::new(API_KEY, "wrong_secret_key"),
+        ));
+
+        assert!(matches!(
+            account_api.get_info(&InfoParams::new()),
+            Err(BinanceError::Api(ApiError { code: -1022, .. }))
+        ));
+    }
+
+    #[test]
+    fn test_unknown_api_key() {
+        let account_api = AccountApi::new(Client::new(
+            shared_test_server(),
+            HmacSha256::new("unknown_api_key", SECRET_KEY),
+        ));
+
+        assert!(matches!(
+            account_api.get_info(&InfoParams::new()),
+            Err(BinanceError::Api(ApiError { code: -2015, .. }))
+        ));
+    }
+
+    #[test]
+    fn test_injected_failures() {
+        let server = MockServer::start().unwrap();
+        let market_api = MarketApi::new(Client::new(&server, HmacSha256::new(API_KEY, SECRET_KEY)));
+
+        server.fail(
+            Method::GET,
+            Market::TickerPrice,
+            Failure::TooManyRequests { retry_after: 1 },
+        );
+        server.fail(
+            Method::GET,
+            Market::TickerPrice,
+            Failure::IpBanned {
+                until: 1760000060000,
+            },
+        );
+        server.fail(Method::GET, Market::TickerPrice, Failure::InternalServer);
+        server.fail(
+            Method::GET,
+            Market::TickerPrice,
+            Failure::Unauthorized {
+                code: -2015,
+                msg: "Invalid API-key, IP, or permissions for action.".to_string(),
+            },
+        );
+
+        let error = market_api.get_price_ticker("BTCUSDC").unwrap_err();
+        let context = error.context().unwrap();
+
+        assert!(matches!(error, BinanceError::TooManyRequest(_)));
+        assert!(error.is_rate_limit() && error.is_retryable() && !error.is_auth());
+        assert_eq!(context.route, "/api/v3/ticker/price");
+        assert_eq!(context.status, 429);
+        assert_eq!(context.header("retry-after"), Some("1"));
+        assert_eq!(error.retry_after(), Some(Duration::from_secs(1)));
+
+        let error = market_api.get_price_ticker("BTCUSDC").unwrap_err();
+        assert!(matches!(error, BinanceError::IpBanned(_)));
+        assert!(error.is_rate_limit() && !error.is_retryable());
+
+        let error = market_api.get_price_ticker("BTCUSDC").unwrap_err();
+        assert!(matches!(error, BinanceError::InternalServer(_)));
+        assert!(error.is_retryable() && !error.is_rate_limit());
+
+        let error = market_api.get_price_ticker("BTCUSDC").unwrap_err();
+        assert_eq!(error.code(), Some(ErrorCode::RejectedApiKey));
+        assert!(error.is_auth() && !error.is_retryable());
+        assert_eq!(error.context().unwrap().status, 401);
+        assert!(std::error::Error::source(&error).is_none());
+
+        assert!(market_api.get_price_ticker("BTCUSDC").is_ok());
+    }
+
+    #[test]
+    fn test_unknown_outcome_is_not_retryable() {
+        let server = MockServer::start().unwrap();
+        let market_api = MarketApi::new(Client::new(&server, HmacSha256::new(API_KEY, SECRET_KEY)));
+
+        for (code, msg) in [
+            (
+                -1000,
+                "An unknown error occurred while processing the request.",
+            ),
+            (-1007, "Timeout waiting for response from backend server."),
+        ] {
+            server.fail(
+                Method::GET,
+                Market::TickerPrice,
+                Failure::bad_request(code, msg),
+            );
+
+            let error = market_api.get_price_ticker("BTCUSDC").unwrap_err();
+            assert!(error.is_unknown_outcome() && !error.is_retryable());
+        }
+
+        server.fail(Method::GET, Market::TickerPrice, Failure::InternalServer);
+
+        let error = market_api.get_price_ticker("BTCUSDC").unwrap_err();
+        assert!(error.is_unknown_outcome() && error.is_retryable());
+
+        let error = BinanceError::from(hmac::digest::InvalidLength);
+        assert!(std::error::Error::source(&error).is_some());
+    }
+
+    #[test]
+    fn test_custom_transport() {
+        let server = shared_test_server();
+        let sent = Rc::new(RefCell::new(Vec::new()));
+        let transport = RecordingTransport {
+            inner: server.transport(),
+            sent: Rc::clone(&sent),
+        };
+        let account_api = AccountApi::new(Client::with_transport(
+            server,
+            HmacSha256::new(API_KEY, SECRET_KEY),
+            transport,
+        ));
+
+        let info: InfoResponse = account_api.get_info(&InfoParams::new()).unwrap();
+
+        assert!(info.can_trade);
+        assert_eq!(
+            *sent.borrow(),
+            vec![(Method::GET, "/api/v3/account".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_send_form_with_ed25519() {
+        let server = shared_test_server();
+        let client = Client::new(
+            server,
+            Ed25519Dalek::new(ED25519_API_KEY.to_string(), &ed25519_keys().0).unwrap(),
+        );
+        let params = NewOrderParams::limit("BTCUSDC", OrderSide::Sell, 120000.0, 0.0001)
+            .new_client_order_id("form_body_order");
+
+        let order: AckResponse = client
+            .send_form(Trade::NewOrder, &params, Method::POST)
+            .unwrap();
+
+        assert!(order.order_id > 0);
+        assert!(server.requests_to(Trade::NewOrder).iter().any(|request| {
+            request.query.is_empty()
+                && request.body.starts_with("symbol=BTCUSDC")
+                && request.param("newClientOrderId") == Some("form_body_order")
+                && request.param("signature").is_some()
+        }));
+    }
+}
@@ -0,0 +1,192 @@
+#[cfg(test)]
+mod sub_account_mock_integration_tests {
+    use binance_common::{
+        enums::sub_account::{AccountType, FuturesType},
+        sub_account::{
+            endpoint::route::{Account, ApiKey, Transfer},
+            model::{
+                params::{
+                    account::{
+                        CreateSubAccountParams, EmailParams, FuturesAccountParams,
+                        SubAccountListParams,
+                    },
+                    api_key::{AddIpRestrictionParams, IpRestrictionParams},
+                    transfer::{TransferHistoryParams, UniversalTransferParams},
+                },
+                response::account::FuturesAccountResponse,
+            },
+        },
+    };
+    use binance_core::{client::synchronous::Client, signer::hmacsha256::HmacSha256};
+    use binance_mock::{credentials::Credentials, server::MockServer};
+    use binance_sub_account::synchronous::{
+        account::AccountApi, api_key::ApiKeyApi, transfer::TransferApi,
+    };
+    use std::sync::OnceLock;
+
+    static API_KEY: &str = "api_key";
+    static SECRET_KEY: &str = "secret_key";
+    static EMAIL: &str = "momentum_virtual@aasaixwqnoemail.com";
+
+    static SERVER: OnceLock<MockServer> = OnceLock::new();
+
+    fn shared_test_server() -> &'static MockServer {
+        SERVER.get_or_init(|| {
+            MockServer::builder()
+                .credentials(API_KEY, Credentials::hmac_sha256(SECRET_KEY))
+                .start()
+                .unwrap()
+        })
+    }
+
+    #[test]
+    fn test_list_and_create_sub_accounts() {
+        let server = shared_test_server();
+        let account_api =
+            AccountApi::new(Client::new(server, HmacSha256::new(API_KEY, SECRET_KEY)));
+
+        let sub_accounts = account_api
+            .get_sub_accounts(&SubAccountListParams::new().is_freeze(false).limit(200))
+            .unwrap();
+        let created = account_api
+            .create_sub_account(&CreateSubAccountParams::new("addsdd"))
+            .unwrap();
+
+        assert_eq!(sub_accounts.sub_accounts.len(), 2);
+        assert!(created.email.starts_with("addsdd"));
+        assert!(server.requests_to(Account::List).iter().any(|request| {
+            request.param("isFreeze") == Some("false") && request.param("signature").is_some()
+        }));
+    }
+
+    #[test]
+    fn test_sub_account_balances() {
+        let server = shared_test_server();
+        let account_api =
+            AccountApi::new(Client::new(server, HmacSha256::new(API_KEY, SECRET_KEY)));
+
+        let spot = account_api
+            .get_spot_assets(&EmailParams::new(EMAIL))
+            .unwrap();
+        let futures = account_api
+            .get_futures_account(&FuturesAccountParams::new(EMAIL, FuturesType::UsdM))
+            .unwrap();
+
+        assert!(
+            spot.balances
+                .iter()
+                .any(|balance| balance.asset == "USDT" && balance.locked == 120.0)
+        );
+        assert_eq!(futures.account.total_wallet_balance, Some(0.88308));
+        assert!(
+            server
+                .requests_to(Account::FuturesAccount)
+                .iter()
+                .any(|request| {
+                    request.param("futuresType") == Some("1")
+                        && request.param("email") == Some(EMAIL)
+                })
+        );
+    }
+
+    #[test]
+    fn test_coin_m_futures_account_alias() {
+        let body = include_str!("../fixtures/sub_account/account/futures_account.json")
+            .replace("futureAccountResp", "deliveryAccountResp");
+
+        let response: FuturesAccountResponse = serde_json::from_str(&body).unwrap();
+
+        assert_eq!(response.account.email, EMAIL);
+    }
+
+    #[test]
+    fn test_enable_futures() {
+        let account_api = AccountApi::new(Client::new(
+            shared_test_server(),
+            HmacSha256::new(API_KEY, SECRET_KEY),
+        ));
+
+        let response = account_api
+            .enable_futures(&EmailParams::new(EMAIL))
+            .unwrap();
+
+        assert!(response.is_futures_enabled);
+    }
+
+    #[test]
+    fn test_master_to_sub_account_transfer() {
+        let server = shared_test_server();
+        let transfer_api =
+            TransferApi::new(Client::new(server, HmacSha256::new(API_KEY, SECRET_KEY)));
+
+        let params = UniversalTransferParams::new(
+            AccountType::Spot,
+            AccountType::UsdtFuture,
+            "USDT",
+            1000.0,
+        )
+        .to_email(EMAIL)
+        .client_tran_id("rebalance-42");
+
+        let transfer = transfer_api.universal_transfer(&params).unwrap();
+        let history = transfer_api
+            .get_transfer_history(&TransferHistoryParams::new().to_email(EMAIL))
+            .unwrap();
+
+        assert_eq!(transfer.tran_id, 11945860693);
+        assert_eq!(history.result[0].from_account_type, AccountType::UsdtFuture);
+        assert!(
+            server
+                .requests_to(Transfer::UniversalTransfer)
+                .iter()
+                .any(|request| {
+                    request.param("fromEmail").is_none()
+                        && request.param("toEmail") == Some(EMAIL)
+                        && request.param("fromAccountType") == Some("SPOT")
+                        && request.param("toAccountType") == Some("USDT_FUTURE")
+                        && request.param("clientTranId") == Some("rebalance-42")
+                })
+        );
+    }
+
+    #[test]
+    fn test_manage_api_key_ip_restriction() {
+        let server = shared_test_server();
+        let api_key_api = ApiKeyApi::new(Client::new(server, HmacSha256::new(API_KEY, SECRET_KEY)));
+        let sub_account_api_key = "k5V49ldtn4tszj6W3hystegdfvmGbqDzjmkCtpTvC0G74WhK7yd4rfCTo4lShf";
+
+        let added = api_key_api
+            .add_ip_restriction(&AddIpRestrictionParams::restricted(
+                EMAIL,
+                sub_account_api_key,
+                ["69.210.67.14", "8.34.21.10"],
+            ))
+            .unwrap();
+        let queried = api_key_api
+            .get_ip_restriction(&IpRestrictionParams::new(EMAIL, sub_account_api_key))
+            .unwrap();
+        api_key_api
+            .delete_ip_restriction(
+                &IpRestrictionParams::new(EMAIL, sub_account_api_key).ip_addresses(["8.34.21.10"]),
+            )
+            .unwrap();
+
+        assert!(added.ip_restrict);
+        assert_eq!(queried.ip_list.len(), 2);
+        assert!(
+            server
+                .requests_to(ApiKey::AddIpRestriction)
+                .iter()
+                .any(|request| {
+                    request.param("status") == Some("2")
+                        && request.param("ipAddress") == Some("69.210.67.14,8.34.21.10")
+                })
+        );
+        assert!(
+            server
+                .requests_to(ApiKey::DeleteIpRestriction)
+                .iter()
+                .any(|request| request.param("ipAddress") == Some("8.34.21.10"))
+        );
+    }
+}
@@ -0,0 +1,242 @@
+#[cfg(test)]
+mod wallet_mock_integration_tests {
+    use binance_common::{
+        enums::wallet::UniversalTransferType,
+        wallet::{
+            endpoint::route::{Asset, Capital},
+            model::params::{
+                RecvWindowParams,
+                asset::{
+                    AssetDetailParams, DustParams, TradeFeeParams, TransferHistoryParams,
+                    UniversalTransferParams,
+                },
+                capital::{DepositHistoryParams, WithdrawHistoryParams, WithdrawParams},
+            },
+        },
+    };
+    use binance_core::{
+        client::{asynchronous, synchronous::Client},
+        signer::hmacsha256::HmacSha256,
+    };
+    use binance_mock::{
+        credentials::Credentials, fixture::Security, request::MockResponse, server::MockServer,
+    };
+    use binance_wallet::synchronous::{account::AccountApi, asset::AssetApi, capital::CapitalApi};
+    use hyper::Method;
+    use std::sync::OnceLock;
+
+    static API_KEY: &str = "api_key";
+    static SECRET_KEY: &str = "secret_key";
+
+    static SERVER: OnceLock<MockServer> = OnceLock::new();
+
+    fn shared_test_server() -> &'static MockServer {
+        SERVER.get_or_init(|| {
+            MockServer::builder()
+                .credentials(API_KEY, Credentials::hmac_sha256(SECRET_KEY))
+                .start()
+                .unwrap()
+        })
+    }
+
+    #[test]
+    fn test_get_coin_info() {
+        let capital_api = CapitalApi::new(Client::new(
+            shared_test_server(),
+            HmacSha256::new(API_KEY, SECRET_KEY),
+        ));
+
+        let coins = capital_api.get_coin_info(&RecvWindowParams::new()).unwrap();
+        let btc = coins.iter().find(|coin| coin.coin == "BTC").unwrap();
+
+        assert!(
+            btc.network_list
+                .iter()
+                .any(|network| network.is_default && network.network == "BTC")
+        );
+    }
+
+    #[test]
+    fn test_deposit_and_withdraw_history() {
+        let capital_api = CapitalApi::new(Client::new(
+            shared_test_server(),
+            HmacSha256::new(API_KEY, SECRET_KEY),
+        ));
+
+        let deposits = capital_api
+            .get_deposit_history(&DepositHistoryParams::new().coin("BNB").status(1))
+            .unwrap();
+        let withdrawals = capital_api
+            .get_withdraw_history(
+                &WithdrawHistoryParams::new().id_list(["b6ae22b3aa844210a7041aee7589627c"]),
+            )
+            .unwrap();
+
+        assert_eq!(deposits[0].amount, 0.001);
+        assert_eq!(withdrawals[0].status, 6);
+        assert_eq!(
+            withdrawals[0].withdraw_order_id.as_deref(),
+            Some("WITHDRAWtest123")
+        );
+    }
+
+    #[test]
+    fn test_withdraw_is_signed_post() {
+        let server = shared_test_server();
+        let capital_api =
+            CapitalApi::new(Client::new(server, HmacSha256::new(API_KEY, SECRET_KEY)));
+
+        let params =
+            WithdrawParams::new("USDT", "0x94df8b352de7f46f64b01d3666bf6e936e44ce60", 8.91)
+                .network("ETH")
+                .withdraw_order_id("rebalance-1");
+
+        let response = capital_api.withdraw(&params).unwrap();
+
+        assert_eq!(response.id, "7213fea8e94b4a5593d507237e5a555b");
+        assert!(server.requests_to(Capital::Withdraw).iter().any(|request| {
+            request.method == Method::POST
+                && request.param("withdrawOrderId") == Some("rebalance-1")
+                && request.param("signature").is_some()
+        }));
+    }
+
+    #[tokio::test]
+    async fn test_async_universal_transfer() {
+        let server = shared_test_server();
+        let client = || asynchronous::Client::new(server, HmacSha256::new(API_KEY, SECRET_KEY));
+        let asset_api = binance_wallet::asynchronous::asset::AssetApi::new(client());
+        let capital_api = binance_wallet::asynchronous::capital::CapitalApi::new(client());
+        let account_api = binance_wallet::asynchronous::account::AccountApi::new(client());
+
+        let transfer = asset_api
+            .universal_transfer(&UniversalTransferParams::new(
+                UniversalTransferType::SpotToUsdMFutures,
+                "USDT",
+                10.0,
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(transfer.tran_id, 13526853623);
+        assert!(
+            !capital_api
+                .get_coin_info(&RecvWindowParams::new())
+                .await
+                .unwrap()
+                .is_empty()
+        );
+        assert!(
+            account_api
+                .get_api_restrictions(&RecvWindowParams::new())
+                .await
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_universal_transfer_between_spot_and_futures() {
+        let server = shared_test_server();
+        let asset_api = AssetApi::new(Client::new(server, HmacSha256::new(API_KEY, SECRET_KEY)));
+
+        let transfer = asset_api
+            .universal_transfer(&UniversalTransferParams::new(
+                UniversalTransferType::UsdMFuturesToSpot,
+                "USDT",
+                250.0,
+            ))
+            .unwrap();
+        let history = asset_api
+            .get_transfer_history(&TransferHistoryParams::new(
+                UniversalTransferType::SpotToUsdMFutures,
+            ))
+            .unwrap();
+
+        assert_eq!(transfer.tran_id, 13526853623);
+        assert!(
+            server
+                .requests_to(Asset::UniversalTransfer)
+                .iter()
+                .any(|request| {
+                    request.method == Method::POST
+                        && request.param("type") == Some("UMFUTURE_MAIN")
+                        && request
+                            .param("amount")
+                            .and_then(|amount| amount.parse::<f64>().ok())
+                            == Some(250.0)
+                })
+        );
+        assert!(
+            history
+                .rows
+                .iter()
+                .all(|row| row.r#type == UniversalTransferType::SpotToUsdMFutures)
+        );
+    }
+
+    #[test]
+    fn test_empty_transfer_history() {
+        let server = MockServer::builder()
+            .credentials(API_KEY, Credentials::hmac_sha256(SECRET_KEY))
+            .respond_with(
+                Method::GET,
+                Asset::TransferHistory,
+                Security::Signed,
+                |_| MockResponse::ok(r#"{"total":0}"#),
+            )
+            .start()
+            .unwrap();
+        let asset_api = AssetApi::new(Client::new(&server, HmacSha256::new(API_KEY, SECRET_KEY)));
+
+        let history = asset_api
+            .get_transfer_history(&TransferHistoryParams::new(
+                UniversalTransferType::FundingToSpot,
+            ))
+            .unwrap();
+
+        assert_eq!(history.total, 0);
+        assert!(history.rows.is_empty());
+    }
+
+    #[test]
+    fn test_dust_fees_and_asset_detail() {
+        let server = shared_test_server();
+        let asset_api = AssetApi::new(Client::new(server, HmacSha256::new(API_KEY, SECRET_KEY)));
+
+        let dust = asset_api
+            .convert_dust(&DustParams::new(["eth", "ltc"]))
+            .unwrap();
+        let fees = asset_api
+            .get_trade_fee(&TradeFeeParams::new().symbol("BNBBTC"))
+            .unwrap();
+        let details = asset_api
+            .get_asset_detail(&AssetDetailParams::new())
+            .unwrap();
+
+        assert_eq!(dust.transfer_result.len(), 2);
+        assert!(
+            server
+                .requests_to(Asset::Dust)
+                .iter()
+                .any(|request| request.param("asset") == Some("ETH,LTC"))
+        );
+        assert!(fees.iter().any(|fee| fee.symbol == "BNBBTC"));
+        assert!(!details["CTR"].deposit_status);
+        assert_eq!(details["CTR"].min_withdraw_amount, 70.0);
+    }
+
+    #[test]
+    fn test_get_api_restrictions() {
+        let account_api = AccountApi::new(Client::new(
+            shared_test_server(),
+            HmacSha256::new(API_KEY, SECRET_KEY),
+        ));
+
+        let restrictions = account_api
+            .get_api_restrictions(&RecvWindowParams::new())
+            .unwrap();
+
+        assert!(restrictions.permits_universal_transfer);
+        assert!(!restrictions.enable_withdrawals);
+    }
+}
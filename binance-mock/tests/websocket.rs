@@ -0,0 +1,393 @@
+#[cfg(test)]
+mod websocket_mock_integration_tests {
+    use binance_common::{
+        enums::{Interval, WebSocketStreamRate, WebSocketType},
+        error::BinanceError,
+        futures::endpoint::host::WebSocketHost,
+        futures::model::{
+            params::{
+                market::ExchangeInformationParams,
+                websocket::{
+                    MAX_STREAMS_PER_CONNECTION, WebSocketParams, WebSocketSymbol, WebSocketSymbols,
+                },
+            },
+            response::websocket::{WebSocketResponse, borrowed::WebSocketResponseRef},
+        },
+    };
+    use binance_core::{
+        client::asynchronous::Client,
+        connection::ConnectionConfig,
+        signer::hmacsha256::HmacSha256,
+        websocket::{
+            futures::market::{
+                shards::WebSocketMarketShards, supervisor::WebSocketMarketSupervisor,
+            },
+            supervisor::WebSocketSupervisor,
+        },
+    };
+    use binance_futures::asynchronous::market::MarketApi;
+    use binance_mock::server::MockServer;
+    use std::{
+        net::{Ipv4Addr, SocketAddr},
+        sync::Arc,
+        time::Duration,
+    };
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::{TcpListener, TcpStream},
+        sync::{mpsc, watch::Receiver},
+    };
+
+    #[tokio::test]
+    async fn test_combined_streams() {
+        let server = MockServer::builder()
+            .stream_interval(Duration::from_millis(10))
+            .start()
+            .unwrap();
+
+        let stream = WebSocketParams::new(server.websocket_host(&WebSocketType::MultiStream))
+            .kline_candlesticks(WebSocketSymbol::EthUsdt, Interval::Minutes5)
+            .agg_trade(WebSocketSymbol::SolUsdt);
+
+        let mut websocket_handler = WebSocketMarketSupervisor::new(WebSocketType::MultiStream);
+
+        websocket_handler
+            .start(stream.route().unwrap())
+            .await
+            .unwrap();
+
+        let buffer = fill_buffer(websocket_handler.watch().await.unwrap(), 10).await;
+
+        websocket_handler.stop().await.unwrap();
+
+        assert_eq!(buffer.len(), 10);
+        assert!(buffer.iter().any(|response| matches!(
+            response,
+            WebSocketResponse::Kline(kline)
+                if kline.symbol == "ETHUSDT" && kline.kline.interval == "5m"
+        )));
+        assert!(buffer.iter().any(|response| matches!(
+            response,
+            WebSocketResponse::AggTrade(trade) if trade.symbol == "SOLUSDT"
+        )));
+    }
+
+    #[tokio::test]
+    async fn test_single_stream() {
+        let server = MockServer::builder()
+            .stream_interval(Duration::from_millis(10))
+            .start()
+            .unwrap();
+
+        let stream = WebSocketParams::new(server.websocket_host(&WebSocketType::SingleStream))
+            .symbol_ticker_mini(WebSocketSymbol::BtcUsdc);
+
+        let mut websocket_handler = WebSocketMarketSupervisor::new(WebSocketType::SingleStream);
+
+        websocket_handler
+            .start(stream.route().unwrap())
+            .await
+            .unwrap();
+
+        let buffer = fill_buffer(websocket_handler.watch().await.unwrap(), 5).await;
+
+        websocket_handler.stop().await.unwrap();
+
+        assert!(buffer.iter().all(|response| matches!(
+            response,
+            WebSocketResponse::MiniTicker(ticker) if ticker.symbol == "BTCUSDC"
+        )));
+    }
+
+    #[test]
+    fn test_params_take_host_or_url() {
+        let hosted =
+            WebSocketParams::new(WebSocketHost::CombinedStreamsMarket).agg_trade("BTCUSDT");
+        let custom = WebSocketParams::new(String::from("ws://127.0.0.1:9000/stream?streams="))
+            .agg_trade("BTCUSDT");
+
+        assert_eq!(
+            hosted.route().unwrap(),
+            "wss://fstream.binance.com/market/stream?streams=btcusdt@aggTrade"
+        );
+        assert_eq!(
+            custom.route().unwrap(),
+            "ws://127.0.0.1:9000/stream?streams=btcusdt@aggTrade"
+        );
+    }
+
+    #[test]
+    fn test_routes_are_joined_and_sharded() {
+        let params = WebSocketParams::new("wss://host/stream?streams=")
+            .agg_trade(WebSocketSymbol::BtcUsdt)
+            .kline_candlesticks(" ETHusdt ", Interval::Minutes1)
+            .agg_trade("BTCUSDT")
+            .symbol_ticker_all_mini();
+
+        assert_eq!(
+            params.route().unwrap(),
+            "wss://host/stream?streams=btcusdt@aggTrade/ethusdt@kline_1m/!miniTicker@arr"
+        );
+
+        let params = params.max_streams(2);
+        assert_eq!(
+            params.routes().unwrap(),
+            vec![
+                "wss://host/stream?streams=btcusdt@aggTrade/ethusdt@kline_1m",
+                "wss://host/stream?streams=!miniTicker@arr",
+            ]
+        );
+        assert!(matches!(params.route(), Err(BinanceError::BuildRequest(_))));
+
+        let params = (0..1500).fold(WebSocketParams::new("wss://host/ws/"), |params, index| {
+            params.agg_trade(format!("coin{}usdt", index))
+        });
+        let routes = params.routes().unwrap();
+
+        assert_eq!(routes.len(), 2);
+        assert_eq!(
+            routes[0].matches("@aggTrade").count(),
+            MAX_STREAMS_PER_CONNECTION
+        );
+        assert!(routes.iter().all(|route| !route.ends_with('/')));
+        assert!(WebSocketParams::new("wss://host/ws/").routes().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_symbols_validated_against_exchange_info() {
+        let server = MockServer::start().unwrap();
+        let market_api = MarketApi::new(Client::new(&server, HmacSha256::new("key", "secret")));
+
+        let exchange_information = market_api
+            .get_exchange_info(&ExchangeInformationParams::new())
+            .await
+            .unwrap();
+        let symbols = Arc::new(WebSocketSymbols::from(&exchange_information));
+
+        assert!(symbols.contains("ethusdc"));
+
+        let params = WebSocketParams::new(server.websocket_host(&WebSocketType::MultiStream))
+            .symbols(symbols.clone())
+            .agg_trade("SolUsdt")
+            .all_book_tickers();
+        assert!(params.route().is_ok());
+
+        let error = params.agg_trade("NEWUSDT").route().unwrap_err();
+        assert!(matches!(&error, BinanceError::BuildRequest(msg) if msg.contains("newusdt")));
+    }
+
+    #[tokio::test]
+    async fn test_sharded_streams() {
+        let server = MockServer::builder()
+            .stream_interval(Duration::from_millis(10))
+            .start()
+            .unwrap();
+
+        let params = WebSocketParams::new(server.websocket_host(&WebSocketType::MultiStream))
+            .max_streams(1)
+            .agg_trade("BTCUSDT")
+            .symbol_ticker_mini("ethusdt");
+
+        let mut shards = WebSocketMarketShards::new(WebSocketType::MultiStream);
+        shards.start(&params).await.unwrap();
+
+        assert_eq!(shards.len(), 2);
+
+        let mut receivers = shards.watch().await.unwrap().into_iter();
+        let agg_trades = fill_buffer(receivers.next().unwrap(), 3).await;
+        let mini_tickers = fill_buffer(receivers.next().unwrap(), 3).await;
+
+        shards.stop().await.unwrap();
+
+        assert!(shards.is_empty());
+        assert!(agg_trades.iter().all(|response| matches!(
+            response,
+            WebSocketResponse::AggTrade(trade) if trade.symbol == "BTCUSDT"
+        )));
+        assert!(mini_tickers.iter().all(|response| matches!(
+            response,
+            WebSocketResponse::MiniTicker(ticker) if ticker.symbol == "ETHUSDT"
+        )));
+    }
+
+    #[tokio::test]
+    async fn test_connection_config_through_proxy() {
+        let server = MockServer::builder()
+            .stream_interval(Duration::from_millis(10))
+            .start()
+            .unwrap();
+        let (tx_connects, mut rx_connects) = mpsc::unbounded_channel();
+        let proxy = connect_proxy(tx_connects).await;
+
+        let params = WebSocketParams::new(server.websocket_host(&WebSocketType::MultiStream))
+            .max_streams(1)
+            .agg_trade("BTCUSDT")
+            .symbol_ticker_mini("ethusdt");
+
+        let mut shards = WebSocketMarketShards::new(WebSocketType::MultiStream);
+        shards.configure(
+            ConnectionConfig::new()
+                .proxy(format!("http://{}", proxy))
+                .local_address(Ipv4Addr::LOCALHOST.into())
+                .connect_timeout(Duration::from_secs(1))
+                .tcp_keepalive(Duration::from_secs(30))
+                .user_agent("binance-rs-colo"),
+        );
+        shards.start(&params).await.unwrap();
+
+        let mut receivers = shards.watch().await.unwrap().into_iter();
+        let agg_trades = fill_buffer(receivers.next().unwrap(), 3).await;
+
+        shards.stop().await.unwrap();
+
+        assert_eq!(agg_trades.len(), 3);
+        for _ in 0..2 {
+            assert!(
+                rx_connects
+                    .recv()
+                    .await
+                    .unwrap()
+                    .starts_with("CONNECT 127.0.0.1:")
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_proxy_handshake_is_bounded_and_sends_decoded_credentials() {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).await.unwrap();
+        let proxy = listener.local_addr().unwrap();
+        let (tx_head, mut rx_head) = mpsc::unbounded_channel();
+
+        // Reads the CONNECT and never answers it.
+        tokio::spawn(async move {
+            let (mut client, _) = listener.accept().await.unwrap();
+            let mut head = Vec::new();
+
+            while !head.ends_with(b"\r\n\r\n") {
+                head.push(client.read_u8().await.unwrap());
+            }
+
+            tx_head.send(String::from_utf8(head).unwrap()).unwrap();
+            tokio::time::sleep(Duration::from_secs(10)).await;
+        });
+
+        let config = ConnectionConfig::new()
+            .proxy(format!("http://us%40er:p%3Ass@{}", proxy))
+            .connect_timeout(Duration::from_millis(200));
+        let connect = config.connect_websocket("ws://127.0.0.1:9/ws");
+
+        assert!(
+            tokio::time::timeout(Duration::from_secs(2), connect)
+                .await
+                .unwrap()
+                .is_err()
+        );
+        assert!(
+            rx_head
+                .recv()
+                .await
+                .unwrap()
+                .contains("Proxy-Authorization: Basic dXNAZXI6cDpzcw==\r\n")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_raw_frames_decode_borrowed() {
+        let server = MockServer::builder()
+            .stream_interval(Duration::from_millis(10))
+            .start()
+            .unwrap();
+
+        let params = WebSocketParams::new(server.websocket_host(&WebSocketType::MultiStream))
+            .agg_trade("BTCUSDT")
+            .mark_price("ETHUSDT", WebSocketStreamRate::Seconds1);
+
+        let mut websocket_handler = WebSocketMarketSupervisor::new(WebSocketType::MultiStream);
+        let mut frames = websocket_handler.frames(16).unwrap();
+
+        websocket_handler
+            .start(params.route().unwrap())
+            .await
+            .unwrap();
+
+        let mut symbols = Vec::new();
+
+        while symbols.len() < 6 {
+            let frame = frames.recv().await.unwrap();
+
+            match WebSocketResponseRef::decode(frame.as_str(), WebSocketType::MultiStream).unwrap()
+            {
+                WebSocketResponseRef::AggTrade(trade) => {
+                    assert!(trade.price.to_f64().unwrap() > 0.0);
+                    symbols.push(trade.symbol.to_string());
+                }
+                WebSocketResponseRef::MarkPrice(mark_price) => {
+                    symbols.push(mark_price.symbol.to_string())
+                }
+                response => panic!("unexpected response: {:?}", response),
+            }
+        }
+
+        websocket_handler.stop().await.unwrap();
+
+        assert!(frames.is_closed());
+        assert!(symbols.iter().any(|symbol| symbol == "BTCUSDT"));
+        assert!(symbols.iter().any(|symbol| symbol == "ETHUSDT"));
+    }
+
+    /// An HTTP proxy tunnelling every `CONNECT`, whose request lines are sent to `tx_connects`.
+    async fn connect_proxy(tx_connects: mpsc::UnboundedSender<String>) -> SocketAddr {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).await.unwrap();
+        let address = listener.local_addr().unwrap();
+
+        tokio::spawn(async move {
+            while let Ok((mut client, _)) = listener.accept().await {
+                let tx_connects = tx_connects.clone();
+
+                tokio::spawn(async move {
+                    let mut head = Vec::new();
+
+                    while !head.ends_with(b"\r\n\r\n") {
+                        head.push(client.read_u8().await.unwrap());
+                    }
+
+                    let head = String::from_utf8(head).unwrap();
+                    let target = head.split(' ').nth(1).unwrap().to_string();
+                    let mut upstream = TcpStream::connect(target).await.unwrap();
+
+                    tx_connects
+                        .send(head.lines().next().unwrap().to_string())
+                        .unwrap();
+                    client
+                        .write_all(b"HTTP/1.1 200 Connection established\r\n\r\n")
+                        .await
+                        .unwrap();
+                    let _ = tokio::io::copy_bidirectional(&mut client, &mut upstream).await;
+                });
+            }
+        });
+
+        address
+    }
+
+    async fn fill_buffer(
+        mut stream: Receiver<Result<WebSocketResponse, BinanceError>>,
+        count: usize,
+    ) -> Vec<WebSocketResponse> {
+        tokio::spawn(async move {
+            let mut buffer = Vec::new();
+
+            while buffer.len() < count && stream.changed().await.is_ok() {
+                if let Ok(response) = &*stream.borrow()
+                    && !matches!(response, WebSocketResponse::Init)
+                {
+                    buffer.push(response.clone());
+                }
+            }
+
+            buffer
+        })
+        .await
+        .unwrap()
+    }
+}
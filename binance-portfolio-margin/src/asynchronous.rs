@@ -0,0 +1,4 @@
+pub mod account;
+pub mod cm;
+pub mod stream;
+pub mod um;
@@ -0,0 +1,77 @@
+use binance_common::error::BinanceError;
+use binance_common::portfolio_margin::{
+    endpoint::route::Account,
+    model::{
+        params::account::{AccountParams, AssetCollectionParams},
+        response::account::{AccountResponse, BalanceResponse, MessageResponse},
+    },
+};
+use reqwest::Method;
+
+use binance_core::transport::asynchronous::Transport;
+use binance_core::{client::asynchronous::Client, signer::signature::Signature};
+
+pub struct AccountApi<'a, S, T = reqwest::Client>
+where
+    S: Signature<'a>,
+    T: Transport,
+{
+    client: Client<'a, S, T>,
+}
+
+impl<'a, S, T> AccountApi<'a, S, T>
+where
+    S: Signature<'a>,
+    T: Transport,
+{
+    pub fn new(client: Client<'a, S, T>) -> Self {
+        AccountApi { client }
+    }
+
+    pub async fn get_balances(
+        &self,
+        params: &AccountParams,
+    ) -> Result<Vec<BalanceResponse>, BinanceError> {
+        self.client
+            .send(Account::Balance, params, Method::GET)
+            .await
+    }
+
+    pub async fn get_account(
+        &self,
+        params: &AccountParams,
+    ) -> Result<AccountResponse, BinanceError> {
+        self.client
+            .send(Account::Account, params, Method::GET)
+            .await
+    }
+
+    /// Sweeps every asset from the UM and CM wallets back into the margin wallet.
+    pub async fn auto_collection(
+        &self,
+        params: &AccountParams,
+    ) -> Result<MessageResponse, BinanceError> {
+        self.client
+            .send(Account::AutoCollection, params, Method::POST)
+            .await
+    }
+
+    pub async fn asset_collection(
+        &self,
+        params: &AssetCollectionParams<'a>,
+    ) -> Result<MessageResponse, BinanceError> {
+        self.client
+            .send(Account::AssetCollection, params, Method::POST)
+            .await
+    }
+
+    /// Repays negative UM and CM balances with the margin wallet.
+    pub async fn repay_futures_negative_balance(
+        &self,
+        params: &AccountParams,
+    ) -> Result<MessageResponse, BinanceError> {
+        self.client
+            .send(Account::RepayFuturesNegativeBalance, params, Method::POST)
+            .await
+    }
+}
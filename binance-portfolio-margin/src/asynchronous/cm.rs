@@ -0,0 +1,81 @@
+use binance_common::error::BinanceError;
+use binance_common::portfolio_margin::{
+    endpoint::route::Cm,
+    model::{
+        params::trade::{
+            CancelAllOrdersParams, CancelOrderParams, GetOrderParams, NewOrderParams, SymbolParams,
+        },
+        response::trade::{CancelAllOrdersResponse, OrderResponse, PositionRiskResponse},
+    },
+};
+use reqwest::Method;
+
+use binance_core::transport::asynchronous::Transport;
+use binance_core::{client::asynchronous::Client, signer::signature::Signature};
+
+/// COIN-M futures traded through the portfolio margin account.
+pub struct CmApi<'a, S, T = reqwest::Client>
+where
+    S: Signature<'a>,
+    T: Transport,
+{
+    client: Client<'a, S, T>,
+}
+
+impl<'a, S, T> CmApi<'a, S, T>
+where
+    S: Signature<'a>,
+    T: Transport,
+{
+    pub fn new(client: Client<'a, S, T>) -> Self {
+        CmApi { client }
+    }
+
+    pub async fn send_new_order(
+        &self,
+        params: &NewOrderParams<'a>,
+    ) -> Result<OrderResponse, BinanceError> {
+        self.client.send(Cm::NewOrder, params, Method::POST).await
+    }
+
+    pub async fn cancel_order(
+        &self,
+        params: &CancelOrderParams<'a>,
+    ) -> Result<OrderResponse, BinanceError> {
+        self.client
+            .send(Cm::CancelOrder, params, Method::DELETE)
+            .await
+    }
+
+    pub async fn get_order(
+        &self,
+        params: &GetOrderParams<'a>,
+    ) -> Result<OrderResponse, BinanceError> {
+        self.client.send(Cm::GetOrder, params, Method::GET).await
+    }
+
+    pub async fn cancel_all_orders(
+        &self,
+        params: &CancelAllOrdersParams<'a>,
+    ) -> Result<CancelAllOrdersResponse, BinanceError> {
+        self.client
+            .send(Cm::CancelAllOrders, params, Method::DELETE)
+            .await
+    }
+
+    pub async fn get_open_orders(
+        &self,
+        params: &SymbolParams<'a>,
+    ) -> Result<Vec<OrderResponse>, BinanceError> {
+        self.client.send(Cm::OpenOrders, params, Method::GET).await
+    }
+
+    pub async fn get_position_risk(
+        &self,
+        params: &SymbolParams<'a>,
+    ) -> Result<Vec<PositionRiskResponse>, BinanceError> {
+        self.client
+            .send(Cm::PositionRisk, params, Method::GET)
+            .await
+    }
+}
@@ -0,0 +1,49 @@
+use binance_common::error::BinanceError;
+use binance_common::portfolio_margin::{
+    endpoint::{host::WebSocketHost, route::UserStream},
+    model::response::stream::ListenKeyResponse,
+};
+use binance_common::spot::model::{params::EmptyParams, response::general::EmptyResponse};
+use reqwest::Method;
+
+use binance_core::transport::synchronous::Transport;
+use binance_core::{client::synchronous::Client, signer::signature::Signature};
+
+/// Listen key lifecycle for the portfolio margin user data stream.
+pub struct UserStreamApi<'a, S, T = reqwest::blocking::Client>
+where
+    S: Signature<'a>,
+    T: Transport,
+{
+    client: Client<'a, S, T>,
+}
+
+impl<'a, S, T> UserStreamApi<'a, S, T>
+where
+    S: Signature<'a>,
+    T: Transport,
+{
+    pub fn new(client: Client<'a, S, T>) -> Self {
+        UserStreamApi { client }
+    }
+
+    pub fn start(&self) -> Result<ListenKeyResponse, BinanceError> {
+        self.client
+            .send_keyed(UserStream::ListenKey, EmptyParams, Method::POST)
+    }
+
+    /// Listen keys expire after 60 minutes unless kept alive.
+    pub fn keepalive(&self) -> Result<EmptyResponse, BinanceError> {
+        self.client
+            .send_keyed(UserStream::ListenKey, EmptyParams, Method::PUT)
+    }
+
+    pub fn close(&self) -> Result<EmptyResponse, BinanceError> {
+        self.client
+            .send_keyed(UserStream::ListenKey, EmptyParams, Method::DELETE)
+    }
+
+    pub fn websocket_url(listen_key: &str) -> String {
+        format!("{}{}", WebSocketHost::UserData.as_ref(), listen_key)
+    }
+}
@@ -1,4 +1,11 @@
 pub use binance_common as common;
 pub use binance_core as core;
+#[cfg(feature = "export")]
+pub use binance_export as export;
 pub use binance_futures as futures;
+pub use binance_margin as margin;
+pub use binance_portfolio_margin as portfolio_margin;
 pub use binance_spot as spot;
+pub use binance_sub_account as sub_account;
+pub use binance_trading as trading;
+pub use binance_wallet as wallet;
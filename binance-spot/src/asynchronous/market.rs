@@ -1,121 +1,82 @@
-use binance_common::spot::endpoint::route::Market;
-use binance_common::spot::model::params::market::*;
+use binance_common::error::BinanceError;
+use binance_common::spot::model::params::market::{BookTickerParams, PriceTickerParams};
+use binance_common::spot::model::params::{
+    market::{RollingTickerParams, Ticker24hParams, TickerDayParams},
+    symbols::SymbolBatches,
+};
 use binance_common::spot::model::response::market::{
-    AvgPriceResponse, BookTickerResponse, HistoricalTradesResponse, KlinesResponse,
-    PriceTickerResponse, RollingTickerMiniResponse, Ticker24hFullResponse, Ticker24hMiniResponse,
-    TickerDayFullResponse, TickerDayMiniResponse, TradesResponse,
+    BookTickerResponse, PriceTickerResponse, RollingTickerMiniResponse, Ticker24hFullResponse,
+    Ticker24hMiniResponse, TickerDayFullResponse, TickerDayMiniResponse,
+};
+use binance_common::spot::model::{
+    params::market::AvgPriceParams, response::market::AvgPriceResponse,
+};
+use binance_core::endpoint::Endpoint;
+use binance_core::history::{KlineEvent, KlineHistory};
+use binance_core::{
+    client::asynchronous::Client, signer::signature::Signature, trading::spot::SpotPriceApi,
 };
-use binance_common::{error::BinanceError, spot::model::response::market::DepthResponse};
-use binance_core::{client::asynchronous::Client, signer::signature::Signature};
+use futures_util::Stream;
 
-pub struct MarketApi<'a, S>
+use crate::endpoint::market::{
+    BookTicker, BookTickerList, PriceTicker, PriceTickerList, RollingTickerFullList,
+    RollingTickerMiniList, Ticker24hFullList, Ticker24hMiniList, TickerDayFullList,
+    TickerDayMiniList,
+};
+use binance_core::transport::asynchronous::Transport;
+
+/// Endpoints are declared in [`crate::endpoint::market`].
+pub struct MarketApi<'a, S, T = reqwest::Client>
 where
     S: Signature<'a>,
+    T: Transport,
 {
-    client: Client<'a, S>,
+    pub(crate) client: Client<'a, S, T>,
 }
 
-impl<'a, S> MarketApi<'a, S>
+impl<'a, S, T> MarketApi<'a, S, T>
 where
     S: Signature<'a>,
+    T: Transport,
 {
-    pub async fn new(client: Client<'a, S>) -> Self {
+    pub fn new(client: Client<'a, S, T>) -> Self {
         MarketApi { client }
     }
 
-    pub async fn get_depth(&self, params: &DepthParams<'a>) -> Result<DepthResponse, BinanceError> {
-        self.client.get(Market::Depth, params).await
-    }
-
-    pub async fn get_trades(
-        &self,
-        params: &TradesParams<'a>,
-    ) -> Result<Vec<TradesResponse>, BinanceError> {
-        self.client.get(Market::Trades, params).await
-    }
-
-    pub async fn get_historical_trades(
-        &self,
-        params: &HistoricalTradesParams<'a>,
-    ) -> Result<Vec<HistoricalTradesResponse>, BinanceError> {
-        self.client.get(Market::HistoricalTrades, params).await
-    }
-
-    pub async fn get_klines(
-        &self,
-        params: &KlinesParams<'a>,
-    ) -> Result<Vec<KlinesResponse>, BinanceError> {
-        self.client.get(Market::Klines, params).await
-    }
-
-    pub async fn get_uiklines(
-        &self,
-        params: &KlinesParams<'a>,
-    ) -> Result<Vec<KlinesResponse>, BinanceError> {
-        self.client.get(Market::UIKlines, params).await
-    }
-
-    pub async fn get_average_price(
-        &self,
-        params: &AvgPriceParams<'a>,
-    ) -> Result<AvgPriceResponse, BinanceError> {
-        self.client.get(Market::AvgPrice, params).await
-    }
-
-    pub async fn get_ticker24h_mini(
-        &self,
-        params: &Ticker24hParams<'a>,
-    ) -> Result<Ticker24hMiniResponse, BinanceError> {
-        self.client.get(Market::Ticker24h, params).await
-    }
-
-    pub async fn get_ticker24h_full(
-        &self,
-        params: &Ticker24hParams<'a>,
-    ) -> Result<Ticker24hFullResponse, BinanceError> {
-        self.client.get(Market::Ticker24h, params).await
+    /// Pages through `history` with `get_klines`, see [`KlineHistory`].
+    pub fn get_klines_history<'b>(
+        &'b self,
+        history: KlineHistory<'b>,
+    ) -> impl Stream<Item = Result<KlineEvent, BinanceError>> + 'b {
+        history.stream(move |params| async move { self.get_klines(&params).await })
     }
 
     pub async fn get_ticker24h_mini_list(
         &self,
-        params: &Ticker24hParams<'a>,
+        params: &Ticker24hParams<'_>,
     ) -> Result<Vec<Ticker24hMiniResponse>, BinanceError> {
-        self.client.get(Market::Ticker24h, params).await
+        self.get_batched::<Ticker24hMiniList, _>(params).await
     }
 
     pub async fn get_ticker24h_full_list(
         &self,
-        params: &Ticker24hParams<'a>,
+        params: &Ticker24hParams<'_>,
     ) -> Result<Vec<Ticker24hFullResponse>, BinanceError> {
-        self.client.get(Market::Ticker24h, params).await
-    }
-
-    pub async fn get_ticker_day_mini(
-        &self,
-        params: &TickerDayParams<'a>,
-    ) -> Result<TickerDayMiniResponse, BinanceError> {
-        self.client.get(Market::TickerDay, params).await
-    }
-
-    pub async fn get_ticker_day_full(
-        &self,
-        params: &TickerDayParams<'a>,
-    ) -> Result<TickerDayFullResponse, BinanceError> {
-        self.client.get(Market::TickerDay, params).await
+        self.get_batched::<Ticker24hFullList, _>(params).await
     }
 
     pub async fn get_ticker_day_mini_list(
         &self,
-        params: &TickerDayParams<'a>,
+        params: &TickerDayParams<'_>,
     ) -> Result<Vec<TickerDayMiniResponse>, BinanceError> {
-        self.client.get(Market::TickerDay, params).await
+        self.get_batched::<TickerDayMiniList, _>(params).await
     }
 
     pub async fn get_ticker_day_full_list(
         &self,
-        params: &TickerDayParams<'a>,
+        params: &TickerDayParams<'_>,
     ) -> Result<Vec<TickerDayFullResponse>, BinanceError> {
-        self.client.get(Market::TickerDay, params).await
+        self.get_batched::<TickerDayFullList, _>(params).await
     }
 
     pub async fn get_price_ticker(
@@ -124,58 +85,72 @@ where
     ) -> Result<PriceTickerResponse, BinanceError> {
         let params = PriceTickerParams::new().symbol(symbol);
 
-        self.client.get(Market::TickerPrice, params).await
+        self.client.call::<PriceTicker>(&params).await
     }
 
-    pub async fn get_price_ticker_list(
+    pub async fn get_price_ticker_list<I: AsRef<str>>(
         &self,
-        symbols: &str,
+        symbols: impl IntoIterator<Item = I>,
     ) -> Result<Vec<PriceTickerResponse>, BinanceError> {
         let params = PriceTickerParams::new().symbols(symbols);
 
-        self.client.get(Market::TickerPrice, params).await
+        self.get_batched::<PriceTickerList, _>(&params).await
     }
 
     pub async fn get_book_ticker(&self, symbol: &str) -> Result<BookTickerResponse, BinanceError> {
         let params = BookTickerParams::new().symbol(symbol);
 
-        self.client.get(Market::BookTicker, params).await
+        self.client.call::<BookTicker>(&params).await
     }
 
-    pub async fn get_book_ticker_list(
+    pub async fn get_book_ticker_list<I: AsRef<str>>(
         &self,
-        symbols: &str,
+        symbols: impl IntoIterator<Item = I>,
     ) -> Result<Vec<BookTickerResponse>, BinanceError> {
         let params = BookTickerParams::new().symbols(symbols);
 
-        self.client.get(Market::BookTicker, params).await
+        self.get_batched::<BookTickerList, _>(&params).await
     }
 
-    pub async fn get_rolling_ticker_mini(
+    pub async fn get_rolling_ticker_mini_list(
         &self,
-        params: &RollingTickerParams<'a>,
-    ) -> Result<RollingTickerMiniResponse, BinanceError> {
-        self.client.get(Market::RollingTicker, params).await
+        params: &RollingTickerParams<'_>,
+    ) -> Result<Vec<RollingTickerMiniResponse>, BinanceError> {
+        self.get_batched::<RollingTickerMiniList, _>(params).await
     }
 
-    pub async fn get_rolling_ticker_mini_list(
+    pub async fn get_rolling_ticker_full_list(
         &self,
-        params: &RollingTickerParams<'a>,
+        params: &RollingTickerParams<'_>,
     ) -> Result<Vec<RollingTickerMiniResponse>, BinanceError> {
-        self.client.get(Market::RollingTicker, params).await
+        self.get_batched::<RollingTickerFullList, _>(params).await
     }
 
-    pub async fn get_rolling_ticker_full(
-        &self,
-        params: &RollingTickerParams<'a>,
-    ) -> Result<RollingTickerMiniResponse, BinanceError> {
-        self.client.get(Market::RollingTicker, params).await
+    /// Sends one request per batch of `params` symbols and merges the results.
+    async fn get_batched<'p, E, R>(&self, params: &E::Params<'p>) -> Result<Vec<R>, BinanceError>
+    where
+        E: Endpoint<Response = Vec<R>>,
+        E::Params<'p>: SymbolBatches,
+    {
+        let mut merged = Vec::new();
+
+        for params in params.batches() {
+            merged.extend(self.client.call::<E>(&params).await?);
+        }
+
+        Ok(merged)
     }
+}
 
-    pub async fn get_rolling_ticker_full_list(
+impl<'a, S, T> SpotPriceApi for MarketApi<'a, S, T>
+where
+    S: Signature<'a> + Sync,
+    T: Transport + Sync,
+{
+    async fn get_average_price(
         &self,
-        params: &RollingTickerParams<'a>,
-    ) -> Result<Vec<RollingTickerMiniResponse>, BinanceError> {
-        self.client.get(Market::RollingTicker, params).await
+        params: &AvgPriceParams<'_>,
+    ) -> Result<AvgPriceResponse, BinanceError> {
+        MarketApi::get_average_price(self, params).await
     }
 }
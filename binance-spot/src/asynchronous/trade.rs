@@ -1,123 +1,114 @@
 use binance_common::enums::spot::OrderResponseType;
 use binance_common::error::BinanceError;
-use binance_common::spot::{
-    endpoint::route::Trade,
-    model::{
-        params::trade::{
-            AllOrderParams, CancelAllOrdersParms, CancelOrderParams, GetOrderParams,
-            NewOrderParams, OpenOrdersParams,
+use binance_common::spot::model::{
+    params::{
+        account::InfoParams,
+        trade::{
+            CancelAllOrdersParms, CancelOrderParams, GetOrderParams, NewOrderParams,
+            OpenOrdersParams,
         },
-        response::trade::{
-            AckResponse, CancelOrderResponse, FullResponse, NewOrderResponse, OrderIdResponse,
-            OrderResponse, ResultResponse,
+    },
+    response::{
+        account::AssetResponse,
+        trade::{
+            CancelOrderResponse, FullResponse, NewOrderResponse, OrderIdResponse, OrderResponse,
+            ResultResponse,
         },
     },
 };
-use reqwest::Method;
 
-use binance_core::{client::asynchronous::Client, signer::signature::Signature};
+use binance_core::{
+    client::asynchronous::Client, signer::signature::Signature, trading::spot::SpotTradingApi,
+};
+
+use crate::endpoint::{account::Info, trade::NewOrder};
+use binance_core::transport::asynchronous::Transport;
 
-pub struct TradeApi<'a, S>
+/// Endpoints are declared in [`crate::endpoint::trade`].
+pub struct TradeApi<'a, S, T = reqwest::Client>
 where
     S: Signature<'a>,
+    T: Transport,
 {
-    client: Client<'a, S>,
+    pub(crate) client: Client<'a, S, T>,
 }
 
-impl<'a, S> TradeApi<'a, S>
+impl<'a, S, T> TradeApi<'a, S, T>
 where
     S: Signature<'a>,
+    T: Transport,
 {
-    pub async fn new(client: Client<'a, S>) -> Self {
+    pub fn new(client: Client<'a, S, T>) -> Self {
         TradeApi { client }
     }
 
     pub async fn send_new_order(
         &self,
-        params: &NewOrderParams<'a>,
+        params: &NewOrderParams<'_>,
     ) -> Result<NewOrderResponse, BinanceError> {
-        if let Some(order_response_type) = &params.new_order_resp_type {
-            match order_response_type {
-                OrderResponseType::Ack => {
-                    return Ok(NewOrderResponse::Ack(
-                        self.client
-                            .send::<AckResponse>(Trade::NewOrder, params, Method::POST)
-                            .await?,
-                    ));
-                }
-                OrderResponseType::Result => {
-                    return Ok(NewOrderResponse::Result(
-                        self.client
-                            .send::<ResultResponse>(Trade::NewOrder, params, Method::POST)
-                            .await?,
-                    ));
-                }
-                OrderResponseType::Full => {
-                    return Ok(NewOrderResponse::Full(
-                        self.client
-                            .send::<FullResponse>(Trade::NewOrder, params, Method::POST)
-                            .await?,
-                    ));
-                }
+        let response = match &params.new_order_resp_type {
+            Some(OrderResponseType::Result) => NewOrderResponse::Result(
+                self.client
+                    .call_as::<NewOrder, ResultResponse>(params)
+                    .await?,
+            ),
+            Some(OrderResponseType::Full) => NewOrderResponse::Full(
+                self.client
+                    .call_as::<NewOrder, FullResponse>(params)
+                    .await?,
+            ),
+            Some(OrderResponseType::Ack) | None => {
+                NewOrderResponse::Ack(self.client.call::<NewOrder>(params).await?)
             }
-        }
-
-        Ok(NewOrderResponse::Ack(
-            self.client
-                .send::<AckResponse>(Trade::NewOrder, params, Method::POST)
-                .await?,
-        ))
-    }
+        };
 
-    pub async fn send_new_test_order(
-        &self,
-        params: &NewOrderParams<'a>,
-    ) -> Result<serde_json::Value, BinanceError> {
-        self.client
-            .send(Trade::TestOrder, params, Method::POST)
-            .await
+        Ok(response)
     }
+}
 
-    pub async fn get_order(
+impl<'a, S, T> SpotTradingApi for TradeApi<'a, S, T>
+where
+    S: Signature<'a> + Sync,
+    T: Transport + Sync,
+{
+    async fn send_new_order(
         &self,
-        params: &GetOrderParams<'a>,
-    ) -> Result<OrderIdResponse, BinanceError> {
-        self.client.send(Trade::GetOrder, params, Method::GET).await
+        params: &NewOrderParams<'_>,
+    ) -> Result<NewOrderResponse, BinanceError> {
+        TradeApi::send_new_order(self, params).await
     }
 
-    pub async fn send_cancel_order(
+    async fn send_cancel_order(
         &self,
-        params: &CancelOrderParams<'a>,
+        params: &CancelOrderParams<'_>,
     ) -> Result<CancelOrderResponse, BinanceError> {
-        self.client
-            .send(Trade::CancelOrder, params, Method::DELETE)
-            .await
+        TradeApi::send_cancel_order(self, params).await
     }
 
-    pub async fn send_cancel_open_orders(
+    async fn send_cancel_open_orders(
         &self,
-        params: &CancelAllOrdersParms<'a>,
+        params: &CancelAllOrdersParms<'_>,
     ) -> Result<Vec<CancelOrderResponse>, BinanceError> {
-        self.client
-            .send(Trade::CancelAllOrders, params, Method::DELETE)
-            .await
+        TradeApi::send_cancel_open_orders(self, params).await
     }
 
-    pub async fn get_open_orders(
+    async fn get_order(
         &self,
-        params: &OpenOrdersParams<'a>,
-    ) -> Result<Vec<OrderResponse>, BinanceError> {
-        self.client
-            .send(Trade::OpenOrders, params, Method::GET)
-            .await
+        params: &GetOrderParams<'_>,
+    ) -> Result<OrderIdResponse, BinanceError> {
+        TradeApi::get_order(self, params).await
     }
 
-    pub async fn get_all_order(
+    async fn get_open_orders(
         &self,
-        params: &AllOrderParams<'a>,
+        params: &OpenOrdersParams<'_>,
     ) -> Result<Vec<OrderResponse>, BinanceError> {
-        self.client
-            .send(Trade::AllOrders, params, Method::GET)
-            .await
+        TradeApi::get_open_orders(self, params).await
+    }
+
+    async fn get_balances(&self) -> Result<Vec<AssetResponse>, BinanceError> {
+        let info = self.client.call::<Info>(&InfoParams::new()).await?;
+
+        Ok(info.balances)
     }
 }
@@ -0,0 +1,4 @@
+pub mod account;
+pub mod general;
+pub mod market;
+pub mod trade;
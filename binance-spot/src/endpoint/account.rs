@@ -0,0 +1,24 @@
+use binance_common::spot::endpoint::route::Account;
+use binance_common::spot::model::params::account::{
+    InfoParams, MyTradesParams, UnfilledOrderCountParams,
+};
+use binance_common::spot::model::response::account::{
+    InfoResponse, MyTradesResponse, UnfilledOrderCountResponse,
+};
+
+binance_core::endpoints! {
+    crate::synchronous::account::AccountApi, crate::asynchronous::account::AccountApi;
+
+    pub fn get_info(InfoParams) -> InfoResponse = Info {
+        GET Account::Info, weight: 20, security: Signed
+    }
+
+    pub fn get_my_trades(MyTradesParams<'p>) -> Vec<MyTradesResponse> = MyTrades {
+        GET Account::MyTrades, weight: 20, security: Signed
+    }
+
+    pub fn get_unfilled_order_count(UnfilledOrderCountParams)
+        -> Vec<UnfilledOrderCountResponse> = UnfilledOrderCount {
+        GET Account::UnfilledOrderCount, weight: 40, security: Signed
+    }
+}
@@ -0,0 +1,22 @@
+use binance_common::spot::endpoint::route::General;
+use binance_common::spot::model::params::general::ExchangeInformationParams;
+use binance_common::spot::model::response::general::{
+    EmptyResponse, ExchangeInformationResponse, ServerTimeResponse,
+};
+
+binance_core::endpoints! {
+    crate::synchronous::general::GeneralApi, crate::asynchronous::general::GeneralApi;
+
+    pub fn ping() -> EmptyResponse = Ping {
+        GET General::Ping, weight: 1, security: None
+    }
+
+    pub fn get_server_time() -> ServerTimeResponse = ServerTime {
+        GET General::ServerTime, weight: 1, security: None
+    }
+
+    pub fn get_exchange_info(ExchangeInformationParams<'p>)
+        -> ExchangeInformationResponse = ExchangeInfo {
+        GET General::ExchangeInfo, weight: 20, security: None
+    }
+}
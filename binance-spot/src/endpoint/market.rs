@@ -0,0 +1,105 @@
+use binance_common::spot::endpoint::route::Market;
+use binance_common::spot::model::params::market::{
+    AvgPriceParams, BookTickerParams, DepthParams, HistoricalTradesParams, KlinesParams,
+    PriceTickerParams, RollingTickerParams, Ticker24hParams, TickerDayParams, TradesParams,
+};
+use binance_common::spot::model::response::market::{
+    AvgPriceResponse, BookTickerResponse, DepthResponse, HistoricalTradesResponse, KlinesResponse,
+    PriceTickerResponse, RollingTickerMiniResponse, Ticker24hFullResponse, Ticker24hMiniResponse,
+    TickerDayFullResponse, TickerDayMiniResponse, TradesResponse,
+};
+
+binance_core::endpoints! {
+    crate::synchronous::market::MarketApi, crate::asynchronous::market::MarketApi;
+
+    pub fn get_depth(DepthParams<'p>) -> DepthResponse = Depth {
+        GET Market::Depth, weight: 5, security: None
+    }
+
+    pub fn get_trades(TradesParams<'p>) -> Vec<TradesResponse> = Trades {
+        GET Market::Trades, weight: 25, security: None
+    }
+
+    pub fn get_historical_trades(HistoricalTradesParams<'p>)
+        -> Vec<HistoricalTradesResponse> = HistoricalTrades {
+        GET Market::HistoricalTrades, weight: 25, security: ApiKey
+    }
+
+    pub fn get_klines(KlinesParams<'p>) -> Vec<KlinesResponse> = Klines {
+        GET Market::Klines, weight: 2, security: None
+    }
+
+    pub fn get_uiklines(KlinesParams<'p>) -> Vec<KlinesResponse> = UiKlines {
+        GET Market::UIKlines, weight: 2, security: None
+    }
+
+    pub fn get_average_price(AvgPriceParams<'p>) -> AvgPriceResponse = AvgPrice {
+        GET Market::AvgPrice, weight: 2, security: None
+    }
+
+    pub fn get_ticker24h_mini(Ticker24hParams<'p>) -> Ticker24hMiniResponse = Ticker24hMini {
+        GET Market::Ticker24h, weight: 2, security: None
+    }
+
+    pub fn get_ticker24h_full(Ticker24hParams<'p>) -> Ticker24hFullResponse = Ticker24hFull {
+        GET Market::Ticker24h, weight: 2, security: None
+    }
+
+    Ticker24hMiniList(Ticker24hParams<'p>) -> Vec<Ticker24hMiniResponse> {
+        GET Market::Ticker24h, weight: 80, security: None
+    }
+
+    Ticker24hFullList(Ticker24hParams<'p>) -> Vec<Ticker24hFullResponse> {
+        GET Market::Ticker24h, weight: 80, security: None
+    }
+
+    pub fn get_ticker_day_mini(TickerDayParams<'p>) -> TickerDayMiniResponse = TickerDayMini {
+        GET Market::TickerDay, weight: 4, security: None
+    }
+
+    pub fn get_ticker_day_full(TickerDayParams<'p>) -> TickerDayFullResponse = TickerDayFull {
+        GET Market::TickerDay, weight: 4, security: None
+    }
+
+    TickerDayMiniList(TickerDayParams<'p>) -> Vec<TickerDayMiniResponse> {
+        GET Market::TickerDay, weight: 200, security: None
+    }
+
+    TickerDayFullList(TickerDayParams<'p>) -> Vec<TickerDayFullResponse> {
+        GET Market::TickerDay, weight: 200, security: None
+    }
+
+    PriceTicker(PriceTickerParams<'p>) -> PriceTickerResponse {
+        GET Market::TickerPrice, weight: 2, security: None
+    }
+
+    PriceTickerList(PriceTickerParams<'p>) -> Vec<PriceTickerResponse> {
+        GET Market::TickerPrice, weight: 4, security: None
+    }
+
+    BookTicker(BookTickerParams<'p>) -> BookTickerResponse {
+        GET Market::BookTicker, weight: 2, security: None
+    }
+
+    BookTickerList(BookTickerParams<'p>) -> Vec<BookTickerResponse> {
+        GET Market::BookTicker, weight: 4, security: None
+    }
+
+    pub fn get_rolling_ticker_mini(RollingTickerParams<'p>)
+        -> RollingTickerMiniResponse = RollingTickerMini {
+        GET Market::RollingTicker, weight: 4, security: None
+    }
+
+    pub fn get_rolling_ticker_full(RollingTickerParams<'p>)
+        -> RollingTickerMiniResponse = RollingTickerFull {
+        GET Market::RollingTicker, weight: 4, security: None
+    }
+
+    RollingTickerMiniList(RollingTickerParams<'p>) -> Vec<RollingTickerMiniResponse> {
+        GET Market::RollingTicker, weight: 200, security: None
+    }
+
+    RollingTickerFullList(RollingTickerParams<'p>) -> Vec<RollingTickerMiniResponse> {
+        GET Market::RollingTicker, weight: 200, security: None
+    }
+}
@@ -0,0 +1,42 @@
+use binance_common::spot::endpoint::route::Trade;
+use binance_common::spot::model::params::trade::{
+    AllOrderParams, CancelAllOrdersParms, CancelOrderParams, GetOrderParams, NewOrderParams,
+    OpenOrdersParams,
+};
+use binance_common::spot::model::response::trade::{
+    AckResponse, CancelOrderResponse, OrderIdResponse, OrderResponse,
+};
+
+binance_core::endpoints! {
+    crate::synchronous::trade::TradeApi, crate::asynchronous::trade::TradeApi;
+
+    /// Decoded as the response type the params ask for by `send_new_order`.
+    NewOrder(NewOrderParams<'p>) -> AckResponse {
+        POST Trade::NewOrder, weight: 1, security: Signed
+    }
+
+    pub fn send_new_test_order(NewOrderParams<'p>) -> serde_json::Value = TestOrder {
+        POST Trade::TestOrder, weight: 1, security: Signed
+    }
+
+    pub fn get_order(GetOrderParams<'p>) -> OrderIdResponse = GetOrder {
+        GET Trade::GetOrder, weight: 4, security: Signed
+    }
+
+    pub fn send_cancel_order(CancelOrderParams<'p>) -> CancelOrderResponse = CancelOrder {
+        DELETE Trade::CancelOrder, weight: 1, security: Signed
+    }
+
+    pub fn send_cancel_open_orders(CancelAllOrdersParms<'p>)
+        -> Vec<CancelOrderResponse> = CancelAllOrders {
+        DELETE Trade::CancelAllOrders, weight: 1, security: Signed
+    }
+
+    pub fn get_open_orders(OpenOrdersParams<'p>) -> Vec<OrderResponse> = OpenOrders {
+        GET Trade::OpenOrders, weight: 6, security: Signed
+    }
+
+    pub fn get_all_order(AllOrderParams<'p>) -> Vec<OrderResponse> = AllOrders {
+        GET Trade::AllOrders, weight: 20, security: Signed
+    }
+}
@@ -1,2 +1,3 @@
 pub mod asynchronous;
+pub mod endpoint;
 pub mod synchronous;
@@ -1,48 +1,34 @@
 use binance_common::error::BinanceError;
-use binance_common::spot::{
-    endpoint::route::Account,
-    model::{
-        params::account::{InfoParams, MyTradesParams, UnfilledOrderCountParams},
-        response::account::{InfoResponse, MyTradesResponse, UnfilledOrderCountResponse},
-    },
-};
-use reqwest::Method;
+use binance_common::spot::model::response::account::MyTradesResponse;
 
-use binance_core::{client::synchronous::Client, signer::signature::Signature};
+use binance_core::transport::synchronous::Transport;
+use binance_core::{
+    client::synchronous::Client, history::TradeHistory, signer::signature::Signature,
+};
 
-pub struct AccountApi<'a, S>
+/// Endpoints are declared in [`crate::endpoint::account`].
+pub struct AccountApi<'a, S, T = reqwest::blocking::Client>
 where
     S: Signature<'a>,
+    T: Transport,
 {
-    client: Client<'a, S>,
+    pub(crate) client: Client<'a, S, T>,
 }
 
-impl<'a, S> AccountApi<'a, S>
+impl<'a, S, T> AccountApi<'a, S, T>
 where
     S: Signature<'a>,
+    T: Transport,
 {
-    pub fn new(client: Client<'a, S>) -> Self {
+    pub fn new(client: Client<'a, S, T>) -> Self {
         AccountApi { client }
     }
 
-    pub fn get_info(&self, params: &InfoParams) -> Result<InfoResponse, BinanceError> {
-        self.client
-            .send::<InfoResponse>(Account::Info, params, Method::GET)
-    }
-
-    pub fn get_my_trades(
-        &self,
-        params: &MyTradesParams,
-    ) -> Result<Vec<MyTradesResponse>, BinanceError> {
-        self.client
-            .send::<Vec<MyTradesResponse>>(Account::MyTrades, params, Method::GET)
-    }
-
-    pub fn get_unfilled_order_count(
-        &self,
-        params: &UnfilledOrderCountParams,
-    ) -> Result<Vec<UnfilledOrderCountResponse>, BinanceError> {
-        self.client
-            .send(Account::UnfilledOrderCount, params, Method::GET)
+    /// Pages through `history` with `get_my_trades`, see [`TradeHistory`].
+    pub fn get_my_trades_history<'b>(
+        &'b self,
+        history: TradeHistory<'b>,
+    ) -> impl Iterator<Item = Result<MyTradesResponse, BinanceError>> + 'b {
+        history.iter(move |params| self.get_my_trades(params))
     }
 }
@@ -1,38 +1,21 @@
-use binance_common::error::BinanceError;
-use binance_common::spot::endpoint::route::General;
-use binance_common::spot::model::params::{EmptyParams, general::ExchangeInformationParams};
-use binance_common::spot::model::response::general::{
-    EmptyResponse, ExchangeInformationResponse, ServerTimeResponse,
-};
+use binance_core::transport::synchronous::Transport;
 use binance_core::{client::synchronous::Client, signer::signature::Signature};
 
-pub struct GeneralApi<'a, S>
+/// Endpoints are declared in [`crate::endpoint::general`].
+pub struct GeneralApi<'a, S, T = reqwest::blocking::Client>
 where
     S: Signature<'a>,
+    T: Transport,
 {
-    client: Client<'a, S>,
+    pub(crate) client: Client<'a, S, T>,
 }
 
-impl<'a, S> GeneralApi<'a, S>
+impl<'a, S, T> GeneralApi<'a, S, T>
 where
     S: Signature<'a>,
+    T: Transport,
 {
-    pub fn new(client: Client<'a, S>) -> GeneralApi<'a, S> {
+    pub fn new(client: Client<'a, S, T>) -> Self {
         GeneralApi { client }
     }
-
-    pub fn ping(&self) -> Result<EmptyResponse, BinanceError> {
-        self.client.get(General::Ping, EmptyParams)
-    }
-
-    pub fn get_server_time(&self) -> Result<ServerTimeResponse, BinanceError> {
-        self.client.get(General::ServerTime, EmptyParams)
-    }
-
-    pub fn get_exchange_info(
-        &self,
-        params: &ExchangeInformationParams,
-    ) -> Result<ExchangeInformationResponse, BinanceError> {
-        self.client.get(General::ExchangeInfo, params)
-    }
 }
@@ -1,169 +1,134 @@
-use binance_common::spot::endpoint::route::Market;
-use binance_common::spot::model::params::market::*;
+use binance_common::error::BinanceError;
+use binance_common::spot::model::params::market::{BookTickerParams, PriceTickerParams};
+use binance_common::spot::model::params::{
+    market::{RollingTickerParams, Ticker24hParams, TickerDayParams},
+    symbols::SymbolBatches,
+};
 use binance_common::spot::model::response::market::{
-    AvgPriceResponse, BookTickerResponse, HistoricalTradesResponse, KlinesResponse,
-    PriceTickerResponse, RollingTickerMiniResponse, Ticker24hFullResponse, Ticker24hMiniResponse,
-    TickerDayFullResponse, TickerDayMiniResponse, TradesResponse,
+    BookTickerResponse, PriceTickerResponse, RollingTickerMiniResponse, Ticker24hFullResponse,
+    Ticker24hMiniResponse, TickerDayFullResponse, TickerDayMiniResponse,
 };
-use binance_common::{error::BinanceError, spot::model::response::market::DepthResponse};
+use binance_core::endpoint::Endpoint;
+use binance_core::history::{KlineEvent, KlineHistory};
 use binance_core::{client::synchronous::Client, signer::signature::Signature};
 
-pub struct MarketApi<'a, S>
+use crate::endpoint::market::{
+    BookTicker, BookTickerList, PriceTicker, PriceTickerList, RollingTickerFullList,
+    RollingTickerMiniList, Ticker24hFullList, Ticker24hMiniList, TickerDayFullList,
+    TickerDayMiniList,
+};
+use binance_core::transport::synchronous::Transport;
+
+/// Endpoints are declared in [`crate::endpoint::market`].
+pub struct MarketApi<'a, S, T = reqwest::blocking::Client>
 where
     S: Signature<'a>,
+    T: Transport,
 {
-    client: Client<'a, S>,
+    pub(crate) client: Client<'a, S, T>,
 }
 
-impl<'a, S> MarketApi<'a, S>
+impl<'a, S, T> MarketApi<'a, S, T>
 where
     S: Signature<'a>,
+    T: Transport,
 {
-    pub fn new(client: Client<'a, S>) -> Self {
+    pub fn new(client: Client<'a, S, T>) -> Self {
         MarketApi { client }
     }
 
-    pub fn get_depth(&self, params: &DepthParams) -> Result<DepthResponse, BinanceError> {
-        self.client.get(Market::Depth, params)
-    }
-
-    pub fn get_trades(&self, params: &TradesParams) -> Result<Vec<TradesResponse>, BinanceError> {
-        self.client.get(Market::Trades, params)
-    }
-
-    pub fn get_historical_trades(
-        &self,
-        params: &HistoricalTradesParams,
-    ) -> Result<Vec<HistoricalTradesResponse>, BinanceError> {
-        self.client.get(Market::HistoricalTrades, params)
-    }
-
-    pub fn get_klines(&self, params: &KlinesParams) -> Result<Vec<KlinesResponse>, BinanceError> {
-        self.client.get(Market::Klines, params)
-    }
-
-    pub fn get_uiklines(&self, params: &KlinesParams) -> Result<Vec<KlinesResponse>, BinanceError> {
-        self.client.get(Market::UIKlines, params)
-    }
-
-    pub fn get_average_price(
-        &self,
-        params: &AvgPriceParams,
-    ) -> Result<AvgPriceResponse, BinanceError> {
-        self.client.get(Market::AvgPrice, params)
-    }
-
-    pub fn get_ticker24h_mini(
-        &self,
-        params: &Ticker24hParams,
-    ) -> Result<Ticker24hMiniResponse, BinanceError> {
-        self.client.get(Market::Ticker24h, params)
-    }
-
-    pub fn get_ticker24h_full(
-        &self,
-        params: &Ticker24hParams,
-    ) -> Result<Ticker24hFullResponse, BinanceError> {
-        self.client.get(Market::Ticker24h, params)
+    /// Pages through `history` with `get_klines`, see [`KlineHistory`].
+    pub fn get_klines_history<'b>(
+        &'b self,
+        history: KlineHistory<'b>,
+    ) -> impl Iterator<Item = Result<KlineEvent, BinanceError>> + 'b {
+        history.iter(move |params| self.get_klines(params))
     }
 
     pub fn get_ticker24h_mini_list(
         &self,
-        params: &Ticker24hParams,
+        params: &Ticker24hParams<'_>,
     ) -> Result<Vec<Ticker24hMiniResponse>, BinanceError> {
-        self.client.get(Market::Ticker24h, params)
+        self.get_batched::<Ticker24hMiniList, _>(params)
     }
 
     pub fn get_ticker24h_full_list(
         &self,
-        params: &Ticker24hParams,
+        params: &Ticker24hParams<'_>,
     ) -> Result<Vec<Ticker24hFullResponse>, BinanceError> {
-        self.client.get(Market::Ticker24h, params)
-    }
-
-    pub fn get_ticker_day_mini(
-        &self,
-        params: &TickerDayParams,
-    ) -> Result<TickerDayMiniResponse, BinanceError> {
-        self.client.get(Market::TickerDay, params)
-    }
-
-    pub fn get_ticker_day_full(
-        &self,
-        params: &TickerDayParams,
-    ) -> Result<TickerDayFullResponse, BinanceError> {
-        self.client.get(Market::TickerDay, params)
+        self.get_batched::<Ticker24hFullList, _>(params)
     }
 
     pub fn get_ticker_day_mini_list(
         &self,
-        params: &TickerDayParams,
+        params: &TickerDayParams<'_>,
     ) -> Result<Vec<TickerDayMiniResponse>, BinanceError> {
-        self.client.get(Market::TickerDay, params)
+        self.get_batched::<TickerDayMiniList, _>(params)
     }
 
     pub fn get_ticker_day_full_list(
         &self,
-        params: &TickerDayParams,
+        params: &TickerDayParams<'_>,
     ) -> Result<Vec<TickerDayFullResponse>, BinanceError> {
-        self.client.get(Market::TickerDay, params)
+        self.get_batched::<TickerDayFullList, _>(params)
     }
 
     pub fn get_price_ticker(&self, symbol: &str) -> Result<PriceTickerResponse, BinanceError> {
         let params = PriceTickerParams::new().symbol(symbol);
 
-        self.client.get(Market::TickerPrice, params)
+        self.client.call::<PriceTicker>(&params)
     }
 
-    pub fn get_price_ticker_list(
+    pub fn get_price_ticker_list<I: AsRef<str>>(
         &self,
-        symbols: &str,
+        symbols: impl IntoIterator<Item = I>,
     ) -> Result<Vec<PriceTickerResponse>, BinanceError> {
         let params = PriceTickerParams::new().symbols(symbols);
 
-        self.client.get(Market::TickerPrice, params)
+        self.get_batched::<PriceTickerList, _>(&params)
     }
 
     pub fn get_book_ticker(&self, symbol: &str) -> Result<BookTickerResponse, BinanceError> {
         let params = BookTickerParams::new().symbol(symbol);
 
-        self.client.get(Market::BookTicker, params)
+        self.client.call::<BookTicker>(&params)
     }
 
-    pub fn get_book_ticker_list(
+    pub fn get_book_ticker_list<I: AsRef<str>>(
         &self,
-        symbols: &str,
+        symbols: impl IntoIterator<Item = I>,
     ) -> Result<Vec<BookTickerResponse>, BinanceError> {
         let params = BookTickerParams::new().symbols(symbols);
 
-        self.client.get(Market::BookTicker, params)
-    }
-
-    pub fn get_rolling_ticker_mini(
-        &self,
-        params: &RollingTickerParams,
-    ) -> Result<RollingTickerMiniResponse, BinanceError> {
-        self.client.get(Market::RollingTicker, params)
+        self.get_batched::<BookTickerList, _>(&params)
     }
 
     pub fn get_rolling_ticker_mini_list(
         &self,
-        params: &RollingTickerParams,
+        params: &RollingTickerParams<'_>,
     ) -> Result<Vec<RollingTickerMiniResponse>, BinanceError> {
-        self.client.get(Market::RollingTicker, params)
-    }
-
-    pub fn get_rolling_ticker_full(
-        &self,
-        params: &RollingTickerParams,
-    ) -> Result<RollingTickerMiniResponse, BinanceError> {
-        self.client.get(Market::RollingTicker, params)
+        self.get_batched::<RollingTickerMiniList, _>(params)
     }
 
     pub fn get_rolling_ticker_full_list(
         &self,
-        params: &RollingTickerParams,
+        params: &RollingTickerParams<'_>,
     ) -> Result<Vec<RollingTickerMiniResponse>, BinanceError> {
-        self.client.get(Market::RollingTicker, params)
+        self.get_batched::<RollingTickerFullList, _>(params)
+    }
+
+    /// Sends one request per batch of `params` symbols and merges the results.
+    fn get_batched<'p, E, R>(&self, params: &E::Params<'p>) -> Result<Vec<R>, BinanceError>
+    where
+        E: Endpoint<Response = Vec<R>>,
+        E::Params<'p>: SymbolBatches,
+    {
+        let mut merged = Vec::new();
+
+        for params in params.batches() {
+            merged.extend(self.client.call::<E>(&params)?);
+        }
+
+        Ok(merged)
     }
 }
@@ -1,113 +1,49 @@
 use binance_common::enums::spot::OrderResponseType;
 use binance_common::error::BinanceError;
-use binance_common::spot::{
-    endpoint::route::Trade,
-    model::{
-        params::trade::{
-            AllOrderParams, CancelAllOrdersParms, CancelOrderParams, GetOrderParams,
-            NewOrderParams, OpenOrdersParams,
-        },
-        response::trade::{
-            AckResponse, CancelOrderResponse, FullResponse, NewOrderResponse, OrderIdResponse,
-            OrderResponse, ResultResponse,
-        },
-    },
+use binance_common::spot::model::{
+    params::trade::NewOrderParams,
+    response::trade::{FullResponse, NewOrderResponse, ResultResponse},
 };
-use reqwest::Method;
 
 use binance_core::{client::synchronous::Client, signer::signature::Signature};
 
-pub struct TradeApi<'a, S>
+use crate::endpoint::trade::NewOrder;
+use binance_core::transport::synchronous::Transport;
+
+/// Endpoints are declared in [`crate::endpoint::trade`].
+pub struct TradeApi<'a, S, T = reqwest::blocking::Client>
 where
     S: Signature<'a>,
+    T: Transport,
 {
-    client: Client<'a, S>,
+    pub(crate) client: Client<'a, S, T>,
 }
 
-impl<'a, S> TradeApi<'a, S>
+impl<'a, S, T> TradeApi<'a, S, T>
 where
     S: Signature<'a>,
+    T: Transport,
 {
-    pub fn new(client: Client<'a, S>) -> Self {
+    pub fn new(client: Client<'a, S, T>) -> Self {
         TradeApi { client }
     }
 
     pub fn send_new_order(
         &self,
-        params: &NewOrderParams,
+        params: &NewOrderParams<'_>,
     ) -> Result<NewOrderResponse, BinanceError> {
-        if let Some(order_response_type) = &params.new_order_resp_type {
-            match order_response_type {
-                OrderResponseType::Ack => {
-                    return Ok(NewOrderResponse::Ack(self.client.send::<AckResponse>(
-                        Trade::NewOrder,
-                        params,
-                        Method::POST,
-                    )?));
-                }
-                OrderResponseType::Result => {
-                    return Ok(NewOrderResponse::Result(
-                        self.client.send::<ResultResponse>(
-                            Trade::NewOrder,
-                            params,
-                            Method::POST,
-                        )?,
-                    ));
-                }
-                OrderResponseType::Full => {
-                    return Ok(NewOrderResponse::Full(self.client.send::<FullResponse>(
-                        Trade::NewOrder,
-                        params,
-                        Method::POST,
-                    )?));
-                }
+        let response = match &params.new_order_resp_type {
+            Some(OrderResponseType::Result) => {
+                NewOrderResponse::Result(self.client.call_as::<NewOrder, ResultResponse>(params)?)
             }
-        }
-
-        Ok(NewOrderResponse::Ack(self.client.send::<AckResponse>(
-            Trade::NewOrder,
-            params,
-            Method::POST,
-        )?))
-    }
-
-    pub fn send_new_test_order(
-        &self,
-        params: &NewOrderParams,
-    ) -> Result<serde_json::Value, BinanceError> {
-        self.client.send(Trade::TestOrder, params, Method::POST)
-    }
-
-    pub fn get_order(&self, params: &GetOrderParams) -> Result<OrderIdResponse, BinanceError> {
-        self.client.send(Trade::GetOrder, params, Method::GET)
-    }
-
-    pub fn send_cancel_order(
-        &self,
-        params: &CancelOrderParams,
-    ) -> Result<CancelOrderResponse, BinanceError> {
-        self.client.send(Trade::CancelOrder, params, Method::DELETE)
-    }
-
-    pub fn send_cancel_open_orders(
-        &self,
-        params: &CancelAllOrdersParms,
-    ) -> Result<Vec<CancelOrderResponse>, BinanceError> {
-        self.client
-            .send(Trade::CancelAllOrders, params, Method::DELETE)
-    }
-
-    pub fn get_open_orders(
-        &self,
-        params: &OpenOrdersParams,
-    ) -> Result<Vec<OrderResponse>, BinanceError> {
-        self.client.send(Trade::OpenOrders, params, Method::GET)
-    }
+            Some(OrderResponseType::Full) => {
+                NewOrderResponse::Full(self.client.call_as::<NewOrder, FullResponse>(params)?)
+            }
+            Some(OrderResponseType::Ack) | None => {
+                NewOrderResponse::Ack(self.client.call::<NewOrder>(params)?)
+            }
+        };
 
-    pub fn get_all_order(
-        &self,
-        params: &AllOrderParams,
-    ) -> Result<Vec<OrderResponse>, BinanceError> {
-        self.client.send(Trade::AllOrders, params, Method::GET)
+        Ok(response)
     }
 }
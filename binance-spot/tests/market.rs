@@ -200,7 +200,7 @@ mod spot_market_api_integration_tests {
     fn test_get_ticker24h_mini_list() {
         let market_api = shared_test_market();
         let params = Ticker24hParams::new()
-            .symbols("[\"BTCUSDC\",\"SOLUSDC\"]")
+            .symbols(["BTCUSDC", "SOLUSDC"])
             .r#type("MINI");
 
         let ticker24h_mini_list: Vec<Ticker24hMiniResponse> =
@@ -215,7 +215,7 @@ mod spot_market_api_integration_tests {
     fn test_get_ticker24h_full_list() {
         let market_api = shared_test_market();
         let params = Ticker24hParams::new()
-            .symbols("[\"BTCUSDC\",\"SOLUSDC\"]")
+            .symbols(["BTCUSDC", "SOLUSDC"])
             .r#type("FULL");
 
         let ticker24h_full_list: Vec<Ticker24hFullResponse> =
@@ -284,7 +284,7 @@ mod spot_market_api_integration_tests {
         let market_api = shared_test_market();
         let symbols = vec!["BTCUSDC", "SOLUSDC"];
         let params = TickerDayParams::new()
-            .symbols("[\"BTCUSDC\",\"SOLUSDC\"]")
+            .symbols(["BTCUSDC", "SOLUSDC"])
             .r#type("MINI");
 
         let ticker_day_mini_list: Vec<TickerDayMiniResponse> =
@@ -305,7 +305,7 @@ mod spot_market_api_integration_tests {
         let market_api = shared_test_market();
         let symbols = vec!["BTCUSDC", "SOLUSDC"];
         let params = TickerDayParams::new()
-            .symbols("[\"BTCUSDC\",\"SOLUSDC\"]")
+            .symbols(["BTCUSDC", "SOLUSDC"])
             .r#type("FULL");
 
         let ticker_day_mini_list: Vec<TickerDayFullResponse> =
@@ -336,7 +336,7 @@ mod spot_market_api_integration_tests {
         let market_api = shared_test_market();
 
         let price_ticker_list: Vec<PriceTickerResponse> = market_api
-            .get_price_ticker_list("[\"BTCUSDC\",\"SOLUSDC\"]")
+            .get_price_ticker_list(["BTCUSDC", "SOLUSDC"])
             .unwrap();
 
         assert!(price_ticker_list.iter().all(|p| p.price > 0.0));
@@ -364,7 +364,7 @@ mod spot_market_api_integration_tests {
         let market_api = shared_test_market();
 
         let book_ticker: Vec<BookTickerResponse> = market_api
-            .get_book_ticker_list("[\"BTCUSDC\",\"SOLUSDC\"]")
+            .get_book_ticker_list(["BTCUSDC", "SOLUSDC"])
             .unwrap();
 
         assert!(check_book_ticker(&book_ticker[0], "BTCUSDC"));
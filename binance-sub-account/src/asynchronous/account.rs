@@ -0,0 +1,78 @@
+use binance_common::error::BinanceError;
+use binance_common::sub_account::{
+    endpoint::route::Account,
+    model::{
+        params::account::{
+            CreateSubAccountParams, EmailParams, FuturesAccountParams, SubAccountListParams,
+        },
+        response::account::{
+            CreateSubAccountResponse, EnableFuturesResponse, FuturesAccountResponse,
+            SpotAssetsResponse, SubAccountListResponse,
+        },
+    },
+};
+use reqwest::Method;
+
+use binance_core::transport::asynchronous::Transport;
+use binance_core::{client::asynchronous::Client, signer::signature::Signature};
+
+pub struct AccountApi<'a, S, T = reqwest::Client>
+where
+    S: Signature<'a>,
+    T: Transport,
+{
+    client: Client<'a, S, T>,
+}
+
+impl<'a, S, T> AccountApi<'a, S, T>
+where
+    S: Signature<'a>,
+    T: Transport,
+{
+    pub fn new(client: Client<'a, S, T>) -> Self {
+        AccountApi { client }
+    }
+
+    pub async fn get_sub_accounts(
+        &self,
+        params: &SubAccountListParams<'a>,
+    ) -> Result<SubAccountListResponse, BinanceError> {
+        self.client.send(Account::List, params, Method::GET).await
+    }
+
+    pub async fn create_sub_account(
+        &self,
+        params: &CreateSubAccountParams<'a>,
+    ) -> Result<CreateSubAccountResponse, BinanceError> {
+        self.client
+            .send(Account::Create, params, Method::POST)
+            .await
+    }
+
+    pub async fn get_spot_assets(
+        &self,
+        params: &EmailParams<'a>,
+    ) -> Result<SpotAssetsResponse, BinanceError> {
+        self.client
+            .send(Account::SpotAssets, params, Method::GET)
+            .await
+    }
+
+    pub async fn get_futures_account(
+        &self,
+        params: &FuturesAccountParams<'a>,
+    ) -> Result<FuturesAccountResponse, BinanceError> {
+        self.client
+            .send(Account::FuturesAccount, params, Method::GET)
+            .await
+    }
+
+    pub async fn enable_futures(
+        &self,
+        params: &EmailParams<'a>,
+    ) -> Result<EnableFuturesResponse, BinanceError> {
+        self.client
+            .send(Account::EnableFutures, params, Method::POST)
+            .await
+    }
+}
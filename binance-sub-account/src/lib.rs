@@ -0,0 +1,2 @@
+pub mod asynchronous;
+pub mod synchronous;
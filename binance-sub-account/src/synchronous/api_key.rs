@@ -0,0 +1,54 @@
+use binance_common::error::BinanceError;
+use binance_common::sub_account::{
+    endpoint::route::ApiKey,
+    model::{
+        params::api_key::{AddIpRestrictionParams, IpRestrictionParams},
+        response::api_key::IpRestrictionResponse,
+    },
+};
+use reqwest::Method;
+
+use binance_core::transport::synchronous::Transport;
+use binance_core::{client::synchronous::Client, signer::signature::Signature};
+
+/// IP restrictions of sub-account API keys, managed from the master account.
+pub struct ApiKeyApi<'a, S, T = reqwest::blocking::Client>
+where
+    S: Signature<'a>,
+    T: Transport,
+{
+    client: Client<'a, S, T>,
+}
+
+impl<'a, S, T> ApiKeyApi<'a, S, T>
+where
+    S: Signature<'a>,
+    T: Transport,
+{
+    pub fn new(client: Client<'a, S, T>) -> Self {
+        ApiKeyApi { client }
+    }
+
+    pub fn get_ip_restriction(
+        &self,
+        params: &IpRestrictionParams<'a>,
+    ) -> Result<IpRestrictionResponse, BinanceError> {
+        self.client.send(ApiKey::IpRestriction, params, Method::GET)
+    }
+
+    pub fn add_ip_restriction(
+        &self,
+        params: &AddIpRestrictionParams<'a>,
+    ) -> Result<IpRestrictionResponse, BinanceError> {
+        self.client
+            .send(ApiKey::AddIpRestriction, params, Method::POST)
+    }
+
+    pub fn delete_ip_restriction(
+        &self,
+        params: &IpRestrictionParams<'a>,
+    ) -> Result<IpRestrictionResponse, BinanceError> {
+        self.client
+            .send(ApiKey::DeleteIpRestriction, params, Method::DELETE)
+    }
+}
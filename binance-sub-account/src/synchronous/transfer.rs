@@ -0,0 +1,47 @@
+use binance_common::error::BinanceError;
+use binance_common::sub_account::{
+    endpoint::route::Transfer,
+    model::{
+        params::transfer::{TransferHistoryParams, UniversalTransferParams},
+        response::transfer::{TransferHistoryResponse, TransferResponse},
+    },
+};
+use reqwest::Method;
+
+use binance_core::transport::synchronous::Transport;
+use binance_core::{client::synchronous::Client, signer::signature::Signature};
+
+pub struct TransferApi<'a, S, T = reqwest::blocking::Client>
+where
+    S: Signature<'a>,
+    T: Transport,
+{
+    client: Client<'a, S, T>,
+}
+
+impl<'a, S, T> TransferApi<'a, S, T>
+where
+    S: Signature<'a>,
+    T: Transport,
+{
+    pub fn new(client: Client<'a, S, T>) -> Self {
+        TransferApi { client }
+    }
+
+    /// Moves funds between the master account and sub-accounts, or between two sub-accounts.
+    pub fn universal_transfer(
+        &self,
+        params: &UniversalTransferParams<'a>,
+    ) -> Result<TransferResponse, BinanceError> {
+        self.client
+            .send(Transfer::UniversalTransfer, params, Method::POST)
+    }
+
+    pub fn get_transfer_history(
+        &self,
+        params: &TransferHistoryParams<'a>,
+    ) -> Result<TransferHistoryResponse, BinanceError> {
+        self.client
+            .send(Transfer::UniversalTransferHistory, params, Method::GET)
+    }
+}
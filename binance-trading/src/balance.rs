@@ -0,0 +1,198 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use binance_common::spot::model::response::{account::InfoResponse, stream::UserDataEvent};
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Balance {
+    pub asset: String,
+    pub free: f64,
+    pub locked: f64,
+    /// Time of the latest change applied, in ms since the epoch.
+    pub update_time: u64,
+}
+
+impl Balance {
+    fn new(asset: &str) -> Self {
+        Balance {
+            asset: asset.to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub fn total(&self) -> f64 {
+        self.free + self.locked
+    }
+}
+
+#[derive(Default)]
+struct BalanceState {
+    balances: BTreeMap<String, Balance>,
+    update_time: u64,
+    /// Time of the latest `outboundAccountPosition` per asset.
+    positions: BTreeMap<String, u64>,
+    /// Applied deltas as asset, clear time, event time and delta bits.
+    deltas: BTreeSet<(String, u64, u64, u64)>,
+}
+
+impl BalanceState {
+    fn entry(&mut self, asset: &str) -> &mut Balance {
+        self.balances
+            .entry(asset.to_string())
+            .or_insert_with(|| Balance::new(asset))
+    }
+}
+
+/// Spot balances kept current between `get_info` snapshots.
+///
+/// `outboundAccountPosition` events overwrite the free and locked amounts of
+/// the assets they list, `balanceUpdate` deposits, withdrawals and transfers
+/// move the free amount. Events no newer than the snapshot, positions no newer
+/// than the last one of their asset and deltas no newer than that position are
+/// already part of it and dropped, as are deltas delivered twice. Clones share
+/// the same book.
+#[derive(Clone, Default)]
+pub struct SpotBalanceBook {
+    state: Arc<Mutex<BalanceState>>,
+}
+
+impl SpotBalanceBook {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replaces every balance with the snapshot. Events received while it was
+    /// requested can be applied afterwards.
+    pub fn reconcile(&self, info: &InfoResponse) {
+        let mut state = self.lock();
+
+        state.update_time = info.update_time;
+        state.positions.clear();
+        state.deltas.clear();
+        state.balances = info
+            .balances
+            .iter()
+            .map(|balance| {
+                (
+                    balance.asset.clone(),
+                    Balance {
+                        asset: balance.asset.clone(),
+                        free: balance.free,
+                        locked: balance.locked,
+                        update_time: info.update_time,
+                    },
+                )
+            })
+            .collect();
+    }
+
+    /// Applies a balance event, order and list events are ignored. Returns
+    /// whether the book changed.
+    pub fn apply(&self, event: &UserDataEvent) -> bool {
+        let mut state = self.lock();
+
+        match event {
+            UserDataEvent::AccountPosition(position) => {
+                let time = position.last_update_time;
+
+                if time <= state.update_time {
+                    return false;
+                }
+
+                let mut changed = false;
+
+                for update in &position.balances {
+                    if state
+                        .positions
+                        .get(&update.asset)
+                        .is_some_and(|position| time <= *position)
+                    {
+                        continue;
+                    }
+
+                    // Deltas cleared up to here are part of the position, later
+                    // ones that already arrived still apply on top of it.
+                    state.positions.insert(update.asset.clone(), time);
+                    state.deltas.retain(|(asset, clear_time, ..)| {
+                        asset != &update.asset || *clear_time > time
+                    });
+
+                    let pending: f64 = state
+                        .deltas
+                        .iter()
+                        .filter(|(asset, ..)| asset == &update.asset)
+                        .map(|(.., delta)| f64::from_bits(*delta))
+                        .sum();
+
+                    let balance = state.entry(&update.asset);
+                    balance.free = update.free + pending;
+                    balance.locked = update.locked;
+                    balance.update_time = balance.update_time.max(time);
+                    changed = true;
+                }
+
+                changed
+            }
+            UserDataEvent::BalanceUpdate(update) => {
+                let time = update.clear_time;
+                let positioned = state.positions.get(&update.asset);
+
+                if time <= state.update_time || positioned.is_some_and(|position| time <= *position)
+                {
+                    return false;
+                }
+
+                let key = (
+                    update.asset.clone(),
+                    time,
+                    update.event_time,
+                    update.delta.to_bits(),
+                );
+
+                if !state.deltas.insert(key) {
+                    return false;
+                }
+
+                let balance = state.entry(&update.asset);
+                balance.free += update.delta;
+                balance.update_time = balance.update_time.max(time);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn balance(&self, asset: &str) -> Option<Balance> {
+        self.lock().balances.get(asset).cloned()
+    }
+
+    /// Free amount of `asset`, zero when the book does not hold it.
+    pub fn free(&self, asset: &str) -> f64 {
+        self.balance(asset)
+            .map(|balance| balance.free)
+            .unwrap_or_default()
+    }
+
+    /// Every balance with a free or locked amount, sorted by asset.
+    pub fn balances(&self) -> Vec<Balance> {
+        self.lock()
+            .balances
+            .values()
+            .filter(|balance| balance.total() != 0.0)
+            .cloned()
+            .collect()
+    }
+
+    /// Update time of the last snapshot.
+    pub fn update_time(&self) -> u64 {
+        self.lock().update_time
+    }
+
+    fn lock(&self) -> MutexGuard<'_, BalanceState> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
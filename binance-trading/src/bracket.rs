@@ -0,0 +1,652 @@
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, Mutex, MutexGuard},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use binance_common::{
+    enums::futures::{OrderSide, OrderStatus, PositionSide, WorkingType},
+    error::{BinanceError, ErrorCode},
+    futures::model::{
+        params::trade::{CancelOrderParams, GetOrderParams, NewOrderParams, OpenOrdersParams},
+        response::{
+            stream::{OrderUpdate, UserDataEvent},
+            trade::{GetOrderResponse, OrderResponse},
+        },
+    },
+};
+use binance_core::trading::futures::FuturesTradingApi;
+
+use crate::tracker::Lifecycle;
+
+/// Exit taken when the price moves against the position.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StopLoss {
+    /// `STOP_MARKET` at a fixed stop price.
+    Fixed(f64),
+    /// `TRAILING_STOP_MARKET` following the best price by `callback_rate`
+    /// percent, from `activation_price` or right away.
+    Trailing {
+        callback_rate: f64,
+        activation_price: Option<f64>,
+    },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Leg {
+    TakeProfit,
+    StopLoss,
+}
+
+impl Leg {
+    pub fn sibling(&self) -> Leg {
+        match self {
+            Leg::TakeProfit => Leg::StopLoss,
+            Leg::StopLoss => Leg::TakeProfit,
+        }
+    }
+
+    fn tag(&self) -> &'static str {
+        match self {
+            Leg::TakeProfit => "tp",
+            Leg::StopLoss => "sl",
+        }
+    }
+}
+
+/// Take-profit and stop-loss pair closing a position, one canceling the other.
+#[derive(Clone, Debug)]
+pub struct Bracket {
+    pub symbol: String,
+    /// Side of both exits, opposite to the position.
+    pub side: OrderSide,
+    pub quantity: f64,
+    /// Stop price of the `TAKE_PROFIT_MARKET` leg.
+    pub take_profit: f64,
+    pub stop_loss: StopLoss,
+    /// Required in hedge mode, the legs are sent reduce-only otherwise.
+    pub position_side: Option<PositionSide>,
+    pub working_type: Option<WorkingType>,
+}
+
+impl Bracket {
+    pub fn new(
+        symbol: &str,
+        side: OrderSide,
+        quantity: f64,
+        take_profit: f64,
+        stop_loss: StopLoss,
+    ) -> Self {
+        Bracket {
+            symbol: symbol.to_string(),
+            side,
+            quantity,
+            take_profit,
+            stop_loss,
+            position_side: None,
+            working_type: None,
+        }
+    }
+
+    pub fn position_side(mut self, position_side: PositionSide) -> Self {
+        self.position_side = Some(position_side);
+        self
+    }
+
+    pub fn working_type(mut self, working_type: WorkingType) -> Self {
+        self.working_type = Some(working_type);
+        self
+    }
+
+    fn params<'a>(&'a self, leg: Leg, client_order_id: &'a str) -> NewOrderParams<'a> {
+        let mut params = match (leg, self.stop_loss) {
+            (Leg::TakeProfit, _) => NewOrderParams::take_profit_market(
+                &self.symbol,
+                self.side.clone(),
+                self.take_profit,
+                self.quantity,
+            ),
+            (Leg::StopLoss, StopLoss::Fixed(stop_price)) => NewOrderParams::stop_market(
+                &self.symbol,
+                self.side.clone(),
+                stop_price,
+                self.quantity,
+            ),
+            (
+                Leg::StopLoss,
+                StopLoss::Trailing {
+                    callback_rate,
+                    activation_price,
+                },
+            ) => NewOrderParams::trailing_stop_market(
+                &self.symbol,
+                self.side.clone(),
+                callback_rate,
+                activation_price,
+            )
+            .quantity(self.quantity),
+        }
+        .new_client_order_id(client_order_id);
+
+        params = match self.position_side {
+            Some(position_side) => params.position_side(position_side),
+            None => params.reduce_only(true),
+        };
+
+        if let Some(working_type) = &self.working_type {
+            params = params.working_type(working_type.clone());
+        }
+
+        params
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct LegState {
+    pub client_order_id: String,
+    /// `None` when the leg was never accepted by the exchange.
+    pub order_id: Option<u64>,
+    pub status: OrderStatus,
+    pub executed: f64,
+    pub average_price: f64,
+}
+
+impl LegState {
+    fn new(client_order_id: String) -> Self {
+        LegState {
+            client_order_id,
+            order_id: None,
+            status: OrderStatus::New,
+            executed: 0.0,
+            average_price: 0.0,
+        }
+    }
+
+    fn missing(client_order_id: String) -> Self {
+        LegState {
+            status: OrderStatus::Rejected,
+            ..Self::new(client_order_id)
+        }
+    }
+
+    fn is_open(&self) -> bool {
+        self.status.is_open()
+    }
+
+    fn update(&mut self, order_id: u64, status: OrderStatus, executed: f64, average_price: f64) {
+        // A final leg is never reopened by a late event.
+        if !self.is_open() && status.is_open() && self.order_id.is_some() {
+            return;
+        }
+
+        self.order_id = Some(order_id);
+        self.status = status;
+        self.executed = self.executed.max(executed);
+
+        if average_price > 0.0 {
+            self.average_price = average_price;
+        }
+    }
+
+    fn from_response(client_order_id: String, order: &GetOrderResponse) -> Self {
+        let mut leg = Self::new(client_order_id);
+        leg.update(
+            order.order_id,
+            order.status,
+            order.executed_qty,
+            order.avg_price,
+        );
+        leg
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BracketStatus {
+    /// Both legs are working.
+    Active,
+    /// The leg is done and its sibling still has to be canceled.
+    Closing(Leg),
+    /// The leg filled and its sibling is gone.
+    Closed(Leg),
+    /// A leg was canceled, expired or rejected without filling and its sibling is gone.
+    Canceled,
+}
+
+#[derive(Clone, Debug)]
+pub struct BracketOrder {
+    pub id: u64,
+    pub symbol: String,
+    pub take_profit: LegState,
+    pub stop_loss: LegState,
+    pub status: BracketStatus,
+}
+
+impl BracketOrder {
+    pub fn leg(&self, leg: Leg) -> &LegState {
+        match leg {
+            Leg::TakeProfit => &self.take_profit,
+            Leg::StopLoss => &self.stop_loss,
+        }
+    }
+
+    pub fn is_done(&self) -> bool {
+        matches!(
+            self.status,
+            BracketStatus::Closed(_) | BracketStatus::Canceled
+        )
+    }
+
+    fn leg_mut(&mut self, leg: Leg) -> &mut LegState {
+        match leg {
+            Leg::TakeProfit => &mut self.take_profit,
+            Leg::StopLoss => &mut self.stop_loss,
+        }
+    }
+
+    /// Moves on once a leg is final, and again once its sibling is gone.
+    /// A filled leg wins over one that went away, the take-profit breaks ties.
+    fn advance(&mut self) {
+        if self.status == BracketStatus::Active
+            && let Some(leg) = self.pick(|state| !state.is_open())
+        {
+            self.status = BracketStatus::Closing(leg);
+        }
+
+        if let BracketStatus::Closing(leg) = self.status
+            && !self.leg(leg.sibling()).is_open()
+        {
+            self.status = match self.pick(|state| state.executed > 0.0) {
+                Some(leg) => BracketStatus::Closed(leg),
+                None => BracketStatus::Canceled,
+            };
+        }
+    }
+
+    /// The leg matching `filter`, preferring a filled one.
+    fn pick(&self, filter: impl Fn(&LegState) -> bool) -> Option<Leg> {
+        let legs = [Leg::TakeProfit, Leg::StopLoss];
+
+        legs.into_iter()
+            .find(|leg| self.leg(*leg).status == OrderStatus::Filled)
+            .or_else(|| legs.into_iter().find(|leg| filter(self.leg(*leg))))
+    }
+}
+
+#[derive(Default)]
+struct BracketState {
+    prefix: String,
+    next_id: u64,
+    brackets: BTreeMap<u64, BracketOrder>,
+}
+
+impl BracketState {
+    fn client_order_id(&self, id: u64, leg: Leg) -> String {
+        format!("{}-{}-{}", self.prefix, id, leg.tag())
+    }
+
+    /// Reads `<prefix>-<id>-<tp|sl>` back into the bracket id and leg.
+    fn parse(&self, client_order_id: &str) -> Option<(u64, Leg)> {
+        let rest = client_order_id
+            .strip_prefix(self.prefix.as_str())?
+            .strip_prefix('-')?;
+        let (id, tag) = rest.split_once('-')?;
+
+        let leg = match tag {
+            "tp" => Leg::TakeProfit,
+            "sl" => Leg::StopLoss,
+            _ => return None,
+        };
+
+        id.parse().ok().map(|id| (id, leg))
+    }
+}
+
+/// Client-side OCO for futures exits.
+///
+/// Places take-profit and stop-loss legs tagged with their bracket id in
+/// `newClientOrderId`, cancels the sibling once a leg trades on the user
+/// stream, and rebuilds its brackets from open orders after a restart.
+/// Clones share the same state.
+#[derive(Clone)]
+pub struct BracketManager {
+    state: Arc<Mutex<BracketState>>,
+}
+
+impl Default for BracketManager {
+    fn default() -> Self {
+        Self::new("bkt")
+    }
+}
+
+impl BracketManager {
+    /// Legs are sent as `<prefix>-<id>-tp` and `<prefix>-<id>-sl`. Ids start at
+    /// the current time in milliseconds so they are not reused across restarts.
+    pub fn new(prefix: &str) -> Self {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_millis() as u64)
+            .unwrap_or_default();
+
+        BracketManager {
+            state: Arc::new(Mutex::new(BracketState {
+                prefix: prefix.to_string(),
+                next_id: now,
+                brackets: BTreeMap::new(),
+            })),
+        }
+    }
+
+    /// Places both legs, canceling the take-profit again if the stop-loss is rejected.
+    ///
+    /// The bracket is tracked before the first leg is sent so fills that
+    /// arrive on the user stream ahead of the REST responses are not lost.
+    /// When the take-profit cannot be withdrawn after a rejected stop-loss,
+    /// the bracket is kept as closing so [`settle`](Self::settle) retries it,
+    /// and the stop-loss error is returned either way.
+    pub async fn place<T: FuturesTradingApi>(
+        &self,
+        api: &T,
+        bracket: &Bracket,
+    ) -> Result<BracketOrder, BinanceError> {
+        let (id, take_profit_id, stop_loss_id) = {
+            let mut state = self.lock();
+            let id = state.next_id;
+            state.next_id += 1;
+
+            let take_profit_id = state.client_order_id(id, Leg::TakeProfit);
+            let stop_loss_id = state.client_order_id(id, Leg::StopLoss);
+
+            state.brackets.insert(
+                id,
+                BracketOrder {
+                    id,
+                    symbol: bracket.symbol.clone(),
+                    take_profit: LegState::new(take_profit_id.clone()),
+                    stop_loss: LegState::new(stop_loss_id.clone()),
+                    status: BracketStatus::Active,
+                },
+            );
+
+            (id, take_profit_id, stop_loss_id)
+        };
+
+        let take_profit = match api
+            .send_new_order(&bracket.params(Leg::TakeProfit, &take_profit_id))
+            .await
+        {
+            Ok(take_profit) => take_profit,
+            Err(error) => {
+                self.lock().brackets.remove(&id);
+                return Err(error);
+            }
+        };
+        self.merge(id, Leg::TakeProfit, &take_profit);
+
+        let stop_loss = match api
+            .send_new_order(&bracket.params(Leg::StopLoss, &stop_loss_id))
+            .await
+        {
+            Ok(stop_loss) => stop_loss,
+            Err(error) => {
+                let canceled = api
+                    .send_cancel_order(
+                        &CancelOrderParams::new(&bracket.symbol).order_id(take_profit.order_id),
+                    )
+                    .await;
+
+                let mut state = self.lock();
+                if canceled.is_ok() {
+                    state.brackets.remove(&id);
+                } else if let Some(order) = state.brackets.get_mut(&id) {
+                    order.stop_loss = LegState::missing(stop_loss_id);
+                    order.advance();
+                }
+
+                return Err(error);
+            }
+        };
+        let order = self.merge(id, Leg::StopLoss, &stop_loss);
+
+        // The take-profit may have traded before the stop-loss was accepted.
+        if let Some(BracketStatus::Closing(_)) = order.as_ref().map(|order| order.status) {
+            self.settle(api).await?;
+        }
+
+        self.bracket(id)
+            .or(order)
+            .ok_or_else(|| BinanceError::Unknown(format!("bracket {id} is no longer tracked")))
+    }
+
+    /// Applies a user stream event to its bracket, returning the bracket when
+    /// it changed. Call [`settle`](Self::settle) afterwards to cancel siblings.
+    pub fn apply(&self, event: &UserDataEvent) -> Option<BracketOrder> {
+        let UserDataEvent::OrderTradeUpdate(update) = event else {
+            return None;
+        };
+
+        let mut state = self.lock();
+        let (id, leg) = state.parse(&update.order.client_order_id)?;
+        let bracket = state.brackets.get_mut(&id)?;
+
+        apply_update(bracket.leg_mut(leg), &update.order);
+        bracket.advance();
+
+        Some(bracket.clone())
+    }
+
+    /// Cancels the sibling of every closing bracket and returns how many were settled.
+    pub async fn settle<T: FuturesTradingApi>(&self, api: &T) -> Result<usize, BinanceError> {
+        let closing: Vec<(u64, String, Leg, String)> = self
+            .lock()
+            .brackets
+            .values()
+            .filter_map(|bracket| match bracket.status {
+                BracketStatus::Closing(leg) => Some((
+                    bracket.id,
+                    bracket.symbol.clone(),
+                    leg.sibling(),
+                    bracket.leg(leg.sibling()).client_order_id.clone(),
+                )),
+                _ => None,
+            })
+            .collect();
+
+        for (id, symbol, sibling, client_order_id) in &closing {
+            let canceled = api
+                .send_cancel_order(
+                    &CancelOrderParams::new(symbol).orig_client_order_id(client_order_id),
+                )
+                .await;
+
+            let (order_id, status, executed, average_price) = match canceled {
+                Ok(order) => (order.order_id, order.status, order.executed_qty, 0.0),
+                // Lost the race against a fill or an earlier cancel, ask what happened.
+                Err(error) => {
+                    let order = api
+                        .get_order(
+                            &GetOrderParams::new(symbol).orig_client_order_id(client_order_id),
+                        )
+                        .await?;
+
+                    if order.status.is_open() {
+                        return Err(error);
+                    }
+
+                    (
+                        order.order_id,
+                        order.status,
+                        order.executed_qty,
+                        order.avg_price,
+                    )
+                }
+            };
+
+            if let Some(bracket) = self.lock().brackets.get_mut(id) {
+                bracket
+                    .leg_mut(*sibling)
+                    .update(order_id, status, executed, average_price);
+                bracket.advance();
+            }
+        }
+
+        Ok(closing.len())
+    }
+
+    /// [`apply`](Self::apply) followed by [`settle`](Self::settle).
+    pub async fn handle<T: FuturesTradingApi>(
+        &self,
+        api: &T,
+        event: &UserDataEvent,
+    ) -> Result<Option<BracketOrder>, BinanceError> {
+        let Some(bracket) = self.apply(event) else {
+            return Ok(None);
+        };
+
+        if let BracketStatus::Closing(_) = bracket.status {
+            self.settle(api).await?;
+            return Ok(self.bracket(bracket.id));
+        }
+
+        Ok(Some(bracket))
+    }
+
+    /// Rebuilds the brackets of `params.symbol`, or of every symbol when unset,
+    /// from the tagged open orders. A leg whose sibling is no longer open is
+    /// canceled, as that sibling traded or went away while nobody was watching.
+    /// Returns how many brackets were recovered.
+    pub async fn recover<T: FuturesTradingApi>(
+        &self,
+        api: &T,
+        params: &OpenOrdersParams<'_>,
+    ) -> Result<usize, BinanceError> {
+        let orders = api.get_all_open_orders(params).await?;
+
+        let mut found: BTreeMap<u64, Vec<(Leg, GetOrderResponse)>> = BTreeMap::new();
+
+        {
+            let state = self.lock();
+
+            for order in orders {
+                if let Some((id, leg)) = state.parse(&order.client_order_id)
+                    && !state.brackets.contains_key(&id)
+                {
+                    found.entry(id).or_default().push((leg, order));
+                }
+            }
+        }
+
+        for (id, legs) in &found {
+            let symbol = legs[0].1.symbol.clone();
+            let (take_profit_id, stop_loss_id) = {
+                let state = self.lock();
+                (
+                    state.client_order_id(*id, Leg::TakeProfit),
+                    state.client_order_id(*id, Leg::StopLoss),
+                )
+            };
+
+            let mut bracket = BracketOrder {
+                id: *id,
+                symbol: symbol.clone(),
+                take_profit: LegState::new(take_profit_id),
+                stop_loss: LegState::new(stop_loss_id),
+                status: BracketStatus::Active,
+            };
+
+            for leg in [Leg::TakeProfit, Leg::StopLoss] {
+                let client_order_id = bracket.leg(leg).client_order_id.clone();
+
+                let state = match legs.iter().find(|(open, _)| *open == leg) {
+                    Some((_, order)) => LegState::from_response(client_order_id, order),
+                    None => match api
+                        .get_order(
+                            &GetOrderParams::new(&symbol).orig_client_order_id(&client_order_id),
+                        )
+                        .await
+                    {
+                        Ok(order) => LegState::from_response(client_order_id, &order),
+                        Err(BinanceError::Api(error))
+                            if error.error_code() == ErrorCode::NoSuchOrder =>
+                        {
+                            LegState::missing(client_order_id)
+                        }
+                        Err(error) => return Err(error),
+                    },
+                };
+
+                *bracket.leg_mut(leg) = state;
+            }
+
+            bracket.advance();
+
+            let mut state = self.lock();
+            state.next_id = state.next_id.max(id + 1);
+            state.brackets.insert(*id, bracket);
+        }
+
+        self.settle(api).await?;
+
+        Ok(found.len())
+    }
+
+    pub fn bracket(&self, id: u64) -> Option<BracketOrder> {
+        self.lock().brackets.get(&id).cloned()
+    }
+
+    /// Brackets that are not done yet.
+    pub fn brackets(&self, symbol: Option<&str>) -> Vec<BracketOrder> {
+        self.lock()
+            .brackets
+            .values()
+            .filter(|bracket| !bracket.is_done())
+            .filter(|bracket| symbol.is_none_or(|symbol| bracket.symbol == symbol))
+            .cloned()
+            .collect()
+    }
+
+    /// Removes and returns the brackets that are done.
+    pub fn drain_closed(&self) -> Vec<BracketOrder> {
+        let mut state = self.lock();
+
+        let done: Vec<u64> = state
+            .brackets
+            .values()
+            .filter(|bracket| bracket.is_done())
+            .map(|bracket| bracket.id)
+            .collect();
+
+        done.iter()
+            .filter_map(|id| state.brackets.remove(id))
+            .collect()
+    }
+
+    /// Applies a REST response to the tracked leg and returns the bracket.
+    fn merge(&self, id: u64, leg: Leg, order: &OrderResponse) -> Option<BracketOrder> {
+        let mut state = self.lock();
+        let bracket = state.brackets.get_mut(&id)?;
+
+        apply_response(bracket.leg_mut(leg), order);
+        bracket.advance();
+
+        Some(bracket.clone())
+    }
+
+    fn lock(&self) -> MutexGuard<'_, BracketState> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+fn apply_response(leg: &mut LegState, order: &OrderResponse) {
+    leg.update(order.order_id, order.status, order.executed_qty, 0.0);
+}
+
+fn apply_update(leg: &mut LegState, order: &OrderUpdate) {
+    leg.update(
+        order.order_id,
+        order.status,
+        order.cumulative_quantity,
+        order.average_price,
+    );
+}
@@ -0,0 +1,592 @@
+pub mod futures;
+pub mod profile;
+pub mod spot;
+
+use std::{
+    sync::{
+        Mutex, MutexGuard,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use binance_common::{
+    error::BinanceError,
+    futures::model::response::{
+        market::ExchangeInformationResponse as FuturesExchangeInformationResponse,
+        websocket::WebSocketResponse,
+    },
+    spot::model::response::general::{ExchangeInformationResponse, SymbolFilter},
+};
+use tokio::{
+    sync::watch,
+    time::{Instant, sleep_until},
+};
+
+pub use crate::paper::engine::Side;
+use crate::paper::{mandatory, print};
+
+/// Quantities below this are not worth a child order.
+const DUST: f64 = 1e-9;
+
+/// Executions started by this process, to tell their default client order ids apart.
+static EXECUTIONS: AtomicU64 = AtomicU64::new(0);
+
+/// How a child order is priced.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ChildPricing {
+    Market,
+    /// `LIMIT IOC`, takes what is available up to the price.
+    ImmediateOrCancel(f64),
+    /// `LIMIT GTC`, rests on the book until filled or canceled.
+    GoodTillCancel(f64),
+}
+
+#[derive(Clone, Debug)]
+pub struct ChildOrder {
+    pub symbol: String,
+    pub side: Side,
+    pub quantity: f64,
+    pub pricing: ChildPricing,
+    pub client_order_id: String,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ChildState {
+    pub executed: f64,
+    pub cumulative_quote: f64,
+    pub open: bool,
+}
+
+/// Order entry the slicers work child orders through.
+pub trait ExecutionVenue {
+    /// Places a child order and returns its order id.
+    fn place(&self, child: &ChildOrder) -> impl Future<Output = Result<u64, BinanceError>> + Send;
+
+    fn status(
+        &self,
+        symbol: &str,
+        order_id: u64,
+    ) -> impl Future<Output = Result<ChildState, BinanceError>> + Send;
+
+    fn cancel(
+        &self,
+        symbol: &str,
+        order_id: u64,
+    ) -> impl Future<Output = Result<(), BinanceError>> + Send;
+}
+
+#[derive(Clone, Debug)]
+pub struct ParentOrder {
+    pub symbol: String,
+    pub side: Side,
+    pub quantity: f64,
+    /// Worst price children may trade at, they are sent as market orders without it.
+    pub limit_price: Option<f64>,
+}
+
+impl ParentOrder {
+    pub fn new(symbol: &str, side: Side, quantity: f64) -> Self {
+        ParentOrder {
+            symbol: symbol.to_string(),
+            side,
+            quantity,
+            limit_price: None,
+        }
+    }
+
+    pub fn limit_price(mut self, limit_price: f64) -> Self {
+        self.limit_price = Some(limit_price);
+        self
+    }
+}
+
+/// Sizes the parent symbol trades in, from the `LOT_SIZE` and `PRICE_FILTER`
+/// filters of exchangeInfo. A zero step or tick leaves values as they are.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SymbolRules {
+    pub step_size: f64,
+    pub min_qty: f64,
+    pub tick_size: f64,
+}
+
+impl SymbolRules {
+    pub fn new(filters: &[SymbolFilter]) -> Self {
+        filters
+            .iter()
+            .fold(SymbolRules::default(), |rules, filter| match filter {
+                SymbolFilter::LotSize {
+                    min_qty, step_size, ..
+                } => SymbolRules {
+                    step_size: *step_size,
+                    min_qty: *min_qty,
+                    ..rules
+                },
+                SymbolFilter::PriceFilter { tick_size, .. } => SymbolRules {
+                    tick_size: *tick_size,
+                    ..rules
+                },
+                _ => rules,
+            })
+    }
+
+    pub fn spot(exchange_info: &ExchangeInformationResponse, symbol: &str) -> Option<Self> {
+        exchange_info
+            .symbols
+            .iter()
+            .find(|info| info.symbol == symbol)
+            .map(|info| SymbolRules::new(&info.filters))
+    }
+
+    pub fn futures(
+        exchange_info: &FuturesExchangeInformationResponse,
+        symbol: &str,
+    ) -> Option<Self> {
+        exchange_info
+            .symbols
+            .iter()
+            .find(|info| info.symbol == symbol)
+            .map(|info| SymbolRules::new(&info.filters))
+    }
+
+    /// `quantity` floored to the step size.
+    pub fn quantity(&self, quantity: f64) -> f64 {
+        grid(quantity, self.step_size, f64::floor)
+    }
+
+    /// `price` on the tick size, rounded to the side of the limit: down for
+    /// buys and up for sells.
+    pub fn price(&self, price: f64, side: Side) -> f64 {
+        match side {
+            Side::Buy => grid(price, self.tick_size, f64::floor),
+            Side::Sell => grid(price, self.tick_size, f64::ceil),
+        }
+    }
+}
+
+/// `value` moved onto the multiples of `step` by `round`, written with no more
+/// decimals than `step` so the exchange accepts its precision.
+fn grid(value: f64, step: f64, round: fn(f64) -> f64) -> f64 {
+    if step <= 0.0 || !step.is_finite() {
+        return value;
+    }
+
+    let units = value / step;
+    let units = match (units - units.round()).abs() < 1e-9 {
+        true => units.round(),
+        false => round(units),
+    };
+    let scale = 10f64.powi((-step.log10()).ceil().max(0.0) as i32);
+
+    (units * step * scale).round() / scale
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Schedule {
+    /// Equal slices spread evenly over the duration.
+    Twap { duration: Duration, slices: usize },
+    /// One slice per weight, spread evenly over the duration and sized by the weights.
+    Vwap {
+        duration: Duration,
+        weights: Vec<f64>,
+    },
+    /// One resting child of at most `display` at a time, replaced once filled.
+    Iceberg { display: f64 },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExecutionStatus {
+    Running,
+    Filled,
+    /// The schedule ran out before the parent was filled, e.g. because of the
+    /// price limit or the participation rate.
+    Finished,
+    Canceled,
+}
+
+#[derive(Clone, Debug)]
+pub struct Progress {
+    pub status: ExecutionStatus,
+    pub quantity: f64,
+    pub executed: f64,
+    pub cumulative_quote: f64,
+    pub children: usize,
+    pub slice: usize,
+    pub slices: usize,
+}
+
+impl Progress {
+    pub fn remaining(&self) -> f64 {
+        (self.quantity - self.executed).max(0.0)
+    }
+
+    pub fn average_price(&self) -> f64 {
+        match self.executed > 0.0 {
+            true => self.cumulative_quote / self.executed,
+            false => 0.0,
+        }
+    }
+}
+
+/// Parent order worked as a schedule of child orders.
+///
+/// `run` drives the schedule while other tasks may feed market trades for the
+/// participation limit, watch the progress or cancel through a shared reference.
+pub struct Execution {
+    parent: ParentOrder,
+    schedule: Schedule,
+    participation: Option<f64>,
+    poll_interval: Duration,
+    client_order_prefix: String,
+    rules: SymbolRules,
+    market_volume: Mutex<f64>,
+    canceled: watch::Sender<bool>,
+    progress: watch::Sender<Progress>,
+}
+
+impl Execution {
+    /// Fails on a parent quantity, slice count, weight or display size no
+    /// order can be cut from.
+    pub fn new(parent: ParentOrder, schedule: Schedule) -> Result<Self, BinanceError> {
+        let positive = |value: f64| value > 0.0 && value.is_finite();
+
+        if !positive(parent.quantity) {
+            return Err(mandatory("quantity"));
+        }
+
+        match &schedule {
+            Schedule::Twap { slices: 0, .. } => return Err(mandatory("slices")),
+            Schedule::Vwap { weights, .. }
+                if weights.is_empty()
+                    || !weights
+                        .iter()
+                        .all(|weight| *weight == 0.0 || positive(*weight)) =>
+            {
+                return Err(mandatory("weights"));
+            }
+            Schedule::Iceberg { display } if !positive(*display) => {
+                return Err(mandatory("display"));
+            }
+            _ => {}
+        }
+
+        let progress = Progress {
+            status: ExecutionStatus::Running,
+            quantity: parent.quantity,
+            executed: 0.0,
+            cumulative_quote: 0.0,
+            children: 0,
+            slice: 0,
+            slices: slices(&schedule, parent.quantity),
+        };
+
+        let started = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|time| time.as_millis() as u64)
+            .unwrap_or_default();
+
+        Ok(Execution {
+            parent,
+            schedule,
+            participation: None,
+            poll_interval: Duration::from_secs(1),
+            client_order_prefix: format!(
+                "exec-{:x}-{:x}",
+                started,
+                EXECUTIONS.fetch_add(1, Ordering::Relaxed)
+            ),
+            rules: SymbolRules::default(),
+            market_volume: Mutex::new(0.0),
+            canceled: watch::Sender::new(false),
+            progress: watch::Sender::new(progress),
+        })
+    }
+
+    pub fn twap(
+        parent: ParentOrder,
+        duration: Duration,
+        slices: usize,
+    ) -> Result<Self, BinanceError> {
+        Self::new(parent, Schedule::Twap { duration, slices })
+    }
+
+    pub fn vwap(
+        parent: ParentOrder,
+        duration: Duration,
+        weights: Vec<f64>,
+    ) -> Result<Self, BinanceError> {
+        Self::new(parent, Schedule::Vwap { duration, weights })
+    }
+
+    pub fn iceberg(parent: ParentOrder, display: f64) -> Result<Self, BinanceError> {
+        Self::new(parent, Schedule::Iceberg { display })
+    }
+
+    /// Cuts children on the step size, the last one taking the remainder, and
+    /// puts the limit price on the tick size. The parent quantity and display
+    /// size are floored to the step size as well.
+    pub fn rules(mut self, rules: SymbolRules) -> Self {
+        self.rules = rules;
+        self.parent.quantity = rules.quantity(self.parent.quantity);
+        self.parent.limit_price = self
+            .parent
+            .limit_price
+            .map(|price| rules.price(price, self.parent.side));
+
+        if let Schedule::Iceberg { display } = &mut self.schedule {
+            *display = rules.quantity(*display);
+        }
+
+        let slices = slices(&self.schedule, self.parent.quantity);
+        self.progress.send_modify(|progress| {
+            progress.quantity = self.parent.quantity;
+            progress.slices = slices;
+        });
+
+        self
+    }
+
+    /// Caps the executed quantity at this share of the market volume fed through
+    /// `apply_market` since the start. Nothing is sent until trades are fed.
+    pub fn participation(mut self, participation: f64) -> Self {
+        self.participation = Some(participation);
+        self
+    }
+
+    /// How often open children are queried.
+    pub fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Children are sent as `<prefix>-<n>`. The default prefix is made of the
+    /// start time and a counter, so executions do not reuse each other's ids.
+    pub fn client_order_prefix(mut self, client_order_prefix: &str) -> Self {
+        self.client_order_prefix = client_order_prefix.to_string();
+        self
+    }
+
+    pub fn progress(&self) -> watch::Receiver<Progress> {
+        self.progress.subscribe()
+    }
+
+    /// Stops the schedule and cancels the open child, `run` returns once it is gone.
+    pub fn cancel(&self) {
+        self.canceled.send_replace(true);
+    }
+
+    /// Counts the prints of the parent symbol towards the participation limit.
+    pub fn apply_market(&self, response: &WebSocketResponse) {
+        if let Some((symbol, _, quantity, _)) = print(response)
+            && symbol == self.parent.symbol
+            && quantity.is_finite()
+        {
+            *self.volume() += quantity;
+        }
+    }
+
+    pub async fn run<V: ExecutionVenue>(&self, venue: &V) -> Result<Progress, BinanceError> {
+        match &self.schedule {
+            Schedule::Twap { duration, slices } => {
+                self.slice(venue, *duration, &vec![1.0; *slices]).await?
+            }
+            Schedule::Vwap { duration, weights } => self.slice(venue, *duration, weights).await?,
+            Schedule::Iceberg { display } => self.refill(venue, *display).await?,
+        }
+
+        Ok(self.progress.borrow().clone())
+    }
+
+    async fn slice<V: ExecutionVenue>(
+        &self,
+        venue: &V,
+        duration: Duration,
+        weights: &[f64],
+    ) -> Result<(), BinanceError> {
+        let total: f64 = weights.iter().sum();
+        let start = Instant::now();
+        let step = duration / weights.len().max(1) as u32;
+        let mut share = 0.0;
+
+        let pricing = match self.parent.limit_price {
+            Some(price) => ChildPricing::ImmediateOrCancel(price),
+            None => ChildPricing::Market,
+        };
+
+        for (index, weight) in weights.iter().enumerate() {
+            if !self.wait(start + step * index as u32).await {
+                self.finish(ExecutionStatus::Canceled);
+                return Ok(());
+            }
+
+            share += match total > 0.0 {
+                true => weight / total,
+                false => 1.0 / weights.len() as f64,
+            };
+
+            let target = match index + 1 == weights.len() {
+                true => self.parent.quantity,
+                false => self.rules.quantity(self.parent.quantity * share),
+            };
+
+            let quantity = self
+                .rules
+                .quantity(self.allowance(target - self.executed()));
+
+            if self.sendable(quantity) {
+                self.work(venue, quantity, pricing).await?;
+            }
+
+            self.progress
+                .send_modify(|progress| progress.slice = index + 1);
+        }
+
+        self.finish(match self.executed() + DUST >= self.parent.quantity {
+            true => ExecutionStatus::Filled,
+            false => ExecutionStatus::Finished,
+        });
+
+        Ok(())
+    }
+
+    async fn refill<V: ExecutionVenue>(&self, venue: &V, display: f64) -> Result<(), BinanceError> {
+        let price = self.parent.limit_price.ok_or_else(|| mandatory("price"))?;
+
+        if !self.sendable(display) {
+            return Err(mandatory("display"));
+        }
+
+        while self.executed() + DUST < self.parent.quantity {
+            let remaining = self.parent.quantity - self.executed();
+
+            // A remainder below the minimum quantity cannot be sent at all.
+            if !self.sendable(remaining) {
+                self.finish(ExecutionStatus::Finished);
+                return Ok(());
+            }
+
+            let quantity = self.rules.quantity(self.allowance(display.min(remaining)));
+
+            if self.sendable(quantity) {
+                self.work(venue, quantity, ChildPricing::GoodTillCancel(price))
+                    .await?;
+                self.progress.send_modify(|progress| progress.slice += 1);
+            } else if !self.wait(Instant::now() + self.poll_interval).await {
+                self.finish(ExecutionStatus::Canceled);
+                return Ok(());
+            }
+
+            if *self.canceled.borrow() {
+                self.finish(ExecutionStatus::Canceled);
+                return Ok(());
+            }
+        }
+
+        self.finish(ExecutionStatus::Filled);
+
+        Ok(())
+    }
+
+    /// Sends one child and follows it until it is done, canceling it when the
+    /// execution is canceled.
+    async fn work<V: ExecutionVenue>(
+        &self,
+        venue: &V,
+        quantity: f64,
+        pricing: ChildPricing,
+    ) -> Result<(), BinanceError> {
+        let children = self.progress.borrow().children + 1;
+        let child = ChildOrder {
+            symbol: self.parent.symbol.clone(),
+            side: self.parent.side,
+            quantity,
+            pricing,
+            client_order_id: format!("{}-{}", self.client_order_prefix, children),
+        };
+
+        let order_id = venue.place(&child).await?;
+        let base = self.progress.borrow().clone();
+        let mut state = venue.status(&child.symbol, order_id).await?;
+
+        while state.open {
+            self.report(&base, children, state);
+
+            if !self.wait(Instant::now() + self.poll_interval).await {
+                let canceled = venue.cancel(&child.symbol, order_id).await;
+                state = venue.status(&child.symbol, order_id).await?;
+
+                // A cancel that lost the race against the last fill is fine.
+                if let Err(error) = canceled
+                    && state.open
+                {
+                    return Err(error);
+                }
+
+                break;
+            }
+
+            state = venue.status(&child.symbol, order_id).await?;
+        }
+
+        self.report(&base, children, state);
+
+        Ok(())
+    }
+
+    /// Sleeps until `deadline`, returning `false` early if canceled.
+    async fn wait(&self, deadline: Instant) -> bool {
+        let mut canceled = self.canceled.subscribe();
+
+        tokio::select! {
+            _ = sleep_until(deadline) => {}
+            _ = canceled.wait_for(|canceled| *canceled) => return false,
+        }
+
+        !*self.canceled.borrow()
+    }
+
+    fn allowance(&self, quantity: f64) -> f64 {
+        let quantity = quantity.min(self.parent.quantity - self.executed());
+
+        match self.participation {
+            Some(participation) => quantity.min(participation * *self.volume() - self.executed()),
+            None => quantity,
+        }
+    }
+
+    fn sendable(&self, quantity: f64) -> bool {
+        quantity > DUST && quantity + DUST >= self.rules.min_qty
+    }
+
+    fn report(&self, base: &Progress, children: usize, state: ChildState) {
+        self.progress.send_modify(|progress| {
+            progress.children = children;
+            progress.executed = base.executed + state.executed;
+            progress.cumulative_quote = base.cumulative_quote + state.cumulative_quote;
+        });
+    }
+
+    fn finish(&self, status: ExecutionStatus) {
+        self.progress
+            .send_modify(|progress| progress.status = status);
+    }
+
+    fn executed(&self) -> f64 {
+        self.progress.borrow().executed
+    }
+
+    fn volume(&self) -> MutexGuard<'_, f64> {
+        self.market_volume
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+fn slices(schedule: &Schedule, quantity: f64) -> usize {
+    match schedule {
+        Schedule::Twap { slices, .. } => *slices,
+        Schedule::Vwap { weights, .. } => weights.len(),
+        Schedule::Iceberg { display } if *display > 0.0 => (quantity / display).ceil() as usize,
+        Schedule::Iceberg { .. } => 0,
+    }
+}
@@ -0,0 +1,94 @@
+use binance_common::{
+    enums::futures::{OrderSide, PositionSide, TimeInForce},
+    error::BinanceError,
+    futures::model::params::trade::{CancelOrderParams, GetOrderParams, NewOrderParams},
+};
+use binance_core::trading::futures::FuturesTradingApi;
+
+use crate::{
+    execution::{ChildOrder, ChildPricing, ChildState, ExecutionVenue, Side},
+    tracker::Lifecycle,
+};
+
+/// Works child orders through any [`FuturesTradingApi`].
+pub struct FuturesVenue<'a, T> {
+    api: &'a T,
+    position_side: Option<PositionSide>,
+    reduce_only: bool,
+}
+
+impl<'a, T: FuturesTradingApi> FuturesVenue<'a, T> {
+    pub fn new(api: &'a T) -> Self {
+        FuturesVenue {
+            api,
+            position_side: None,
+            reduce_only: false,
+        }
+    }
+
+    /// Required in hedge mode.
+    pub fn position_side(mut self, position_side: PositionSide) -> Self {
+        self.position_side = Some(position_side);
+        self
+    }
+
+    /// One-way mode only.
+    pub fn reduce_only(mut self, reduce_only: bool) -> Self {
+        self.reduce_only = reduce_only;
+        self
+    }
+}
+
+impl<T: FuturesTradingApi + Sync> ExecutionVenue for FuturesVenue<'_, T> {
+    async fn place(&self, child: &ChildOrder) -> Result<u64, BinanceError> {
+        let side = match child.side {
+            Side::Buy => OrderSide::Buy,
+            Side::Sell => OrderSide::Sell,
+        };
+
+        let mut params = match child.pricing {
+            ChildPricing::Market => NewOrderParams::market(&child.symbol, side, child.quantity),
+            ChildPricing::ImmediateOrCancel(price) => {
+                NewOrderParams::limit(&child.symbol, side, price, child.quantity)
+                    .time_in_force(TimeInForce::Ioc)
+            }
+            ChildPricing::GoodTillCancel(price) => {
+                NewOrderParams::limit(&child.symbol, side, price, child.quantity)
+            }
+        }
+        .new_client_order_id(&child.client_order_id);
+
+        if let Some(position_side) = self.position_side {
+            params = params.position_side(position_side);
+        }
+
+        if self.reduce_only {
+            params = params.reduce_only(true);
+        }
+
+        self.api
+            .send_new_order(&params)
+            .await
+            .map(|order| order.order_id)
+    }
+
+    async fn status(&self, symbol: &str, order_id: u64) -> Result<ChildState, BinanceError> {
+        let order = self
+            .api
+            .get_order(&GetOrderParams::new(symbol).order_id(order_id))
+            .await?;
+
+        Ok(ChildState {
+            executed: order.executed_qty,
+            cumulative_quote: order.cum_quote,
+            open: order.status.is_open(),
+        })
+    }
+
+    async fn cancel(&self, symbol: &str, order_id: u64) -> Result<(), BinanceError> {
+        self.api
+            .send_cancel_order(&CancelOrderParams::new(symbol).order_id(order_id))
+            .await
+            .map(|_| ())
+    }
+}
@@ -0,0 +1,65 @@
+use std::{collections::BTreeMap, time::Duration};
+
+use binance_common::spot::model::response::market::KlinesResponse;
+
+const DAY: u64 = 86_400_000;
+
+/// Average traded volume by time of day, learned from historical klines.
+#[derive(Clone, Debug, Default)]
+pub struct VolumeProfile {
+    interval: u64,
+    buckets: BTreeMap<u64, (f64, usize)>,
+}
+
+impl VolumeProfile {
+    /// Buckets the klines by their open time of day. The kline interval sets
+    /// the bucket width, so days of history of one interval are expected.
+    pub fn from_klines(klines: &[KlinesResponse]) -> Self {
+        let interval = klines
+            .first()
+            .map(|kline| kline.close_time + 1 - kline.open_time)
+            .filter(|interval| *interval > 0)
+            .unwrap_or(60_000);
+
+        let mut buckets: BTreeMap<u64, (f64, usize)> = BTreeMap::new();
+
+        for kline in klines {
+            let bucket = buckets.entry(kline.open_time % DAY / interval).or_default();
+            bucket.0 += kline.volume;
+            bucket.1 += 1;
+        }
+
+        VolumeProfile { interval, buckets }
+    }
+
+    /// Mean volume of the kline interval containing `time`, in ms since the epoch.
+    pub fn volume_at(&self, time: u64) -> Option<f64> {
+        self.buckets
+            .get(&(time % DAY / self.interval))
+            .map(|(volume, count)| volume / *count as f64)
+    }
+
+    /// Weights of `slices` equal slices of `duration` from `start`, each the
+    /// volume expected at its midpoint. Times without history get the mean.
+    pub fn weights(&self, start: u64, duration: Duration, slices: usize) -> Vec<f64> {
+        let mean = match self.buckets.is_empty() {
+            true => 1.0,
+            false => {
+                self.buckets
+                    .values()
+                    .map(|(volume, count)| volume / *count as f64)
+                    .sum::<f64>()
+                    / self.buckets.len() as f64
+            }
+        };
+
+        let step = duration.as_millis() as u64 / slices.max(1) as u64;
+
+        (0..slices as u64)
+            .map(|slice| {
+                self.volume_at(start + slice * step + step / 2)
+                    .unwrap_or(mean)
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,75 @@
+use binance_common::{
+    enums::spot::{OrderResponseType, OrderSide, OrderStatus, OrderType, TimeInForce},
+    error::BinanceError,
+    spot::model::{
+        params::trade::{CancelOrderParams, GetOrderParams, NewOrderParams},
+        response::trade::NewOrderResponse,
+    },
+};
+use binance_core::trading::spot::SpotTradingApi;
+
+use crate::{
+    execution::{ChildOrder, ChildPricing, ChildState, ExecutionVenue, Side},
+    tracker::{Lifecycle, parse},
+};
+
+/// Works child orders through any [`SpotTradingApi`].
+pub struct SpotVenue<'a, T> {
+    api: &'a T,
+}
+
+impl<'a, T: SpotTradingApi> SpotVenue<'a, T> {
+    pub fn new(api: &'a T) -> Self {
+        SpotVenue { api }
+    }
+}
+
+impl<T: SpotTradingApi + Sync> ExecutionVenue for SpotVenue<'_, T> {
+    async fn place(&self, child: &ChildOrder) -> Result<u64, BinanceError> {
+        let side = match child.side {
+            Side::Buy => OrderSide::Buy,
+            Side::Sell => OrderSide::Sell,
+        };
+
+        let params = match child.pricing {
+            ChildPricing::Market => {
+                NewOrderParams::new(&child.symbol, side, OrderType::Market).quantity(child.quantity)
+            }
+            ChildPricing::ImmediateOrCancel(price) => {
+                NewOrderParams::limit(&child.symbol, side, price, child.quantity)
+                    .time_in_force(TimeInForce::Ioc)
+            }
+            ChildPricing::GoodTillCancel(price) => {
+                NewOrderParams::limit(&child.symbol, side, price, child.quantity)
+            }
+        }
+        .new_client_order_id(&child.client_order_id)
+        .new_order_resp_type(OrderResponseType::Ack);
+
+        Ok(match self.api.send_new_order(&params).await? {
+            NewOrderResponse::Ack(order) => order.order_id,
+            NewOrderResponse::Result(order) => order.order_id,
+            NewOrderResponse::Full(order) => order.order_id,
+        })
+    }
+
+    async fn status(&self, symbol: &str, order_id: u64) -> Result<ChildState, BinanceError> {
+        let order = self
+            .api
+            .get_order(&GetOrderParams::new(symbol).order_id(order_id))
+            .await?;
+
+        Ok(ChildState {
+            executed: order.executed_qty,
+            cumulative_quote: order.cummulative_quote_qty,
+            open: parse::<OrderStatus>(&order.status).is_some_and(|status| status.is_open()),
+        })
+    }
+
+    async fn cancel(&self, symbol: &str, order_id: u64) -> Result<(), BinanceError> {
+        self.api
+            .send_cancel_order(&CancelOrderParams::new(symbol).order_id(order_id))
+            .await
+            .map(|_| ())
+    }
+}
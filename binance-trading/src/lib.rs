@@ -0,0 +1,7 @@
+pub mod balance;
+pub mod bracket;
+pub mod execution;
+pub mod paper;
+pub mod position;
+pub mod risk;
+pub mod tracker;
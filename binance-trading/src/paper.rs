@@ -0,0 +1,105 @@
+pub mod engine;
+pub mod futures;
+pub mod spot;
+
+use std::time::Duration;
+
+use binance_common::{
+    error::{ApiError, BinanceError},
+    futures::model::response::websocket::WebSocketResponse,
+    symbol,
+};
+use serde::Serialize;
+
+#[derive(Clone, Debug)]
+pub struct PaperConfig {
+    pub maker_fee: f64,
+    pub taker_fee: f64,
+    pub latency: Duration,
+}
+
+impl Default for PaperConfig {
+    fn default() -> Self {
+        PaperConfig {
+            maker_fee: 0.001,
+            taker_fee: 0.001,
+            latency: Duration::ZERO,
+        }
+    }
+}
+
+impl PaperConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn maker_fee(mut self, maker_fee: f64) -> Self {
+        self.maker_fee = maker_fee;
+        self
+    }
+
+    pub fn taker_fee(mut self, taker_fee: f64) -> Self {
+        self.taker_fee = taker_fee;
+        self
+    }
+
+    /// Delay between accepting an order and letting it match, measured on the
+    /// event time of the feed so replays stay deterministic.
+    pub fn latency(mut self, latency: Duration) -> Self {
+        self.latency = latency;
+        self
+    }
+}
+
+pub(crate) fn reject(code: i16, msg: &str) -> BinanceError {
+    BinanceError::Api(ApiError::new(code, msg))
+}
+
+pub(crate) fn mandatory(parameter: &str) -> BinanceError {
+    reject(
+        -1102,
+        &format!(
+            "Mandatory parameter '{}' was not sent, was empty/null, or malformed.",
+            parameter
+        ),
+    )
+}
+
+/// Renders a serde enum the way Binance spells it in responses, e.g. `STOP_LOSS`.
+pub(crate) fn name<T: Serialize>(value: &T) -> String {
+    serde_json::to_value(value)
+        .ok()
+        .and_then(|value| value.as_str().map(str::to_string))
+        .unwrap_or_default()
+}
+
+pub(crate) fn split_symbol(symbol: &str) -> Option<(String, String)> {
+    symbol::split_symbol(symbol).map(|(base, quote)| (base.to_string(), quote.to_string()))
+}
+
+/// Reads a print `(symbol, price, quantity, time)` from a market stream event.
+/// Aggregate trades carry their own quantity, kline and mini ticker updates are
+/// treated as prints of the close price with unlimited quantity.
+pub(crate) fn print(response: &WebSocketResponse) -> Option<(&str, f64, f64, u64)> {
+    match response {
+        WebSocketResponse::AggTrade(trade) => {
+            Some((&trade.symbol, trade.price, trade.quantity, trade.trade_time))
+        }
+        WebSocketResponse::Kline(kline) => Some((
+            &kline.symbol,
+            kline.kline.close_price,
+            f64::INFINITY,
+            kline.event_time,
+        )),
+        WebSocketResponse::MiniTicker(ticker) => Some((
+            &ticker.symbol,
+            ticker.close_price,
+            f64::INFINITY,
+            ticker.event_time,
+        )),
+        WebSocketResponse::MarkPrice(_)
+        | WebSocketResponse::BookTicker(_)
+        | WebSocketResponse::DepthUpdate(_)
+        | WebSocketResponse::Init => None,
+    }
+}
@@ -0,0 +1,396 @@
+use std::collections::{BTreeMap, HashMap};
+
+use binance_common::error::BinanceError;
+
+use crate::paper::{PaperConfig, reject};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Side {
+    Buy,
+    Sell,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status {
+    New,
+    PartiallyFilled,
+    Filled,
+    Canceled,
+    Expired,
+}
+
+impl Status {
+    pub fn is_open(&self) -> bool {
+        matches!(self, Status::New | Status::PartiallyFilled)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Validity {
+    GoodTillCancel,
+    ImmediateOrCancel,
+    FillOrKill,
+    PostOnly,
+}
+
+/// Price condition that has to print before a stop or take-profit order becomes active.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Trigger {
+    AtOrAbove(f64),
+    AtOrBelow(f64),
+}
+
+impl Trigger {
+    /// Stop orders trigger against the side's adverse move, take-profit orders
+    /// against the favourable one.
+    pub fn new(side: Side, stop_price: f64, take_profit: bool) -> Self {
+        match (side, take_profit) {
+            (Side::Buy, false) | (Side::Sell, true) => Trigger::AtOrAbove(stop_price),
+            (Side::Sell, false) | (Side::Buy, true) => Trigger::AtOrBelow(stop_price),
+        }
+    }
+
+    pub fn is_hit(&self, price: f64) -> bool {
+        match self {
+            Trigger::AtOrAbove(stop_price) => price >= *stop_price,
+            Trigger::AtOrBelow(stop_price) => price <= *stop_price,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct OrderRequest<M> {
+    pub symbol: String,
+    pub client_order_id: Option<String>,
+    pub side: Side,
+    pub price: Option<f64>,
+    pub quantity: Option<f64>,
+    pub quote_quantity: Option<f64>,
+    pub validity: Validity,
+    pub trigger: Option<Trigger>,
+    pub meta: M,
+}
+
+#[derive(Clone, Debug)]
+pub struct PaperOrder<M> {
+    pub order_id: u64,
+    pub client_order_id: String,
+    pub symbol: String,
+    pub side: Side,
+    pub price: Option<f64>,
+    pub quantity: f64,
+    pub quote_quantity: Option<f64>,
+    pub validity: Validity,
+    pub trigger: Option<Trigger>,
+    pub executed: f64,
+    pub cumulative_quote: f64,
+    pub status: Status,
+    pub time: u64,
+    pub update_time: u64,
+    pub meta: M,
+    active_at: u64,
+    arrived: bool,
+}
+
+impl<M> PaperOrder<M> {
+    pub fn remaining(&self) -> f64 {
+        self.quantity - self.executed
+    }
+
+    pub fn average_price(&self) -> f64 {
+        match self.executed > 0.0 {
+            true => self.cumulative_quote / self.executed,
+            false => 0.0,
+        }
+    }
+
+    fn crosses(&self, price: f64) -> bool {
+        match (self.price, self.side) {
+            (None, _) => true,
+            (Some(limit), Side::Buy) => price <= limit,
+            (Some(limit), Side::Sell) => price >= limit,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Fill {
+    pub trade_id: u64,
+    pub order_id: u64,
+    pub symbol: String,
+    pub side: Side,
+    pub price: f64,
+    pub quantity: f64,
+    pub maker: bool,
+    pub fee_rate: f64,
+    pub time: u64,
+}
+
+impl Fill {
+    pub fn quote_quantity(&self) -> f64 {
+        self.price * self.quantity
+    }
+}
+
+/// Price-time matching of simulated orders against a public trade feed.
+///
+/// Orders arrive at the first event at least `latency` after they were
+/// accepted. An order that crosses on arrival takes liquidity for its whole
+/// quantity at the event price, resting orders are filled as maker at their
+/// limit price, sharing the quantity of each print in order of arrival.
+pub struct Engine<M> {
+    config: PaperConfig,
+    orders: BTreeMap<u64, PaperOrder<M>>,
+    prices: HashMap<String, f64>,
+    clock: u64,
+    next_order_id: u64,
+    next_trade_id: u64,
+}
+
+impl<M> Engine<M> {
+    pub fn new(config: PaperConfig) -> Self {
+        Engine {
+            config,
+            orders: BTreeMap::new(),
+            prices: HashMap::new(),
+            clock: 0,
+            next_order_id: 1,
+            next_trade_id: 1,
+        }
+    }
+
+    pub fn config(&self) -> &PaperConfig {
+        &self.config
+    }
+
+    /// Event time of the latest print, the only clock the engine knows about.
+    pub fn clock(&self) -> u64 {
+        self.clock
+    }
+
+    pub fn price(&self, symbol: &str) -> Option<f64> {
+        self.prices.get(symbol).copied()
+    }
+
+    pub fn order(&self, order_id: u64) -> Option<&PaperOrder<M>> {
+        self.orders.get(&order_id)
+    }
+
+    pub fn find(
+        &self,
+        symbol: &str,
+        order_id: Option<u64>,
+        client_order_id: Option<&str>,
+    ) -> Option<&PaperOrder<M>> {
+        self.orders.values().rev().find(|order| {
+            order.symbol == symbol
+                && order_id.is_none_or(|order_id| order.order_id == order_id)
+                && client_order_id
+                    .is_none_or(|client_order_id| order.client_order_id == client_order_id)
+        })
+    }
+
+    pub fn open_orders<'a>(
+        &'a self,
+        symbol: Option<&'a str>,
+    ) -> impl Iterator<Item = &'a PaperOrder<M>> + 'a {
+        self.orders.values().filter(move |order| {
+            order.status.is_open() && symbol.is_none_or(|symbol| order.symbol == symbol)
+        })
+    }
+
+    pub fn submit(&mut self, request: OrderRequest<M>) -> Result<(u64, Vec<Fill>), BinanceError> {
+        if let Some(client_order_id) = &request.client_order_id
+            && self.open_orders(None).any(|order| {
+                order.symbol == request.symbol && order.client_order_id == *client_order_id
+            })
+        {
+            return Err(reject(-2010, "Duplicate order sent."));
+        }
+
+        let order_id = self.next_order_id;
+        self.next_order_id += 1;
+
+        let order = PaperOrder {
+            order_id,
+            client_order_id: request
+                .client_order_id
+                .unwrap_or_else(|| format!("paper-{}", order_id)),
+            symbol: request.symbol,
+            side: request.side,
+            price: request.price,
+            quantity: request.quantity.unwrap_or_default(),
+            quote_quantity: request.quote_quantity,
+            validity: request.validity,
+            trigger: request.trigger,
+            executed: 0.0,
+            cumulative_quote: 0.0,
+            status: Status::New,
+            time: self.clock,
+            update_time: self.clock,
+            meta: request.meta,
+            active_at: self.clock + self.config.latency.as_millis() as u64,
+            arrived: false,
+        };
+
+        let price = match order.active_at <= self.clock {
+            true => self.prices.get(&order.symbol).copied(),
+            false => None,
+        };
+
+        self.orders.insert(order_id, order);
+
+        let fills = price
+            .and_then(|price| self.step(order_id, price, f64::INFINITY))
+            .into_iter()
+            .collect();
+
+        Ok((order_id, fills))
+    }
+
+    pub fn cancel(
+        &mut self,
+        symbol: &str,
+        order_id: Option<u64>,
+        client_order_id: Option<&str>,
+    ) -> Result<&PaperOrder<M>, BinanceError> {
+        let order_id = self
+            .find(symbol, order_id, client_order_id)
+            .filter(|order| order.status.is_open())
+            .map(|order| order.order_id)
+            .ok_or_else(|| reject(-2011, "Unknown order sent."))?;
+
+        self.close(order_id, Status::Canceled);
+
+        Ok(&self.orders[&order_id])
+    }
+
+    /// Applies a public print and returns the fills it produced.
+    pub fn trade(&mut self, symbol: &str, price: f64, quantity: f64, time: u64) -> Vec<Fill> {
+        self.clock = self.clock.max(time);
+        self.prices.insert(symbol.to_string(), price);
+
+        let order_ids: Vec<u64> = self
+            .open_orders(Some(symbol))
+            .map(|order| order.order_id)
+            .collect();
+
+        let mut liquidity = quantity;
+        let mut fills = Vec::new();
+
+        for order_id in order_ids {
+            if let Some(fill) = self.step(order_id, price, liquidity) {
+                if fill.maker {
+                    liquidity -= fill.quantity;
+                }
+
+                fills.push(fill);
+            }
+        }
+
+        fills
+    }
+
+    fn step(&mut self, order_id: u64, price: f64, liquidity: f64) -> Option<Fill> {
+        let order = self.orders.get_mut(&order_id)?;
+        let mut fresh = false;
+
+        if !order.arrived {
+            if self.clock < order.active_at {
+                return None;
+            }
+
+            order.arrived = true;
+            fresh = true;
+        }
+
+        if let Some(trigger) = order.trigger {
+            if !trigger.is_hit(price) {
+                return None;
+            }
+
+            order.trigger = None;
+            fresh = true;
+        }
+
+        let crosses = order.crosses(price);
+
+        if fresh {
+            let expired = match order.validity {
+                Validity::GoodTillCancel => false,
+                Validity::ImmediateOrCancel | Validity::FillOrKill => !crosses,
+                Validity::PostOnly => crosses,
+            };
+
+            if expired {
+                self.close(order_id, Status::Expired);
+                return None;
+            }
+
+            return match crosses {
+                true => self.fill(order_id, price, f64::INFINITY, false),
+                false => None,
+            };
+        }
+
+        match crosses && liquidity > 0.0 {
+            true => {
+                let limit = order.price.unwrap_or(price);
+                self.fill(order_id, limit, liquidity, true)
+            }
+            false => None,
+        }
+    }
+
+    fn fill(&mut self, order_id: u64, price: f64, liquidity: f64, maker: bool) -> Option<Fill> {
+        let order = self.orders.get_mut(&order_id)?;
+
+        if order.quantity == 0.0
+            && let Some(quote_quantity) = order.quote_quantity
+        {
+            order.quantity = quote_quantity / price;
+        }
+
+        let quantity = order.remaining().min(liquidity);
+
+        if quantity <= 0.0 {
+            return None;
+        }
+
+        order.executed = match quantity == order.remaining() {
+            true => order.quantity,
+            false => order.executed + quantity,
+        };
+        order.cumulative_quote += price * quantity;
+        order.update_time = self.clock;
+        order.status = match order.executed >= order.quantity {
+            true => Status::Filled,
+            false => Status::PartiallyFilled,
+        };
+
+        let trade_id = self.next_trade_id;
+        self.next_trade_id += 1;
+
+        Some(Fill {
+            trade_id,
+            order_id,
+            symbol: order.symbol.clone(),
+            side: order.side,
+            price,
+            quantity,
+            maker,
+            fee_rate: match maker {
+                true => self.config.maker_fee,
+                false => self.config.taker_fee,
+            },
+            time: self.clock,
+        })
+    }
+
+    fn close(&mut self, order_id: u64, status: Status) {
+        if let Some(order) = self.orders.get_mut(&order_id) {
+            order.status = status;
+            order.update_time = self.clock;
+        }
+    }
+}
@@ -0,0 +1,655 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use binance_common::{
+    enums::futures::{
+        AdlLevel, OrderSide, OrderStatus, OrderType, PositionSide, StpModes, TimeInForce,
+        WorkingType,
+    },
+    error::BinanceError,
+    futures::model::{
+        params::{
+            market::Symbol,
+            trade::{
+                AutoCancelAllOpenOrdersParams, CancelAllOrdersParams, CancelOrderParams,
+                GetOrderParams, NewOrderParams, OpenOrdersParams, PositionRiskV3Params,
+            },
+        },
+        response::{
+            account::FuturesBalanceResponse,
+            market::MarkPriceResponse,
+            trade::{
+                AutoCancelAllOpenOrdersResponse, CancelAllOrdersResponse, GetOrderResponse,
+                OrderResponse, PositionRiskV3Response,
+            },
+            websocket::WebSocketResponse,
+        },
+    },
+};
+use binance_core::trading::futures::{FuturesPriceApi, FuturesTradingApi};
+
+use crate::paper::{
+    PaperConfig,
+    engine::{Engine, Fill, OrderRequest, PaperOrder, Side, Status, Trigger, Validity},
+    mandatory, name, print, reject, split_symbol,
+};
+
+#[derive(Clone, Debug)]
+pub struct FuturesMeta {
+    pub r#type: OrderType,
+    pub time_in_force: TimeInForce,
+    pub stop_price: f64,
+    pub reduce_only: bool,
+    pub close_position: bool,
+    pub position_side: PositionSide,
+    pub working_type: WorkingType,
+    pub good_till_date: u64,
+}
+
+/// Net one-way position of a symbol, positive when long.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Position {
+    pub amount: f64,
+    pub entry_price: f64,
+}
+
+struct FuturesState {
+    engine: Engine<FuturesMeta>,
+    wallets: BTreeMap<String, f64>,
+    markets: HashMap<String, String>,
+    positions: HashMap<String, Position>,
+    /// Engine clock at which the open orders of a symbol are canceled.
+    countdowns: HashMap<String, u64>,
+}
+
+/// Simulated USDT-M futures exchange implementing [`FuturesTradingApi`].
+///
+/// Positions are netted in one-way mode, commissions are charged on the notional
+/// of each fill and realized PnL is booked to the wallet of the margin asset.
+/// Leverage and liquidation are not simulated.
+#[derive(Clone)]
+pub struct FuturesPaperExchange {
+    state: Arc<Mutex<FuturesState>>,
+}
+
+impl FuturesPaperExchange {
+    pub fn new(config: PaperConfig) -> Self {
+        FuturesPaperExchange {
+            state: Arc::new(Mutex::new(FuturesState {
+                engine: Engine::new(config),
+                wallets: BTreeMap::new(),
+                markets: HashMap::new(),
+                positions: HashMap::new(),
+                countdowns: HashMap::new(),
+            })),
+        }
+    }
+
+    pub fn balance(self, asset: &str, balance: f64) -> Self {
+        self.lock().wallets.insert(asset.to_string(), balance);
+        self
+    }
+
+    /// Registers the margin asset of a symbol the quote suffix rules can't split.
+    pub fn market(self, symbol: &str, margin_asset: &str) -> Self {
+        self.lock()
+            .markets
+            .insert(symbol.to_string(), margin_asset.to_string());
+        self
+    }
+
+    pub fn position(&self, symbol: &str) -> Position {
+        self.lock()
+            .positions
+            .get(symbol)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Applies a print, first firing the countdown of the symbol if it ran out.
+    pub fn trade(&self, symbol: &str, price: f64, quantity: f64, time: u64) {
+        let mut state = self.lock();
+
+        if state
+            .countdowns
+            .get(symbol)
+            .is_some_and(|deadline| *deadline <= time)
+        {
+            state.countdowns.remove(symbol);
+            state.cancel_all(symbol);
+        }
+
+        let fills = state.engine.trade(symbol, price, quantity, time);
+        state.settle(&fills);
+    }
+
+    pub fn apply(&self, response: &WebSocketResponse) {
+        if let Some((symbol, price, quantity, time)) = print(response) {
+            self.trade(symbol, price, quantity, time);
+        }
+    }
+
+    pub fn replay(&self, responses: impl IntoIterator<Item = WebSocketResponse>) {
+        for response in responses {
+            self.apply(&response);
+        }
+    }
+
+    /// Matches against a live feed until the sender side is dropped. A watch channel
+    /// only keeps the latest event, so bursts faster than the matching are coalesced.
+    pub async fn run(
+        &self,
+        mut stream: tokio::sync::watch::Receiver<Result<WebSocketResponse, BinanceError>>,
+    ) {
+        while stream.changed().await.is_ok() {
+            if let Ok(response) = &*stream.borrow_and_update() {
+                self.apply(response);
+            }
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, FuturesState> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn new_order(&self, params: &NewOrderParams<'_>) -> Result<OrderResponse, BinanceError> {
+        let mut state = self.lock();
+        let margin_asset = state.margin_asset(params.symbol)?;
+
+        if state
+            .wallets
+            .get(&margin_asset)
+            .copied()
+            .unwrap_or_default()
+            <= 0.0
+        {
+            return Err(reject(-2019, "Margin is insufficient."));
+        }
+
+        let position = state
+            .positions
+            .get(params.symbol)
+            .copied()
+            .unwrap_or_default();
+        let request = order_request(params, position)?;
+
+        if request.meta.reduce_only {
+            let reduces = match request.side {
+                Side::Buy => position.amount < 0.0,
+                Side::Sell => position.amount > 0.0,
+            };
+
+            if !reduces {
+                return Err(reject(-2022, "ReduceOnly Order is rejected."));
+            }
+        }
+
+        let (order_id, fills) = state.engine.submit(request)?;
+        state.settle(&fills);
+
+        state
+            .engine
+            .order(order_id)
+            .map(order_response)
+            .ok_or_else(|| reject(-2013, "Order does not exist."))
+    }
+
+    fn cancel_order(&self, params: &CancelOrderParams<'_>) -> Result<OrderResponse, BinanceError> {
+        if params.order_id.is_none() && params.orig_client_order_id.is_none() {
+            return Err(missing_order_id());
+        }
+
+        let mut state = self.lock();
+
+        state
+            .engine
+            .cancel(params.symbol, params.order_id, params.orig_client_order_id)
+            .map(order_response)
+    }
+
+    fn cancel_all_orders(&self, params: &CancelAllOrdersParams<'_>) -> CancelAllOrdersResponse {
+        self.lock().cancel_all(params.symbol);
+
+        CancelAllOrdersResponse {
+            code: 200,
+            msg: "The operation of cancel all open order is done.".to_string(),
+        }
+    }
+
+    /// Counts down on the engine clock, so the orders go with the first print past the deadline.
+    fn auto_cancel_all_open_orders(
+        &self,
+        params: &AutoCancelAllOpenOrdersParams<'_>,
+    ) -> AutoCancelAllOpenOrdersResponse {
+        let mut state = self.lock();
+
+        match params.countdown_time {
+            0 => state.countdowns.remove(params.symbol),
+            countdown_time => {
+                let deadline = state.engine.clock() + countdown_time;
+                state.countdowns.insert(params.symbol.to_string(), deadline)
+            }
+        };
+
+        AutoCancelAllOpenOrdersResponse {
+            symbol: params.symbol.to_string(),
+            countdown_time: params.countdown_time,
+        }
+    }
+
+    fn get_order(&self, params: &GetOrderParams<'_>) -> Result<GetOrderResponse, BinanceError> {
+        if params.order_id.is_none() && params.orig_client_order_id.is_none() {
+            return Err(missing_order_id());
+        }
+
+        self.lock()
+            .engine
+            .find(params.symbol, params.order_id, params.orig_client_order_id)
+            .map(get_order_response)
+            .ok_or_else(|| reject(-2013, "Order does not exist."))
+    }
+
+    fn open_orders(&self, params: &OpenOrdersParams<'_>) -> Vec<GetOrderResponse> {
+        let state = self.lock();
+
+        state
+            .engine
+            .open_orders(params.symbol)
+            .map(get_order_response)
+            .collect()
+    }
+
+    fn balances(&self) -> Vec<FuturesBalanceResponse> {
+        let state = self.lock();
+        let time = state.engine.clock();
+
+        state
+            .wallets
+            .iter()
+            .map(|(asset, balance)| {
+                let unrealized_pnl: f64 = state
+                    .positions
+                    .iter()
+                    .filter(|(symbol, _)| {
+                        state
+                            .margin_asset(symbol)
+                            .is_ok_and(|margin_asset| margin_asset == *asset)
+                    })
+                    .map(|(symbol, position)| {
+                        state
+                            .engine
+                            .price(symbol)
+                            .map(|price| (price - position.entry_price) * position.amount)
+                            .unwrap_or_default()
+                    })
+                    .sum();
+
+                FuturesBalanceResponse {
+                    account_alias: "paper".to_string(),
+                    asset: asset.clone(),
+                    balance: *balance,
+                    cross_wallet_balance: *balance,
+                    cross_un_pnl: unrealized_pnl,
+                    available_balance: balance + unrealized_pnl,
+                    max_withdraw_amount: balance + unrealized_pnl.min(0.0),
+                    margin_available: true,
+                    update_time: time,
+                }
+            })
+            .collect()
+    }
+
+    /// Open positions as one-way `BOTH` positions in cross margin, with the last
+    /// print standing in for the mark price.
+    fn position_risk(&self, params: &PositionRiskV3Params<'_>) -> Vec<PositionRiskV3Response> {
+        let state = self.lock();
+        let symbol = Some(params.symbol).filter(|symbol| !symbol.is_empty());
+
+        state
+            .positions
+            .iter()
+            .filter(|(position_symbol, position)| {
+                position.amount != 0.0
+                    && symbol.is_none_or(|symbol| symbol == position_symbol.as_str())
+            })
+            .map(|(symbol, position)| {
+                let mark_price = state.engine.price(symbol).unwrap_or(position.entry_price);
+                let notional = position.amount * mark_price;
+
+                PositionRiskV3Response {
+                    symbol: symbol.clone(),
+                    position_side: PositionSide::Both,
+                    position_amt: position.amount,
+                    entry_price: position.entry_price,
+                    break_even_price: position.entry_price,
+                    mark_price,
+                    un_realized_profit: (mark_price - position.entry_price) * position.amount,
+                    liquidation_price: 0.0,
+                    isolated_margin: 0.0,
+                    notional,
+                    margin_asset: state.margin_asset(symbol).unwrap_or_default(),
+                    isolated_wallet: 0.0,
+                    initial_margin: 0.0,
+                    maint_margin: 0.0,
+                    position_initial_margin: 0.0,
+                    open_order_initial_margin: 0.0,
+                    adl: AdlLevel::LowestRisk,
+                    bid_notional: 0.0,
+                    ask_notional: 0.0,
+                    update_time: state.engine.clock(),
+                }
+            })
+            .collect()
+    }
+}
+
+impl FuturesState {
+    fn cancel_all(&mut self, symbol: &str) {
+        let order_ids: Vec<u64> = self
+            .engine
+            .open_orders(Some(symbol))
+            .map(|order| order.order_id)
+            .collect();
+
+        for order_id in order_ids {
+            let _ = self.engine.cancel(symbol, Some(order_id), None);
+        }
+    }
+
+    fn margin_asset(&self, symbol: &str) -> Result<String, BinanceError> {
+        self.markets
+            .get(symbol)
+            .cloned()
+            .or_else(|| split_symbol(symbol).map(|(_, quote)| quote))
+            .ok_or_else(|| reject(-1121, "Invalid symbol."))
+    }
+
+    fn settle(&mut self, fills: &[Fill]) {
+        for fill in fills {
+            let Ok(margin_asset) = self.margin_asset(&fill.symbol) else {
+                continue;
+            };
+
+            let quantity = match fill.side {
+                Side::Buy => fill.quantity,
+                Side::Sell => -fill.quantity,
+            };
+
+            let position = self.positions.entry(fill.symbol.clone()).or_default();
+            let mut realized_pnl = 0.0;
+
+            if position.amount == 0.0 || position.amount.signum() == quantity.signum() {
+                let amount = position.amount + quantity;
+                position.entry_price = (position.entry_price * position.amount.abs()
+                    + fill.price * quantity.abs())
+                    / amount.abs();
+                position.amount = amount;
+            } else {
+                let closed = quantity.abs().min(position.amount.abs());
+                realized_pnl =
+                    (fill.price - position.entry_price) * closed * position.amount.signum();
+
+                let amount = position.amount + quantity;
+
+                if amount == 0.0 {
+                    position.entry_price = 0.0;
+                } else if amount.signum() != position.amount.signum() {
+                    position.entry_price = fill.price;
+                }
+
+                position.amount = amount;
+            }
+
+            *self.wallets.entry(margin_asset).or_default() +=
+                realized_pnl - fill.quote_quantity() * fill.fee_rate;
+        }
+    }
+}
+
+impl FuturesTradingApi for FuturesPaperExchange {
+    async fn send_new_order(
+        &self,
+        params: &NewOrderParams<'_>,
+    ) -> Result<OrderResponse, BinanceError> {
+        self.new_order(params)
+    }
+
+    async fn send_cancel_order(
+        &self,
+        params: &CancelOrderParams<'_>,
+    ) -> Result<OrderResponse, BinanceError> {
+        self.cancel_order(params)
+    }
+
+    async fn send_cancel_all_orders(
+        &self,
+        params: &CancelAllOrdersParams<'_>,
+    ) -> Result<CancelAllOrdersResponse, BinanceError> {
+        Ok(self.cancel_all_orders(params))
+    }
+
+    async fn send_auto_cancel_all_open_orders(
+        &self,
+        params: &AutoCancelAllOpenOrdersParams<'_>,
+    ) -> Result<AutoCancelAllOpenOrdersResponse, BinanceError> {
+        Ok(self.auto_cancel_all_open_orders(params))
+    }
+
+    async fn get_order(
+        &self,
+        params: &GetOrderParams<'_>,
+    ) -> Result<GetOrderResponse, BinanceError> {
+        FuturesPaperExchange::get_order(self, params)
+    }
+
+    async fn get_all_open_orders(
+        &self,
+        params: &OpenOrdersParams<'_>,
+    ) -> Result<Vec<GetOrderResponse>, BinanceError> {
+        Ok(self.open_orders(params))
+    }
+
+    async fn get_balances(&self) -> Result<Vec<FuturesBalanceResponse>, BinanceError> {
+        Ok(self.balances())
+    }
+
+    async fn get_position_risk_v3(
+        &self,
+        params: &PositionRiskV3Params<'_>,
+    ) -> Result<Vec<PositionRiskV3Response>, BinanceError> {
+        Ok(self.position_risk(params))
+    }
+}
+
+/// The last print stands in for the mark and index price, without funding.
+impl FuturesPriceApi for FuturesPaperExchange {
+    async fn get_mark_price(&self, params: &Symbol<'_>) -> Result<MarkPriceResponse, BinanceError> {
+        let state = self.lock();
+
+        state
+            .engine
+            .price(params.symbol)
+            .map(|price| MarkPriceResponse {
+                symbol: params.symbol.to_string(),
+                mark_price: price,
+                index_price: price,
+                estimated_settle_price: price,
+                last_funding_rate: 0.0,
+                interest_rate: 0.0,
+                next_funding_time: 0,
+                time: state.engine.clock(),
+            })
+            .ok_or_else(|| reject(-1121, "Invalid symbol."))
+    }
+}
+
+fn order_request(
+    params: &NewOrderParams<'_>,
+    position: Position,
+) -> Result<OrderRequest<FuturesMeta>, BinanceError> {
+    let side = match params.side {
+        OrderSide::Buy => Side::Buy,
+        OrderSide::Sell => Side::Sell,
+    };
+
+    let close_position = params.close_position == Some("true");
+    let price = || params.price.ok_or_else(|| mandatory("price"));
+    let stop_price = || params.stop_price.ok_or_else(|| mandatory("stopPrice"));
+    let validity = match params.time_in_force {
+        Some(TimeInForce::Gtc) | Some(TimeInForce::Gtd) | None => Validity::GoodTillCancel,
+        Some(TimeInForce::Ioc) => Validity::ImmediateOrCancel,
+        Some(TimeInForce::Fok) => Validity::FillOrKill,
+        Some(TimeInForce::Gtx) => Validity::PostOnly,
+    };
+
+    let (price, trigger) = match params.r#type {
+        OrderType::Market => (None, None),
+        OrderType::Limit => {
+            if params.time_in_force.is_none() {
+                return Err(mandatory("timeInForce"));
+            }
+
+            (Some(price()?), None)
+        }
+        OrderType::Stop => (
+            Some(price()?),
+            Some(Trigger::new(side, stop_price()?, false)),
+        ),
+        OrderType::TakeProfit => (
+            Some(price()?),
+            Some(Trigger::new(side, stop_price()?, true)),
+        ),
+        OrderType::StopMarket => (None, Some(Trigger::new(side, stop_price()?, false))),
+        OrderType::TakeProfitMarket => (None, Some(Trigger::new(side, stop_price()?, true))),
+        OrderType::TrailingStopMarket => {
+            return Err(reject(
+                -2010,
+                "Trailing orders are not supported by the paper exchange.",
+            ));
+        }
+    };
+
+    let quantity = match close_position {
+        true => Some(position.amount.abs()).filter(|amount| *amount > 0.0),
+        false => params.quantity,
+    };
+
+    let Some(quantity) = quantity else {
+        return Err(match close_position {
+            true => reject(-2022, "ReduceOnly Order is rejected."),
+            false => mandatory("quantity"),
+        });
+    };
+
+    Ok(OrderRequest {
+        symbol: params.symbol.to_string(),
+        client_order_id: params.new_client_order_id.map(str::to_string),
+        side,
+        price,
+        quantity: Some(quantity),
+        quote_quantity: None,
+        validity,
+        trigger,
+        meta: FuturesMeta {
+            r#type: params.r#type.clone(),
+            time_in_force: params.time_in_force.clone().unwrap_or(TimeInForce::Gtc),
+            stop_price: params.stop_price.unwrap_or_default(),
+            reduce_only: params.reduce_only.unwrap_or_default() || close_position,
+            close_position,
+            position_side: params.position_side.unwrap_or(PositionSide::Both),
+            working_type: params
+                .working_type
+                .clone()
+                .unwrap_or(WorkingType::ContractPrice),
+            good_till_date: params.good_till_date.unwrap_or_default(),
+        },
+    })
+}
+
+fn order_response(order: &PaperOrder<FuturesMeta>) -> OrderResponse {
+    OrderResponse {
+        client_order_id: order.client_order_id.clone(),
+        cum_qty: order.executed,
+        executed_qty: order.executed,
+        order_id: order.order_id,
+        orig_qty: order.quantity,
+        price: order.price.unwrap_or_default(),
+        reduce_only: order.meta.reduce_only,
+        side: order_side(order.side),
+        position_side: order.meta.position_side,
+        status: order_status(order.status),
+        stop_price: Some(order.meta.stop_price.to_string()),
+        close_position: order.meta.close_position,
+        symbol: order.symbol.clone(),
+        time_in_force: order.meta.time_in_force.clone(),
+        r#type: order.meta.r#type.clone(),
+        orig_type: name(&order.meta.r#type),
+        activate_price: None,
+        price_rate: None,
+        update_time: order.update_time,
+        working_type: order.meta.working_type.clone(),
+        price_protect: false,
+        price_match: "NONE".to_string(),
+        self_trade_prevention_mode: StpModes::ExpireMaker,
+        good_till_date: Some(order.meta.good_till_date),
+    }
+}
+
+fn get_order_response(order: &PaperOrder<FuturesMeta>) -> GetOrderResponse {
+    GetOrderResponse {
+        avg_price: order.average_price(),
+        client_order_id: order.client_order_id.clone(),
+        cum_quote: order.cumulative_quote,
+        executed_qty: order.executed,
+        order_id: order.order_id,
+        orig_qty: order.quantity,
+        orig_type: name(&order.meta.r#type),
+        price: order.price.unwrap_or_default(),
+        reduce_only: order.meta.reduce_only,
+        side: order_side(order.side),
+        position_side: name(&order.meta.position_side),
+        status: order_status(order.status),
+        stop_price: order.meta.stop_price,
+        close_position: order.meta.close_position,
+        symbol: order.symbol.clone(),
+        time: order.time,
+        time_in_force: order.meta.time_in_force.clone(),
+        order_type: name(&order.meta.r#type),
+        activate_price: None,
+        price_rate: None,
+        update_time: order.update_time,
+        working_type: order.meta.working_type.clone(),
+        price_protect: false,
+        price_match: "NONE".to_string(),
+        self_trade_prevention_mode: StpModes::ExpireMaker,
+        good_till_date: order.meta.good_till_date,
+    }
+}
+
+fn missing_order_id() -> BinanceError {
+    reject(
+        -1102,
+        "Param 'origClientOrderId' or 'orderId' must be sent, but both were empty/null!",
+    )
+}
+
+fn order_side(side: Side) -> OrderSide {
+    match side {
+        Side::Buy => OrderSide::Buy,
+        Side::Sell => OrderSide::Sell,
+    }
+}
+
+fn order_status(status: Status) -> OrderStatus {
+    match status {
+        Status::New => OrderStatus::New,
+        Status::PartiallyFilled => OrderStatus::PartiallyFilled,
+        Status::Filled => OrderStatus::Filled,
+        Status::Canceled => OrderStatus::Canceled,
+        Status::Expired => OrderStatus::Expired,
+    }
+}
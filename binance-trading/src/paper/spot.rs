@@ -0,0 +1,667 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use binance_common::{
+    enums::spot::{OrderResponseType, OrderSide, OrderStatus, OrderType, TimeInForce},
+    error::BinanceError,
+    futures::model::response::websocket::WebSocketResponse,
+    spot::model::{
+        params::{
+            market::AvgPriceParams,
+            trade::{
+                CancelAllOrdersParms, CancelOrderParams, GetOrderParams, NewOrderParams,
+                OpenOrdersParams,
+            },
+        },
+        response::{
+            account::AssetResponse,
+            market::AvgPriceResponse,
+            trade::{
+                AckResponse, CancelOrderResponse, Fill as FillResponse, FullResponse,
+                NewOrderResponse, OrderIdResponse, OrderResponse, ResultResponse,
+            },
+        },
+    },
+};
+use binance_core::trading::spot::{SpotPriceApi, SpotTradingApi};
+
+use crate::paper::{
+    PaperConfig,
+    engine::{Engine, Fill, OrderRequest, PaperOrder, Side, Status, Trigger, Validity},
+    mandatory, name, print, reject, split_symbol,
+};
+
+#[derive(Clone, Debug)]
+pub struct SpotMeta {
+    pub r#type: OrderType,
+    pub time_in_force: Option<TimeInForce>,
+    pub stop_price: f64,
+    pub iceberg_qty: f64,
+    pub orig_quote_order_qty: f64,
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+struct Balance {
+    free: f64,
+    locked: f64,
+}
+
+#[derive(Clone, Debug)]
+struct Lock {
+    asset: String,
+    amount: f64,
+}
+
+struct SpotState {
+    engine: Engine<SpotMeta>,
+    balances: BTreeMap<String, Balance>,
+    markets: HashMap<String, (String, String)>,
+    locks: HashMap<u64, Lock>,
+}
+
+/// Simulated spot exchange implementing [`SpotTradingApi`].
+///
+/// Orders lock the quote asset on buys and the base asset on sells, and pay
+/// the commission in the asset they receive, as Binance does without BNB
+/// discounts. Clones share the same account, so one clone can be driven by a
+/// market feed while another is handed to the strategy.
+#[derive(Clone)]
+pub struct SpotPaperExchange {
+    state: Arc<Mutex<SpotState>>,
+}
+
+impl SpotPaperExchange {
+    pub fn new(config: PaperConfig) -> Self {
+        SpotPaperExchange {
+            state: Arc::new(Mutex::new(SpotState {
+                engine: Engine::new(config),
+                balances: BTreeMap::new(),
+                markets: HashMap::new(),
+                locks: HashMap::new(),
+            })),
+        }
+    }
+
+    pub fn balance(self, asset: &str, free: f64) -> Self {
+        self.lock()
+            .balances
+            .entry(asset.to_string())
+            .or_default()
+            .free = free;
+        self
+    }
+
+    /// Registers the base and quote asset of a symbol the quote suffix rules can't split.
+    pub fn market(self, symbol: &str, base: &str, quote: &str) -> Self {
+        self.lock()
+            .markets
+            .insert(symbol.to_string(), (base.to_string(), quote.to_string()));
+        self
+    }
+
+    pub fn trade(&self, symbol: &str, price: f64, quantity: f64, time: u64) {
+        let mut state = self.lock();
+        let fills = state.engine.trade(symbol, price, quantity, time);
+        state.settle(&fills);
+    }
+
+    pub fn apply(&self, response: &WebSocketResponse) {
+        if let Some((symbol, price, quantity, time)) = print(response) {
+            self.trade(symbol, price, quantity, time);
+        }
+    }
+
+    pub fn replay(&self, responses: impl IntoIterator<Item = WebSocketResponse>) {
+        for response in responses {
+            self.apply(&response);
+        }
+    }
+
+    /// Matches against a live feed until the sender side is dropped. A watch channel
+    /// only keeps the latest event, so bursts faster than the matching are coalesced.
+    pub async fn run(
+        &self,
+        mut stream: tokio::sync::watch::Receiver<Result<WebSocketResponse, BinanceError>>,
+    ) {
+        while stream.changed().await.is_ok() {
+            if let Ok(response) = &*stream.borrow_and_update() {
+                self.apply(response);
+            }
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, SpotState> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn new_order(&self, params: &NewOrderParams<'_>) -> Result<NewOrderResponse, BinanceError> {
+        let mut state = self.lock();
+        let (base, quote) = state.assets(params.symbol)?;
+        let request = order_request(params)?;
+
+        let estimate = request.price.or(state.engine.price(params.symbol));
+        let lock = match request.side {
+            Side::Buy => Lock {
+                asset: quote,
+                amount: request
+                    .quote_quantity
+                    .or(request
+                        .quantity
+                        .zip(estimate)
+                        .map(|(qty, price)| qty * price))
+                    .unwrap_or_default(),
+            },
+            Side::Sell => Lock {
+                asset: base,
+                amount: request
+                    .quantity
+                    .or(request
+                        .quote_quantity
+                        .zip(estimate)
+                        .map(|(qty, price)| qty / price))
+                    .unwrap_or_default(),
+            },
+        };
+
+        let balance = state.balances.get(&lock.asset).copied().unwrap_or_default();
+
+        if balance.free < lock.amount {
+            return Err(reject(
+                -2010,
+                "Account has insufficient balance for requested action.",
+            ));
+        }
+
+        let (order_id, fills) = state.engine.submit(request)?;
+
+        let balance = state.balances.entry(lock.asset.clone()).or_default();
+        balance.free -= lock.amount;
+        balance.locked += lock.amount;
+        state.locks.insert(order_id, lock);
+
+        state.settle(&fills);
+
+        let order = state
+            .engine
+            .order(order_id)
+            .ok_or_else(|| reject(-2013, "Order does not exist."))?;
+
+        Ok(match params.new_order_resp_type {
+            Some(OrderResponseType::Result) => NewOrderResponse::Result(result_response(order)),
+            Some(OrderResponseType::Full) => {
+                let result = result_response(order);
+
+                NewOrderResponse::Full(FullResponse {
+                    symbol: result.symbol,
+                    order_id: result.order_id,
+                    order_list_id: result.order_list_id,
+                    client_order_id: result.client_order_id,
+                    transact_time: result.transact_time,
+                    price: result.price,
+                    orig_qty: result.orig_qty,
+                    executed_qty: result.executed_qty,
+                    orig_quote_order_qty: result.orig_quote_order_qty,
+                    cummulative_quote_qty: result.cummulative_quote_qty,
+                    status: result.status,
+                    time_in_force: result.time_in_force,
+                    r#type: result.r#type,
+                    side: result.side,
+                    working_time: result.working_time,
+                    self_trade_prevention_mode: result.self_trade_prevention_mode,
+                    fills: fills
+                        .iter()
+                        .map(|fill| {
+                            let (commission, commission_asset) = state.commission(fill);
+
+                            FillResponse {
+                                price: fill.price,
+                                qty: fill.quantity,
+                                commission,
+                                commission_asset,
+                                trade_id: fill.trade_id,
+                            }
+                        })
+                        .collect(),
+                })
+            }
+            None | Some(OrderResponseType::Ack) => NewOrderResponse::Ack(AckResponse {
+                symbol: order.symbol.clone(),
+                order_id: order.order_id,
+                order_list_id: -1,
+                client_order_id: order.client_order_id.clone(),
+                transact_time: order.time,
+            }),
+        })
+    }
+
+    fn cancel_order(
+        &self,
+        params: &CancelOrderParams<'_>,
+    ) -> Result<CancelOrderResponse, BinanceError> {
+        if params.order_id.is_none() && params.orig_client_order_id.is_none() {
+            return Err(missing_order_id());
+        }
+
+        let mut state = self.lock();
+        let order =
+            state
+                .engine
+                .cancel(params.symbol, params.order_id, params.orig_client_order_id)?;
+
+        let response = cancel_response(order, params.new_client_order_id);
+        state.release();
+
+        Ok(response)
+    }
+
+    fn cancel_open_orders(
+        &self,
+        params: &CancelAllOrdersParms<'_>,
+    ) -> Result<Vec<CancelOrderResponse>, BinanceError> {
+        let mut state = self.lock();
+
+        let order_ids: Vec<u64> = state
+            .engine
+            .open_orders(Some(params.symbol))
+            .map(|order| order.order_id)
+            .collect();
+
+        if order_ids.is_empty() {
+            return Err(reject(-2011, "Unknown order sent."));
+        }
+
+        let responses = order_ids
+            .into_iter()
+            .map(|order_id| {
+                state
+                    .engine
+                    .cancel(params.symbol, Some(order_id), None)
+                    .map(|order| cancel_response(order, None))
+            })
+            .collect();
+
+        state.release();
+
+        responses
+    }
+
+    fn get_order(&self, params: &GetOrderParams<'_>) -> Result<OrderIdResponse, BinanceError> {
+        if params.order_id.is_none() && params.orig_client_order_id.is_none() {
+            return Err(missing_order_id());
+        }
+
+        let state = self.lock();
+        let order = state
+            .engine
+            .find(params.symbol, params.order_id, params.orig_client_order_id)
+            .ok_or_else(|| reject(-2013, "Order does not exist."))?;
+
+        Ok(OrderIdResponse {
+            symbol: order.symbol.clone(),
+            order_id: order.order_id,
+            order_list_id: -1,
+            client_order_id: order.client_order_id.clone(),
+            price: order.price.unwrap_or_default(),
+            orig_qty: order.quantity,
+            executed_qty: order.executed,
+            cummulative_quote_qty: order.cumulative_quote,
+            status: name(&order_status(order.status)),
+            time_in_force: time_in_force(order),
+            order_type: name(&order.meta.r#type),
+            side: name(&order_side(order.side)),
+            time: order.time,
+            update_time: order.update_time,
+            is_working: order.trigger.is_none(),
+            working_time: order.time,
+        })
+    }
+
+    fn open_orders(&self, params: &OpenOrdersParams<'_>) -> Vec<OrderResponse> {
+        let state = self.lock();
+
+        state
+            .engine
+            .open_orders(params.symbol)
+            .map(|order| OrderResponse {
+                symbol: order.symbol.clone(),
+                order_id: order.order_id,
+                order_list_id: -1,
+                client_order_id: order.client_order_id.clone(),
+                price: order.price.unwrap_or_default(),
+                orig_qty: order.quantity,
+                executed_qty: order.executed,
+                cummulative_quote_qty: order.cumulative_quote,
+                status: name(&order_status(order.status)),
+                time_in_force: time_in_force(order),
+                r#type: name(&order.meta.r#type),
+                side: name(&order_side(order.side)),
+                stop_price: order.meta.stop_price,
+                iceberg_qty: order.meta.iceberg_qty,
+                time: order.time,
+                update_time: order.update_time,
+                is_working: order.trigger.is_none(),
+                orig_quote_order_qty: order.meta.orig_quote_order_qty,
+                working_time: order.time,
+                self_trade_prevention_mode: "NONE".to_string(),
+            })
+            .collect()
+    }
+
+    fn balances(&self) -> Vec<AssetResponse> {
+        self.lock()
+            .balances
+            .iter()
+            .map(|(asset, balance)| AssetResponse {
+                asset: asset.clone(),
+                free: balance.free,
+                locked: balance.locked,
+            })
+            .collect()
+    }
+}
+
+impl SpotState {
+    fn assets(&self, symbol: &str) -> Result<(String, String), BinanceError> {
+        self.markets
+            .get(symbol)
+            .cloned()
+            .or_else(|| split_symbol(symbol))
+            .ok_or_else(|| reject(-1121, "Invalid symbol."))
+    }
+
+    fn commission(&self, fill: &Fill) -> (f64, String) {
+        let (base, quote) = self.assets(&fill.symbol).unwrap_or_default();
+
+        match fill.side {
+            Side::Buy => (fill.quantity * fill.fee_rate, base),
+            Side::Sell => (fill.quote_quantity() * fill.fee_rate, quote),
+        }
+    }
+
+    fn settle(&mut self, fills: &[Fill]) {
+        for fill in fills {
+            let Ok((base, quote)) = self.assets(&fill.symbol) else {
+                continue;
+            };
+
+            let (commission, _) = self.commission(fill);
+
+            match fill.side {
+                Side::Buy => {
+                    self.spend(fill.order_id, &quote, fill.quote_quantity());
+                    self.receive(&base, fill.quantity - commission);
+                }
+                Side::Sell => {
+                    self.spend(fill.order_id, &base, fill.quantity);
+                    self.receive(&quote, fill.quote_quantity() - commission);
+                }
+            }
+        }
+
+        self.release();
+    }
+
+    /// Pays from the order's lock first and from the free balance for anything the
+    /// estimate at submission didn't cover, like market orders priced after latency.
+    fn spend(&mut self, order_id: u64, asset: &str, amount: f64) {
+        let locked = self
+            .locks
+            .get_mut(&order_id)
+            .map(|lock| {
+                let locked = lock.amount.min(amount);
+                lock.amount -= locked;
+                locked
+            })
+            .unwrap_or_default();
+
+        let balance = self.balances.entry(asset.to_string()).or_default();
+        balance.locked -= locked;
+        balance.free -= amount - locked;
+    }
+
+    fn receive(&mut self, asset: &str, amount: f64) {
+        self.balances.entry(asset.to_string()).or_default().free += amount;
+    }
+
+    /// Returns what is left locked by orders that are no longer open.
+    fn release(&mut self) {
+        let closed: Vec<u64> = self
+            .locks
+            .keys()
+            .copied()
+            .filter(|order_id| {
+                self.engine
+                    .order(*order_id)
+                    .is_none_or(|order| !order.status.is_open())
+            })
+            .collect();
+
+        for order_id in closed {
+            if let Some(lock) = self.locks.remove(&order_id) {
+                let balance = self.balances.entry(lock.asset).or_default();
+                balance.locked -= lock.amount;
+                balance.free += lock.amount;
+            }
+        }
+    }
+}
+
+impl SpotTradingApi for SpotPaperExchange {
+    async fn send_new_order(
+        &self,
+        params: &NewOrderParams<'_>,
+    ) -> Result<NewOrderResponse, BinanceError> {
+        self.new_order(params)
+    }
+
+    async fn send_cancel_order(
+        &self,
+        params: &CancelOrderParams<'_>,
+    ) -> Result<CancelOrderResponse, BinanceError> {
+        self.cancel_order(params)
+    }
+
+    async fn send_cancel_open_orders(
+        &self,
+        params: &CancelAllOrdersParms<'_>,
+    ) -> Result<Vec<CancelOrderResponse>, BinanceError> {
+        self.cancel_open_orders(params)
+    }
+
+    async fn get_order(
+        &self,
+        params: &GetOrderParams<'_>,
+    ) -> Result<OrderIdResponse, BinanceError> {
+        SpotPaperExchange::get_order(self, params)
+    }
+
+    async fn get_open_orders(
+        &self,
+        params: &OpenOrdersParams<'_>,
+    ) -> Result<Vec<OrderResponse>, BinanceError> {
+        Ok(self.open_orders(params))
+    }
+
+    async fn get_balances(&self) -> Result<Vec<AssetResponse>, BinanceError> {
+        Ok(self.balances())
+    }
+}
+
+/// The last print stands in for the average price.
+impl SpotPriceApi for SpotPaperExchange {
+    async fn get_average_price(
+        &self,
+        params: &AvgPriceParams<'_>,
+    ) -> Result<AvgPriceResponse, BinanceError> {
+        let state = self.lock();
+
+        state
+            .engine
+            .price(params.symbol)
+            .map(|price| AvgPriceResponse {
+                mins: 5,
+                price,
+                close_time: state.engine.clock(),
+            })
+            .ok_or_else(|| reject(-1121, "Invalid symbol."))
+    }
+}
+
+fn order_request(params: &NewOrderParams<'_>) -> Result<OrderRequest<SpotMeta>, BinanceError> {
+    if params.trailing_delta.is_some() {
+        return Err(reject(
+            -2010,
+            "Trailing orders are not supported by the paper exchange.",
+        ));
+    }
+
+    let side = match params.side {
+        OrderSide::Buy => Side::Buy,
+        OrderSide::Sell => Side::Sell,
+    };
+
+    let price = || params.price.ok_or_else(|| mandatory("price"));
+    let stop_price = || params.stop_price.ok_or_else(|| mandatory("stopPrice"));
+    let validity = || match params.time_in_force {
+        Some(TimeInForce::Gtc) => Ok(Validity::GoodTillCancel),
+        Some(TimeInForce::Ioc) => Ok(Validity::ImmediateOrCancel),
+        Some(TimeInForce::Fok) => Ok(Validity::FillOrKill),
+        None => Err(mandatory("timeInForce")),
+    };
+
+    let (price, trigger, validity) = match params.r#type {
+        OrderType::Market => (None, None, Validity::GoodTillCancel),
+        OrderType::Limit => (Some(price()?), None, validity()?),
+        OrderType::LimitMaker => (Some(price()?), None, Validity::PostOnly),
+        OrderType::StopLoss => (
+            None,
+            Some(Trigger::new(side, stop_price()?, false)),
+            Validity::GoodTillCancel,
+        ),
+        OrderType::StopLossLimit => (
+            Some(price()?),
+            Some(Trigger::new(side, stop_price()?, false)),
+            validity()?,
+        ),
+        OrderType::TakeProfit => (
+            None,
+            Some(Trigger::new(side, stop_price()?, true)),
+            Validity::GoodTillCancel,
+        ),
+        OrderType::TakeProfitLimit => (
+            Some(price()?),
+            Some(Trigger::new(side, stop_price()?, true)),
+            validity()?,
+        ),
+    };
+
+    let quote_quantity = match params.r#type {
+        OrderType::Market => params.quote_order_qty,
+        _ => None,
+    };
+
+    if params.quantity.is_none() && quote_quantity.is_none() {
+        return Err(mandatory("quantity"));
+    }
+
+    Ok(OrderRequest {
+        symbol: params.symbol.to_string(),
+        client_order_id: params.new_client_order_id.map(str::to_string),
+        side,
+        price,
+        quantity: params.quantity,
+        quote_quantity: quote_quantity.filter(|_| params.quantity.is_none()),
+        validity,
+        trigger,
+        meta: SpotMeta {
+            r#type: params.r#type.clone(),
+            time_in_force: params.time_in_force.clone(),
+            stop_price: params.stop_price.unwrap_or_default(),
+            iceberg_qty: params.iceberg_qty.unwrap_or_default(),
+            orig_quote_order_qty: quote_quantity.unwrap_or_default(),
+        },
+    })
+}
+
+fn result_response(order: &PaperOrder<SpotMeta>) -> ResultResponse {
+    ResultResponse {
+        symbol: order.symbol.clone(),
+        order_id: order.order_id,
+        order_list_id: -1,
+        client_order_id: order.client_order_id.clone(),
+        transact_time: order.time,
+        price: order.price.unwrap_or_default(),
+        orig_qty: order.quantity,
+        executed_qty: order.executed,
+        orig_quote_order_qty: order.meta.orig_quote_order_qty,
+        cummulative_quote_qty: order.cumulative_quote,
+        status: name(&order_status(order.status)),
+        time_in_force: time_in_force(order),
+        r#type: name(&order.meta.r#type),
+        side: name(&order_side(order.side)),
+        working_time: order.time,
+        self_trade_prevention_mode: "NONE".to_string(),
+    }
+}
+
+fn cancel_response(
+    order: &PaperOrder<SpotMeta>,
+    new_client_order_id: Option<&str>,
+) -> CancelOrderResponse {
+    CancelOrderResponse {
+        symbol: order.symbol.clone(),
+        orig_client_order_id: order.client_order_id.clone(),
+        order_id: order.order_id,
+        order_list_id: -1,
+        client_order_id: new_client_order_id
+            .map(str::to_string)
+            .unwrap_or_else(|| format!("paper-cancel-{}", order.order_id)),
+        transact_time: order.update_time,
+        price: order.price.unwrap_or_default(),
+        orig_qty: order.quantity,
+        executed_qty: order.executed,
+        cummulative_quote_qty: order.cumulative_quote,
+        status: name(&order_status(order.status)),
+        time_in_force: time_in_force(order),
+        r#type: name(&order.meta.r#type),
+        side: name(&order_side(order.side)),
+        self_trade_prevention_mode: "NONE".to_string(),
+    }
+}
+
+fn missing_order_id() -> BinanceError {
+    reject(
+        -1102,
+        "Param 'origClientOrderId' or 'orderId' must be sent, but both were empty/null!",
+    )
+}
+
+fn time_in_force(order: &PaperOrder<SpotMeta>) -> String {
+    order
+        .meta
+        .time_in_force
+        .as_ref()
+        .map(name)
+        .unwrap_or_else(|| "GTC".to_string())
+}
+
+fn order_side(side: Side) -> OrderSide {
+    match side {
+        Side::Buy => OrderSide::Buy,
+        Side::Sell => OrderSide::Sell,
+    }
+}
+
+fn order_status(status: Status) -> OrderStatus {
+    match status {
+        Status::New => OrderStatus::New,
+        Status::PartiallyFilled => OrderStatus::PartiallyFilled,
+        Status::Filled => OrderStatus::Filled,
+        Status::Canceled => OrderStatus::Canceled,
+        Status::Expired => OrderStatus::Expired,
+    }
+}
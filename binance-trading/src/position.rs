@@ -0,0 +1,422 @@
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    sync::{Arc, Mutex, MutexGuard},
+    time::Duration,
+};
+
+use binance_common::{
+    enums::{IncomeType, futures::PositionSide},
+    error::BinanceError,
+    futures::model::{
+        params::trade::PositionRiskV3Params,
+        response::{
+            account::IncomeHistoryResponse,
+            stream::{PositionUpdate, UserDataEvent},
+            trade::PositionRiskV3Response,
+            websocket::{MarkPriceResponse, WebSocketResponse},
+        },
+    },
+};
+use binance_core::trading::futures::FuturesTradingApi;
+
+use crate::paper::split_symbol;
+
+/// Maintenance margin rate assumed until a position risk snapshot reports one.
+const DEFAULT_MAINTENANCE_RATE: f64 = 0.004;
+
+#[derive(Clone, Debug)]
+pub struct TrackedPosition {
+    pub symbol: String,
+    pub position_side: PositionSide,
+    /// Negative for shorts.
+    pub amount: f64,
+    pub entry_price: f64,
+    pub break_even_price: f64,
+    pub mark_price: f64,
+    pub margin_asset: String,
+    pub isolated: bool,
+    pub isolated_wallet: f64,
+    /// Maintenance margin over notional, taken from the latest snapshot.
+    pub maintenance_rate: f64,
+    /// Accumulated realized PnL as reported by `ACCOUNT_UPDATE`.
+    pub realized_pnl: f64,
+    /// Funding paid at each funding time, estimated from the mark price
+    /// stream. Negative when funding was received.
+    pub funding_paid: f64,
+    pub update_time: u64,
+}
+
+impl TrackedPosition {
+    fn new(symbol: &str, position_side: PositionSide) -> Self {
+        TrackedPosition {
+            symbol: symbol.to_string(),
+            position_side,
+            amount: 0.0,
+            entry_price: 0.0,
+            break_even_price: 0.0,
+            mark_price: 0.0,
+            margin_asset: split_symbol(symbol)
+                .map(|(_, quote)| quote)
+                .unwrap_or_else(|| "USDT".to_string()),
+            isolated: false,
+            isolated_wallet: 0.0,
+            maintenance_rate: DEFAULT_MAINTENANCE_RATE,
+            realized_pnl: 0.0,
+            funding_paid: 0.0,
+            update_time: 0,
+        }
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.amount != 0.0
+    }
+
+    pub fn notional(&self) -> f64 {
+        self.amount * self.mark_price
+    }
+
+    pub fn unrealized_pnl(&self) -> f64 {
+        self.amount * (self.mark_price - self.entry_price)
+    }
+
+    pub fn maintenance_margin(&self) -> f64 {
+        self.notional().abs() * self.maintenance_rate
+    }
+}
+
+#[derive(Default)]
+struct PositionState {
+    positions: BTreeMap<(String, PositionSide), TrackedPosition>,
+    wallets: BTreeMap<String, f64>,
+    /// Next funding time and rate announced for each symbol.
+    funding: HashMap<String, (u64, f64)>,
+    booked_funding: BTreeMap<String, f64>,
+    booked_income: BTreeSet<u64>,
+}
+
+/// Futures positions kept current between snapshots.
+///
+/// `ACCOUNT_UPDATE` events move amounts, entry prices and wallets, mark price
+/// updates revalue the positions and settle funding at each funding time.
+/// Positions are keyed by symbol and position side, so one-way `BOTH`
+/// positions and hedge mode `LONG`/`SHORT` pairs are tracked alike. Clones
+/// share the same book.
+#[derive(Clone, Default)]
+pub struct PositionBook {
+    state: Arc<Mutex<PositionState>>,
+}
+
+impl PositionBook {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Loads wallets and positions, of every symbol when `params.symbol` is empty.
+    pub async fn seed<T: FuturesTradingApi>(
+        &self,
+        api: &T,
+        params: &PositionRiskV3Params<'_>,
+    ) -> Result<(), BinanceError> {
+        let balances = api.get_balances().await?;
+
+        self.lock().wallets.extend(
+            balances
+                .into_iter()
+                .map(|balance| (balance.asset, balance.cross_wallet_balance)),
+        );
+
+        self.reconcile(api, params).await
+    }
+
+    /// Replaces the tracked positions of `params.symbol` with a fresh snapshot.
+    pub async fn reconcile<T: FuturesTradingApi>(
+        &self,
+        api: &T,
+        params: &PositionRiskV3Params<'_>,
+    ) -> Result<(), BinanceError> {
+        let positions = api.get_position_risk_v3(params).await?;
+        let symbol = Some(params.symbol).filter(|symbol| !symbol.is_empty());
+
+        self.lock().reconcile(&positions, symbol);
+
+        Ok(())
+    }
+
+    /// Reconciles every `period` and returns the first error.
+    pub async fn reconcile_every<T: FuturesTradingApi>(
+        &self,
+        api: &T,
+        params: &PositionRiskV3Params<'_>,
+        period: Duration,
+    ) -> BinanceError {
+        let mut interval = tokio::time::interval(period);
+
+        loop {
+            interval.tick().await;
+
+            if let Err(error) = self.reconcile(api, params).await {
+                return error;
+            }
+        }
+    }
+
+    pub fn apply(&self, event: &UserDataEvent) {
+        let UserDataEvent::AccountUpdate(update) = event else {
+            return;
+        };
+
+        let mut state = self.lock();
+
+        for balance in &update.account.balances {
+            state
+                .wallets
+                .insert(balance.asset.clone(), balance.cross_wallet_balance);
+        }
+
+        for position in &update.account.positions {
+            state.update(position, update.transaction_time);
+        }
+    }
+
+    /// Applies a mark price update, other market events are ignored.
+    pub fn apply_market(&self, response: &WebSocketResponse) {
+        if let WebSocketResponse::MarkPrice(mark) = response {
+            self.lock().mark(mark);
+        }
+    }
+
+    /// Books `FUNDING_FEE` income entries, each transaction only once.
+    pub fn apply_income(&self, entries: &[IncomeHistoryResponse]) {
+        let mut state = self.lock();
+
+        for entry in entries {
+            if matches!(entry.income_type, IncomeType::FundingFee)
+                && state.booked_income.insert(entry.tran_id)
+            {
+                *state
+                    .booked_funding
+                    .entry(entry.symbol.clone())
+                    .or_default() -= entry.income;
+            }
+        }
+    }
+
+    pub fn position(&self, symbol: &str, position_side: PositionSide) -> Option<TrackedPosition> {
+        self.lock()
+            .positions
+            .get(&(symbol.to_string(), position_side))
+            .cloned()
+    }
+
+    /// Open positions, of every symbol when `symbol` is `None`.
+    pub fn positions(&self, symbol: Option<&str>) -> Vec<TrackedPosition> {
+        self.lock()
+            .positions
+            .values()
+            .filter(|position| {
+                position.is_open() && symbol.is_none_or(|symbol| position.symbol == symbol)
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Cross wallet balance of a margin asset.
+    pub fn wallet(&self, asset: &str) -> f64 {
+        self.lock().wallets.get(asset).copied().unwrap_or_default()
+    }
+
+    /// Funding paid on `symbol` as booked in the income history.
+    pub fn booked_funding(&self, symbol: &str) -> f64 {
+        self.lock()
+            .booked_funding
+            .get(symbol)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    pub fn unrealized_pnl(&self, asset: &str) -> f64 {
+        self.lock()
+            .positions
+            .values()
+            .filter(|position| position.margin_asset == asset)
+            .map(TrackedPosition::unrealized_pnl)
+            .sum()
+    }
+
+    /// Maintenance margin over margin balance, of the position itself when
+    /// isolated and of all cross positions in its margin asset otherwise.
+    pub fn margin_ratio(&self, symbol: &str, position_side: PositionSide) -> Option<f64> {
+        let state = self.lock();
+        let position = state.positions.get(&(symbol.to_string(), position_side))?;
+
+        let (maintenance_margin, margin_balance) = match position.isolated {
+            true => (
+                position.maintenance_margin(),
+                position.isolated_wallet + position.unrealized_pnl(),
+            ),
+            false => state.cross(&position.margin_asset).fold(
+                (0.0, state.cross_wallet(&position.margin_asset)),
+                |sum, other| {
+                    (
+                        sum.0 + other.maintenance_margin(),
+                        sum.1 + other.unrealized_pnl(),
+                    )
+                },
+            ),
+        };
+
+        (margin_balance > 0.0).then(|| maintenance_margin / margin_balance)
+    }
+
+    /// Mark price at which the position would be liquidated, assuming the other
+    /// cross positions keep their current PnL. `None` for flat positions and
+    /// longs that can't be liquidated.
+    pub fn liquidation_price(&self, symbol: &str, position_side: PositionSide) -> Option<f64> {
+        let state = self.lock();
+        let position = state.positions.get(&(symbol.to_string(), position_side))?;
+
+        if !position.is_open() {
+            return None;
+        }
+
+        let wallet = match position.isolated {
+            true => position.isolated_wallet,
+            false => state
+                .cross(&position.margin_asset)
+                .filter(|other| {
+                    other.symbol != position.symbol || other.position_side != position_side
+                })
+                .fold(
+                    state.cross_wallet(&position.margin_asset),
+                    |wallet, other| wallet - other.maintenance_margin() + other.unrealized_pnl(),
+                ),
+        };
+
+        let side = position.amount.signum();
+        let quantity = position.amount.abs();
+        let price = (wallet - side * quantity * position.entry_price)
+            / (quantity * position.maintenance_rate - side * quantity);
+
+        (price > 0.0).then_some(price)
+    }
+
+    fn lock(&self) -> MutexGuard<'_, PositionState> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl PositionState {
+    fn cross<'a>(&'a self, asset: &'a str) -> impl Iterator<Item = &'a TrackedPosition> + 'a {
+        self.positions.values().filter(move |position| {
+            position.is_open() && !position.isolated && position.margin_asset == asset
+        })
+    }
+
+    fn cross_wallet(&self, asset: &str) -> f64 {
+        self.wallets.get(asset).copied().unwrap_or_default()
+    }
+
+    fn entry(&mut self, symbol: &str, position_side: PositionSide) -> &mut TrackedPosition {
+        self.positions
+            .entry((symbol.to_string(), position_side))
+            .or_insert_with(|| TrackedPosition::new(symbol, position_side))
+    }
+
+    fn update(&mut self, update: &PositionUpdate, time: u64) {
+        let mark_price = self.mark_price(&update.symbol);
+        let position = self.entry(&update.symbol, update.position_side);
+
+        if time < position.update_time {
+            return;
+        }
+
+        position.amount = update.position_amount;
+        position.entry_price = update.entry_price;
+        position.break_even_price = update.break_even_price;
+        position.realized_pnl = update.accumulated_realized;
+        position.isolated = update.margin_type == "isolated";
+        position.isolated_wallet = update.isolated_wallet;
+        position.update_time = time;
+
+        if position.mark_price == 0.0 {
+            position.mark_price = mark_price.unwrap_or(update.entry_price);
+        }
+    }
+
+    /// Positions of the snapshot scope missing from it were closed.
+    fn reconcile(&mut self, snapshot: &[PositionRiskV3Response], symbol: Option<&str>) {
+        let mut seen = BTreeSet::new();
+
+        for risk in snapshot {
+            seen.insert((risk.symbol.clone(), risk.position_side));
+
+            let position = self.entry(&risk.symbol, risk.position_side);
+
+            if risk.update_time < position.update_time {
+                continue;
+            }
+
+            position.amount = risk.position_amt;
+            position.entry_price = risk.entry_price;
+            position.break_even_price = risk.break_even_price;
+            position.mark_price = risk.mark_price;
+            position.margin_asset = risk.margin_asset.clone();
+            position.isolated = risk.isolated_wallet != 0.0;
+            position.isolated_wallet = risk.isolated_wallet;
+            position.update_time = risk.update_time;
+
+            if risk.notional != 0.0 && risk.maint_margin != 0.0 {
+                position.maintenance_rate = risk.maint_margin / risk.notional.abs();
+            }
+        }
+
+        for (key, position) in self.positions.iter_mut() {
+            if !seen.contains(key) && symbol.is_none_or(|symbol| position.symbol == symbol) {
+                position.amount = 0.0;
+            }
+        }
+    }
+
+    fn mark(&mut self, mark: &MarkPriceResponse) {
+        let settled = match self.funding.get(&mark.symbol) {
+            Some((funding_time, rate))
+                if mark.event_time >= *funding_time && mark.next_funding_time > *funding_time =>
+            {
+                Some(*rate)
+            }
+            _ => None,
+        };
+
+        self.funding.insert(
+            mark.symbol.clone(),
+            (mark.next_funding_time, mark.funding_rate),
+        );
+
+        for position in self
+            .positions
+            .values_mut()
+            .filter(|position| position.symbol == mark.symbol)
+        {
+            // Funding settles at the mark price of the funding time, the last
+            // one seen before this tick.
+            if let Some(rate) = settled {
+                if position.mark_price == 0.0 {
+                    position.mark_price = mark.mark_price;
+                }
+
+                position.funding_paid += position.notional() * rate;
+            }
+
+            position.mark_price = mark.mark_price;
+        }
+    }
+
+    fn mark_price(&self, symbol: &str) -> Option<f64> {
+        self.positions
+            .values()
+            .find(|position| position.symbol == symbol && position.mark_price != 0.0)
+            .map(|position| position.mark_price)
+    }
+}
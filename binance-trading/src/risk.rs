@@ -0,0 +1,341 @@
+pub mod futures;
+pub mod spot;
+
+use std::{
+    collections::{BTreeSet, HashMap},
+    fmt::Display,
+    sync::{Mutex, MutexGuard},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use binance_common::error::BinanceError;
+
+use crate::paper::reject;
+
+const DAY: u64 = 86_400_000;
+
+/// Limits checked before an order is forwarded, unset limits are not checked.
+#[derive(Clone, Debug, Default)]
+pub struct RiskLimits {
+    /// Quote notional of a single order.
+    pub max_notional: Option<f64>,
+    /// Absolute base quantity held per symbol, the empty symbol applies to every
+    /// symbol without its own limit.
+    pub max_position: HashMap<String, f64>,
+    /// Open orders across all symbols, including the one being placed. Every
+    /// order then lists the open orders of all symbols first, which weighs 40
+    /// on futures and 80 on spot, so keep the order rate well below the limit.
+    pub max_open_orders: Option<usize>,
+    /// Largest relative deviation of the limit or stop price from the reference price.
+    pub price_band: Option<f64>,
+    /// Realized loss per UTC day after which the kill switch fires.
+    pub daily_loss: Option<f64>,
+}
+
+impl RiskLimits {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn max_notional(mut self, max_notional: f64) -> Self {
+        self.max_notional = Some(max_notional);
+        self
+    }
+
+    pub fn max_position(mut self, symbol: &str, max_position: f64) -> Self {
+        self.max_position.insert(symbol.to_string(), max_position);
+        self
+    }
+
+    pub fn max_open_orders(mut self, max_open_orders: usize) -> Self {
+        self.max_open_orders = Some(max_open_orders);
+        self
+    }
+
+    pub fn price_band(mut self, price_band: f64) -> Self {
+        self.price_band = Some(price_band);
+        self
+    }
+
+    pub fn daily_loss(mut self, daily_loss: f64) -> Self {
+        self.daily_loss = Some(daily_loss);
+        self
+    }
+
+    fn position_limit(&self, symbol: &str) -> Option<f64> {
+        self.max_position
+            .get(symbol)
+            .or_else(|| self.max_position.get(""))
+            .copied()
+    }
+
+    /// Whether checking the order needs the reference price.
+    fn needs_reference(&self) -> bool {
+        self.max_notional.is_some() || self.price_band.is_some()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Violation {
+    Killed(String),
+    MaxNotional {
+        notional: f64,
+        limit: f64,
+    },
+    MaxPosition {
+        position: f64,
+        limit: f64,
+    },
+    MaxOpenOrders {
+        open: usize,
+        limit: usize,
+    },
+    PriceBand {
+        price: f64,
+        reference: f64,
+        limit: f64,
+    },
+    DailyLoss {
+        loss: f64,
+        limit: f64,
+    },
+}
+
+impl Display for Violation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Violation::Killed(reason) => write!(f, "kill switch is active ({})", reason),
+            Violation::MaxNotional { notional, limit } => {
+                write!(f, "notional {} exceeds {}", notional, limit)
+            }
+            Violation::MaxPosition { position, limit } => {
+                write!(f, "position {} would exceed {}", position, limit)
+            }
+            Violation::MaxOpenOrders { open, limit } => {
+                write!(f, "{} open orders reach the limit of {}", open, limit)
+            }
+            Violation::PriceBand {
+                price,
+                reference,
+                limit,
+            } => write!(
+                f,
+                "price {} deviates more than {} from {}",
+                price, limit, reference
+            ),
+            Violation::DailyLoss { loss, limit } => {
+                write!(f, "daily loss {} exceeds {}", loss, limit)
+            }
+        }
+    }
+}
+
+/// Failures of a kill, which still went through every symbol it could.
+#[derive(Debug)]
+pub struct KillError {
+    /// Symbols or orders the kill went through, as its `Ok` would have reported.
+    pub done: usize,
+    /// Each failed call with its symbol, the empty symbol for the open orders lookup.
+    pub errors: Vec<(String, BinanceError)>,
+}
+
+impl KillError {
+    fn check(done: usize, errors: Vec<(String, BinanceError)>) -> Result<usize, KillError> {
+        match errors.is_empty() {
+            true => Ok(done),
+            false => Err(KillError { done, errors }),
+        }
+    }
+}
+
+impl Display for KillError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "kill switch failed {} times", self.errors.len())?;
+
+        for (symbol, error) in &self.errors {
+            match symbol.is_empty() {
+                true => write!(f, "; open orders: {}", error)?,
+                false => write!(f, "; {}: {}", symbol, error)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for KillError {}
+
+impl From<KillError> for BinanceError {
+    fn from(value: KillError) -> Self {
+        BinanceError::Unknown(value.to_string())
+    }
+}
+
+/// Order as the checks see it, after market orders were priced at the reference.
+struct OrderCheck {
+    price: Option<f64>,
+    stop_price: Option<f64>,
+    notional: Option<f64>,
+    reference: Option<f64>,
+}
+
+#[derive(Default)]
+struct RiskState {
+    killed: Option<String>,
+    day: u64,
+    realized: f64,
+    symbols: BTreeSet<String>,
+    last_violation: Option<Violation>,
+}
+
+/// Kill switch, daily PnL and rejections shared by the spot and futures gates.
+struct Guard {
+    limits: RiskLimits,
+    state: Mutex<RiskState>,
+}
+
+impl Guard {
+    fn new(limits: RiskLimits) -> Self {
+        Guard {
+            limits,
+            state: Mutex::new(RiskState::default()),
+        }
+    }
+
+    /// The kill switch or the daily loss, whichever stops trading first.
+    fn halted(&self) -> Option<Violation> {
+        let state = self.lock();
+
+        if let Some(reason) = &state.killed {
+            return Some(Violation::Killed(reason.clone()));
+        }
+
+        let loss = match state.day == today() {
+            true => -state.realized,
+            false => 0.0,
+        };
+
+        self.limits
+            .daily_loss
+            .filter(|limit| loss >= *limit)
+            .map(|limit| Violation::DailyLoss { loss, limit })
+    }
+
+    fn check(&self, order: &OrderCheck) -> Result<(), Violation> {
+        if let (Some(limit), Some(notional)) = (self.limits.max_notional, order.notional)
+            && notional > limit
+        {
+            return Err(Violation::MaxNotional { notional, limit });
+        }
+
+        if let (Some(limit), Some(reference)) = (self.limits.price_band, order.reference) {
+            for price in [order.price, order.stop_price].into_iter().flatten() {
+                if (price / reference - 1.0).abs() > limit {
+                    return Err(Violation::PriceBand {
+                        price,
+                        reference,
+                        limit,
+                    });
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn check_open_orders(&self, open: usize) -> Result<(), Violation> {
+        match self.limits.max_open_orders {
+            Some(limit) if open >= limit => Err(Violation::MaxOpenOrders { open, limit }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Only orders growing the absolute position are held to the limit.
+    fn check_position(&self, symbol: &str, before: f64, after: f64) -> Result<(), Violation> {
+        match self.limits.position_limit(symbol) {
+            Some(limit) if after.abs() > limit && after.abs() > before.abs() => {
+                Err(Violation::MaxPosition {
+                    position: after,
+                    limit,
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns the daily loss when this booking just crossed today's limit.
+    fn record_pnl(&self, pnl: f64, time: u64) -> Option<Violation> {
+        let mut state = self.lock();
+        let day = time / DAY;
+
+        if day > state.day {
+            state.day = day;
+            state.realized = 0.0;
+        }
+
+        if day != state.day {
+            return None;
+        }
+
+        let before = -state.realized;
+        state.realized += pnl;
+        let loss = -state.realized;
+
+        self.limits
+            .daily_loss
+            .filter(|limit| day == today() && before < *limit && loss >= *limit)
+            .map(|limit| Violation::DailyLoss { loss, limit })
+    }
+
+    fn realized_today(&self) -> f64 {
+        let state = self.lock();
+
+        match state.day == today() {
+            true => state.realized,
+            false => 0.0,
+        }
+    }
+
+    fn kill(&self, reason: &str) {
+        self.lock().killed.get_or_insert_with(|| reason.to_string());
+    }
+
+    fn reset(&self) {
+        self.lock().killed = None;
+    }
+
+    fn killed(&self) -> Option<String> {
+        self.lock().killed.clone()
+    }
+
+    fn route(&self, symbol: &str) {
+        self.lock().symbols.insert(symbol.to_string());
+    }
+
+    fn symbols(&self) -> BTreeSet<String> {
+        self.lock().symbols.clone()
+    }
+
+    fn reject(&self, violation: Violation) -> BinanceError {
+        let error = reject(-2010, &format!("Rejected by the risk gate: {}", violation));
+        self.lock().last_violation = Some(violation);
+        error
+    }
+
+    fn last_violation(&self) -> Option<Violation> {
+        self.lock().last_violation.clone()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, RiskState> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+fn today() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_millis() as u64 / DAY)
+        .unwrap_or_default()
+}
@@ -0,0 +1,308 @@
+use std::collections::BTreeSet;
+
+use binance_common::{
+    enums::futures::{OrderSide, PositionSide},
+    error::BinanceError,
+    futures::model::{
+        params::{
+            market::Symbol,
+            trade::{
+                AutoCancelAllOpenOrdersParams, CancelAllOrdersParams, CancelOrderParams,
+                GetOrderParams, NewOrderParams, OpenOrdersParams, PositionRiskV3Params,
+            },
+        },
+        response::{
+            account::FuturesBalanceResponse,
+            stream::UserDataEvent,
+            trade::{
+                AutoCancelAllOpenOrdersResponse, CancelAllOrdersResponse, GetOrderResponse,
+                OrderResponse, PositionRiskV3Response,
+            },
+        },
+    },
+};
+use binance_core::trading::futures::{FuturesPriceApi, FuturesTradingApi};
+
+use crate::{
+    paper::split_symbol,
+    risk::{Guard, KillError, OrderCheck, RiskLimits, Violation},
+};
+
+/// Pre-trade checks in front of a [`FuturesTradingApi`], itself usable wherever
+/// one is expected.
+///
+/// Limit and stop prices are held to a band around the mark price, which also
+/// prices market orders for the notional limit. Positions come from the position
+/// risk of the symbol, reduce-only and close-position orders are never held to
+/// the position limit. A failing lookup rejects the order. The daily loss is
+/// fed with the realized profit and commission of `ORDER_TRADE_UPDATE` events,
+/// crossing it fires the kill switch.
+pub struct FuturesRiskGate<T, P> {
+    api: T,
+    prices: P,
+    guard: Guard,
+    kill_countdown: u64,
+}
+
+impl<T, P> FuturesRiskGate<T, P>
+where
+    T: FuturesTradingApi + Sync,
+    P: FuturesPriceApi + Sync,
+{
+    pub fn new(api: T, prices: P, limits: RiskLimits) -> Self {
+        FuturesRiskGate {
+            api,
+            prices,
+            guard: Guard::new(limits),
+            kill_countdown: 10_000,
+        }
+    }
+
+    /// Countdown in ms armed by the kill switch, catching orders that were still
+    /// in flight when the open orders were canceled. `0` arms nothing.
+    pub fn kill_countdown(mut self, kill_countdown: u64) -> Self {
+        self.kill_countdown = kill_countdown;
+        self
+    }
+
+    pub fn api(&self) -> &T {
+        &self.api
+    }
+
+    /// Books the realized profit and commission of a fill towards the daily loss,
+    /// see [`record_pnl`](Self::record_pnl). Commission paid in another asset
+    /// than the margin asset, such as BNB, is left out.
+    pub async fn apply(&self, event: &UserDataEvent) -> Result<bool, KillError> {
+        match event {
+            UserDataEvent::OrderTradeUpdate(update) => {
+                let order = &update.order;
+                let margin_asset = split_symbol(&order.symbol).map(|(_, quote)| quote);
+
+                let commission = match &order.commission_asset {
+                    Some(asset) if margin_asset.as_ref() == Some(asset) => {
+                        order.commission.unwrap_or_default()
+                    }
+                    _ => 0.0,
+                };
+
+                self.record_pnl(order.realized_profit - commission, update.transaction_time)
+                    .await
+            }
+            _ => Ok(false),
+        }
+    }
+
+    /// Books realized PnL in the margin asset at `time`, in ms since the epoch,
+    /// and fires the kill switch once it crosses the daily loss limit. Returns
+    /// whether it did.
+    pub async fn record_pnl(&self, pnl: f64, time: u64) -> Result<bool, KillError> {
+        match self.guard.record_pnl(pnl, time) {
+            Some(violation) => self.kill(&violation.to_string()).await.map(|_| true),
+            None => Ok(false),
+        }
+    }
+
+    /// Realized PnL of the current UTC day.
+    pub fn realized_today(&self) -> f64 {
+        self.guard.realized_today()
+    }
+
+    /// Rejects every new order, cancels the open orders of every symbol with
+    /// open orders or routed through the gate, and arms the countdown on them.
+    /// Returns the symbols it went through, failing calls do not stop the others.
+    pub async fn kill(&self, reason: &str) -> Result<usize, KillError> {
+        self.guard.kill(reason);
+
+        let mut errors = Vec::new();
+        let mut symbols = self.guard.symbols();
+
+        match self.api.get_all_open_orders(&OpenOrdersParams::all()).await {
+            Ok(open) => symbols.extend(open.into_iter().map(|order| order.symbol)),
+            Err(error) => errors.push((String::new(), error)),
+        }
+
+        for symbol in &symbols {
+            let canceled = self
+                .api
+                .send_cancel_all_orders(&CancelAllOrdersParams::new(symbol))
+                .await
+                .map(|_| ());
+
+            let armed = match self.kill_countdown {
+                0 => Ok(()),
+                countdown => self
+                    .api
+                    .send_auto_cancel_all_open_orders(&AutoCancelAllOpenOrdersParams::new(
+                        symbol, countdown,
+                    ))
+                    .await
+                    .map(|_| ()),
+            };
+
+            errors.extend(
+                [canceled, armed]
+                    .into_iter()
+                    .filter_map(Result::err)
+                    .map(|error| (symbol.clone(), error)),
+            );
+        }
+
+        KillError::check(symbols.len(), errors)
+    }
+
+    /// Renews the dead man's switch of every symbol routed through the gate, so
+    /// their orders are canceled if this process stops calling it within
+    /// `countdown` ms. `0` disarms it. Returns the symbols it went through.
+    pub async fn heartbeat(&self, countdown: u64) -> Result<usize, BinanceError> {
+        let symbols: BTreeSet<String> = self.guard.symbols();
+
+        for symbol in &symbols {
+            self.api
+                .send_auto_cancel_all_open_orders(&AutoCancelAllOpenOrdersParams::new(
+                    symbol, countdown,
+                ))
+                .await?;
+        }
+
+        Ok(symbols.len())
+    }
+
+    /// Lets orders through again after a kill. Armed countdowns keep running
+    /// until they fire or [`heartbeat`](Self::heartbeat) disarms them.
+    pub fn reset(&self) {
+        self.guard.reset();
+    }
+
+    pub fn killed(&self) -> Option<String> {
+        self.guard.killed()
+    }
+
+    pub fn last_violation(&self) -> Option<Violation> {
+        self.guard.last_violation()
+    }
+
+    async fn check(&self, params: &NewOrderParams<'_>) -> Result<(), BinanceError> {
+        let limits = &self.guard.limits;
+        let reject = |violation| self.guard.reject(violation);
+
+        let reference = match limits.needs_reference() {
+            true => Some(
+                self.prices
+                    .get_mark_price(&Symbol::new(params.symbol))
+                    .await?
+                    .mark_price,
+            ),
+            false => None,
+        };
+
+        let quantity = params.quantity.unwrap_or_default();
+
+        self.guard
+            .check(&OrderCheck {
+                price: params.price,
+                stop_price: params.stop_price.or(params.activation_price),
+                notional: params.price.or(reference).map(|price| price * quantity),
+                reference,
+            })
+            .map_err(reject)?;
+
+        if limits.max_open_orders.is_some() {
+            let open = self
+                .api
+                .get_all_open_orders(&OpenOrdersParams::all())
+                .await?;
+
+            self.guard.check_open_orders(open.len()).map_err(reject)?;
+        }
+
+        let reduces = params.reduce_only == Some(true) || params.close_position == Some("true");
+
+        if limits.position_limit(params.symbol).is_some() && !reduces {
+            let position_side = params.position_side.unwrap_or(PositionSide::Both);
+            let held: f64 = self
+                .api
+                .get_position_risk_v3(&PositionRiskV3Params::new(params.symbol))
+                .await?
+                .iter()
+                .filter(|position| position.position_side == position_side)
+                .map(|position| position.position_amt)
+                .sum();
+
+            let change = match params.side {
+                OrderSide::Buy => quantity,
+                OrderSide::Sell => -quantity,
+            };
+
+            self.guard
+                .check_position(params.symbol, held, held + change)
+                .map_err(reject)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T, P> FuturesTradingApi for FuturesRiskGate<T, P>
+where
+    T: FuturesTradingApi + Sync,
+    P: FuturesPriceApi + Sync,
+{
+    async fn send_new_order(
+        &self,
+        params: &NewOrderParams<'_>,
+    ) -> Result<OrderResponse, BinanceError> {
+        if let Some(violation) = self.guard.halted() {
+            return Err(self.guard.reject(violation));
+        }
+
+        self.check(params).await?;
+        self.guard.route(params.symbol);
+        self.api.send_new_order(params).await
+    }
+
+    async fn send_cancel_order(
+        &self,
+        params: &CancelOrderParams<'_>,
+    ) -> Result<OrderResponse, BinanceError> {
+        self.api.send_cancel_order(params).await
+    }
+
+    async fn send_cancel_all_orders(
+        &self,
+        params: &CancelAllOrdersParams<'_>,
+    ) -> Result<CancelAllOrdersResponse, BinanceError> {
+        self.api.send_cancel_all_orders(params).await
+    }
+
+    async fn send_auto_cancel_all_open_orders(
+        &self,
+        params: &AutoCancelAllOpenOrdersParams<'_>,
+    ) -> Result<AutoCancelAllOpenOrdersResponse, BinanceError> {
+        self.api.send_auto_cancel_all_open_orders(params).await
+    }
+
+    async fn get_order(
+        &self,
+        params: &GetOrderParams<'_>,
+    ) -> Result<GetOrderResponse, BinanceError> {
+        self.api.get_order(params).await
+    }
+
+    async fn get_all_open_orders(
+        &self,
+        params: &OpenOrdersParams<'_>,
+    ) -> Result<Vec<GetOrderResponse>, BinanceError> {
+        self.api.get_all_open_orders(params).await
+    }
+
+    async fn get_balances(&self) -> Result<Vec<FuturesBalanceResponse>, BinanceError> {
+        self.api.get_balances().await
+    }
+
+    async fn get_position_risk_v3(
+        &self,
+        params: &PositionRiskV3Params<'_>,
+    ) -> Result<Vec<PositionRiskV3Response>, BinanceError> {
+        self.api.get_position_risk_v3(params).await
+    }
+}
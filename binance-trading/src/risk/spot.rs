@@ -0,0 +1,227 @@
+use std::collections::BTreeSet;
+
+use binance_common::{
+    enums::spot::OrderSide,
+    error::BinanceError,
+    spot::model::{
+        params::{
+            market::AvgPriceParams,
+            trade::{
+                CancelAllOrdersParms, CancelOrderParams, GetOrderParams, NewOrderParams,
+                OpenOrdersParams,
+            },
+        },
+        response::{
+            account::AssetResponse,
+            trade::{CancelOrderResponse, NewOrderResponse, OrderIdResponse, OrderResponse},
+        },
+    },
+};
+use binance_core::trading::spot::{SpotPriceApi, SpotTradingApi};
+
+use crate::{
+    paper::split_symbol,
+    risk::{Guard, KillError, OrderCheck, RiskLimits, Violation},
+};
+
+/// Pre-trade checks in front of a [`SpotTradingApi`], itself usable wherever
+/// one is expected.
+///
+/// Limit and stop prices are held to a band around `get_average_price`, which
+/// also prices market orders for the notional limit. Positions are the free and
+/// locked balance of the base asset, and a failing lookup rejects the order.
+/// Spot fills carry no realized PnL, so the daily loss is fed through
+/// [`record_pnl`](Self::record_pnl).
+pub struct SpotRiskGate<T, P> {
+    api: T,
+    prices: P,
+    guard: Guard,
+}
+
+impl<T, P> SpotRiskGate<T, P>
+where
+    T: SpotTradingApi + Sync,
+    P: SpotPriceApi + Sync,
+{
+    pub fn new(api: T, prices: P, limits: RiskLimits) -> Self {
+        SpotRiskGate {
+            api,
+            prices,
+            guard: Guard::new(limits),
+        }
+    }
+
+    pub fn api(&self) -> &T {
+        &self.api
+    }
+
+    /// Books realized PnL in the quote currency at `time`, in ms since the epoch,
+    /// and fires the kill switch once it crosses the daily loss limit. Returns
+    /// whether it did.
+    pub async fn record_pnl(&self, pnl: f64, time: u64) -> Result<bool, KillError> {
+        match self.guard.record_pnl(pnl, time) {
+            Some(violation) => self.kill(&violation.to_string()).await.map(|_| true),
+            None => Ok(false),
+        }
+    }
+
+    /// Realized PnL of the current UTC day.
+    pub fn realized_today(&self) -> f64 {
+        self.guard.realized_today()
+    }
+
+    /// Rejects every new order and cancels the open orders of every symbol.
+    /// Returns how many orders were canceled, failing symbols do not stop the others.
+    pub async fn kill(&self, reason: &str) -> Result<usize, KillError> {
+        self.guard.kill(reason);
+
+        let open = self
+            .api
+            .get_open_orders(&OpenOrdersParams::all())
+            .await
+            .map_err(|error| KillError {
+                done: 0,
+                errors: vec![(String::new(), error)],
+            })?;
+        let symbols: BTreeSet<&str> = open.iter().map(|order| order.symbol.as_str()).collect();
+        let mut canceled = 0;
+        let mut errors = Vec::new();
+
+        for symbol in symbols {
+            match self
+                .api
+                .send_cancel_open_orders(&CancelAllOrdersParms::new(symbol))
+                .await
+            {
+                Ok(orders) => canceled += orders.len(),
+                Err(error) => errors.push((symbol.to_string(), error)),
+            }
+        }
+
+        KillError::check(canceled, errors)
+    }
+
+    /// Lets orders through again after a kill.
+    pub fn reset(&self) {
+        self.guard.reset();
+    }
+
+    pub fn killed(&self) -> Option<String> {
+        self.guard.killed()
+    }
+
+    pub fn last_violation(&self) -> Option<Violation> {
+        self.guard.last_violation()
+    }
+
+    async fn check(&self, params: &NewOrderParams<'_>) -> Result<(), BinanceError> {
+        let limits = &self.guard.limits;
+        let reject = |violation| self.guard.reject(violation);
+
+        let reference = match limits.needs_reference() {
+            true => Some(
+                self.prices
+                    .get_average_price(&AvgPriceParams::new(params.symbol))
+                    .await?
+                    .price,
+            ),
+            false => None,
+        };
+
+        let price = params.price.or(reference);
+        let quantity = params
+            .quantity
+            .or_else(|| Some(params.quote_order_qty? / price?))
+            .unwrap_or_default();
+
+        self.guard
+            .check(&OrderCheck {
+                price: params.price,
+                stop_price: params.stop_price,
+                notional: params
+                    .quote_order_qty
+                    .or_else(|| Some(price? * params.quantity?)),
+                reference,
+            })
+            .map_err(reject)?;
+
+        if limits.max_open_orders.is_some() {
+            let open = self.api.get_open_orders(&OpenOrdersParams::all()).await?;
+
+            self.guard.check_open_orders(open.len()).map_err(reject)?;
+        }
+
+        if limits.position_limit(params.symbol).is_some() {
+            let base = split_symbol(params.symbol).map(|(base, _)| base);
+            let held: f64 = self
+                .api
+                .get_balances()
+                .await?
+                .iter()
+                .filter(|balance| base.as_deref() == Some(balance.asset.as_str()))
+                .map(|balance| balance.free + balance.locked)
+                .sum();
+
+            let change = match params.side {
+                OrderSide::Buy => quantity,
+                OrderSide::Sell => -quantity,
+            };
+
+            self.guard
+                .check_position(params.symbol, held, held + change)
+                .map_err(reject)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T, P> SpotTradingApi for SpotRiskGate<T, P>
+where
+    T: SpotTradingApi + Sync,
+    P: SpotPriceApi + Sync,
+{
+    async fn send_new_order(
+        &self,
+        params: &NewOrderParams<'_>,
+    ) -> Result<NewOrderResponse, BinanceError> {
+        if let Some(violation) = self.guard.halted() {
+            return Err(self.guard.reject(violation));
+        }
+
+        self.check(params).await?;
+        self.api.send_new_order(params).await
+    }
+
+    async fn send_cancel_order(
+        &self,
+        params: &CancelOrderParams<'_>,
+    ) -> Result<CancelOrderResponse, BinanceError> {
+        self.api.send_cancel_order(params).await
+    }
+
+    async fn send_cancel_open_orders(
+        &self,
+        params: &CancelAllOrdersParms<'_>,
+    ) -> Result<Vec<CancelOrderResponse>, BinanceError> {
+        self.api.send_cancel_open_orders(params).await
+    }
+
+    async fn get_order(
+        &self,
+        params: &GetOrderParams<'_>,
+    ) -> Result<OrderIdResponse, BinanceError> {
+        self.api.get_order(params).await
+    }
+
+    async fn get_open_orders(
+        &self,
+        params: &OpenOrdersParams<'_>,
+    ) -> Result<Vec<OrderResponse>, BinanceError> {
+        self.api.get_open_orders(params).await
+    }
+
+    async fn get_balances(&self) -> Result<Vec<AssetResponse>, BinanceError> {
+        self.api.get_balances().await
+    }
+}
@@ -0,0 +1,328 @@
+pub mod futures;
+pub mod spot;
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Debug;
+
+use binance_common::enums::{futures::OrderStatus as FuturesOrderStatus, spot::OrderStatus};
+use serde::de::DeserializeOwned;
+
+/// Quantities closer than this are treated as equal.
+const EPSILON: f64 = 1e-9;
+
+/// Order statuses the tracker can tell apart into open and final ones.
+pub trait Lifecycle: Copy + PartialEq + Debug {
+    fn is_open(&self) -> bool;
+}
+
+impl Lifecycle for OrderStatus {
+    fn is_open(&self) -> bool {
+        matches!(
+            self,
+            OrderStatus::New
+                | OrderStatus::PendingNew
+                | OrderStatus::PartiallyFilled
+                | OrderStatus::PendingCancel
+        )
+    }
+}
+
+impl Lifecycle for FuturesOrderStatus {
+    fn is_open(&self) -> bool {
+        matches!(
+            self,
+            FuturesOrderStatus::New | FuturesOrderStatus::PartiallyFilled
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct TrackedFill {
+    /// `None` for fills inferred from a REST query because their events were missed.
+    pub trade_id: Option<u64>,
+    pub price: f64,
+    pub quantity: f64,
+    pub commission: f64,
+    pub commission_asset: Option<String>,
+    pub maker: bool,
+    pub time: u64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Transition<S> {
+    /// `None` for the state the order was first seen in.
+    pub from: Option<S>,
+    pub to: S,
+    pub time: u64,
+}
+
+/// Order state as reported by a REST query or carried by a stream event.
+#[derive(Clone, Debug)]
+pub struct OrderState<S, D> {
+    pub symbol: String,
+    pub order_id: u64,
+    pub client_order_id: String,
+    pub side: D,
+    pub price: f64,
+    pub quantity: f64,
+    pub executed: f64,
+    pub cumulative_quote: f64,
+    pub status: S,
+    pub time: u64,
+}
+
+#[derive(Clone, Debug)]
+pub struct TrackedOrder<S, D> {
+    pub symbol: String,
+    pub order_id: u64,
+    pub client_order_id: String,
+    pub side: D,
+    pub price: f64,
+    pub quantity: f64,
+    pub executed: f64,
+    pub cumulative_quote: f64,
+    pub status: S,
+    pub update_time: u64,
+    pub fills: Vec<TrackedFill>,
+    pub transitions: Vec<Transition<S>>,
+}
+
+impl<S: Lifecycle, D> TrackedOrder<S, D> {
+    pub fn remaining(&self) -> f64 {
+        (self.quantity - self.executed).max(0.0)
+    }
+
+    pub fn average_price(&self) -> f64 {
+        match self.executed > 0.0 {
+            true => self.cumulative_quote / self.executed,
+            false => 0.0,
+        }
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.status.is_open()
+    }
+
+    /// Books the quantity executed beyond what the tracked fills account for as
+    /// one fill at the average price of the difference.
+    fn catch_up(&mut self, executed: f64, cumulative_quote: f64, time: u64) {
+        let quantity = executed - self.executed;
+
+        if quantity <= EPSILON {
+            return;
+        }
+
+        self.fills.push(TrackedFill {
+            trade_id: None,
+            price: (cumulative_quote - self.cumulative_quote) / quantity,
+            quantity,
+            commission: 0.0,
+            commission_asset: None,
+            maker: false,
+            time,
+        });
+        self.executed = executed;
+        self.cumulative_quote = cumulative_quote;
+    }
+
+    /// Moves to `status` unless that would reopen a final order, which only a
+    /// late event can ask for.
+    fn advance(&mut self, status: S, time: u64) {
+        self.update_time = self.update_time.max(time);
+
+        if status == self.status || (!self.status.is_open() && status.is_open()) {
+            return;
+        }
+
+        self.transitions.push(Transition {
+            from: Some(self.status),
+            to: status,
+            time,
+        });
+        self.status = status;
+    }
+}
+
+/// Authoritative state of the orders of one account.
+///
+/// Seeded from REST snapshots and advanced by execution reports. Events that
+/// arrive late or twice are dropped by comparing cumulative quantities, fills
+/// skipped between two events are inferred from the cumulative fields, and
+/// orders whose fate the stream can no longer tell are kept as stale until a
+/// REST query is reconciled for them.
+pub struct OrderBook<S, D> {
+    orders: BTreeMap<(String, u64), TrackedOrder<S, D>>,
+    stale: BTreeSet<(String, u64)>,
+}
+
+impl<S, D> Default for OrderBook<S, D> {
+    fn default() -> Self {
+        OrderBook {
+            orders: BTreeMap::new(),
+            stale: BTreeSet::new(),
+        }
+    }
+}
+
+impl<S: Lifecycle, D: Clone> OrderBook<S, D> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn order(&self, symbol: &str, order_id: u64) -> Option<&TrackedOrder<S, D>> {
+        self.orders.get(&(symbol.to_string(), order_id))
+    }
+
+    pub fn find(&self, symbol: &str, client_order_id: &str) -> Option<&TrackedOrder<S, D>> {
+        self.orders
+            .values()
+            .rev()
+            .find(|order| order.symbol == symbol && order.client_order_id == client_order_id)
+    }
+
+    pub fn open_orders<'a>(
+        &'a self,
+        symbol: Option<&'a str>,
+    ) -> impl Iterator<Item = &'a TrackedOrder<S, D>> + 'a {
+        self.orders.values().filter(move |order| {
+            order.is_open() && symbol.is_none_or(|symbol| order.symbol == symbol)
+        })
+    }
+
+    /// Orders waiting for a REST query, as `(symbol, order_id)`.
+    pub fn stale(&self) -> impl Iterator<Item = (&str, u64)> {
+        self.stale
+            .iter()
+            .map(|(symbol, order_id)| (symbol.as_str(), *order_id))
+    }
+
+    /// Marks every open order as stale, e.g. after the user stream reconnected.
+    pub fn invalidate(&mut self, symbol: Option<&str>) {
+        let keys: Vec<(String, u64)> = self
+            .open_orders(symbol)
+            .map(|order| (order.symbol.clone(), order.order_id))
+            .collect();
+
+        self.stale.extend(keys);
+    }
+
+    /// Merges an open orders snapshot. Tracked open orders of `symbol`, or of
+    /// every symbol when `None`, that the snapshot no longer lists became final
+    /// unseen and are marked as stale.
+    pub fn seed(
+        &mut self,
+        states: impl IntoIterator<Item = OrderState<S, D>>,
+        symbol: Option<&str>,
+    ) {
+        let mut seen = BTreeSet::new();
+
+        for state in states {
+            seen.insert((state.symbol.clone(), state.order_id));
+            self.reconcile(state);
+        }
+
+        let vanished: Vec<(String, u64)> = self
+            .open_orders(symbol)
+            .map(|order| (order.symbol.clone(), order.order_id))
+            .filter(|key| !seen.contains(key))
+            .collect();
+
+        self.stale.extend(vanished);
+    }
+
+    /// Merges the state of one order as returned by a REST query.
+    pub fn reconcile(&mut self, state: OrderState<S, D>) -> &TrackedOrder<S, D> {
+        let key = (state.symbol.clone(), state.order_id);
+        self.stale.remove(&key);
+
+        let order = self.entry(&state);
+
+        if state.executed + EPSILON < order.executed {
+            return order;
+        }
+
+        order.catch_up(state.executed, state.cumulative_quote, state.time);
+        order.price = state.price;
+        order.quantity = state.quantity;
+        order.advance(state.status, state.time);
+
+        order
+    }
+
+    /// Applies an execution report, returning `None` when it was a late or
+    /// repeated event.
+    pub fn apply(
+        &mut self,
+        state: OrderState<S, D>,
+        fill: Option<TrackedFill>,
+    ) -> Option<&TrackedOrder<S, D>> {
+        let order = self.entry(&state);
+
+        if state.executed + EPSILON < order.executed
+            || (fill.is_some() && state.executed <= order.executed + EPSILON)
+        {
+            return None;
+        }
+
+        if let Some(fill) = fill {
+            order.catch_up(
+                state.executed - fill.quantity,
+                state.cumulative_quote - fill.price * fill.quantity,
+                state.time,
+            );
+            order.fills.push(fill);
+            order.executed = state.executed;
+            order.cumulative_quote = state.cumulative_quote;
+        } else {
+            order.catch_up(state.executed, state.cumulative_quote, state.time);
+        }
+
+        order.price = state.price;
+        order.quantity = state.quantity;
+        order.advance(state.status, state.time);
+
+        Some(order)
+    }
+
+    /// Removes and returns the orders that reached a final status.
+    pub fn drain_closed(&mut self) -> Vec<TrackedOrder<S, D>> {
+        let keys: Vec<(String, u64)> = self
+            .orders
+            .iter()
+            .filter(|(key, order)| !order.is_open() && !self.stale.contains(*key))
+            .map(|(key, _)| key.clone())
+            .collect();
+
+        keys.iter()
+            .filter_map(|key| self.orders.remove(key))
+            .collect()
+    }
+
+    fn entry(&mut self, state: &OrderState<S, D>) -> &mut TrackedOrder<S, D> {
+        self.orders
+            .entry((state.symbol.clone(), state.order_id))
+            .or_insert_with(|| TrackedOrder {
+                symbol: state.symbol.clone(),
+                order_id: state.order_id,
+                client_order_id: state.client_order_id.clone(),
+                side: state.side.clone(),
+                price: state.price,
+                quantity: state.quantity,
+                executed: 0.0,
+                cumulative_quote: 0.0,
+                status: state.status,
+                update_time: state.time,
+                fills: Vec::new(),
+                transitions: vec![Transition {
+                    from: None,
+                    to: state.status,
+                    time: state.time,
+                }],
+            })
+    }
+}
+
+/// Reads a serde enum from the way Binance spells it, e.g. `PARTIALLY_FILLED`.
+pub(crate) fn parse<T: DeserializeOwned>(value: &str) -> Option<T> {
+    serde_json::from_value(serde_json::Value::String(value.to_string())).ok()
+}
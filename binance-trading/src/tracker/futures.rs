@@ -0,0 +1,146 @@
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use binance_common::{
+    enums::futures::{OrderSide, OrderStatus},
+    error::BinanceError,
+    futures::model::{
+        params::trade::{GetOrderParams, OpenOrdersParams},
+        response::{
+            stream::{OrderUpdate, UserDataEvent},
+            trade::GetOrderResponse,
+        },
+    },
+};
+use binance_core::trading::futures::FuturesTradingApi;
+
+use crate::tracker::{OrderBook, OrderState, TrackedFill, TrackedOrder};
+
+pub type FuturesOrder = TrackedOrder<OrderStatus, OrderSide>;
+
+/// Futures order tracker fed by `ORDER_TRADE_UPDATE` events.
+///
+/// Clones share the same book, so one clone can be driven by the user stream
+/// while another is handed to the strategy.
+#[derive(Clone, Default)]
+pub struct FuturesOrderTracker {
+    book: Arc<Mutex<OrderBook<OrderStatus, OrderSide>>>,
+}
+
+impl FuturesOrderTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Merges the open orders of `params.symbol`, or of every symbol when unset.
+    pub async fn seed<T: FuturesTradingApi>(
+        &self,
+        api: &T,
+        params: &OpenOrdersParams<'_>,
+    ) -> Result<(), BinanceError> {
+        let orders = api.get_all_open_orders(params).await?;
+
+        self.lock()
+            .seed(orders.iter().map(order_state), params.symbol);
+
+        Ok(())
+    }
+
+    /// Applies a user stream event, returning the order it advanced.
+    pub fn apply(&self, event: &UserDataEvent) -> Option<FuturesOrder> {
+        let UserDataEvent::OrderTradeUpdate(update) = event else {
+            return None;
+        };
+
+        let (state, fill) = update_state(&update.order);
+
+        self.lock().apply(state, fill).cloned()
+    }
+
+    /// Queries every stale order and returns how many were reconciled.
+    pub async fn reconcile<T: FuturesTradingApi>(&self, api: &T) -> Result<usize, BinanceError> {
+        let stale: Vec<(String, u64)> = self
+            .lock()
+            .stale()
+            .map(|(symbol, order_id)| (symbol.to_string(), order_id))
+            .collect();
+
+        for (symbol, order_id) in &stale {
+            let response = api
+                .get_order(&GetOrderParams::new(symbol).order_id(*order_id))
+                .await?;
+
+            self.lock().reconcile(order_state(&response));
+        }
+
+        Ok(stale.len())
+    }
+
+    /// Marks every open order as stale, to be called when the user stream reconnects.
+    pub fn invalidate(&self) {
+        self.lock().invalidate(None);
+    }
+
+    pub fn order(&self, symbol: &str, order_id: u64) -> Option<FuturesOrder> {
+        self.lock().order(symbol, order_id).cloned()
+    }
+
+    pub fn find(&self, symbol: &str, client_order_id: &str) -> Option<FuturesOrder> {
+        self.lock().find(symbol, client_order_id).cloned()
+    }
+
+    pub fn open_orders(&self, symbol: Option<&str>) -> Vec<FuturesOrder> {
+        self.lock().open_orders(symbol).cloned().collect()
+    }
+
+    pub fn drain_closed(&self) -> Vec<FuturesOrder> {
+        self.lock().drain_closed()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, OrderBook<OrderStatus, OrderSide>> {
+        self.book
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+fn order_state(order: &GetOrderResponse) -> OrderState<OrderStatus, OrderSide> {
+    OrderState {
+        symbol: order.symbol.clone(),
+        order_id: order.order_id,
+        client_order_id: order.client_order_id.clone(),
+        side: order.side.clone(),
+        price: order.price,
+        quantity: order.orig_qty,
+        executed: order.executed_qty,
+        cumulative_quote: order.cum_quote,
+        status: order.status,
+        time: order.update_time,
+    }
+}
+
+fn update_state(order: &OrderUpdate) -> (OrderState<OrderStatus, OrderSide>, Option<TrackedFill>) {
+    let state = OrderState {
+        symbol: order.symbol.clone(),
+        order_id: order.order_id,
+        client_order_id: order.client_order_id.clone(),
+        side: order.side.clone(),
+        price: order.price,
+        quantity: order.quantity,
+        executed: order.cumulative_quantity,
+        cumulative_quote: order.average_price * order.cumulative_quantity,
+        status: order.status,
+        time: order.trade_time,
+    };
+
+    let fill = (order.last_quantity > 0.0).then(|| TrackedFill {
+        trade_id: Some(order.trade_id),
+        price: order.last_price,
+        quantity: order.last_quantity,
+        commission: order.commission.unwrap_or_default(),
+        commission_asset: order.commission_asset.clone(),
+        maker: order.is_maker,
+        time: order.trade_time,
+    });
+
+    (state, fill)
+}
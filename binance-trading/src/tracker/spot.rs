@@ -0,0 +1,165 @@
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use binance_common::{
+    enums::spot::{OrderSide, OrderStatus},
+    error::BinanceError,
+    spot::model::{
+        params::trade::{GetOrderParams, OpenOrdersParams},
+        response::{
+            stream::{ExecutionReportEvent, UserDataEvent},
+            trade::{OrderIdResponse, OrderResponse},
+        },
+    },
+};
+use binance_core::trading::spot::SpotTradingApi;
+
+use crate::tracker::{OrderBook, OrderState, TrackedFill, TrackedOrder, parse};
+
+pub type SpotOrder = TrackedOrder<OrderStatus, OrderSide>;
+
+/// Spot order tracker fed by `executionReport` events.
+///
+/// Clones share the same book, so one clone can be driven by the user stream
+/// while another is handed to the strategy.
+#[derive(Clone, Default)]
+pub struct SpotOrderTracker {
+    book: Arc<Mutex<OrderBook<OrderStatus, OrderSide>>>,
+}
+
+impl SpotOrderTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Merges the open orders of `params.symbol`, or of every symbol when unset.
+    pub async fn seed<T: SpotTradingApi>(
+        &self,
+        api: &T,
+        params: &OpenOrdersParams<'_>,
+    ) -> Result<(), BinanceError> {
+        let orders = api.get_open_orders(params).await?;
+
+        self.lock()
+            .seed(orders.iter().filter_map(open_order_state), params.symbol);
+
+        Ok(())
+    }
+
+    /// Applies a user stream event, returning the order it advanced.
+    pub fn apply(&self, event: &UserDataEvent) -> Option<SpotOrder> {
+        let UserDataEvent::ExecutionReport(report) = event else {
+            return None;
+        };
+
+        let (state, fill) = report_state(report)?;
+
+        self.lock().apply(state, fill).cloned()
+    }
+
+    /// Queries every stale order and returns how many were reconciled.
+    pub async fn reconcile<T: SpotTradingApi>(&self, api: &T) -> Result<usize, BinanceError> {
+        let stale: Vec<(String, u64)> = self
+            .lock()
+            .stale()
+            .map(|(symbol, order_id)| (symbol.to_string(), order_id))
+            .collect();
+
+        for (symbol, order_id) in &stale {
+            let response = api
+                .get_order(&GetOrderParams::new(symbol).order_id(*order_id))
+                .await?;
+
+            if let Some(state) = order_id_state(&response) {
+                self.lock().reconcile(state);
+            }
+        }
+
+        Ok(stale.len())
+    }
+
+    /// Marks every open order as stale, to be called when the user stream reconnects.
+    pub fn invalidate(&self) {
+        self.lock().invalidate(None);
+    }
+
+    pub fn order(&self, symbol: &str, order_id: u64) -> Option<SpotOrder> {
+        self.lock().order(symbol, order_id).cloned()
+    }
+
+    pub fn find(&self, symbol: &str, client_order_id: &str) -> Option<SpotOrder> {
+        self.lock().find(symbol, client_order_id).cloned()
+    }
+
+    pub fn open_orders(&self, symbol: Option<&str>) -> Vec<SpotOrder> {
+        self.lock().open_orders(symbol).cloned().collect()
+    }
+
+    pub fn drain_closed(&self) -> Vec<SpotOrder> {
+        self.lock().drain_closed()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, OrderBook<OrderStatus, OrderSide>> {
+        self.book
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+fn open_order_state(order: &OrderResponse) -> Option<OrderState<OrderStatus, OrderSide>> {
+    Some(OrderState {
+        symbol: order.symbol.clone(),
+        order_id: order.order_id,
+        client_order_id: order.client_order_id.clone(),
+        side: parse(&order.side)?,
+        price: order.price,
+        quantity: order.orig_qty,
+        executed: order.executed_qty,
+        cumulative_quote: order.cummulative_quote_qty,
+        status: parse(&order.status)?,
+        time: order.update_time,
+    })
+}
+
+fn order_id_state(order: &OrderIdResponse) -> Option<OrderState<OrderStatus, OrderSide>> {
+    Some(OrderState {
+        symbol: order.symbol.clone(),
+        order_id: order.order_id,
+        client_order_id: order.client_order_id.clone(),
+        side: parse(&order.side)?,
+        price: order.price,
+        quantity: order.orig_qty,
+        executed: order.executed_qty,
+        cumulative_quote: order.cummulative_quote_qty,
+        status: parse(&order.status)?,
+        time: order.update_time,
+    })
+}
+
+fn report_state(
+    report: &ExecutionReportEvent,
+) -> Option<(OrderState<OrderStatus, OrderSide>, Option<TrackedFill>)> {
+    let state = OrderState {
+        symbol: report.symbol.clone(),
+        order_id: report.order_id,
+        client_order_id: report.client_order_id.clone(),
+        side: parse(&report.side)?,
+        price: report.price,
+        quantity: report.quantity,
+        executed: report.cumulative_quantity,
+        cumulative_quote: report.cumulative_quote_quantity,
+        status: parse(&report.status)?,
+        time: report.transaction_time,
+    };
+
+    let fill = (report.last_quantity > 0.0).then(|| TrackedFill {
+        trade_id: u64::try_from(report.trade_id).ok(),
+        price: report.last_price,
+        quantity: report.last_quantity,
+        commission: report.commission,
+        commission_asset: report.commission_asset.clone(),
+        maker: report.is_maker,
+        time: report.transaction_time,
+    });
+
+    Some((state, fill))
+}
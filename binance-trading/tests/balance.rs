@@ -0,0 +1,121 @@
+#[cfg(test)]
+mod balance_integration_tests {
+    use binance_common::spot::model::response::{account::InfoResponse, stream::UserDataEvent};
+    use binance_trading::balance::SpotBalanceBook;
+
+    fn info(update_time: u64, balances: &[(&str, f64, f64)]) -> InfoResponse {
+        let balances: Vec<String> = balances
+            .iter()
+            .map(|(asset, free, locked)| {
+                format!(r#"{{"asset":"{asset}","free":"{free}","locked":"{locked}"}}"#)
+            })
+            .collect();
+
+        serde_json::from_str(&format!(
+            r#"{{"makerCommission":10,"takerCommission":10,"buyerCommission":0,"sellerCommission":0,"commissionRates":{{"maker":"0.001","taker":"0.001","buyer":"0","seller":"0"}},"canTrade":true,"canWithdraw":true,"canDeposit":true,"brokered":false,"requireSelfTradePrevention":false,"preventSor":false,"updateTime":{update_time},"accountType":"SPOT","balances":[{}],"permissions":["SPOT"],"uid":1}}"#,
+            balances.join(",")
+        ))
+        .unwrap()
+    }
+
+    fn account_position(time: u64, balances: &[(&str, f64, f64)]) -> UserDataEvent {
+        let balances: Vec<String> = balances
+            .iter()
+            .map(|(asset, free, locked)| {
+                format!(r#"{{"a":"{asset}","f":"{free}","l":"{locked}"}}"#)
+            })
+            .collect();
+
+        serde_json::from_str(&format!(
+            r#"{{"e":"outboundAccountPosition","E":{time},"u":{time},"B":[{}]}}"#,
+            balances.join(",")
+        ))
+        .unwrap()
+    }
+
+    fn balance_update(time: u64, asset: &str, delta: f64) -> UserDataEvent {
+        cleared_update(time, time, asset, delta)
+    }
+
+    fn cleared_update(event_time: u64, clear_time: u64, asset: &str, delta: f64) -> UserDataEvent {
+        serde_json::from_str(&format!(
+            r#"{{"e":"balanceUpdate","E":{event_time},"a":"{asset}","d":"{delta}","T":{clear_time}}}"#
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_events_move_the_snapshot() {
+        let book = SpotBalanceBook::new();
+        book.reconcile(&info(1_000, &[("BTC", 1.0, 0.0), ("USDT", 500.0, 0.0)]));
+
+        // A buy order locks quote, then a deposit lands.
+        assert!(book.apply(&account_position(1_100, &[("USDT", 400.0, 100.0)])));
+        assert!(book.apply(&balance_update(1_200, "USDT", 50.0)));
+
+        let usdt = book.balance("USDT").unwrap();
+
+        assert_eq!((usdt.free, usdt.locked), (450.0, 100.0));
+        assert_eq!(usdt.total(), 550.0);
+        assert_eq!(usdt.update_time, 1_200);
+        assert_eq!(book.free("BTC"), 1.0);
+
+        // New assets show up, emptied ones drop out of the listing.
+        book.apply(&account_position(
+            1_300,
+            &[("BTC", 0.0, 0.0), ("ETH", 2.0, 0.0)],
+        ));
+
+        let assets: Vec<String> = book
+            .balances()
+            .into_iter()
+            .map(|balance| balance.asset)
+            .collect();
+
+        assert_eq!(assets, vec!["ETH", "USDT"]);
+        assert_eq!(book.free("XRP"), 0.0);
+    }
+
+    #[test]
+    fn test_events_older_than_the_snapshot_are_dropped() {
+        let book = SpotBalanceBook::new();
+        book.reconcile(&info(2_000, &[("USDT", 500.0, 0.0)]));
+
+        // Both were already part of the snapshot.
+        assert!(!book.apply(&balance_update(1_900, "USDT", 100.0)));
+        assert!(!book.apply(&account_position(2_000, &[("USDT", 1.0, 0.0)])));
+
+        assert_eq!(book.free("USDT"), 500.0);
+
+        // A position arriving before the delta it already includes.
+        book.apply(&account_position(2_200, &[("USDT", 600.0, 0.0)]));
+
+        assert!(!book.apply(&balance_update(2_100, "USDT", 100.0)));
+        assert_eq!(book.free("USDT"), 600.0);
+
+        book.reconcile(&info(3_000, &[("USDT", 650.0, 0.0)]));
+
+        assert_eq!(book.free("USDT"), 650.0);
+        assert_eq!(book.update_time(), 3_000);
+    }
+
+    #[test]
+    fn test_deltas_are_told_apart_by_the_event_not_the_clear_time() {
+        let book = SpotBalanceBook::new();
+        book.reconcile(&info(1_000, &[("USDT", 500.0, 0.0)]));
+
+        // Two transfers cleared in the same ms, the first delivered twice.
+        assert!(book.apply(&cleared_update(1_201, 1_200, "USDT", 50.0)));
+        assert!(book.apply(&cleared_update(1_202, 1_200, "USDT", 25.0)));
+        assert!(!book.apply(&cleared_update(1_201, 1_200, "USDT", 50.0)));
+
+        // One cleared earlier but delivered late.
+        assert!(book.apply(&cleared_update(1_203, 1_100, "USDT", 10.0)));
+        assert_eq!(book.free("USDT"), 585.0);
+
+        // The position includes the deltas up to its time and keeps the later one.
+        assert!(book.apply(&cleared_update(1_301, 1_300, "USDT", 5.0)));
+        assert!(book.apply(&account_position(1_200, &[("USDT", 585.0, 0.0)])));
+        assert_eq!(book.free("USDT"), 590.0);
+    }
+}
@@ -0,0 +1,381 @@
+#[cfg(test)]
+mod bracket_integration_tests {
+    use binance_common::{
+        enums::futures::{OrderSide, OrderStatus},
+        error::BinanceError,
+        futures::model::{
+            params::trade::{
+                AutoCancelAllOpenOrdersParams, CancelAllOrdersParams, CancelOrderParams,
+                GetOrderParams, NewOrderParams, OpenOrdersParams, PositionRiskV3Params,
+            },
+            response::{
+                account::FuturesBalanceResponse,
+                stream::UserDataEvent,
+                trade::{
+                    AutoCancelAllOpenOrdersResponse, CancelAllOrdersResponse, GetOrderResponse,
+                    OrderResponse, PositionRiskV3Response,
+                },
+            },
+        },
+    };
+    use binance_core::trading::futures::FuturesTradingApi;
+    use binance_trading::{
+        bracket::{Bracket, BracketManager, BracketStatus, Leg, StopLoss},
+        paper::{PaperConfig, futures::FuturesPaperExchange},
+    };
+
+    fn order_trade_update(
+        client_order_id: &str,
+        order_id: u64,
+        status: &str,
+        executed: f64,
+        price: f64,
+        time: u64,
+    ) -> UserDataEvent {
+        serde_json::from_str(&format!(
+            r#"{{"e":"ORDER_TRADE_UPDATE","E":{time},"T":{time},"o":{{"s":"BTCUSDT","c":"{client_order_id}","S":"SELL","o":"MARKET","f":"GTC","q":"0.01","p":"0","ap":"{price}","sp":"0","x":"TRADE","X":"{status}","i":{order_id},"l":"{executed}","z":"{executed}","L":"{price}","T":{time},"t":{time},"b":"0","a":"0","m":false,"R":true,"wt":"CONTRACT_PRICE","ot":"TAKE_PROFIT_MARKET","ps":"BOTH","cp":false,"rp":"10","pP":false,"si":0,"ss":0,"V":"NONE","pm":"NONE","gtd":0}}}}"#
+        ))
+        .unwrap()
+    }
+
+    async fn long_exchange() -> FuturesPaperExchange {
+        let paper = FuturesPaperExchange::new(PaperConfig::new()).balance("USDT", 1_000.0);
+        paper.trade("BTCUSDT", 50_000.0, 1.0, 1_000);
+        paper
+            .send_new_order(&NewOrderParams::market("BTCUSDT", OrderSide::Buy, 0.01))
+            .await
+            .unwrap();
+        paper
+    }
+
+    /// Streams a take-profit fill before the stop-loss is accepted.
+    struct RacingExchange {
+        paper: FuturesPaperExchange,
+        manager: BracketManager,
+    }
+
+    impl FuturesTradingApi for RacingExchange {
+        async fn send_new_order(
+            &self,
+            params: &NewOrderParams<'_>,
+        ) -> Result<OrderResponse, BinanceError> {
+            if params
+                .new_client_order_id
+                .is_some_and(|id| id.ends_with("-sl"))
+            {
+                let take_profit = self.manager.brackets(None)[0].take_profit.clone();
+                self.manager.apply(&order_trade_update(
+                    &take_profit.client_order_id,
+                    take_profit.order_id.unwrap(),
+                    "FILLED",
+                    0.01,
+                    51_000.0,
+                    2_000,
+                ));
+            }
+
+            self.paper.send_new_order(params).await
+        }
+
+        async fn send_cancel_order(
+            &self,
+            params: &CancelOrderParams<'_>,
+        ) -> Result<OrderResponse, BinanceError> {
+            self.paper.send_cancel_order(params).await
+        }
+
+        async fn send_cancel_all_orders(
+            &self,
+            params: &CancelAllOrdersParams<'_>,
+        ) -> Result<CancelAllOrdersResponse, BinanceError> {
+            self.paper.send_cancel_all_orders(params).await
+        }
+
+        async fn send_auto_cancel_all_open_orders(
+            &self,
+            params: &AutoCancelAllOpenOrdersParams<'_>,
+        ) -> Result<AutoCancelAllOpenOrdersResponse, BinanceError> {
+            self.paper.send_auto_cancel_all_open_orders(params).await
+        }
+
+        async fn get_order(
+            &self,
+            params: &GetOrderParams<'_>,
+        ) -> Result<GetOrderResponse, BinanceError> {
+            self.paper.get_order(params).await
+        }
+
+        async fn get_all_open_orders(
+            &self,
+            params: &OpenOrdersParams<'_>,
+        ) -> Result<Vec<GetOrderResponse>, BinanceError> {
+            self.paper.get_all_open_orders(params).await
+        }
+
+        async fn get_balances(&self) -> Result<Vec<FuturesBalanceResponse>, BinanceError> {
+            self.paper.get_balances().await
+        }
+
+        async fn get_position_risk_v3(
+            &self,
+            params: &PositionRiskV3Params<'_>,
+        ) -> Result<Vec<PositionRiskV3Response>, BinanceError> {
+            self.paper.get_position_risk_v3(params).await
+        }
+    }
+
+    fn exit() -> Bracket {
+        Bracket::new(
+            "BTCUSDT",
+            OrderSide::Sell,
+            0.01,
+            51_000.0,
+            StopLoss::Fixed(49_000.0),
+        )
+    }
+
+    async fn open_orders(paper: &FuturesPaperExchange) -> usize {
+        paper
+            .get_all_open_orders(&OpenOrdersParams::new("BTCUSDT"))
+            .await
+            .unwrap()
+            .len()
+    }
+
+    #[tokio::test]
+    async fn test_take_profit_fill_cancels_the_stop_loss() {
+        let paper = long_exchange().await;
+        let manager = BracketManager::new("bot");
+
+        let placed = manager.place(&paper, &exit()).await.unwrap();
+
+        assert_eq!(placed.status, BracketStatus::Active);
+        assert_eq!(open_orders(&paper).await, 2);
+        assert!(placed.take_profit.client_order_id.starts_with("bot-"));
+        assert!(placed.stop_loss.client_order_id.ends_with("-sl"));
+
+        paper.trade("BTCUSDT", 51_000.0, 1.0, 2_000);
+
+        let event = order_trade_update(
+            &placed.take_profit.client_order_id,
+            placed.take_profit.order_id.unwrap(),
+            "FILLED",
+            0.01,
+            51_000.0,
+            2_000,
+        );
+        let bracket = manager.handle(&paper, &event).await.unwrap().unwrap();
+
+        assert_eq!(bracket.status, BracketStatus::Closed(Leg::TakeProfit));
+        assert_eq!(bracket.stop_loss.status, OrderStatus::Canceled);
+        assert_eq!(bracket.take_profit.average_price, 51_000.0);
+        assert_eq!(open_orders(&paper).await, 0);
+        assert_eq!(paper.position("BTCUSDT").amount, 0.0);
+
+        // The sibling's own cancel event changes nothing.
+        let late = order_trade_update(
+            &placed.stop_loss.client_order_id,
+            placed.stop_loss.order_id.unwrap(),
+            "CANCELED",
+            0.0,
+            0.0,
+            2_001,
+        );
+
+        assert_eq!(
+            manager.apply(&late).unwrap().status,
+            BracketStatus::Closed(Leg::TakeProfit)
+        );
+        assert_eq!(manager.drain_closed().len(), 1);
+        assert!(manager.brackets(None).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_manual_cancel_of_a_leg_takes_down_the_other() {
+        let paper = long_exchange().await;
+        let manager = BracketManager::new("bot");
+        let placed = manager.place(&paper, &exit()).await.unwrap();
+
+        paper
+            .send_cancel_order(
+                &CancelOrderParams::new("BTCUSDT")
+                    .orig_client_order_id(&placed.stop_loss.client_order_id),
+            )
+            .await
+            .unwrap();
+
+        let event = order_trade_update(
+            &placed.stop_loss.client_order_id,
+            placed.stop_loss.order_id.unwrap(),
+            "CANCELED",
+            0.0,
+            0.0,
+            2_000,
+        );
+        let bracket = manager.handle(&paper, &event).await.unwrap().unwrap();
+
+        assert_eq!(bracket.status, BracketStatus::Canceled);
+        assert_eq!(open_orders(&paper).await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_filled_leg_wins_over_an_expired_sibling() {
+        let paper = long_exchange().await;
+        let manager = BracketManager::new("bot");
+        let placed = manager.place(&paper, &exit()).await.unwrap();
+
+        let expired = order_trade_update(
+            &placed.take_profit.client_order_id,
+            placed.take_profit.order_id.unwrap(),
+            "EXPIRED",
+            0.0,
+            0.0,
+            2_000,
+        );
+        assert_eq!(
+            manager.apply(&expired).unwrap().status,
+            BracketStatus::Closing(Leg::TakeProfit)
+        );
+
+        let filled = order_trade_update(
+            &placed.stop_loss.client_order_id,
+            placed.stop_loss.order_id.unwrap(),
+            "FILLED",
+            0.01,
+            49_000.0,
+            2_001,
+        );
+        assert_eq!(
+            manager.apply(&filled).unwrap().status,
+            BracketStatus::Closed(Leg::StopLoss)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_partial_fill_keeps_the_bracket_active() {
+        let paper = long_exchange().await;
+        let manager = BracketManager::new("bot");
+        let placed = manager.place(&paper, &exit()).await.unwrap();
+
+        let partial = order_trade_update(
+            &placed.take_profit.client_order_id,
+            placed.take_profit.order_id.unwrap(),
+            "PARTIALLY_FILLED",
+            0.004,
+            51_000.0,
+            2_000,
+        );
+        let bracket = manager.handle(&paper, &partial).await.unwrap().unwrap();
+
+        assert_eq!(bracket.status, BracketStatus::Active);
+        assert_eq!(open_orders(&paper).await, 2);
+        assert!(manager.drain_closed().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_restart_cancels_the_leg_left_behind() {
+        let paper = long_exchange().await;
+        let placed = BracketManager::new("bot")
+            .place(&paper, &exit())
+            .await
+            .unwrap();
+
+        // The stop-loss triggers while the process is down.
+        paper.trade("BTCUSDT", 49_000.0, 1.0, 2_000);
+
+        assert_eq!(open_orders(&paper).await, 1);
+
+        let manager = BracketManager::new("bot");
+        let recovered = manager
+            .recover(&paper, &OpenOrdersParams::new("BTCUSDT"))
+            .await
+            .unwrap();
+        let bracket = manager.bracket(placed.id).unwrap();
+
+        assert_eq!(recovered, 1);
+        assert_eq!(bracket.status, BracketStatus::Closed(Leg::StopLoss));
+        assert!(bracket.stop_loss.executed > 0.0);
+        assert_eq!(open_orders(&paper).await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_restart_resumes_working_brackets_and_ignores_foreign_orders() {
+        let paper = long_exchange().await;
+        let placed = BracketManager::new("bot")
+            .place(&paper, &exit())
+            .await
+            .unwrap();
+
+        paper
+            .send_new_order(
+                &NewOrderParams::limit("BTCUSDT", OrderSide::Buy, 45_000.0, 0.01)
+                    .new_client_order_id("manual-1"),
+            )
+            .await
+            .unwrap();
+
+        let manager = BracketManager::new("bot");
+
+        assert_eq!(
+            manager
+                .recover(&paper, &OpenOrdersParams::all())
+                .await
+                .unwrap(),
+            1
+        );
+        assert_eq!(manager.brackets(Some("BTCUSDT")).len(), 1);
+        assert_eq!(
+            manager.bracket(placed.id).unwrap().status,
+            BracketStatus::Active
+        );
+        assert_eq!(open_orders(&paper).await, 3);
+
+        let next = manager.place(&paper, &exit()).await.unwrap();
+
+        assert!(next.id > placed.id);
+    }
+
+    #[tokio::test]
+    async fn test_rejected_stop_loss_withdraws_the_take_profit() {
+        let paper = long_exchange().await;
+        let manager = BracketManager::new("bot");
+
+        // The paper exchange does not simulate trailing stops.
+        let trailing = Bracket {
+            stop_loss: StopLoss::Trailing {
+                callback_rate: 1.0,
+                activation_price: None,
+            },
+            ..exit()
+        };
+
+        assert!(manager.place(&paper, &trailing).await.is_err());
+        assert_eq!(open_orders(&paper).await, 0);
+        assert!(manager.brackets(None).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_fill_before_the_stop_loss_is_accepted_cancels_it() {
+        let manager = BracketManager::new("bot");
+        let racing = RacingExchange {
+            paper: long_exchange().await,
+            manager: manager.clone(),
+        };
+
+        let placed = manager.place(&racing, &exit()).await.unwrap();
+
+        assert_eq!(placed.status, BracketStatus::Closed(Leg::TakeProfit));
+        assert_eq!(placed.stop_loss.status, OrderStatus::Canceled);
+
+        let stop_loss = racing
+            .paper
+            .get_order(
+                &GetOrderParams::new("BTCUSDT")
+                    .orig_client_order_id(&placed.stop_loss.client_order_id),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(stop_loss.status, OrderStatus::Canceled);
+    }
+}
@@ -0,0 +1,335 @@
+#[cfg(test)]
+mod execution_integration_tests {
+    use std::{sync::Mutex, time::Duration};
+
+    use binance_common::{
+        error::BinanceError,
+        futures::model::{
+            params::trade::OpenOrdersParams as FuturesOpenOrdersParams,
+            response::websocket::{AggTradeResponse, WebSocketResponse},
+        },
+        spot::model::{
+            params::{general::ExchangeInformationParams, trade::OpenOrdersParams},
+            response::market::KlinesResponse,
+        },
+    };
+    use binance_core::{
+        client::asynchronous::Client,
+        signer::hmacsha256::HmacSha256,
+        trading::{futures::FuturesTradingApi, spot::SpotTradingApi},
+    };
+    use binance_mock::server::MockServer;
+    use binance_spot::asynchronous::general::GeneralApi;
+    use binance_trading::{
+        execution::{
+            ChildOrder, ChildPricing, ChildState, Execution, ExecutionStatus, ExecutionVenue,
+            ParentOrder, Side, SymbolRules, futures::FuturesVenue, profile::VolumeProfile,
+            spot::SpotVenue,
+        },
+        paper::{PaperConfig, futures::FuturesPaperExchange, spot::SpotPaperExchange},
+    };
+
+    fn agg_trade(symbol: &str, price: f64, quantity: f64, time: u64) -> WebSocketResponse {
+        WebSocketResponse::AggTrade(AggTradeResponse {
+            event_time: time,
+            symbol: symbol.to_string(),
+            aggregate_trade_id: time,
+            price,
+            quantity,
+            first_trade_id: time,
+            last_trade_id: time,
+            trade_time: time,
+            is_buyer_market_maker: false,
+        })
+    }
+
+    fn kline(open_time: u64, volume: f64) -> KlinesResponse {
+        serde_json::from_str(&format!(
+            r#"[{open_time},"100","100","100","100","{volume}",{},"0",1,"0","0","0"]"#,
+            open_time + 59_999
+        ))
+        .unwrap()
+    }
+
+    /// Fills every child in full at 100 and keeps it for inspection.
+    #[derive(Default)]
+    struct FillingVenue {
+        children: Mutex<Vec<ChildOrder>>,
+    }
+
+    impl ExecutionVenue for FillingVenue {
+        async fn place(&self, child: &ChildOrder) -> Result<u64, BinanceError> {
+            let mut children = self.children.lock().unwrap();
+            children.push(child.clone());
+            Ok(children.len() as u64)
+        }
+
+        async fn status(&self, _: &str, order_id: u64) -> Result<ChildState, BinanceError> {
+            let quantity = self.children.lock().unwrap()[order_id as usize - 1].quantity;
+
+            Ok(ChildState {
+                executed: quantity,
+                cumulative_quote: quantity * 100.0,
+                open: false,
+            })
+        }
+
+        async fn cancel(&self, _: &str, _: u64) -> Result<(), BinanceError> {
+            Ok(())
+        }
+    }
+
+    fn spot_exchange() -> SpotPaperExchange {
+        let paper = SpotPaperExchange::new(PaperConfig::new()).balance("USDT", 10_000.0);
+        paper.trade("BTCUSDT", 100.0, 1.0, 1_000);
+        paper
+    }
+
+    #[tokio::test]
+    async fn test_twap_fills_in_equal_slices() {
+        let paper =
+            SpotPaperExchange::new(PaperConfig::new().taker_fee(0.0)).balance("USDT", 10_000.0);
+        paper.trade("BTCUSDT", 100.0, 1.0, 1_000);
+
+        let execution = Execution::twap(
+            ParentOrder::new("BTCUSDT", Side::Buy, 1.0),
+            Duration::from_millis(40),
+            4,
+        )
+        .unwrap()
+        .client_order_prefix("twap");
+
+        let progress = execution.run(&SpotVenue::new(&paper)).await.unwrap();
+
+        assert_eq!(progress.status, ExecutionStatus::Filled);
+        assert_eq!(progress.children, 4);
+        assert_eq!(progress.slice, 4);
+        assert!((progress.executed - 1.0).abs() < 1e-9);
+        assert!((progress.average_price() - 100.0).abs() < 1e-9);
+
+        let balances = paper.get_balances().await.unwrap();
+        let btc = balances.iter().find(|balance| balance.asset == "BTC");
+
+        assert!(btc.is_some_and(|balance| (balance.free - 1.0).abs() < 1e-6));
+    }
+
+    #[tokio::test]
+    async fn test_vwap_slices_follow_the_volume_profile() {
+        // Two minutes of history, the second trading three times the first.
+        let profile = VolumeProfile::from_klines(&[
+            kline(0, 1.0),
+            kline(60_000, 3.0),
+            kline(86_400_000, 1.0),
+        ]);
+        let weights = profile.weights(0, Duration::from_secs(120), 2);
+
+        assert_eq!(weights, vec![1.0, 3.0]);
+        assert_eq!(profile.volume_at(86_460_000), Some(3.0));
+
+        let paper = FuturesPaperExchange::new(PaperConfig::new()).balance("USDT", 10_000.0);
+        paper.trade("BTCUSDT", 50_000.0, 1.0, 1_000);
+
+        let execution = Execution::vwap(
+            ParentOrder::new("BTCUSDT", Side::Buy, 0.04).limit_price(50_100.0),
+            Duration::from_millis(20),
+            weights,
+        )
+        .unwrap();
+        let mut watcher = execution.progress();
+
+        let venue = FuturesVenue::new(&paper);
+        let (progress, first) = tokio::join!(execution.run(&venue), async {
+            watcher
+                .wait_for(|progress| progress.slice == 1)
+                .await
+                .unwrap();
+            watcher.borrow().executed
+        });
+        let progress = progress.unwrap();
+
+        assert!((first - 0.01).abs() < 1e-9);
+        assert_eq!(progress.status, ExecutionStatus::Filled);
+        assert!((progress.executed - 0.04).abs() < 1e-9);
+
+        let orders = paper
+            .get_all_open_orders(&FuturesOpenOrdersParams::new("BTCUSDT"))
+            .await
+            .unwrap();
+
+        assert!(orders.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_iceberg_refills_as_the_market_trades_through() {
+        let paper = spot_exchange();
+        paper.trade("BTCUSDT", 101.0, 1.0, 2_000);
+
+        let execution = Execution::iceberg(
+            ParentOrder::new("BTCUSDT", Side::Buy, 1.0).limit_price(100.0),
+            0.4,
+        )
+        .unwrap()
+        .poll_interval(Duration::from_millis(5));
+        let mut watcher = execution.progress();
+
+        let venue = SpotVenue::new(&paper);
+        let (progress, _) = tokio::join!(execution.run(&venue), async {
+            for time in 3_000.. {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+
+                if watcher.borrow_and_update().status != ExecutionStatus::Running {
+                    break;
+                }
+
+                paper.trade("BTCUSDT", 99.0, 0.4, time);
+            }
+        });
+        let progress = progress.unwrap();
+
+        assert_eq!(progress.status, ExecutionStatus::Filled);
+        assert_eq!(progress.children, 3);
+        assert!((progress.executed - 1.0).abs() < 1e-9);
+        // Children arrive with the next print and never pay above the limit.
+        assert!(progress.average_price() <= 100.0);
+    }
+
+    #[tokio::test]
+    async fn test_iceberg_requires_a_limit_price() {
+        let paper = spot_exchange();
+        let execution =
+            Execution::iceberg(ParentOrder::new("BTCUSDT", Side::Buy, 1.0), 0.4).unwrap();
+
+        assert!(execution.run(&SpotVenue::new(&paper)).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_participation_caps_the_executed_quantity() {
+        let paper = spot_exchange();
+        let execution = Execution::twap(
+            ParentOrder::new("BTCUSDT", Side::Buy, 1.0),
+            Duration::from_millis(20),
+            2,
+        )
+        .unwrap()
+        .participation(0.1);
+
+        execution.apply_market(&agg_trade("BTCUSDT", 100.0, 2.0, 2_000));
+        execution.apply_market(&agg_trade("ETHUSDT", 3_000.0, 50.0, 2_000));
+
+        let progress = execution.run(&SpotVenue::new(&paper)).await.unwrap();
+
+        assert_eq!(progress.status, ExecutionStatus::Finished);
+        assert!((progress.executed - 0.2).abs() < 1e-9);
+        assert!((progress.remaining() - 0.8).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_cancel_pulls_the_resting_child() {
+        let paper = spot_exchange();
+        let execution = Execution::iceberg(
+            ParentOrder::new("BTCUSDT", Side::Buy, 1.0).limit_price(90.0),
+            0.5,
+        )
+        .unwrap()
+        .poll_interval(Duration::from_millis(5));
+
+        let venue = SpotVenue::new(&paper);
+        let (progress, _) = tokio::join!(execution.run(&venue), async {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            execution.cancel();
+        });
+        let progress = progress.unwrap();
+
+        assert_eq!(progress.status, ExecutionStatus::Canceled);
+        assert_eq!(progress.executed, 0.0);
+
+        let orders = paper
+            .get_open_orders(&OpenOrdersParams::new("BTCUSDT"))
+            .await
+            .unwrap();
+
+        assert!(orders.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_children_follow_the_exchange_filters() {
+        let server = MockServer::start().unwrap();
+        let general_api = GeneralApi::new(Client::new(&server, HmacSha256::new("", "")));
+        let exchange_info = general_api
+            .get_exchange_info(&ExchangeInformationParams::new())
+            .await
+            .unwrap();
+        let rules = SymbolRules::spot(&exchange_info, "BTCUSDC").unwrap();
+
+        assert_eq!(rules.step_size, 0.00001);
+        assert_eq!(rules.tick_size, 0.01);
+        assert_eq!(rules.price(100.019, Side::Sell), 100.02);
+
+        let execution = Execution::twap(
+            ParentOrder::new("BTCUSDC", Side::Buy, 1.000004).limit_price(100.019),
+            Duration::from_millis(20),
+            3,
+        )
+        .unwrap()
+        .rules(rules);
+
+        let venue = FillingVenue::default();
+        let progress = execution.run(&venue).await.unwrap();
+        let children = venue.children.into_inner().unwrap();
+        let quantities: Vec<f64> = children.iter().map(|child| child.quantity).collect();
+
+        assert_eq!(progress.status, ExecutionStatus::Filled);
+        assert_eq!(progress.quantity, 1.0);
+        assert_eq!(quantities, vec![0.33333, 0.33333, 0.33334]);
+        assert!(
+            children
+                .iter()
+                .all(|child| child.pricing == ChildPricing::ImmediateOrCancel(100.01))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_iceberg_rejects_an_empty_display() {
+        let parent = || ParentOrder::new("BTCUSDT", Side::Buy, 1.0).limit_price(100.0);
+
+        assert!(Execution::iceberg(parent(), 0.0).is_err());
+        assert!(Execution::iceberg(parent(), -0.4).is_err());
+        assert!(Execution::iceberg(parent(), f64::NAN).is_err());
+
+        // A display below the step size is empty once floored.
+        let execution = Execution::iceberg(parent(), 0.0004)
+            .unwrap()
+            .rules(SymbolRules {
+                step_size: 0.001,
+                min_qty: 0.001,
+                tick_size: 0.01,
+            });
+
+        assert!(execution.run(&FillingVenue::default()).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_default_client_order_ids_differ_between_executions() {
+        let mut prefixes = Vec::new();
+
+        for _ in 0..2 {
+            let execution = Execution::twap(
+                ParentOrder::new("BTCUSDT", Side::Buy, 1.0),
+                Duration::ZERO,
+                1,
+            )
+            .unwrap();
+            let venue = FillingVenue::default();
+
+            execution.run(&venue).await.unwrap();
+            prefixes.push(
+                venue.children.into_inner().unwrap()[0]
+                    .client_order_id
+                    .clone(),
+            );
+        }
+
+        assert_ne!(prefixes[0], prefixes[1]);
+        assert!(prefixes.iter().all(|id| id.len() <= 36));
+    }
+}
@@ -0,0 +1,286 @@
+#[cfg(test)]
+mod paper_integration_tests {
+    use std::time::Duration;
+
+    use binance_common::{
+        enums::{
+            futures::{self, OrderStatus},
+            spot::{OrderResponseType, OrderSide, OrderType},
+        },
+        error::{ApiError, BinanceError},
+        futures::model::{
+            params::trade::{
+                GetOrderParams as FuturesGetOrderParams, NewOrderParams as FuturesNewOrderParams,
+                OpenOrdersParams as FuturesOpenOrdersParams,
+            },
+            response::websocket::{AggTradeResponse, WebSocketResponse},
+        },
+        spot::model::{
+            params::trade::{CancelOrderParams, GetOrderParams, NewOrderParams, OpenOrdersParams},
+            response::{account::AssetResponse, trade::NewOrderResponse},
+        },
+    };
+    use binance_core::{
+        client::asynchronous::Client,
+        signer::hmacsha256::HmacSha256,
+        trading::{futures::FuturesTradingApi, spot::SpotTradingApi},
+    };
+    use binance_mock::{credentials::Credentials, server::MockServer};
+    use binance_spot::asynchronous::trade::TradeApi;
+    use binance_trading::paper::{
+        PaperConfig, futures::FuturesPaperExchange, spot::SpotPaperExchange,
+    };
+
+    fn agg_trade(symbol: &str, price: f64, quantity: f64, time: u64) -> WebSocketResponse {
+        WebSocketResponse::AggTrade(AggTradeResponse {
+            event_time: time,
+            symbol: symbol.to_string(),
+            aggregate_trade_id: time,
+            price,
+            quantity,
+            first_trade_id: time,
+            last_trade_id: time,
+            trade_time: time,
+            is_buyer_market_maker: false,
+        })
+    }
+
+    fn free(balances: &[AssetResponse], asset: &str) -> f64 {
+        balances
+            .iter()
+            .find(|balance| balance.asset == asset)
+            .map(|balance| balance.free)
+            .unwrap_or_default()
+    }
+
+    /// Places a resting bid, checks it is listed and pulls it again.
+    async fn quote_and_pull<T: SpotTradingApi>(api: &T, symbol: &str) -> Result<u64, BinanceError> {
+        let order = api
+            .send_new_order(
+                &NewOrderParams::limit(symbol, OrderSide::Buy, 100.0, 0.5)
+                    .new_order_resp_type(OrderResponseType::Result),
+            )
+            .await?;
+
+        let NewOrderResponse::Result(order) = order else {
+            return Err(BinanceError::Unknown(
+                "Expected a RESULT response".to_string(),
+            ));
+        };
+
+        assert!(
+            !api.get_open_orders(&OpenOrdersParams::new(symbol))
+                .await?
+                .is_empty()
+        );
+
+        api.send_cancel_order(&CancelOrderParams::new(symbol).order_id(order.order_id))
+            .await?;
+
+        Ok(order.order_id)
+    }
+
+    #[tokio::test]
+    async fn test_same_strategy_on_live_and_paper() {
+        let server = MockServer::builder()
+            .credentials("api_key", Credentials::hmac_sha256("secret_key"))
+            .start()
+            .unwrap();
+        let live = TradeApi::new(Client::new(
+            &server,
+            HmacSha256::new("api_key", "secret_key"),
+        ));
+
+        assert!(quote_and_pull(&live, "BTCUSDC").await.is_ok());
+
+        let paper = SpotPaperExchange::new(PaperConfig::new()).balance("USDC", 1000.0);
+        let order_id = quote_and_pull(&paper, "BTCUSDC").await.unwrap();
+
+        let order = paper
+            .get_order(&GetOrderParams::new("BTCUSDC").order_id(order_id))
+            .await
+            .unwrap();
+
+        assert_eq!(order.status, "CANCELED");
+        assert_eq!(free(&paper.get_balances().await.unwrap(), "USDC"), 1000.0);
+    }
+
+    #[tokio::test]
+    async fn test_spot_limit_fills_as_maker() {
+        let paper = SpotPaperExchange::new(PaperConfig::new().maker_fee(0.001).taker_fee(0.002))
+            .balance("USDT", 1000.0);
+
+        paper.replay([agg_trade("ETHUSDT", 105.0, 1.0, 1_000)]);
+
+        paper
+            .send_new_order(&NewOrderParams::limit(
+                "ETHUSDT",
+                OrderSide::Buy,
+                100.0,
+                2.0,
+            ))
+            .await
+            .unwrap();
+
+        let balances = paper.get_balances().await.unwrap();
+        assert_eq!(free(&balances, "USDT"), 800.0);
+
+        paper.replay([
+            agg_trade("ETHUSDT", 101.0, 3.0, 2_000),
+            agg_trade("ETHUSDT", 99.5, 1.5, 3_000),
+            agg_trade("ETHUSDT", 99.0, 1.0, 4_000),
+        ]);
+
+        let order = paper
+            .get_order(&GetOrderParams::new("ETHUSDT").order_id(1))
+            .await
+            .unwrap();
+
+        assert_eq!(order.status, "FILLED");
+        assert_eq!(order.executed_qty, 2.0);
+        assert_eq!(order.cummulative_quote_qty, 200.0);
+
+        let balances = paper.get_balances().await.unwrap();
+        assert_eq!(free(&balances, "USDT"), 800.0);
+        assert!((free(&balances, "ETH") - 1.998).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_spot_market_order_waits_for_latency() {
+        let paper = SpotPaperExchange::new(
+            PaperConfig::new()
+                .taker_fee(0.0)
+                .latency(Duration::from_millis(200)),
+        )
+        .balance("BTC", 1.0);
+
+        paper.trade("BTCUSDT", 60_000.0, 0.1, 10_000);
+
+        let order = paper
+            .send_new_order(
+                &NewOrderParams::new("BTCUSDT", OrderSide::Sell, OrderType::Market)
+                    .quantity(0.5)
+                    .new_order_resp_type(OrderResponseType::Full),
+            )
+            .await
+            .unwrap();
+
+        let NewOrderResponse::Full(order) = order else {
+            panic!("Expected a FULL response");
+        };
+
+        assert_eq!(order.status, "NEW");
+        assert!(order.fills.is_empty());
+
+        paper.trade("BTCUSDT", 59_000.0, 0.1, 10_100);
+        paper.trade("BTCUSDT", 58_000.0, 0.1, 10_200);
+
+        let order = paper
+            .get_order(&GetOrderParams::new("BTCUSDT").order_id(order.order_id))
+            .await
+            .unwrap();
+
+        assert_eq!(order.status, "FILLED");
+        assert_eq!(order.cummulative_quote_qty, 29_000.0);
+        assert_eq!(free(&paper.get_balances().await.unwrap(), "USDT"), 29_000.0);
+    }
+
+    #[tokio::test]
+    async fn test_spot_insufficient_balance() {
+        let paper = SpotPaperExchange::new(PaperConfig::new()).balance("USDT", 10.0);
+
+        assert!(matches!(
+            paper
+                .send_new_order(&NewOrderParams::limit(
+                    "ETHUSDT",
+                    OrderSide::Buy,
+                    100.0,
+                    1.0
+                ))
+                .await,
+            Err(BinanceError::Api(ApiError { code: -2010, .. }))
+        ));
+        assert!(matches!(
+            paper
+                .send_new_order(&NewOrderParams::limit(
+                    "NOTASYMBOL",
+                    OrderSide::Buy,
+                    1.0,
+                    1.0
+                ))
+                .await,
+            Err(BinanceError::Api(ApiError { code: -1121, .. }))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_futures_round_trip_and_stop() {
+        let paper = FuturesPaperExchange::new(PaperConfig::new().maker_fee(0.0).taker_fee(0.0005))
+            .balance("USDT", 1000.0);
+
+        paper.trade("BTCUSDT", 50_000.0, 1.0, 1_000);
+
+        let entry = paper
+            .send_new_order(&FuturesNewOrderParams::market(
+                "BTCUSDT",
+                futures::OrderSide::Buy,
+                0.01,
+            ))
+            .await
+            .unwrap();
+
+        assert!(matches!(entry.status, OrderStatus::Filled));
+        assert_eq!(paper.position("BTCUSDT").amount, 0.01);
+
+        let stop = paper
+            .send_new_order(
+                &FuturesNewOrderParams::stop_market(
+                    "BTCUSDT",
+                    futures::OrderSide::Sell,
+                    49_000.0,
+                    0.01,
+                )
+                .reduce_only(true),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            paper
+                .get_all_open_orders(&FuturesOpenOrdersParams::new("BTCUSDT"))
+                .await
+                .unwrap()
+                .len(),
+            1
+        );
+
+        paper.trade("BTCUSDT", 51_000.0, 1.0, 2_000);
+
+        let balance = &paper.get_balances().await.unwrap()[0];
+        assert_eq!(balance.cross_un_pnl, 10.0);
+
+        paper.trade("BTCUSDT", 48_900.0, 1.0, 3_000);
+
+        let stop = paper
+            .get_order(&FuturesGetOrderParams::new("BTCUSDT").order_id(stop.order_id))
+            .await
+            .unwrap();
+
+        assert!(matches!(stop.status, OrderStatus::Filled));
+        assert_eq!(stop.avg_price, 48_900.0);
+        assert_eq!(paper.position("BTCUSDT").amount, 0.0);
+
+        let fees = 0.0005 * (500.0 + 489.0);
+        let balance = &paper.get_balances().await.unwrap()[0];
+        assert!((balance.balance - (1000.0 - 11.0 - fees)).abs() < 1e-9);
+        assert!(matches!(
+            paper
+                .send_new_order(
+                    &FuturesNewOrderParams::market("BTCUSDT", futures::OrderSide::Sell, 0.01)
+                        .reduce_only(true)
+                )
+                .await,
+            Err(BinanceError::Api(ApiError { code: -2022, .. }))
+        ));
+    }
+}
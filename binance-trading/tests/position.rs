@@ -0,0 +1,166 @@
+#[cfg(test)]
+mod position_integration_tests {
+    use binance_common::{
+        enums::futures::{OrderSide, PositionSide},
+        futures::model::{
+            params::trade::{NewOrderParams, PositionRiskV3Params},
+            response::{
+                account::IncomeHistoryResponse, stream::UserDataEvent, websocket::WebSocketResponse,
+            },
+        },
+    };
+    use binance_core::trading::futures::FuturesTradingApi;
+    use binance_trading::{
+        paper::{PaperConfig, futures::FuturesPaperExchange},
+        position::PositionBook,
+    };
+
+    fn mark_price(symbol: &str, price: f64, rate: f64, time: u64, next: u64) -> WebSocketResponse {
+        serde_json::from_str(&format!(
+            r#"{{"e":"markPriceUpdate","E":{time},"s":"{symbol}","p":"{price}","i":"{price}","P":"{price}","r":"{rate}","T":{next}}}"#
+        ))
+        .unwrap()
+    }
+
+    fn hedge_account_update() -> UserDataEvent {
+        serde_json::from_str(
+            r#"{"e":"ACCOUNT_UPDATE","E":1000,"T":1000,"a":{"m":"ORDER","B":[{"a":"USDT","wb":"1000","cw":"938","bc":"0"}],"P":[
+                {"s":"ETHUSDT","pa":"0.1","ep":"3000","bep":"3001","cr":"0","up":"0","mt":"cross","iw":"0","ps":"LONG","ma":"USDT"},
+                {"s":"ETHUSDT","pa":"-0.2","ep":"3100","bep":"3099","cr":"1.5","up":"0","mt":"isolated","iw":"62","ps":"SHORT","ma":"USDT"}
+            ]}}"#,
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_seeded_position_is_revalued_by_mark_price() {
+        let paper = FuturesPaperExchange::new(PaperConfig::new()).balance("USDT", 100.0);
+        paper.trade("BTCUSDT", 50_000.0, 1.0, 1_000);
+        paper
+            .send_new_order(&NewOrderParams::market("BTCUSDT", OrderSide::Buy, 0.01))
+            .await
+            .unwrap();
+
+        let book = PositionBook::new();
+        book.seed(&paper, &PositionRiskV3Params::new(""))
+            .await
+            .unwrap();
+
+        let wallet = book.wallet("USDT");
+        let position = book.position("BTCUSDT", PositionSide::Both).unwrap();
+
+        assert!(wallet < 100.0);
+        assert_eq!(position.amount, 0.01);
+        assert_eq!(position.entry_price, 50_000.0);
+
+        book.apply_market(&mark_price("BTCUSDT", 51_000.0, 0.0001, 2_000, 28_800_000));
+
+        let position = book.position("BTCUSDT", PositionSide::Both).unwrap();
+        let expected = (wallet - 0.01 * 50_000.0) / (0.01 * 0.004 - 0.01);
+
+        assert!((position.unrealized_pnl() - 10.0).abs() < 1e-9);
+        assert!((book.unrealized_pnl("USDT") - 10.0).abs() < 1e-9);
+        assert!(
+            (book
+                .liquidation_price("BTCUSDT", PositionSide::Both)
+                .unwrap()
+                - expected)
+                .abs()
+                < 1e-6
+        );
+        assert!(book.margin_ratio("BTCUSDT", PositionSide::Both).unwrap() > 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_reconcile_drops_positions_closed_on_the_exchange() {
+        let paper = FuturesPaperExchange::new(PaperConfig::new()).balance("USDT", 1_000.0);
+        paper.trade("BTCUSDT", 50_000.0, 1.0, 1_000);
+        paper
+            .send_new_order(&NewOrderParams::market("BTCUSDT", OrderSide::Buy, 0.01))
+            .await
+            .unwrap();
+
+        let book = PositionBook::new();
+        book.seed(&paper, &PositionRiskV3Params::new("BTCUSDT"))
+            .await
+            .unwrap();
+
+        assert_eq!(book.positions(None).len(), 1);
+
+        paper.trade("BTCUSDT", 50_100.0, 1.0, 2_000);
+        paper
+            .send_new_order(&NewOrderParams::market("BTCUSDT", OrderSide::Sell, 0.01))
+            .await
+            .unwrap();
+
+        book.reconcile(&paper, &PositionRiskV3Params::new("BTCUSDT"))
+            .await
+            .unwrap();
+
+        assert!(book.positions(Some("BTCUSDT")).is_empty());
+    }
+
+    #[test]
+    fn test_hedge_mode_sides_settle_funding_separately() {
+        let book = PositionBook::new();
+
+        book.apply(&hedge_account_update());
+        book.apply_market(&mark_price("ETHUSDT", 3_050.0, 0.0001, 1_000, 28_800_000));
+        book.apply_market(&mark_price(
+            "ETHUSDT", 3_050.0, 0.0002, 28_800_001, 57_600_000,
+        ));
+
+        let long = book.position("ETHUSDT", PositionSide::Long).unwrap();
+        let short = book.position("ETHUSDT", PositionSide::Short).unwrap();
+
+        assert_eq!(book.wallet("USDT"), 938.0);
+        assert!((long.unrealized_pnl() - 5.0).abs() < 1e-9);
+        assert!((short.unrealized_pnl() - 10.0).abs() < 1e-9);
+        assert!((long.funding_paid - 0.0305).abs() < 1e-9);
+        assert!((short.funding_paid + 0.061).abs() < 1e-9);
+        assert_eq!(short.realized_pnl, 1.5);
+        assert!(short.isolated);
+
+        let ratio = book.margin_ratio("ETHUSDT", PositionSide::Short).unwrap();
+
+        assert!((ratio - 0.2 * 3_050.0 * 0.004 / 72.0).abs() < 1e-9);
+        assert!(
+            book.liquidation_price("ETHUSDT", PositionSide::Short)
+                .unwrap()
+                > 3_050.0
+        );
+    }
+
+    #[test]
+    fn test_funding_settles_at_the_mark_price_before_the_tick() {
+        let book = PositionBook::new();
+
+        book.apply(&hedge_account_update());
+        book.apply_market(&mark_price("ETHUSDT", 3_050.0, 0.0001, 1_000, 28_800_000));
+        book.apply_market(&mark_price(
+            "ETHUSDT", 3_200.0, 0.0002, 28_800_001, 57_600_000,
+        ));
+
+        let long = book.position("ETHUSDT", PositionSide::Long).unwrap();
+
+        assert_eq!(long.mark_price, 3_200.0);
+        assert!((long.funding_paid - 0.1 * 3_050.0 * 0.0001).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_funding_income_is_booked_once() {
+        let book = PositionBook::new();
+        let entries: Vec<IncomeHistoryResponse> = serde_json::from_str(
+            r#"[
+                {"symbol":"ETHUSDT","incomeType":"FUNDING_FEE","income":"-0.0305","asset":"USDT","info":"","time":28800000,"tranId":7,"tradeId":""},
+                {"symbol":"ETHUSDT","incomeType":"COMMISSION","income":"-0.2","asset":"USDT","info":"","time":28800000,"tranId":8,"tradeId":"1"}
+            ]"#,
+        )
+        .unwrap();
+
+        book.apply_income(&entries);
+        book.apply_income(&entries);
+
+        assert!((book.booked_funding("ETHUSDT") - 0.0305).abs() < 1e-9);
+    }
+}
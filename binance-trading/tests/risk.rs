@@ -0,0 +1,344 @@
+#[cfg(test)]
+mod risk_integration_tests {
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    use binance_common::{
+        enums::{
+            futures,
+            spot::{OrderSide, OrderType},
+        },
+        futures::model::{
+            params::trade::{
+                NewOrderParams as FuturesNewOrderParams,
+                OpenOrdersParams as FuturesOpenOrdersParams,
+            },
+            response::stream::UserDataEvent,
+        },
+        spot::{
+            endpoint::route::Trade,
+            model::params::trade::{NewOrderParams, OpenOrdersParams},
+        },
+    };
+    use binance_core::{
+        client::asynchronous::Client,
+        endpoint::Method,
+        signer::hmacsha256::HmacSha256,
+        trading::{futures::FuturesTradingApi, spot::SpotTradingApi},
+    };
+    use binance_mock::{
+        credentials::Credentials, failure::Failure, fixture::Security, request::MockResponse,
+        server::MockServer,
+    };
+    use binance_spot::asynchronous::trade::TradeApi;
+    use binance_trading::{
+        paper::{PaperConfig, futures::FuturesPaperExchange, spot::SpotPaperExchange},
+        risk::{RiskLimits, Violation, futures::FuturesRiskGate, spot::SpotRiskGate},
+    };
+
+    fn now() -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as u64
+    }
+
+    fn losing_fill(
+        realized_profit: f64,
+        commission: f64,
+        commission_asset: &str,
+        time: u64,
+    ) -> UserDataEvent {
+        serde_json::from_str(&format!(
+            r#"{{"e":"ORDER_TRADE_UPDATE","E":{time},"T":{time},"o":{{"s":"BTCUSDT","c":"bot-1","S":"SELL","o":"MARKET","f":"GTC","q":"0.01","p":"0","ap":"45000","sp":"0","x":"TRADE","X":"FILLED","i":1,"l":"0.01","z":"0.01","L":"45000","N":"{commission_asset}","n":"{commission}","T":{time},"t":7,"b":"0","a":"0","m":false,"R":false,"wt":"CONTRACT_PRICE","ot":"MARKET","ps":"BOTH","cp":false,"rp":"{realized_profit}","pP":false,"si":0,"ss":0,"V":"NONE","pm":"NONE","gtd":0}}}}"#
+        ))
+        .unwrap()
+    }
+
+    fn spot_gate(limits: RiskLimits) -> SpotRiskGate<SpotPaperExchange, SpotPaperExchange> {
+        let paper = SpotPaperExchange::new(PaperConfig::new())
+            .balance("USDT", 100_000.0)
+            .balance("BTC", 1.0);
+        paper.trade("BTCUSDT", 100.0, 1.0, 1_000);
+
+        SpotRiskGate::new(paper.clone(), paper, limits)
+    }
+
+    fn futures_gate(
+        limits: RiskLimits,
+    ) -> FuturesRiskGate<FuturesPaperExchange, FuturesPaperExchange> {
+        let paper = FuturesPaperExchange::new(PaperConfig::new()).balance("USDT", 10_000.0);
+        paper.trade("BTCUSDT", 50_000.0, 1.0, 1_000);
+
+        FuturesRiskGate::new(paper.clone(), paper, limits)
+    }
+
+    #[tokio::test]
+    async fn test_notional_and_price_band_stop_fat_fingers() {
+        let gate = spot_gate(RiskLimits::new().max_notional(1_000.0).price_band(0.05));
+
+        // Market orders are priced at the average price.
+        let market =
+            NewOrderParams::new("BTCUSDT", OrderSide::Buy, OrderType::Market).quantity(20.0);
+
+        assert!(gate.send_new_order(&market).await.is_err());
+        assert_eq!(
+            gate.last_violation(),
+            Some(Violation::MaxNotional {
+                notional: 2_000.0,
+                limit: 1_000.0
+            })
+        );
+
+        let far = NewOrderParams::limit("BTCUSDT", OrderSide::Sell, 10.0, 1.0);
+
+        assert!(gate.send_new_order(&far).await.is_err());
+        assert!(matches!(
+            gate.last_violation(),
+            Some(Violation::PriceBand { price: 10.0, .. })
+        ));
+
+        let near = NewOrderParams::limit("BTCUSDT", OrderSide::Buy, 98.0, 1.0);
+
+        assert!(gate.send_new_order(&near).await.is_ok());
+        assert_eq!(
+            gate.api()
+                .get_open_orders(&OpenOrdersParams::new("BTCUSDT"))
+                .await
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn test_open_orders_and_position_limits() {
+        let gate = spot_gate(
+            RiskLimits::new()
+                .max_open_orders(2)
+                .max_position("BTCUSDT", 1.5),
+        );
+
+        // Holding 1 BTC, buying another would take the position to 2.
+        let buy = NewOrderParams::limit("BTCUSDT", OrderSide::Buy, 90.0, 1.0);
+
+        assert!(gate.send_new_order(&buy).await.is_err());
+        assert!(matches!(
+            gate.last_violation(),
+            Some(Violation::MaxPosition { limit: 1.5, .. })
+        ));
+
+        for price in [110.0, 120.0] {
+            let sell = NewOrderParams::limit("BTCUSDT", OrderSide::Sell, price, 0.1);
+            gate.send_new_order(&sell).await.unwrap();
+        }
+
+        let third = NewOrderParams::limit("BTCUSDT", OrderSide::Sell, 130.0, 0.1);
+
+        assert!(gate.send_new_order(&third).await.is_err());
+        assert_eq!(
+            gate.last_violation(),
+            Some(Violation::MaxOpenOrders { open: 2, limit: 2 })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_spot_kill_switch_cancels_and_blocks_until_reset() {
+        let gate = spot_gate(RiskLimits::new());
+
+        for price in [90.0, 95.0] {
+            gate.send_new_order(&NewOrderParams::limit(
+                "BTCUSDT",
+                OrderSide::Buy,
+                price,
+                0.1,
+            ))
+            .await
+            .unwrap();
+        }
+
+        assert_eq!(gate.kill("manual").await.unwrap(), 2);
+        assert_eq!(gate.killed().as_deref(), Some("manual"));
+
+        let order = NewOrderParams::limit("BTCUSDT", OrderSide::Buy, 90.0, 0.1);
+
+        assert!(gate.send_new_order(&order).await.is_err());
+        assert_eq!(
+            gate.last_violation(),
+            Some(Violation::Killed("manual".to_string()))
+        );
+
+        gate.reset();
+
+        assert!(gate.send_new_order(&order).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_kill_goes_through_every_symbol_and_reports_the_failures() {
+        let server = MockServer::builder()
+            .credentials("api_key", Credentials::hmac_sha256("secret_key"))
+            .respond_with(Method::GET, Trade::OpenOrders, Security::Signed, |_| {
+                MockResponse::ok(
+                    include_str!("../../binance-mock/fixtures/spot/trade/open_orders.json")
+                        .replacen("BTCUSDC", "ETHUSDC", 1),
+                )
+            })
+            .start()
+            .unwrap();
+        server.fail(
+            Method::DELETE,
+            Trade::CancelAllOrders,
+            Failure::invalid_symbol(),
+        );
+
+        let live = TradeApi::new(Client::new(
+            &server,
+            HmacSha256::new("api_key", "secret_key"),
+        ));
+        let gate = SpotRiskGate::new(
+            live,
+            SpotPaperExchange::new(PaperConfig::new()),
+            RiskLimits::new(),
+        );
+
+        let error = gate.kill("manual").await.unwrap_err();
+
+        assert_eq!(error.done, 2);
+        assert_eq!(error.errors.len(), 1);
+        assert_eq!(error.errors[0].0, "BTCUSDC");
+
+        // Listing and canceling share the route.
+        let requests = server.requests_to(Trade::OpenOrders);
+        let (listed, canceled): (Vec<_>, Vec<_>) = requests
+            .iter()
+            .partition(|request| request.method == Method::GET);
+
+        assert_eq!(listed[0].param("symbol"), None);
+        assert_eq!(canceled.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_daily_loss_fires_the_kill_switch_and_arms_the_countdown() {
+        let gate = futures_gate(RiskLimits::new().daily_loss(500.0)).kill_countdown(5_000);
+        let paper = gate.api().clone();
+
+        gate.send_new_order(&FuturesNewOrderParams::limit(
+            "BTCUSDT",
+            futures::OrderSide::Buy,
+            49_000.0,
+            0.01,
+        ))
+        .await
+        .unwrap();
+
+        // Fills of earlier days do not count.
+        assert!(
+            !gate
+                .apply(&losing_fill(-1_000.0, 0.0, "USDT", now() - 86_400_000))
+                .await
+                .unwrap()
+        );
+        assert!(
+            !gate
+                .apply(&losing_fill(-400.0, 0.0, "USDT", now()))
+                .await
+                .unwrap()
+        );
+        assert_eq!(
+            paper
+                .get_all_open_orders(&FuturesOpenOrdersParams::new("BTCUSDT"))
+                .await
+                .unwrap()
+                .len(),
+            1
+        );
+
+        // Commission in BNB is not booked against the margin asset.
+        assert!(
+            !gate
+                .apply(&losing_fill(0.0, 200.0, "BNB", now()))
+                .await
+                .unwrap()
+        );
+        assert_eq!(gate.realized_today(), -400.0);
+
+        // Crossing the limit cancels the open orders right away.
+        assert!(
+            gate.apply(&losing_fill(-80.0, 30.0, "USDT", now()))
+                .await
+                .unwrap()
+        );
+        assert_eq!(gate.realized_today(), -510.0);
+        assert!(
+            gate.killed()
+                .is_some_and(|reason| reason.starts_with("daily loss"))
+        );
+        assert!(
+            paper
+                .get_all_open_orders(&FuturesOpenOrdersParams::new("BTCUSDT"))
+                .await
+                .unwrap()
+                .is_empty()
+        );
+
+        // Further losses do not fire it again.
+        assert!(
+            !gate
+                .apply(&losing_fill(-10.0, 0.0, "USDT", now()))
+                .await
+                .unwrap()
+        );
+
+        let order =
+            FuturesNewOrderParams::limit("BTCUSDT", futures::OrderSide::Buy, 49_500.0, 0.01);
+
+        assert!(gate.send_new_order(&order).await.is_err());
+        assert!(matches!(gate.last_violation(), Some(Violation::Killed(_))));
+
+        // An order landing behind the gate's back goes with the countdown.
+        paper.send_new_order(&order).await.unwrap();
+        paper.trade("BTCUSDT", 50_000.0, 1.0, 3_000);
+
+        assert_eq!(
+            paper
+                .get_all_open_orders(&FuturesOpenOrdersParams::new("BTCUSDT"))
+                .await
+                .unwrap()
+                .len(),
+            1
+        );
+
+        paper.trade("BTCUSDT", 50_000.0, 1.0, 6_000);
+
+        assert!(
+            paper
+                .get_all_open_orders(&FuturesOpenOrdersParams::new("BTCUSDT"))
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_reduce_only_orders_pass_the_position_limit() {
+        let gate = futures_gate(RiskLimits::new().max_position("", 0.01));
+
+        gate.send_new_order(&FuturesNewOrderParams::market(
+            "BTCUSDT",
+            futures::OrderSide::Buy,
+            0.01,
+        ))
+        .await
+        .unwrap();
+
+        let more = FuturesNewOrderParams::market("BTCUSDT", futures::OrderSide::Buy, 0.01);
+
+        assert!(gate.send_new_order(&more).await.is_err());
+
+        let close = FuturesNewOrderParams::market("BTCUSDT", futures::OrderSide::Sell, 0.01)
+            .reduce_only(true);
+
+        assert!(gate.send_new_order(&close).await.is_ok());
+        assert_eq!(gate.api().position("BTCUSDT").amount, 0.0);
+        assert_eq!(gate.heartbeat(0).await.unwrap(), 1);
+    }
+}
@@ -0,0 +1,196 @@
+#[cfg(test)]
+mod tracker_integration_tests {
+    use binance_common::{
+        enums::{futures, spot::OrderSide, spot::OrderStatus},
+        futures::model::{
+            params::trade::{
+                NewOrderParams as FuturesNewOrderParams,
+                OpenOrdersParams as FuturesOpenOrdersParams,
+            },
+            response::stream::UserDataEvent as FuturesUserDataEvent,
+        },
+        spot::model::{
+            params::trade::{CancelOrderParams, NewOrderParams, OpenOrdersParams},
+            response::stream::UserDataEvent,
+        },
+    };
+    use binance_core::trading::{futures::FuturesTradingApi, spot::SpotTradingApi};
+    use binance_trading::{
+        paper::{PaperConfig, futures::FuturesPaperExchange, spot::SpotPaperExchange},
+        tracker::{futures::FuturesOrderTracker, spot::SpotOrderTracker},
+    };
+
+    fn execution_report(
+        order_id: u64,
+        status: &str,
+        last_quantity: f64,
+        cumulative_quantity: f64,
+        cumulative_quote: f64,
+        trade_id: i64,
+        time: u64,
+    ) -> UserDataEvent {
+        serde_json::from_str(&format!(
+            r#"{{"e":"executionReport","E":{time},"s":"BTCUSDT","c":"bot-1","S":"BUY","o":"LIMIT","f":"GTC","q":"1.00000000","p":"90.00000000","P":"0.00000000","F":"0.00000000","g":-1,"C":"","x":"TRADE","X":"{status}","r":"NONE","i":{order_id},"l":"{last_quantity}","z":"{cumulative_quantity}","L":"90.00000000","n":"0","N":"BTC","T":{time},"t":{trade_id},"I":1,"w":false,"m":true,"M":true,"O":1000,"Z":"{cumulative_quote}","Y":"0","Q":"0"}}"#
+        ))
+        .unwrap()
+    }
+
+    fn order_trade_update(order_id: u64, status: &str, time: u64) -> FuturesUserDataEvent {
+        serde_json::from_str(&format!(
+            r#"{{"e":"ORDER_TRADE_UPDATE","E":{time},"T":{time},"o":{{"s":"BTCUSDT","c":"bot-2","S":"BUY","o":"LIMIT","f":"GTC","q":"0.01","p":"49000","ap":"0","sp":"0","x":"NEW","X":"{status}","i":{order_id},"l":"0","z":"0","L":"0","T":{time},"t":0,"b":"490","a":"0","m":false,"R":false,"wt":"CONTRACT_PRICE","ot":"LIMIT","ps":"BOTH","cp":false,"rp":"0","pP":false,"si":0,"ss":0,"V":"NONE","pm":"NONE","gtd":0}}}}"#
+        ))
+        .unwrap()
+    }
+
+    async fn spot_exchange_with_bid() -> (SpotPaperExchange, u64) {
+        let paper = SpotPaperExchange::new(PaperConfig::new()).balance("USDT", 10_000.0);
+        paper.trade("BTCUSDT", 100.0, 1.0, 1_000);
+
+        paper
+            .send_new_order(&NewOrderParams::limit("BTCUSDT", OrderSide::Buy, 90.0, 1.0))
+            .await
+            .unwrap();
+
+        let order_id = paper
+            .get_open_orders(&OpenOrdersParams::new("BTCUSDT"))
+            .await
+            .unwrap()[0]
+            .order_id;
+
+        (paper, order_id)
+    }
+
+    #[tokio::test]
+    async fn test_spot_reports_accumulate_fills_and_transitions() {
+        let (paper, order_id) = spot_exchange_with_bid().await;
+        let tracker = SpotOrderTracker::new();
+
+        tracker
+            .seed(&paper, &OpenOrdersParams::new("BTCUSDT"))
+            .await
+            .unwrap();
+
+        assert_eq!(tracker.open_orders(None).len(), 1);
+
+        let partial = execution_report(order_id, "PARTIALLY_FILLED", 0.4, 0.4, 36.0, 1, 2_000);
+        let order = tracker.apply(&partial).unwrap();
+
+        assert_eq!(order.status, OrderStatus::PartiallyFilled);
+        assert!((order.remaining() - 0.6).abs() < 1e-9);
+
+        // Repeated delivery of the same trade is dropped.
+        assert!(tracker.apply(&partial).is_none());
+
+        // Trade 2 was missed, trade 3 completes the order.
+        let order = tracker
+            .apply(&execution_report(
+                order_id, "FILLED", 0.2, 1.0, 90.0, 3, 3_000,
+            ))
+            .unwrap();
+
+        assert_eq!(order.status, OrderStatus::Filled);
+        assert_eq!(order.fills.len(), 3);
+        assert_eq!(order.fills[0].trade_id, Some(1));
+        assert_eq!(order.fills[1].trade_id, None);
+        assert!((order.fills[1].quantity - 0.4).abs() < 1e-9);
+        assert!((order.fills[1].price - 90.0).abs() < 1e-9);
+        assert!((order.average_price() - 90.0).abs() < 1e-9);
+        assert_eq!(
+            order
+                .transitions
+                .iter()
+                .map(|transition| transition.to)
+                .collect::<Vec<_>>(),
+            vec![
+                OrderStatus::New,
+                OrderStatus::PartiallyFilled,
+                OrderStatus::Filled
+            ]
+        );
+
+        let closed = tracker.drain_closed();
+
+        assert_eq!(closed.len(), 1);
+        assert!(tracker.order("BTCUSDT", order_id).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_spot_vanished_order_is_reconciled_over_rest() {
+        let (paper, order_id) = spot_exchange_with_bid().await;
+        let tracker = SpotOrderTracker::new();
+
+        tracker
+            .seed(&paper, &OpenOrdersParams::new("BTCUSDT"))
+            .await
+            .unwrap();
+
+        // Canceled while the user stream was down.
+        paper
+            .send_cancel_order(&CancelOrderParams::new("BTCUSDT").order_id(order_id))
+            .await
+            .unwrap();
+
+        tracker
+            .seed(&paper, &OpenOrdersParams::new("BTCUSDT"))
+            .await
+            .unwrap();
+
+        assert_eq!(tracker.reconcile(&paper).await.unwrap(), 1);
+        assert_eq!(tracker.reconcile(&paper).await.unwrap(), 0);
+
+        let order = tracker.order("BTCUSDT", order_id).unwrap();
+
+        assert_eq!(order.status, OrderStatus::Canceled);
+        assert!(tracker.open_orders(Some("BTCUSDT")).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_futures_fill_missed_during_reconnect_is_inferred() {
+        let paper = FuturesPaperExchange::new(PaperConfig::new()).balance("USDT", 1_000.0);
+        paper.trade("BTCUSDT", 50_000.0, 1.0, 1_000);
+
+        let order_id = paper
+            .send_new_order(&FuturesNewOrderParams::limit(
+                "BTCUSDT",
+                futures::OrderSide::Buy,
+                49_000.0,
+                0.01,
+            ))
+            .await
+            .unwrap()
+            .order_id;
+
+        let tracker = FuturesOrderTracker::new();
+
+        tracker
+            .seed(&paper, &FuturesOpenOrdersParams::new("BTCUSDT"))
+            .await
+            .unwrap();
+
+        let order = tracker
+            .apply(&order_trade_update(order_id, "NEW", 1_000))
+            .unwrap();
+
+        assert_eq!(order.status, futures::OrderStatus::New);
+        assert_eq!(order.transitions.len(), 1);
+
+        tracker.invalidate();
+        paper.trade("BTCUSDT", 48_900.0, 1.0, 2_000);
+
+        assert_eq!(tracker.reconcile(&paper).await.unwrap(), 1);
+
+        let order = tracker.find("BTCUSDT", &order.client_order_id).unwrap();
+
+        assert_eq!(order.status, futures::OrderStatus::Filled);
+        assert_eq!(order.fills.len(), 1);
+        assert_eq!(order.fills[0].trade_id, None);
+        assert!((order.average_price() - 49_000.0).abs() < 1e-6);
+
+        // The NEW event arriving late does not reopen the order.
+        assert!(
+            tracker
+                .apply(&order_trade_update(order_id, "NEW", 1_000))
+                .is_none()
+        );
+    }
+}
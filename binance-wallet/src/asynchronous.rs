@@ -0,0 +1,3 @@
+pub mod account;
+pub mod asset;
+pub mod capital;
@@ -0,0 +1,91 @@
+use binance_common::error::BinanceError;
+use binance_common::wallet::{
+    endpoint::route::Asset,
+    model::{
+        params::asset::{
+            AssetDetailParams, DustConvertibleParams, DustLogParams, DustParams, TradeFeeParams,
+            TransferHistoryParams, UniversalTransferParams,
+        },
+        response::asset::{
+            AssetDetailResponse, DustConvertibleResponse, DustLogResponse, DustResponse,
+            TradeFeeResponse, TransferHistoryResponse, TransferResponse,
+        },
+    },
+};
+use reqwest::Method;
+
+use binance_core::transport::asynchronous::Transport;
+use binance_core::{client::asynchronous::Client, signer::signature::Signature};
+
+pub struct AssetApi<'a, S, T = reqwest::Client>
+where
+    S: Signature<'a>,
+    T: Transport,
+{
+    client: Client<'a, S, T>,
+}
+
+impl<'a, S, T> AssetApi<'a, S, T>
+where
+    S: Signature<'a>,
+    T: Transport,
+{
+    pub fn new(client: Client<'a, S, T>) -> Self {
+        AssetApi { client }
+    }
+
+    /// Moves funds between the spot, futures, margin and funding wallets.
+    pub async fn universal_transfer(
+        &self,
+        params: &UniversalTransferParams<'a>,
+    ) -> Result<TransferResponse, BinanceError> {
+        self.client
+            .send(Asset::UniversalTransfer, params, Method::POST)
+            .await
+    }
+
+    pub async fn get_transfer_history(
+        &self,
+        params: &TransferHistoryParams<'a>,
+    ) -> Result<TransferHistoryResponse, BinanceError> {
+        self.client
+            .send(Asset::TransferHistory, params, Method::GET)
+            .await
+    }
+
+    pub async fn get_dust_convertible(
+        &self,
+        params: &DustConvertibleParams,
+    ) -> Result<DustConvertibleResponse, BinanceError> {
+        self.client
+            .send(Asset::DustConvertible, params, Method::POST)
+            .await
+    }
+
+    pub async fn convert_dust(&self, params: &DustParams) -> Result<DustResponse, BinanceError> {
+        self.client.send(Asset::Dust, params, Method::POST).await
+    }
+
+    pub async fn get_dust_log(
+        &self,
+        params: &DustLogParams,
+    ) -> Result<DustLogResponse, BinanceError> {
+        self.client.send(Asset::DustLog, params, Method::GET).await
+    }
+
+    pub async fn get_trade_fee(
+        &self,
+        params: &TradeFeeParams<'a>,
+    ) -> Result<Vec<TradeFeeResponse>, BinanceError> {
+        self.client.send(Asset::TradeFee, params, Method::GET).await
+    }
+
+    pub async fn get_asset_detail(
+        &self,
+        params: &AssetDetailParams<'a>,
+    ) -> Result<AssetDetailResponse, BinanceError> {
+        self.client
+            .send(Asset::AssetDetail, params, Method::GET)
+            .await
+    }
+}
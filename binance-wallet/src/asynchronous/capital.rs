@@ -0,0 +1,83 @@
+use binance_common::error::BinanceError;
+use binance_common::wallet::{
+    endpoint::route::Capital,
+    model::{
+        params::{
+            RecvWindowParams,
+            capital::{
+                DepositAddressParams, DepositHistoryParams, WithdrawHistoryParams, WithdrawParams,
+            },
+        },
+        response::capital::{
+            CoinInfoResponse, DepositAddressResponse, DepositResponse, WithdrawRecordResponse,
+            WithdrawResponse,
+        },
+    },
+};
+use reqwest::Method;
+
+use binance_core::transport::asynchronous::Transport;
+use binance_core::{client::asynchronous::Client, signer::signature::Signature};
+
+pub struct CapitalApi<'a, S, T = reqwest::Client>
+where
+    S: Signature<'a>,
+    T: Transport,
+{
+    client: Client<'a, S, T>,
+}
+
+impl<'a, S, T> CapitalApi<'a, S, T>
+where
+    S: Signature<'a>,
+    T: Transport,
+{
+    pub fn new(client: Client<'a, S, T>) -> Self {
+        CapitalApi { client }
+    }
+
+    pub async fn get_coin_info(
+        &self,
+        params: &RecvWindowParams,
+    ) -> Result<Vec<CoinInfoResponse>, BinanceError> {
+        self.client
+            .send(Capital::CoinInfo, params, Method::GET)
+            .await
+    }
+
+    pub async fn get_deposit_address(
+        &self,
+        params: &DepositAddressParams<'a>,
+    ) -> Result<DepositAddressResponse, BinanceError> {
+        self.client
+            .send(Capital::DepositAddress, params, Method::GET)
+            .await
+    }
+
+    pub async fn get_deposit_history(
+        &self,
+        params: &DepositHistoryParams<'a>,
+    ) -> Result<Vec<DepositResponse>, BinanceError> {
+        self.client
+            .send(Capital::DepositHistory, params, Method::GET)
+            .await
+    }
+
+    pub async fn withdraw(
+        &self,
+        params: &WithdrawParams<'a>,
+    ) -> Result<WithdrawResponse, BinanceError> {
+        self.client
+            .send(Capital::Withdraw, params, Method::POST)
+            .await
+    }
+
+    pub async fn get_withdraw_history(
+        &self,
+        params: &WithdrawHistoryParams<'a>,
+    ) -> Result<Vec<WithdrawRecordResponse>, BinanceError> {
+        self.client
+            .send(Capital::WithdrawHistory, params, Method::GET)
+            .await
+    }
+}
@@ -0,0 +1,35 @@
+use binance_common::error::BinanceError;
+use binance_common::wallet::{
+    endpoint::route::Account,
+    model::{params::RecvWindowParams, response::account::ApiRestrictionsResponse},
+};
+use reqwest::Method;
+
+use binance_core::transport::synchronous::Transport;
+use binance_core::{client::synchronous::Client, signer::signature::Signature};
+
+pub struct AccountApi<'a, S, T = reqwest::blocking::Client>
+where
+    S: Signature<'a>,
+    T: Transport,
+{
+    client: Client<'a, S, T>,
+}
+
+impl<'a, S, T> AccountApi<'a, S, T>
+where
+    S: Signature<'a>,
+    T: Transport,
+{
+    pub fn new(client: Client<'a, S, T>) -> Self {
+        AccountApi { client }
+    }
+
+    pub fn get_api_restrictions(
+        &self,
+        params: &RecvWindowParams,
+    ) -> Result<ApiRestrictionsResponse, BinanceError> {
+        self.client
+            .send(Account::ApiRestrictions, params, Method::GET)
+    }
+}
@@ -0,0 +1,73 @@
+use binance_common::error::BinanceError;
+use binance_common::wallet::{
+    endpoint::route::Capital,
+    model::{
+        params::{
+            RecvWindowParams,
+            capital::{
+                DepositAddressParams, DepositHistoryParams, WithdrawHistoryParams, WithdrawParams,
+            },
+        },
+        response::capital::{
+            CoinInfoResponse, DepositAddressResponse, DepositResponse, WithdrawRecordResponse,
+            WithdrawResponse,
+        },
+    },
+};
+use reqwest::Method;
+
+use binance_core::transport::synchronous::Transport;
+use binance_core::{client::synchronous::Client, signer::signature::Signature};
+
+pub struct CapitalApi<'a, S, T = reqwest::blocking::Client>
+where
+    S: Signature<'a>,
+    T: Transport,
+{
+    client: Client<'a, S, T>,
+}
+
+impl<'a, S, T> CapitalApi<'a, S, T>
+where
+    S: Signature<'a>,
+    T: Transport,
+{
+    pub fn new(client: Client<'a, S, T>) -> Self {
+        CapitalApi { client }
+    }
+
+    pub fn get_coin_info(
+        &self,
+        params: &RecvWindowParams,
+    ) -> Result<Vec<CoinInfoResponse>, BinanceError> {
+        self.client.send(Capital::CoinInfo, params, Method::GET)
+    }
+
+    pub fn get_deposit_address(
+        &self,
+        params: &DepositAddressParams<'a>,
+    ) -> Result<DepositAddressResponse, BinanceError> {
+        self.client
+            .send(Capital::DepositAddress, params, Method::GET)
+    }
+
+    pub fn get_deposit_history(
+        &self,
+        params: &DepositHistoryParams<'a>,
+    ) -> Result<Vec<DepositResponse>, BinanceError> {
+        self.client
+            .send(Capital::DepositHistory, params, Method::GET)
+    }
+
+    pub fn withdraw(&self, params: &WithdrawParams<'a>) -> Result<WithdrawResponse, BinanceError> {
+        self.client.send(Capital::Withdraw, params, Method::POST)
+    }
+
+    pub fn get_withdraw_history(
+        &self,
+        params: &WithdrawHistoryParams<'a>,
+    ) -> Result<Vec<WithdrawRecordResponse>, BinanceError> {
+        self.client
+            .send(Capital::WithdrawHistory, params, Method::GET)
+    }
+}